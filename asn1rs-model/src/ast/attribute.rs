@@ -4,7 +4,8 @@ use super::tag::AttrTag;
 use crate::ast::constants::ConstLit;
 use crate::model::LiteralValue;
 use crate::model::{
-    Charset, Choice, ChoiceVariant, Enumerated, EnumeratedVariant, Range, Size, Tag, Type,
+    Charset, Choice, ChoiceVariant, Enumerated, EnumeratedVariant, OpenTypeVariant,
+    PermittedAlphabet, Range, Real, Size, Tag, TagDefault, TagMode, TimeType, Type,
 };
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -20,9 +21,14 @@ use syn::Token;
 pub(crate) struct AsnAttribute<C: Context> {
     pub(crate) primary: C::Primary,
     pub(crate) tag: Option<Tag>,
+    pub(crate) tag_mode: Option<TagMode>,
+    pub(crate) tag_default: Option<TagDefault>,
     pub(crate) consts: Vec<ConstLit>,
     pub(crate) extensible_after: Option<String>,
+    pub(crate) extension_group: bool,
     pub(crate) default_value: Option<LiteralValue>,
+    pub(crate) identifier: Option<String>,
+    pub(crate) key: Option<i64>,
     _c: PhantomData<C>,
 }
 
@@ -31,9 +37,14 @@ impl<C: Context> AsnAttribute<C> {
         Self {
             primary,
             tag: None,
+            tag_mode: None,
+            tag_default: None,
             consts: Vec::default(),
             extensible_after: None,
+            extension_group: false,
             default_value: None,
+            identifier: None,
+            key: None,
             _c: Default::default(),
         }
     }
@@ -41,8 +52,11 @@ impl<C: Context> AsnAttribute<C> {
 
 impl<C: Context> Parse for AsnAttribute<C> {
     fn parse<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Self> {
+        let before_primary = input.cursor();
         let mut asn = Self::new(C::Primary::parse(input)?);
-        eof_or_comma(input, "Primary attribute must be separated by comma")?;
+        if input.cursor() != before_primary {
+            eof_or_comma(input, "Primary attribute must be separated by comma")?;
+        }
 
         while !input.cursor().eof() {
             let lowercase_ident = input
@@ -56,6 +70,17 @@ impl<C: Context> Parse for AsnAttribute<C> {
                 "tag" if C::TAGGABLE => {
                     let tag = AttrTag::parse(input)?;
                     asn.tag = Some(tag.0);
+                    asn.tag_mode = Some(tag.1);
+                }
+                "tags" if C::TAG_DEFAULT => {
+                    let content;
+                    parenthesized!(content in input);
+                    let tag_default = parse_ident(&content, "Expected tagging environment")?;
+                    asn.tag_default =
+                        Some(TagDefault::try_from_text(&tag_default).ok_or_else(|| {
+                            content
+                                .error(format!("Unexpected tagging environment `{}`", tag_default))
+                        })?);
                 }
                 "extensible_after" if C::EXTENSIBLE_AFTER => {
                     let content;
@@ -64,6 +89,21 @@ impl<C: Context> Parse for AsnAttribute<C> {
                         .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
                     asn.extensible_after = Some(ident.to_string());
                 }
+                "extension_group" if C::EXTENSION_GROUP => {
+                    asn.extension_group = true;
+                }
+                "identifier" if C::IDENTIFIER => {
+                    let content;
+                    parenthesized!(content in input);
+                    asn.identifier = Some(content.parse::<syn::LitStr>()?.value());
+                }
+                "key" if C::KEY => {
+                    let content;
+                    parenthesized!(content in input);
+                    let negative = content.parse::<Option<Token![-]>>()?.is_some();
+                    let key = content.parse::<syn::LitInt>()?.base10_parse::<i64>()?;
+                    asn.key = Some(if negative { -key } else { key });
+                }
                 "const" if C::CONSTS => {
                     let content;
                     parenthesized!(content in input);
@@ -118,7 +158,8 @@ fn parse_type_pre_stepped<'a>(
             let charset = &string[..len - "string".chars().count()];
             let charset = Charset::from_str(charset)
                 .map_err(|_| input.error(format!("Unexpected charset '{}'", charset)))?;
-            parse_opt_size_or_any(input).map(|size| Type::String(size, charset))
+            parse_opt_size_and_alphabet(input)
+                .map(|(size, alphabet)| Type::String(size, charset, alphabet))
         }
         "integer" => {
             if input.is_empty() {
@@ -152,6 +193,17 @@ fn parse_type_pre_stepped<'a>(
             let tag = AttrTag::parse(&content)?;
             Ok(Type::TypeReference(ident.to_string(), Some(tag.0)))
         }
+        "related" => {
+            let content;
+            parenthesized!(content in input);
+            let relation = parse_ident(&content, "Expected name of the related field")?;
+            let _ = content.parse::<Token![,]>()?;
+            let inner = parse_ident(&content, "Expected name of the open type")?;
+            Ok(Type::Related(
+                relation,
+                Box::new(Type::TypeReference(inner, None)),
+            ))
+        }
         "option" | "optional" => {
             let content;
             parenthesized!(content in input);
@@ -209,6 +261,10 @@ fn parse_type_pre_stepped<'a>(
         }
         "boolean" => Ok(Type::Boolean),
         "null" => Ok(Type::Null),
+        "real" => Ok(Type::Real(Real::default())),
+        "object_identifier" => Ok(Type::ObjectIdentifier),
+        "relative_oid" => Ok(Type::RelativeOid),
+        "open_type" => Ok(Type::OpenType),
         "sequence_of" | "set_of" => {
             let content;
             parenthesized!(content in input);
@@ -237,7 +293,9 @@ fn parse_type_pre_stepped<'a>(
                 Ok(Type::SetOf(Box::new(inner), size))
             }
         }
-        r#type => Err(input.error(format!("Unexpected attribute: `{}`", r#type))),
+        r#type => TimeType::from_str(r#type)
+            .map(Type::Time)
+            .map_err(|_| input.error(format!("Unexpected attribute: `{}`", r#type))),
     }
 }
 
@@ -266,6 +324,51 @@ fn parse_opt_size_or_any(input: ParseStream) -> syn::Result<Size> {
     }
 }
 
+fn parse_opt_size_and_alphabet(input: ParseStream) -> syn::Result<(Size, PermittedAlphabet)> {
+    let mut size = Size::Any;
+    let mut alphabet = PermittedAlphabet::default();
+    if input.is_empty() || !input.peek(token::Paren) {
+        return Ok((size, alphabet));
+    }
+
+    let content;
+    parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident = parse_ident(&content, "Expected size or from")?.to_lowercase();
+        let inner;
+        parenthesized!(inner in content);
+        match ident.as_str() {
+            "size" => size = Size::parse(&inner)?,
+            "from" => alphabet = parse_alphabet(&inner)?,
+            _ => {
+                return Err(content.error(format!(
+                    "Invalid identifier, expected none, size or from but got: {}",
+                    ident
+                )))
+            }
+        }
+        eof_or_comma(&content, "Constraints must be separated by comma")?;
+    }
+    Ok((size, alphabet))
+}
+
+/// Parses the characters `'a'` and ranges `'a'..='z'` of a permitted alphabet
+fn parse_alphabet(input: ParseStream) -> syn::Result<PermittedAlphabet> {
+    let mut ranges = Vec::new();
+    while !input.is_empty() {
+        let min = input.parse::<syn::LitChar>()?.value();
+        let max = if input.peek(Token![..=]) {
+            input.parse::<Token![..=]>()?;
+            input.parse::<syn::LitChar>()?.value()
+        } else {
+            min
+        };
+        ranges.push((min, max));
+        eof_or_comma(input, "Characters must be separated by comma")?;
+    }
+    Ok(PermittedAlphabet::from_ranges(ranges))
+}
+
 fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
     if !input.cursor().eof() && !input.peek(syn::token::Comma) {
         Err(input.error(msg))
@@ -299,6 +402,10 @@ impl PrimaryContext for Type {
 
 impl PrimaryContext for Option<usize> {
     fn parse(input: &ParseBuffer<'_>) -> syn::Result<Self> {
+        if input.peek(syn::Ident) {
+            // no number but another attribute
+            return Ok(None);
+        }
         input
             .step(|c| {
                 ident_or_literal_or_punct(*c)
@@ -323,6 +430,10 @@ pub trait Context: Debug {
     const EXTENSIBLE_AFTER: bool;
     const TAGGABLE: bool;
     const CONSTS: bool;
+    const IDENTIFIER: bool;
+    const KEY: bool = false;
+    const TAG_DEFAULT: bool = false;
+    const EXTENSION_GROUP: bool = false;
 }
 
 impl Context for Choice {
@@ -330,6 +441,7 @@ impl Context for Choice {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
 }
 
 impl Context for ChoiceVariant {
@@ -337,6 +449,16 @@ impl Context for ChoiceVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+}
+
+impl Context for OpenTypeVariant {
+    type Primary = Type;
+    const EXTENSIBLE_AFTER: bool = false;
+    const TAGGABLE: bool = false;
+    const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
+    const KEY: bool = true;
 }
 
 impl Context for Enumerated {
@@ -344,6 +466,7 @@ impl Context for Enumerated {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
 }
 
 impl Context for EnumeratedVariant {
@@ -351,6 +474,7 @@ impl Context for EnumeratedVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = false;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = true;
 }
 
 #[derive(Debug)]
@@ -360,6 +484,7 @@ impl Context for Transparent {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = true;
+    const IDENTIFIER: bool = true;
 }
 
 #[derive(Debug)]
@@ -369,6 +494,9 @@ impl Context for DefinitionHeader {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const IDENTIFIER: bool = false;
+    const TAG_DEFAULT: bool = true;
+    const EXTENSION_GROUP: bool = true;
 }
 
 impl Deref for DefinitionHeader {
@@ -8,8 +8,12 @@ mod tag;
 use crate::ast::attribute::{Context, DefinitionHeader, Transparent};
 use crate::ast::constants::ConstLit;
 use crate::model::lor::Resolved;
+use crate::model::rust::{
+    UNKNOWN_EXTENSIONS_FIELD, UNKNOWN_EXTENSION_VARIANT, UNKNOWN_OBJECT_VARIANT,
+};
 use crate::model::{Choice, ChoiceVariant, Definition, Enumerated, Field, Model, Type};
-use crate::model::{ComponentTypeList, EnumeratedVariant, TagProperty, TagResolver};
+use crate::model::{ComponentTypeList, EnumeratedVariant, TagDefault, TagProperty, TagResolver};
+use crate::model::{ExtensionAdditionGroup, OpenTypeChoice, OpenTypeVariant};
 use attribute::AsnAttribute;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -30,7 +34,7 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
         println!();
     }
 
-    let (definition, item) = match parse_asn_definition(attr, item) {
+    let (definition, tag_default, item) = match parse_asn_definition_with_tag_default(attr, item) {
         Ok(v) => v,
         Err(e) => {
             println!("Errör: {}", e);
@@ -50,7 +54,20 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
         println!();
     }
 
-    let additional_impl = expand(definition);
+    // without the field or variant, the extension additions unknown to the type are skipped
+    let unknown_extensions = match &item {
+        Item::Struct(strct) => strct
+            .fields
+            .iter()
+            .any(|field| is_named(field.ident.as_ref(), UNKNOWN_EXTENSIONS_FIELD)),
+        Item::Enum(enm) => enm
+            .variants
+            .iter()
+            .any(|v| is_named(Some(&v.ident), UNKNOWN_EXTENSION_VARIANT)),
+        _ => false,
+    };
+
+    let additional_impl = expand_definition(definition, tag_default, unknown_extensions);
 
     let result = quote! {
         #item
@@ -67,9 +84,28 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream> {
+    expand_with_tag_default(definition, TagDefault::Automatic)
+}
+
+/// Like [`expand`], but for a definition of a module with the given tagging environment
+pub fn expand_with_tag_default(
+    definition: Option<Definition<AsnModelType>>,
+    tag_default: TagDefault,
+) -> Vec<TokenStream> {
+    expand_definition(definition, tag_default, true)
+}
+
+/// `unknown_extensions` tells whether an extensible type keeps the extension additions unknown
+/// to it, in the field [`UNKNOWN_EXTENSIONS_FIELD`] or the variant [`UNKNOWN_EXTENSION_VARIANT`]
+fn expand_definition(
+    definition: Option<Definition<AsnModelType>>,
+    tag_default: TagDefault,
+    unknown_extensions: bool,
+) -> Vec<TokenStream> {
     let mut additional_impl: Vec<TokenStream> = Vec::default();
     let mut model: Model<AsnModelType> = Model {
         name: "__proc_macro".to_string(),
+        tag_default,
         ..Default::default()
     };
 
@@ -83,9 +119,13 @@ pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream>
             println!("---------- parsed definition to rust end ----------");
             println!();
         }
-        additional_impl.push(
-            TokenStream::from_str(&AsnDefWriter::stringify(&model.to_rust_keep_names())).unwrap(),
-        );
+        let mut rust = model.to_rust_keep_names();
+        if !unknown_extensions {
+            rust.definitions
+                .iter_mut()
+                .for_each(|Definition(_, rust)| rust.skip_unknown_extensions());
+        }
+        additional_impl.push(TokenStream::from_str(&AsnDefWriter::stringify(&rust)).unwrap());
     }
 
     additional_impl
@@ -95,6 +135,16 @@ pub fn parse_asn_definition(
     attr: TokenStream,
     item: TokenStream,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    parse_asn_definition_with_tag_default(attr, item)
+        .map(|(definition, _tag_default, item)| (definition, item))
+}
+
+/// Like [`parse_asn_definition`], but also returns the tagging environment given by `tags(..)`,
+/// which is [`TagDefault::Automatic`] if not given
+pub fn parse_asn_definition_with_tag_default(
+    attr: TokenStream,
+    item: TokenStream,
+) -> Result<(Option<Definition<AsnModelType>>, TagDefault, Item), TokenStream> {
     let item_span = item.span();
     let attr_span = attr.span();
 
@@ -117,7 +167,8 @@ pub fn parse_asn_definition(
         println!("Matching item {:?}", item);
     }
 
-    match item {
+    let tag_default = asn.tag_default.unwrap_or(TagDefault::Automatic);
+    let (definition, item) = match item {
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("sequence") => {
             parse_sequence_or_set(strct, &asn, attr_span, Type::Sequence)
         }
@@ -133,8 +184,12 @@ pub fn parse_asn_definition(
         Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("choice") => {
             parse_choice(enm, &asn, attr_span)
         }
+        Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("open_type_choice") => {
+            parse_open_type_choice(enm)
+        }
         item => Ok((None, item)),
-    }
+    }?;
+    Ok((definition, tag_default, item))
 }
 
 fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
@@ -143,9 +198,19 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
     asn_span: proc_macro2::Span,
     mapper: F,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    let unknown_extensions = asn.extensible_after.is_some()
+        && strct
+            .fields
+            .iter()
+            .any(|field| is_named(field.ident.as_ref(), UNKNOWN_EXTENSIONS_FIELD));
+
     let fields = strct
         .fields
         .iter_mut()
+        // not a component, but the extension additions of a newer version of the type
+        .filter(|field| {
+            !unknown_extensions || !is_named(field.ident.as_ref(), UNKNOWN_EXTENSIONS_FIELD)
+        })
         .map(|field| {
             if field.ident.is_none() {
                 compile_err_ts(
@@ -154,15 +219,13 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<Transparent>(
-                field.span(),
-                &field.ty,
-                &mut field.attrs,
+            parse_and_remove_first_asn_attribute::<Transparent>(field.span(), &mut field.attrs).map(
+                |asn| Field {
+                    name: field.ident.as_ref().unwrap().to_string(),
+                    identifier: asn.identifier.clone(),
+                    role: into_asn(&field.ty, asn),
+                },
             )
-            .map(|asn| Field {
-                name: field.ident.as_ref().unwrap().to_string(),
-                role: asn,
-            })
         })
         .vec_result()?;
 
@@ -175,9 +238,20 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
                     asn_span,
                     fields.iter().map(|v| &v.name),
                 )?,
+                // the content of a group on its own, see ComponentTypeList::is_extension_group
+                extension_groups: if asn.extension_group {
+                    vec![ExtensionAdditionGroup {
+                        version: None,
+                        start: 0,
+                        end: fields.len(),
+                    }]
+                } else {
+                    Vec::new()
+                },
                 fields,
             })
-            .opt_tagged(asn.tag),
+            .opt_tagged(asn.tag)
+            .with_tag_mode(asn.tag_mode),
         )),
         Item::Struct(strct),
     ))
@@ -205,7 +279,7 @@ fn parse_transparent(
         (
             Some(Definition(
                 strct.ident.to_string(),
-                parsed.with_tag_opt(asn.tag),
+                parsed.with_tag_opt(asn.tag).with_tag_mode(asn.tag_mode),
             )),
             Item::Struct(strct),
         )
@@ -242,7 +316,9 @@ fn parse_enumerated(
                         compile_err_ts(v.span(), "ENUMERATED Variants must not have a Tag")?;
                     }
 
-                    Ok(variant.with_number_opt(attr.primary))
+                    Ok(variant
+                        .with_number_opt(attr.primary)
+                        .with_identifier_opt(attr.identifier))
                 })
             } else {
                 Ok(variant)
@@ -257,7 +333,9 @@ fn parse_enumerated(
     Ok((
         Some(Definition(
             enm.ident.to_string(),
-            Type::Enumerated(enumerated).opt_tagged(asn.tag),
+            Type::Enumerated(enumerated)
+                .opt_tagged(asn.tag)
+                .with_tag_mode(asn.tag_mode),
         )),
         Item::Enum(enm),
     ))
@@ -279,9 +357,17 @@ fn parse_choice(
         })
         .transpose()?;
 
+    let unknown_extension = asn.extensible_after.is_some()
+        && enm
+            .variants
+            .iter()
+            .any(|v| is_named(Some(&v.ident), UNKNOWN_EXTENSION_VARIANT));
+
     let variants = enm
         .variants
         .iter_mut()
+        // not an alternative, but one of a newer version of the type
+        .filter(|v| !unknown_extension || !is_named(Some(&v.ident), UNKNOWN_EXTENSION_VARIANT))
         .map(|v| {
             if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
                 compile_err_ts(
@@ -290,20 +376,18 @@ fn parse_choice(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<ChoiceVariant>(
-                v.span(),
-                &v.fields.iter().next().unwrap().ty,
-                &mut v.attrs,
+            parse_and_remove_first_asn_attribute::<ChoiceVariant>(v.span(), &mut v.attrs).map(
+                |asn| {
+                    // TODO extensible
+                    ChoiceVariant {
+                        name: v.ident.to_string(),
+                        identifier: asn.identifier.clone(),
+                        tag: asn.tag,
+                        tag_mode: asn.tag_mode,
+                        r#type: into_asn(&v.fields.iter().next().unwrap().ty, asn).r#type,
+                    }
+                },
             )
-            .map(|asn| {
-                // TODO extensible
-                // TODO tags
-                ChoiceVariant {
-                    name: v.ident.to_string(),
-                    tag: asn.tag,
-                    r#type: asn.r#type,
-                }
-            })
         })
         .vec_result()?;
 
@@ -313,10 +397,63 @@ fn parse_choice(
         Choice::from_variants(variants.into_iter()).with_maybe_extension_after(extensible_after),
     );
 
-    let tag = asn.tag.or_else(|| TagResolver::resolve_default(&choice));
+    let tag = asn.tag.or_else(|| {
+        TagResolver::resolve_default_in(asn.tag_default.unwrap_or(TagDefault::Automatic), &choice)
+    });
 
     Ok((
-        Some(Definition(enm.ident.to_string(), choice.opt_tagged(tag))),
+        Some(Definition(
+            enm.ident.to_string(),
+            choice.opt_tagged(tag).with_tag_mode(asn.tag_mode),
+        )),
+        Item::Enum(enm),
+    ))
+}
+
+fn parse_open_type_choice(
+    mut enm: syn::ItemEnum,
+) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    // the object set is extensible if objects unknown to the enum can be kept
+    let extensible = enm
+        .variants
+        .iter()
+        .any(|v| is_named(Some(&v.ident), UNKNOWN_OBJECT_VARIANT));
+
+    let variants = enm
+        .variants
+        .iter_mut()
+        .filter(|v| !is_named(Some(&v.ident), UNKNOWN_OBJECT_VARIANT))
+        .map(|v| {
+            if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
+                compile_err_ts(
+                    v.span(),
+                    "Variants of an open type have to have exactly one unnamed field",
+                )?;
+            }
+
+            let asn =
+                parse_and_remove_first_asn_attribute::<OpenTypeVariant>(v.span(), &mut v.attrs)?;
+            let key = asn.key.ok_or_else(|| {
+                compile_error_ts(v.span(), "Variants of an open type require a key")
+            })?;
+            Ok::<_, TokenStream>(OpenTypeVariant {
+                key,
+                name: v.ident.to_string(),
+                identifier: asn.identifier.clone(),
+                r#type: into_asn(&v.fields.iter().next().unwrap().ty, asn).r#type,
+            })
+        })
+        .vec_result()?;
+
+    Ok((
+        Some(Definition(
+            enm.ident.to_string(),
+            Type::OpenTypeChoice(OpenTypeChoice {
+                variants,
+                extensible,
+            })
+            .untagged(),
+        )),
         Item::Enum(enm),
     ))
 }
@@ -369,6 +506,7 @@ fn parse_and_remove_first_asn_attribute<C: Context>(
 fn into_asn<C: Context<Primary = Type>>(ty: &syn::Type, mut asn: AsnAttribute<C>) -> AsnModelType {
     AsnModelType {
         tag: asn.tag,
+        tag_mode: asn.tag_mode,
         r#type: if let Type::TypeReference(_, empty_tag) = asn.primary {
             Type::TypeReference(quote! { #ty }.to_string(), empty_tag.or(asn.tag))
         } else {
@@ -386,6 +524,10 @@ fn into_asn<C: Context<Primary = Type>>(ty: &syn::Type, mut asn: AsnAttribute<C>
     }
 }
 
+fn is_named(ident: Option<&syn::Ident>, name: &str) -> bool {
+    ident.is_some_and(|ident| ident == name)
+}
+
 fn compile_err_ts<T: std::fmt::Display>(
     span: proc_macro2::Span,
     msg: T,
@@ -425,12 +567,12 @@ trait VecResult<T, E> {
         Self: Sized;
 }
 
-impl<T, E, I: ExactSizeIterator<Item = Result<T, E>>> VecResult<T, E> for I {
+impl<T, E, I: Iterator<Item = Result<T, E>>> VecResult<T, E> for I {
     fn vec_result(self) -> Result<Vec<T>, E>
     where
         Self: Sized,
     {
-        let mut result = Vec::with_capacity(self.len());
+        let mut result = Vec::with_capacity(self.size_hint().0);
         for value in self {
             result.push(value?);
         }
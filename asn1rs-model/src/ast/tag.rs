@@ -1,8 +1,11 @@
-use crate::model::Tag;
+use crate::model::{Tag, TagMode};
 use proc_macro2::Delimiter;
+use syn::buffer::Cursor;
 use syn::parse::{Parse, ParseBuffer};
 
-pub struct AttrTag(pub Tag);
+/// A tag like `tag(1)`, `tag(APPLICATION(7))` or `tag(explicit(PRIVATE(3)))`, which is implicit
+/// unless wrapped in `explicit(..)`
+pub struct AttrTag(pub Tag, pub TagMode);
 
 impl Parse for AttrTag {
     fn parse<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Self> {
@@ -10,34 +13,49 @@ impl Parse for AttrTag {
             let (group, _span, outer) = s
                 .group(Delimiter::Parenthesis)
                 .ok_or_else(|| input.error("Expected parenthesis"))?;
-            if let Some((variant, cursor)) = group.ident() {
-                let (variant_group, _span, _outer) = cursor
-                    .group(Delimiter::Parenthesis)
-                    .ok_or_else(|| syn::Error::new(cursor.span(), "Expected parenthesis"))?;
-                let (number, _cursor) = variant_group.literal().ok_or_else(|| {
-                    syn::Error::new(variant_group.span(), "Expected number literal")
-                })?;
-                let number = number.to_string().parse::<usize>().map_err(|_| {
-                    syn::Error::new(variant_group.span(), "Literal is not a number")
-                })?;
-                Ok((
-                    AttrTag(match variant.to_string().to_lowercase().as_str() {
-                        "universal" => Tag::Universal(number),
-                        "application" => Tag::Application(number),
-                        "private" => Tag::Private(number),
-                        v => return Err(input.error(format!("Unexpected tag variant `{}`", v))),
-                    }),
-                    outer,
+            match group.ident() {
+                Some((mode, cursor)) if mode.to_string().eq_ignore_ascii_case("explicit") => {
+                    let (tag_group, _span, _outer) = cursor
+                        .group(Delimiter::Parenthesis)
+                        .ok_or_else(|| syn::Error::new(cursor.span(), "Expected parenthesis"))?;
+                    Ok((AttrTag(parse_tag(tag_group)?, TagMode::Explicit), outer))
+                }
+                _ => Ok((AttrTag(parse_tag(group)?, TagMode::Implicit), outer)),
+            }
+        })
+    }
+}
+
+fn parse_tag(group: Cursor<'_>) -> syn::Result<Tag> {
+    if let Some((variant, cursor)) = group.ident() {
+        let (variant_group, _span, _outer) = cursor
+            .group(Delimiter::Parenthesis)
+            .ok_or_else(|| syn::Error::new(cursor.span(), "Expected parenthesis"))?;
+        let (number, _cursor) = variant_group
+            .literal()
+            .ok_or_else(|| syn::Error::new(variant_group.span(), "Expected number literal"))?;
+        let number = number
+            .to_string()
+            .parse::<usize>()
+            .map_err(|_| syn::Error::new(variant_group.span(), "Literal is not a number"))?;
+        Ok(match variant.to_string().to_lowercase().as_str() {
+            "universal" => Tag::Universal(number),
+            "application" => Tag::Application(number),
+            "private" => Tag::Private(number),
+            v => {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    format!("Unexpected tag variant `{}`", v),
                 ))
-            } else if let Some((literal, _cursor)) = group.literal() {
-                let number = literal
-                    .to_string()
-                    .parse::<usize>()
-                    .map_err(|_| syn::Error::new(group.span(), "Literal is not a number"))?;
-                Ok((AttrTag(Tag::ContextSpecific(number)), outer))
-            } else {
-                Err(syn::Error::new(group.span(), "Expected tag variant"))
             }
         })
+    } else if let Some((literal, _cursor)) = group.literal() {
+        let number = literal
+            .to_string()
+            .parse::<usize>()
+            .map_err(|_| syn::Error::new(group.span(), "Literal is not a number"))?;
+        Ok(Tag::ContextSpecific(number))
+    } else {
+        Err(syn::Error::new(group.span(), "Expected tag variant"))
     }
 }
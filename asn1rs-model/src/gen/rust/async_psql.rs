@@ -2,7 +2,7 @@ use crate::gen::rust::shared_psql::*;
 use crate::gen::rust::GeneratorSupplement;
 use crate::gen::RustCodeGenerator;
 use crate::model::rust::PlainEnum;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::{DataEnum, Field, UNKNOWN_EXTENSIONS_FIELD};
 use crate::model::sql::{Sql, SqlType, ToSql};
 use crate::model::{Definition, Model, Rust, RustType};
 use codegen::{Block, Function, Impl, Scope};
@@ -20,10 +20,16 @@ impl GeneratorSupplement<Rust> for AsyncPsqlInserter {
 
     fn impl_supplement(&self, _scope: &mut Scope, _definition: &Definition<Rust>) {}
 
-    fn extend_impl_of_struct<'a>(&self, name: &str, impl_scope: &mut Impl, fields: &[Field]) {
+    fn extend_impl_of_struct<'a>(
+        &self,
+        name: &str,
+        impl_scope: &mut Impl,
+        fields: &[Field],
+        unknown_extensions: bool,
+    ) {
         AsyncPsqlInserter::append_retrieve_many_for_container_type(name, impl_scope);
         AsyncPsqlInserter::append_retrieve_for_container_type(name, impl_scope);
-        AsyncPsqlInserter::append_load_struct(name, impl_scope, fields);
+        AsyncPsqlInserter::append_load_struct(name, impl_scope, fields, unknown_extensions);
 
         let fn_insert = create_insert_fn(impl_scope, true);
         fn_insert.line(prepare_struct_insert_statement(name, fields));
@@ -622,7 +628,12 @@ impl AsyncPsqlInserter {
         fn_retrieve.line(format!("Self::{}(context, &row).await", load_fn_name()));
     }
 
-    fn append_load_struct(name: &str, impl_scope: &mut Impl, fields: &[Field]) {
+    fn append_load_struct(
+        name: &str,
+        impl_scope: &mut Impl,
+        fields: &[Field],
+        unknown_extensions: bool,
+    ) {
         let fn_load = create_load_fn(
             impl_scope,
             fields.iter().any(|field| {
@@ -648,6 +659,9 @@ impl AsyncPsqlInserter {
                 RustCodeGenerator::rust_field_name(field.name(), true)
             ));
         }
+        if unknown_extensions {
+            result_block.line(format!("{}: Default::default(),", UNKNOWN_EXTENSIONS_FIELD));
+        }
         result_block.after(")");
         fn_load.push_block(result_block);
     }
@@ -810,7 +824,7 @@ impl AsyncPsqlInserter {
                 RustCodeGenerator::rust_field_name(field, true),
                 sql.to_rust().as_no_option().to_inner_type_string(),
                 index + 1,
-                if !sql.to_rust().similar(f_type) {
+                if !sql.to_rust().as_no_option().similar(inner) {
                     format!(".map(|v| v as {})", inner.to_inner_type_string())
                 } else {
                     String::default()
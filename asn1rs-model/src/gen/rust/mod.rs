@@ -10,13 +10,17 @@ pub mod async_psql;
 pub(crate) mod shared_psql;
 
 use crate::gen::Generator;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::{DataEnum, DataVariant, Field};
 use crate::model::rust::{EncodingOrdering, PlainEnum};
+use crate::model::rust::{
+    UNKNOWN_EXTENSIONS_FIELD, UNKNOWN_EXTENSION_VARIANT, UNKNOWN_OBJECT_VARIANT,
+};
 use crate::model::Model;
 use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::TagProperty;
 use crate::model::{Definition, Tag, Type as AsnType, Type};
+use crate::model::{TagDefault, TagMode};
 use codegen::Block;
 use codegen::Enum;
 use codegen::Impl;
@@ -39,7 +43,16 @@ const KEYWORDS: [&str; 9] = [
 pub trait GeneratorSupplement<T> {
     fn add_imports(&self, scope: &mut Scope);
     fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<T>);
-    fn extend_impl_of_struct(&self, _name: &str, _impl_scope: &mut Impl, _fields: &[Field]) {}
+    /// `unknown_extensions` tells whether the struct has the additional field
+    /// [`UNKNOWN_EXTENSIONS_FIELD`] for extension additions it does not know
+    fn extend_impl_of_struct(
+        &self,
+        _name: &str,
+        _impl_scope: &mut Impl,
+        _fields: &[Field],
+        _unknown_extensions: bool,
+    ) {
+    }
     fn extend_impl_of_enum(&self, _name: &str, _impl_scope: &mut Impl, _enumeration: &PlainEnum) {}
     fn extend_impl_of_data_enum(
         &self,
@@ -58,6 +71,7 @@ pub struct RustCodeGenerator {
     global_derives: Vec<String>,
     direct_field_access: bool,
     getter_and_setter: bool,
+    unknown_extensions: bool,
 }
 
 impl From<Model<Rust>> for RustCodeGenerator {
@@ -75,6 +89,7 @@ impl Default for RustCodeGenerator {
             global_derives: Vec::default(),
             direct_field_access: true,
             getter_and_setter: false,
+            unknown_extensions: false,
         }
     }
 }
@@ -130,6 +145,17 @@ impl RustCodeGenerator {
         self.getter_and_setter = allow;
     }
 
+    pub const fn unknown_extensions_are_kept(&self) -> bool {
+        self.unknown_extensions
+    }
+
+    /// Whether extensible structs and enums keep the extension additions of a newer version of
+    /// the type in the additional field [`UNKNOWN_EXTENSIONS_FIELD`] or variant
+    /// [`UNKNOWN_EXTENSION_VARIANT`], so that they can be written back unchanged
+    pub fn set_unknown_extensions_kept(&mut self, keep: bool) {
+        self.unknown_extensions = keep;
+    }
+
     pub fn to_string_without_generators(&self) -> Vec<(String, String)> {
         self.to_string_with_generators(&[])
     }
@@ -167,8 +193,11 @@ impl RustCodeGenerator {
             }
         }
 
+        let public = Self::public_definitions(model);
+
         for vref in &model.value_references {
             scope.raw(&Self::fmt_const(
+                Self::visibility(model.exports.contains(&vref.name)),
                 &vref.name,
                 &vref.role,
                 &vref.value.as_rust_const_literal(true),
@@ -177,7 +206,21 @@ impl RustCodeGenerator {
         }
 
         for definition in &model.definitions {
-            self.add_definition(&mut scope, definition);
+            let skipped;
+            let definition = if self.unknown_extensions {
+                definition
+            } else {
+                let mut definition = definition.clone();
+                definition.1.skip_unknown_extensions();
+                skipped = definition;
+                &skipped
+            };
+            self.add_definition_with_visibility(
+                &mut scope,
+                definition,
+                model.tag_default,
+                Self::visibility(public.contains(&definition.name())),
+            );
             Self::impl_definition(&mut scope, definition, generators, self.getter_and_setter);
 
             generators
@@ -188,41 +231,133 @@ impl RustCodeGenerator {
         (file, scope.to_string())
     }
 
-    fn fmt_const(name: &str, r#type: &RustType, value: &impl Display, indent: usize) -> String {
+    /// Symbols that are not exported by the ASN.1 module are only visible within the crate
+    fn visibility(public: bool) -> &'static str {
+        if public {
+            "pub"
+        } else {
+            "pub(crate)"
+        }
+    }
+
+    /// The exported definitions and all definitions they refer to, which are part of their
+    /// public interface even though they are not exported themselves
+    fn public_definitions(model: &Model<Rust>) -> Vec<&str> {
+        let mut public = model
+            .definitions
+            .iter()
+            .map(Definition::name)
+            .filter(|name| model.exports.contains(name))
+            .collect::<Vec<_>>();
+
+        let mut index = 0;
+        while let Some(name) = public.get(index).copied() {
+            index += 1;
+            for Definition(_, rust) in model.definitions.iter().filter(|d| d.name() == name) {
+                let referenced = match rust {
+                    Rust::Struct { fields, .. } => fields.iter().map(Field::r#type).collect(),
+                    Rust::Enum(_) => Vec::new(),
+                    Rust::DataEnum(data) | Rust::OpenTypeChoice { variants: data, .. } => {
+                        data.variants().map(DataVariant::r#type).collect()
+                    }
+                    Rust::TupleStruct { r#type, .. } => vec![r#type],
+                };
+                for r#type in referenced {
+                    if let RustType::Complex(other, _) = r#type.as_inner_type() {
+                        if !public.contains(&other.as_str()) {
+                            public.push(other.as_str());
+                        }
+                    }
+                }
+            }
+        }
+
+        public
+    }
+
+    fn fmt_const(
+        vis: &str,
+        name: &str,
+        r#type: &RustType,
+        value: &impl Display,
+        indent: usize,
+    ) -> String {
         format!(
-            "{}pub const {}: {} = {};",
+            "{}{} const {}: {} = {};",
             "    ".repeat(indent),
+            vis,
             name,
             r#type.to_const_lit_string(),
             if let RustType::Complex(..) = r#type {
                 format!("{}::new({})", r#type.to_const_lit_string(), value)
+            } else if let RustType::ObjectIdentifier | RustType::RelativeOid | RustType::Time(_) =
+                r#type
+            {
+                format!("{}::from_static({})", r#type.to_const_lit_string(), value)
             } else {
                 value.to_string()
             }
         )
     }
 
-    pub fn add_definition(&self, scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+    pub fn add_definition(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        self.add_definition_with_tag_default(scope, definition, TagDefault::Automatic)
+    }
+
+    /// Like [`RustCodeGenerator::add_definition`], but for a definition of a module with the
+    /// given tagging environment, which is only noted on SEQUENCE, SET and CHOICE definitions
+    /// because only these are affected by automatic tagging
+    pub fn add_definition_with_tag_default(
+        &self,
+        scope: &mut Scope,
+        definition: &Definition<Rust>,
+        tag_default: TagDefault,
+    ) {
+        self.add_definition_with_visibility(scope, definition, tag_default, "pub")
+    }
+
+    fn add_definition_with_visibility(
+        &self,
+        scope: &mut Scope,
+        Definition(name, rust): &Definition<Rust>,
+        tag_default: TagDefault,
+        vis: &str,
+    ) {
+        let hashable = !self.definition_contains_real(rust, &mut Vec::new());
         match rust {
             Rust::Struct {
                 fields,
                 tag,
+                tag_mode,
                 extension_after,
+                extension_group,
+                unknown_extensions,
                 ordering,
             } => {
-                scope.raw(&Self::asn_attribute(
+                let header = Self::asn_attribute_header(
                     match ordering {
                         EncodingOrdering::Keep => "sequence",
                         EncodingOrdering::Sort => "set",
                     },
+                    tag_default,
+                );
+                scope.raw(&Self::asn_attribute(
+                    if *extension_group {
+                        format!("{}, extension_group", header)
+                    } else {
+                        header
+                    },
                     *tag,
+                    *tag_mode,
                     extension_after.map(|index| fields[index].name().to_string()),
                     &[],
+                    None,
                 ));
                 Self::add_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(scope, name, vis, hashable),
                     name,
                     fields,
+                    *unknown_extensions,
                     self.direct_field_access,
                 )
             }
@@ -230,32 +365,63 @@ impl RustCodeGenerator {
                 scope.raw(&Self::asn_attribute(
                     "enumerated",
                     plain.tag(),
-                    plain.extension_after_variant().cloned(),
+                    plain.tag_mode(),
+                    plain
+                        .extension_after_variant()
+                        .map(|v| v.name().to_string()),
                     &[],
+                    None,
                 ));
                 Self::add_enum(
-                    self.new_enum(scope, name, true).derive("Default"),
+                    self.new_enum(scope, name, vis, true, hashable)
+                        .derive("Default"),
                     name,
                     plain,
                 )
             }
             Rust::DataEnum(data) => {
                 scope.raw(&Self::asn_attribute(
-                    "choice",
+                    Self::asn_attribute_header("choice", tag_default),
                     data.tag(),
+                    data.tag_mode(),
                     data.extension_after_variant().map(|v| v.name().to_string()),
                     &[],
+                    None,
                 ));
-                Self::add_data_enum(self.new_enum(scope, name, false), name, data)
+                Self::add_data_enum(self.new_enum(scope, name, vis, false, hashable), name, data)
+            }
+            Rust::OpenTypeChoice { variants, keys } => {
+                scope.raw(&Self::asn_attribute(
+                    "open_type_choice",
+                    variants.tag(),
+                    variants.tag_mode(),
+                    None,
+                    &[],
+                    None,
+                ));
+                Self::add_open_type_choice(
+                    self.new_enum(scope, name, vis, false, hashable),
+                    name,
+                    variants,
+                    keys,
+                )
             }
             Rust::TupleStruct {
                 r#type,
                 tag,
+                tag_mode,
                 constants,
             } => {
-                scope.raw(&Self::asn_attribute("transparent", *tag, None, &[]));
+                scope.raw(&Self::asn_attribute(
+                    "transparent",
+                    *tag,
+                    *tag_mode,
+                    None,
+                    &[],
+                    None,
+                ));
                 Self::add_tuple_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(scope, name, vis, hashable),
                     name,
                     r#type,
                     self.direct_field_access,
@@ -266,28 +432,65 @@ impl RustCodeGenerator {
         }
     }
 
-    fn add_struct(str_ct: &mut Struct, _name: &str, fields: &[Field], pub_access: bool) {
+    fn add_struct(
+        str_ct: &mut Struct,
+        _name: &str,
+        fields: &[Field],
+        unknown_extensions: bool,
+        pub_access: bool,
+    ) {
         for field in fields {
+            let field_name = Self::rust_field_name(field.name(), true);
             str_ct.field(
                 &format!(
                     "{} {}{}",
                     Self::asn_attribute(
                         Self::asn_attribute_type(&field.r#type().clone().into_asn()),
                         field.tag(),
+                        field.tag_mode(),
                         None,
                         field.constants(),
+                        Some(field.identifier()).filter(|i| *i != field_name),
                     ),
                     if pub_access { "pub " } else { "" },
-                    Self::rust_field_name(field.name(), true),
+                    field_name,
                 ),
                 field.r#type().to_string(),
             );
         }
+        if unknown_extensions {
+            str_ct.field(
+                &format!(
+                    "#[doc(hidden)] {}{}",
+                    if pub_access { "pub " } else { "" },
+                    UNKNOWN_EXTENSIONS_FIELD
+                ),
+                "UnknownExtensions",
+            );
+        }
     }
 
     fn add_enum(en_m: &mut Enum, _name: &str, rust_enum: &PlainEnum) {
+        // the values are only written if they differ from the implicit ones, but then for all
+        // variants, so that they are not numbered implicitly in a different way
+        let numbered = rust_enum
+            .variants()
+            .enumerate()
+            .any(|(index, variant)| variant.value().is_some_and(|v| v != index as i64));
         for (index, variant) in rust_enum.variants().enumerate() {
-            let name = Self::rust_variant_name(variant);
+            let name = Self::rust_variant_name(variant.name());
+            let mut attributes = Vec::new();
+            if let Some(value) = variant.value().filter(|_| numbered) {
+                attributes.push(value.to_string());
+            }
+            if variant.identifier() != name {
+                attributes.push(Self::asn_attribute_identifier(variant.identifier()));
+            }
+            let name = if attributes.is_empty() {
+                name
+            } else {
+                format!("#[asn({})] {}", attributes.join(", "), name)
+            };
             let name = if index == 0 {
                 format!("#[default] {name}")
             } else {
@@ -299,18 +502,56 @@ impl RustCodeGenerator {
 
     fn add_data_enum(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
         for variant in enumeration.variants() {
+            let variant_name = Self::rust_variant_name(variant.name());
             en_m.new_variant(&format!(
                 "{} {}({})",
                 Self::asn_attribute(
                     Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
                     variant.tag(),
+                    variant.tag_mode(),
                     None,
                     &[],
+                    Some(variant.identifier()).filter(|i| *i != variant_name),
                 ),
-                Self::rust_variant_name(variant.name()),
+                variant_name,
                 variant.r#type().to_string(),
             ));
         }
+        if enumeration.has_unknown_extension() {
+            en_m.new_variant(&format!(
+                "#[doc(hidden)] {}(u64, OpenType)",
+                UNKNOWN_EXTENSION_VARIANT
+            ));
+        }
+    }
+
+    fn add_open_type_choice(en_m: &mut Enum, _name: &str, variants: &DataEnum, keys: &[i64]) {
+        for (variant, key) in variants.variants().zip(keys) {
+            let variant_name = Self::rust_variant_name(variant.name());
+            en_m.new_variant(&format!(
+                "{} {}({})",
+                Self::asn_attribute(
+                    format!(
+                        "{}, key({})",
+                        Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
+                        key
+                    ),
+                    None,
+                    TagMode::default(),
+                    None,
+                    &[],
+                    Some(variant.identifier()).filter(|i| *i != variant_name),
+                ),
+                variant_name,
+                variant.r#type().to_string(),
+            ));
+        }
+        if variants.has_unknown_extension() {
+            en_m.new_variant(&format!(
+                "#[doc(hidden)] {}(i64, OpenType)",
+                UNKNOWN_OBJECT_VARIANT
+            ));
+        }
     }
 
     fn add_tuple_struct(
@@ -326,8 +567,10 @@ impl RustCodeGenerator {
             Self::asn_attribute(
                 Self::asn_attribute_type(&inner.clone().into_asn()),
                 tag,
+                TagMode::default(),
                 None,
                 constants,
+                None,
             ),
             if pub_access { "pub " } else { "" },
             inner.to_string(),
@@ -337,14 +580,16 @@ impl RustCodeGenerator {
     fn asn_attribute<T: ToString>(
         r#type: T,
         tag: Option<Tag>,
+        tag_mode: TagMode,
         extensible_after: Option<String>,
         constants: &[(String, String)],
+        identifier: Option<&str>,
     ) -> String {
         format!(
             "#[asn({})]",
             vec![
                 Some(r#type.to_string()),
-                tag.map(Self::asn_attribute_tag),
+                tag.map(|tag| Self::asn_attribute_tag(tag, tag_mode)),
                 extensible_after.map(Self::asn_attribute_extensible_after),
                 if constants.is_empty() {
                     None
@@ -357,7 +602,8 @@ impl RustCodeGenerator {
                             .collect::<Vec<_>>()
                             .join(", ")
                     ))
-                }
+                },
+                identifier.map(Self::asn_attribute_identifier),
             ]
             .into_iter()
             .flatten()
@@ -392,9 +638,9 @@ impl RustCodeGenerator {
                     }
                 )],
             ),
-            Type::String(size, charset) => (
+            Type::String(size, charset, alphabet) => (
                 Cow::Owned(format!("{:?}string", charset).to_lowercase()),
-                vec![size.to_constraint_string()]
+                vec![size.to_constraint_string(), alphabet.to_constraint_string()]
                     .into_iter()
                     .flatten()
                     .collect(),
@@ -414,6 +660,11 @@ impl RustCodeGenerator {
                     .collect()],
             ),
             Type::Null => (Cow::Borrowed("null"), Vec::default()),
+            Type::Real(_) => (Cow::Borrowed("real"), Vec::default()),
+            Type::ObjectIdentifier => (Cow::Borrowed("object_identifier"), Vec::default()),
+            Type::RelativeOid => (Cow::Borrowed("relative_oid"), Vec::default()),
+            Type::Time(time) => (Cow::Borrowed(time.attribute_name()), Vec::default()),
+            Type::OpenType => (Cow::Borrowed("open_type"), Vec::default()),
             Type::Optional(inner) => (
                 Cow::Borrowed("optional"),
                 vec![Self::asn_attribute_type(inner)],
@@ -450,12 +701,30 @@ impl RustCodeGenerator {
             Type::Set(_) => (Cow::Borrowed("set"), Vec::default()),
             Type::Enumerated(_) => (Cow::Borrowed("enumerated"), Vec::default()),
             Type::Choice(_) => (Cow::Borrowed("choice"), Vec::default()),
+            Type::OpenTypeChoice(_) => (Cow::Borrowed("open_type_choice"), Vec::default()),
+            Type::ObjectClassField(never)
+            | Type::Instance(never)
+            | Type::Selection(never)
+            | Type::ComponentsOf(never) => match *never {},
+            Type::Related(relation, inner) => (
+                Cow::Borrowed("related"),
+                vec![
+                    relation.clone(),
+                    match &**inner {
+                        Type::TypeReference(name, _) => name.clone(),
+                        other => Self::asn_attribute_type(other),
+                    },
+                ],
+            ),
             Type::TypeReference(inner, tag) => (
                 Cow::Borrowed("complex"),
-                vec![Some(inner.clone()), (*tag).map(Self::asn_attribute_tag)]
-                    .into_iter()
-                    .flatten()
-                    .collect(),
+                vec![
+                    Some(inner.clone()),
+                    (*tag).map(|tag| Self::asn_attribute_tag(tag, TagMode::default())),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
             ),
         };
         if parameters.is_empty() {
@@ -465,12 +734,24 @@ impl RustCodeGenerator {
         }
     }
 
-    fn asn_attribute_tag(tag: Tag) -> String {
-        match tag {
-            Tag::Universal(t) => format!("tag(UNIVERSAL({}))", t),
-            Tag::Application(t) => format!("tag(APPLICATION({}))", t),
-            Tag::Private(t) => format!("tag(PRIVATE({}))", t),
-            Tag::ContextSpecific(t) => format!("tag({})", t),
+    fn asn_attribute_tag(tag: Tag, tag_mode: TagMode) -> String {
+        let tag = match tag {
+            Tag::Universal(t) => format!("UNIVERSAL({})", t),
+            Tag::Application(t) => format!("APPLICATION({})", t),
+            Tag::Private(t) => format!("PRIVATE({})", t),
+            Tag::ContextSpecific(t) => format!("{}", t),
+        };
+        match tag_mode {
+            TagMode::Implicit => format!("tag({})", tag),
+            TagMode::Explicit => format!("tag(explicit({}))", tag),
+        }
+    }
+
+    fn asn_attribute_header(primary: &str, tag_default: TagDefault) -> String {
+        match tag_default {
+            TagDefault::Automatic => primary.to_string(),
+            TagDefault::Explicit => format!("{}, tags(explicit)", primary),
+            TagDefault::Implicit => format!("{}, tags(implicit)", primary),
         }
     }
 
@@ -478,6 +759,10 @@ impl RustCodeGenerator {
         format!("extensible_after({})", variant)
     }
 
+    fn asn_attribute_identifier(identifier: &str) -> String {
+        format!("identifier({:?})", identifier)
+    }
+
     fn impl_definition(
         scope: &mut Scope,
         Definition(name, rust): &Definition<Rust>,
@@ -488,7 +773,10 @@ impl RustCodeGenerator {
             Rust::Struct {
                 fields,
                 tag: _,
+                tag_mode: _,
                 extension_after: _,
+                extension_group: _,
+                unknown_extensions,
                 ordering: _,
             } => {
                 Self::impl_consts(
@@ -500,7 +788,7 @@ impl RustCodeGenerator {
                 );
                 let implementation = Self::impl_struct(scope, name, fields, getter_and_setter);
                 for g in generators {
-                    g.extend_impl_of_struct(name, implementation, fields);
+                    g.extend_impl_of_struct(name, implementation, fields, *unknown_extensions);
                 }
             }
             Rust::Enum(r_enum) => {
@@ -509,8 +797,13 @@ impl RustCodeGenerator {
                     g.extend_impl_of_enum(name, implementation, r_enum);
                 }
             }
-            Rust::DataEnum(enumeration) => {
-                let implementation = Self::impl_data_enum(scope, name, enumeration);
+            Rust::DataEnum(enumeration)
+            | Rust::OpenTypeChoice {
+                variants: enumeration,
+                ..
+            } => {
+                let open_type = matches!(rust, Rust::OpenTypeChoice { .. });
+                let implementation = Self::impl_data_enum(scope, name, enumeration, open_type);
                 for g in generators {
                     g.extend_impl_of_data_enum(name, implementation, enumeration);
                 }
@@ -519,6 +812,7 @@ impl RustCodeGenerator {
             Rust::TupleStruct {
                 r#type: inner,
                 tag: _,
+                tag_mode: _,
                 constants,
             } => {
                 Self::impl_consts(scope, name, Some(("", inner, &constants[..])).into_iter());
@@ -621,6 +915,7 @@ impl RustCodeGenerator {
             }
             for (name, value) in constants {
                 scope.raw(&Self::fmt_const(
+                    "pub",
                     &if field.is_empty() {
                         Cow::Borrowed(name)
                     } else {
@@ -697,7 +992,7 @@ impl RustCodeGenerator {
                 "{} => Some({}::{}),",
                 index,
                 name,
-                Self::rust_variant_name(variant)
+                Self::rust_variant_name(variant.name())
             ));
         }
         block_match.line("_ => None,");
@@ -712,7 +1007,11 @@ impl RustCodeGenerator {
             .line("[");
 
         for variant in r_enum.variants() {
-            values_fn.line(format!("{}::{},", name, Self::rust_variant_name(variant)));
+            values_fn.line(format!(
+                "{}::{},",
+                name,
+                Self::rust_variant_name(variant.name())
+            ));
         }
         values_fn.line("]");
     }
@@ -732,7 +1031,7 @@ impl RustCodeGenerator {
                 block.line(format!(
                     "{}::{} => {},",
                     name,
-                    Self::rust_variant_name(variant),
+                    Self::rust_variant_name(variant.name()),
                     ordinal
                 ));
             });
@@ -744,11 +1043,12 @@ impl RustCodeGenerator {
         scope: &'a mut Scope,
         name: &str,
         enumeration: &DataEnum,
+        open_type: bool,
     ) -> &'a mut Impl {
         let implementation = scope.new_impl(name);
 
         Self::impl_data_enum_values_fn(implementation, name, enumeration);
-        Self::impl_data_enum_value_index_fn(implementation, name, enumeration);
+        Self::impl_data_enum_value_index_fn(implementation, name, enumeration, open_type);
 
         for variant in enumeration.variants() {
             let field_name = Self::rust_module_name(variant.name());
@@ -779,6 +1079,7 @@ impl RustCodeGenerator {
         implementation: &mut Impl,
         name: &str,
         enumeration: &DataEnum,
+        open_type: bool,
     ) {
         let ordinal_fn = implementation
             .new_fn("value_index")
@@ -799,6 +1100,21 @@ impl RustCodeGenerator {
                 ));
             });
 
+        if enumeration.has_unknown_extension() && open_type {
+            // an object unknown to the enum is placed after all known ones
+            block.line(format!(
+                "{}::{}(..) => {},",
+                name,
+                UNKNOWN_OBJECT_VARIANT,
+                enumeration.len()
+            ));
+        } else if enumeration.has_unknown_extension() {
+            block.line(format!(
+                "{}::{}(index, _) => *index as usize,",
+                name, UNKNOWN_EXTENSION_VARIANT
+            ));
+        }
+
         ordinal_fn.push_block(block);
     }
 
@@ -911,29 +1227,76 @@ impl RustCodeGenerator {
         out
     }
 
-    fn new_struct<'a>(&self, scope: &'a mut Scope, name: &str) -> &'a mut Struct {
+    /// `f64` implements neither `Eq` nor `Hash`, therefore a definition that contains a `REAL`
+    /// - directly or through one of the definitions it refers to - must not derive `Hash`
+    fn definition_contains_real<'a>(&'a self, rust: &'a Rust, visited: &mut Vec<&'a str>) -> bool {
+        match rust {
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .any(|field| self.type_contains_real(field.r#type(), visited)),
+            Rust::Enum(_) => false,
+            Rust::DataEnum(data) | Rust::OpenTypeChoice { variants: data, .. } => data
+                .variants()
+                .any(|variant| self.type_contains_real(variant.r#type(), visited)),
+            Rust::TupleStruct { r#type, .. } => self.type_contains_real(r#type, visited),
+        }
+    }
+
+    fn type_contains_real<'a>(&'a self, r#type: &'a RustType, visited: &mut Vec<&'a str>) -> bool {
+        match r#type.as_inner_type() {
+            RustType::F64 => true,
+            RustType::Complex(name, _) if !visited.contains(&name.as_str()) => {
+                visited.push(name);
+                self.models
+                    .iter()
+                    .flat_map(|model| model.definitions.iter())
+                    .filter(|Definition(other, _)| other == name)
+                    .any(|Definition(_, rust)| self.definition_contains_real(rust, visited))
+            }
+            _ => false,
+        }
+    }
+
+    fn new_struct<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        vis: &str,
+        hashable: bool,
+    ) -> &'a mut Struct {
         let str_ct = scope
             .new_struct(name)
-            .vis("pub")
+            .vis(vis)
             .derive("Default")
             .derive("Debug")
             .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+            .derive("PartialEq");
+        if hashable {
+            str_ct.derive("Hash");
+        }
         self.global_derives.iter().for_each(|derive| {
             str_ct.derive(derive);
         });
         str_ct
     }
 
-    fn new_enum<'a>(&self, scope: &'a mut Scope, name: &str, c_enum: bool) -> &'a mut Enum {
+    fn new_enum<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        vis: &str,
+        c_enum: bool,
+        hashable: bool,
+    ) -> &'a mut Enum {
         let en_m = scope
             .new_enum(name)
-            .vis("pub")
+            .vis(vis)
             .derive("Debug")
             .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+            .derive("PartialEq");
+        if hashable {
+            en_m.derive("Hash");
+        }
         if c_enum {
             en_m.derive("Copy").derive("PartialOrd").derive("Eq");
         }
@@ -995,6 +1358,48 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    pub fn test_real_is_not_hashable() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"BasicReal DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Wrapper ::= SEQUENCE {
+                inner Measurement
+            }
+
+            Measurement ::= REAL
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let (_file_name, file_content) = RustCodeGenerator::from(model)
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            #[asn(sequence)]
+            #[derive(Default, Debug, Clone, PartialEq)]
+            pub struct Wrapper {
+                #[asn(complex(Measurement, tag(UNIVERSAL(9))))] pub inner: Measurement,
+            }
+
+        "#,
+            &file_content,
+        );
+    }
+
     #[test]
     pub fn test_integer_tuple_constants() {
         let model = Model::try_from(Tokenizer::default().parse(
@@ -1035,4 +1440,162 @@ pub(crate) mod tests {
             &file_content,
         );
     }
+
+    #[test]
+    pub fn test_not_exported_definitions_are_crate_visible() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"BasicExports DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            EXPORTS Visible, visible-value;
+
+            visible-value INTEGER ::= 5
+            hidden-value INTEGER ::= 6
+
+            Visible ::= SEQUENCE {
+                inline SEQUENCE { flag BOOLEAN },
+                referenced SEQUENCE OF Referenced
+            }
+
+            Referenced ::= SEQUENCE { nested Nested }
+
+            Nested ::= BOOLEAN
+
+            Hidden ::= BOOLEAN
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let (_file_name, file_content) = RustCodeGenerator::from(model)
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            pub const VISIBLE_VALUE: u64 = 5;
+            pub(crate) const HIDDEN_VALUE: u64 = 6;
+            #[asn(sequence)]
+            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            pub struct VisibleInline {
+                #[asn(boolean)] pub flag: bool,
+            }
+
+        "#,
+            &file_content,
+        );
+        assert!(file_content.contains("pub struct Visible {"));
+        // part of the public interface of Visible
+        assert!(file_content.contains("pub struct Referenced {"));
+        assert!(file_content.contains("pub struct Nested(#[asn(boolean)] pub bool);"));
+        assert!(file_content.contains("pub(crate) struct Hidden(#[asn(boolean)] pub bool);"));
+    }
+
+    #[test]
+    pub fn test_extension_addition_group_is_an_optional_struct() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"BasicGroups DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Versioned ::= SEQUENCE {
+                id INTEGER (0..255),
+                ...,
+                [[ 2: flag BOOLEAN ]]
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let (_file_name, file_content) = RustCodeGenerator::from(model)
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            #[asn(sequence, extension_group)]
+            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            pub struct VersionedExtensionGroup2 {
+                #[asn(boolean, tag(1))] pub flag: bool,
+            }
+
+            impl VersionedExtensionGroup2 {
+            }
+
+            #[asn(sequence, extensible_after(id))]
+            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            pub struct Versioned {
+                #[asn(integer(0..255), tag(0))] pub id: u8,
+                #[asn(optional(complex(VersionedExtensionGroup2, tag(UNIVERSAL(16)))))] pub extension_group_2: Option<VersionedExtensionGroup2>,
+            }
+        "#,
+            &file_content,
+        );
+    }
+
+    #[test]
+    fn test_unknown_extensions_are_kept() {
+        let model = Model::try_from(Tokenizer.parse(
+            r#"Kept DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Versioned ::= SEQUENCE {
+                id INTEGER (0..255),
+                ...
+            }
+
+            Alternatives ::= CHOICE {
+                id INTEGER (0..255),
+                ...
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.set_unknown_extensions_kept(true);
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            #[asn(sequence, extensible_after(id))]
+            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            pub struct Versioned {
+                #[asn(integer(0..255))] pub id: u8,
+                #[doc(hidden)] pub unknown_extensions: UnknownExtensions,
+            }
+        "#,
+            &file_content,
+        );
+        assert!(file_content.contains("#[doc(hidden)] UnknownExtension(u64, OpenType),"));
+    }
 }
@@ -2,7 +2,7 @@ use crate::gen::rust::shared_psql::*;
 use crate::gen::rust::GeneratorSupplement;
 use crate::gen::rust::RustCodeGenerator;
 use crate::model::rust::PlainEnum;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::{DataEnum, Field, UNKNOWN_EXTENSIONS_FIELD};
 use crate::model::sql::Sql;
 use crate::model::sql::ToSql;
 use crate::model::Definition;
@@ -78,7 +78,10 @@ impl PsqlInserter {
             Rust::Struct {
                 fields,
                 tag: _,
+                tag_mode: _,
                 extension_after: _,
+                extension_group: _,
+                unknown_extensions: _,
                 ordering: _,
             } => {
                 Self::impl_struct_insert_statement(
@@ -92,7 +95,11 @@ impl PsqlInserter {
                     fields.iter().map(Field::fallback_representation),
                 );
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration)
+            | Rust::OpenTypeChoice {
+                variants: enumeration,
+                ..
+            } => {
                 Self::impl_data_enum_insert_statement(
                     Self::new_insert_statement_fn(implementation),
                     name,
@@ -411,7 +418,10 @@ impl PsqlInserter {
             Rust::Struct {
                 fields,
                 tag: _,
+                tag_mode: _,
                 extension_after: _,
+                extension_group: _,
+                unknown_extensions,
                 ordering: _,
             } => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
@@ -425,9 +435,14 @@ impl PsqlInserter {
                     ),
                     name,
                     fields.iter().map(Field::fallback_representation),
+                    *unknown_extensions,
                 );
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration)
+            | Rust::OpenTypeChoice {
+                variants: enumeration,
+                ..
+            } => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_data_enum_query_fn(Self::new_query_fn(implementation, true), name);
                 Self::impl_data_enum_load_fn(
@@ -481,6 +496,7 @@ impl PsqlInserter {
         func: &mut Function,
         struct_name: &str,
         variants: impl ExactSizeIterator<Item = &'a (String, RustType)>,
+        unknown_extensions: bool,
     ) {
         let mut block = Block::new(&format!("Ok({}", struct_name));
         let mut index_negative_offset = 0;
@@ -531,7 +547,7 @@ impl PsqlInserter {
                         ERROR_TYPE,
                         sql.to_rust().to_string(),
                     ));
-                    if *rust < sql.to_rust() {
+                    if !rust.similar(&sql.to_rust()) {
                         rows_foreach.line(&format!("let value = value as {};", rust.to_string()));
                     }
                     rows_foreach.line("vec.push(value);");
@@ -549,7 +565,12 @@ impl PsqlInserter {
                 let load = format!(
                     "{}::value_at_column::<{}>(&row, {})?",
                     ERROR_TYPE,
-                    if rust.to_sql().to_rust().similar(rust) {
+                    if rust
+                        .to_sql()
+                        .to_rust()
+                        .as_no_option()
+                        .similar(rust.as_no_option())
+                    {
                         rust.to_string()
                     } else {
                         rust.to_sql().to_rust().to_string()
@@ -587,6 +608,11 @@ impl PsqlInserter {
             }
         }
 
+        if unknown_extensions {
+            // extension additions unknown to the type are not persisted
+            block.line(format!("{}: Default::default(),", UNKNOWN_EXTENSIONS_FIELD));
+        }
+
         block.after(")");
         func.push_block(block);
     }
@@ -666,7 +692,7 @@ impl PsqlInserter {
     fn impl_enum_query_fn(func: &mut Function, name: &str, r_enum: &PlainEnum) {
         let mut block = Block::new("match id");
         for (index, variant) in r_enum.variants().enumerate() {
-            block.line(&format!("{} => Ok({}::{}),", index, name, variant));
+            block.line(&format!("{} => Ok({}::{}),", index, name, variant.name()));
         }
         block.line(&format!("_ => Err({}::no_result()),", ERROR_TYPE));
         func.push_block(block);
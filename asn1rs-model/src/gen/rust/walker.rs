@@ -1,7 +1,11 @@
 use crate::gen::RustCodeGenerator;
-use crate::model::rust::{DataEnum, EncodingOrdering, Field, PlainEnum};
+use crate::model::rust::{DataEnum, DataVariant, EncodingOrdering, Field, PlainEnum, PlainVariant};
+use crate::model::rust::{
+    UNKNOWN_EXTENSIONS_FIELD, UNKNOWN_EXTENSION_VARIANT, UNKNOWN_OBJECT_VARIANT,
+};
 use crate::model::{
-    Charset, Definition, LiteralValue, Model, Range, Rust, RustType, Size, Tag, TagProperty,
+    Charset, Definition, LiteralValue, Model, PermittedAlphabet, Range, Rust, RustType, Size, Tag,
+    TagDefault, TagMode, TagProperty,
 };
 use codegen::{Block, Impl, Scope};
 use std::fmt::Display;
@@ -23,28 +27,51 @@ impl AsnDefWriter {
         match r#type {
             Rust::Struct {
                 fields,
-                tag: _,
+                tag,
+                tag_mode,
                 extension_after: _,
+                extension_group: _,
+                unknown_extensions: _,
                 ordering,
             } => {
                 scope.raw(&format!(
-                    "type AsnDef{} = {}{}<{}>;",
+                    "type AsnDef{} = {};",
                     name,
-                    CRATE_SYN_PREFIX,
-                    match ordering {
-                        EncodingOrdering::Keep => "Sequence",
-                        EncodingOrdering::Sort => "Set",
-                    },
-                    name
+                    Self::definition_type_declaration(
+                        name,
+                        format!(
+                            "{}{}<{}>",
+                            CRATE_SYN_PREFIX,
+                            match ordering {
+                                EncodingOrdering::Keep => "Sequence",
+                                EncodingOrdering::Sort => "Set",
+                            },
+                            name
+                        ),
+                        *tag,
+                        *tag_mode,
+                    )
                 ));
                 for field in fields {
-                    self.write_type_declaration(scope, name, field.name(), field.r#type());
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        field.name(),
+                        field.r#type(),
+                        field.tag_mode(),
+                    );
                 }
             }
-            Rust::Enum(_enm) => {
+            Rust::Enum(enm) => {
                 scope.raw(&format!(
-                    "type AsnDef{} = {}Enumerated<{}>;",
-                    name, CRATE_SYN_PREFIX, name
+                    "type AsnDef{} = {};",
+                    name,
+                    Self::definition_type_declaration(
+                        name,
+                        format!("{}Enumerated<{}>", CRATE_SYN_PREFIX, name),
+                        enm.tag(),
+                        enm.tag_mode(),
+                    )
                 ));
             }
             Rust::DataEnum(enm) => {
@@ -53,19 +80,39 @@ impl AsnDefWriter {
                     name, CRATE_SYN_PREFIX, name
                 ));
                 for variant in enm.variants() {
-                    self.write_type_declaration(scope, name, variant.name(), variant.r#type());
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        variant.name(),
+                        variant.r#type(),
+                        variant.tag_mode(),
+                    );
+                }
+            }
+            Rust::OpenTypeChoice { variants, .. } => {
+                // there is no AsnDef for the open type itself, because reading it requires the
+                // value of the related field, see AsnDef::OpenTypeChoice::read_value
+                for variant in variants.variants() {
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        variant.name(),
+                        variant.r#type(),
+                        TagMode::default(),
+                    );
                 }
             }
             Rust::TupleStruct {
                 r#type: field,
                 tag: _,
+                tag_mode,
                 constants: _,
             } => {
                 scope.raw(&format!(
                     "type AsnDef{} = {}Sequence<{}>;",
                     name, CRATE_SYN_PREFIX, name
                 ));
-                self.write_type_declaration(scope, name, "0", field);
+                self.write_type_declaration(scope, name, "0", field, *tag_mode);
             }
         }
     }
@@ -73,7 +120,7 @@ impl AsnDefWriter {
     #[must_use]
     pub fn type_declaration(r#type: &RustType, name: &str) -> String {
         match r#type {
-            RustType::Bool => format!("{}Boolean", CRATE_SYN_PREFIX),
+            RustType::Bool => format!("{}Boolean<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I8(_) => format!("{}Integer<i8, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U8(_) => format!("{}Integer<u8, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I16(_) => format!("{}Integer<i16, {}Constraint>", CRATE_SYN_PREFIX, name),
@@ -82,13 +129,28 @@ impl AsnDefWriter {
             RustType::U32(_) => format!("{}Integer<u32, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I64(_) => format!("{}Integer<i64, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U64(_) => format!("{}Integer<u64, {}Constraint>", CRATE_SYN_PREFIX, name),
-            RustType::String(_, charset) => format!(
+            RustType::F64 => format!("{}Real<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::String(_, charset, _) => format!(
                 "{}{:?}String<{}Constraint>",
                 CRATE_SYN_PREFIX, charset, name
             ),
             RustType::VecU8(_) => format!("{}OctetString<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::BitVec(_) => format!("{}BitString<{}Constraint>", CRATE_SYN_PREFIX, name),
-            RustType::Null => format!("{}NullT", CRATE_SYN_PREFIX),
+            RustType::Null => format!("{}NullT<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::ObjectIdentifier => {
+                format!("{}ObjectIdentifierT<{}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::RelativeOid => {
+                format!("{}RelativeOidT<{}Constraint>", CRATE_SYN_PREFIX, name)
+            }
+            RustType::OpenType => format!("{}OpenTypeT<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::Time(time) => format!(
+                "{}TimeT<{}{}, {}Constraint>",
+                CRATE_SYN_PREFIX,
+                CRATE_SYN_PREFIX,
+                time.rust_name(),
+                name
+            ),
             RustType::Vec(inner, _, ordering) => {
                 let virtual_field = Self::vec_virtual_field_name(name);
                 format!(
@@ -115,12 +177,78 @@ impl AsnDefWriter {
             RustType::Complex(inner, _tag) => {
                 format!("{}Complex<{}, {}Constraint>", CRATE_SYN_PREFIX, inner, name)
             }
+            RustType::Related(_, inner) => format!(
+                "{}OpenTypeChoice<{}, {}Constraint>",
+                CRATE_SYN_PREFIX,
+                inner.to_string(),
+                name
+            ),
+        }
+    }
+
+    /// Like [`AsnDefWriter::type_declaration`], but wraps the type below any `Option` or
+    /// `DefaultValue` into an [`Explicit`](crate::model::TagMode::Explicit) one
+    fn explicit_type_declaration(r#type: &RustType, name: &str) -> String {
+        match r#type {
+            RustType::Option(inner) => {
+                format!("Option<{}>", Self::explicit_type_declaration(inner, name))
+            }
+            RustType::Default(inner, _default) => {
+                let virtual_field = Self::default_virtual_field_name(name);
+                format!(
+                    "{}DefaultValue<{}, {}Constraint>",
+                    CRATE_SYN_PREFIX,
+                    Self::explicit_type_declaration(inner, &virtual_field),
+                    name
+                )
+            }
+            _ if !Self::is_explicit(r#type, TagMode::Explicit) => {
+                Self::type_declaration(r#type, name)
+            }
+            _ => {
+                let virtual_field = Self::explicit_virtual_field_name(name);
+                format!(
+                    "{}Explicit<{}, {}Constraint>",
+                    CRATE_SYN_PREFIX,
+                    Self::type_declaration(r#type, &virtual_field),
+                    name
+                )
+            }
+        }
+    }
+
+    fn definition_type_declaration(
+        name: &str,
+        declaration: String,
+        tag: Option<Tag>,
+        tag_mode: TagMode,
+    ) -> String {
+        if tag.is_some() && tag_mode == TagMode::Explicit {
+            format!(
+                "{}Explicit<{}, {}>",
+                CRATE_SYN_PREFIX,
+                declaration,
+                Self::explicit_constraint_name(name)
+            )
+        } else {
+            declaration
         }
     }
 
-    fn write_type_declaration(&self, scope: &mut Scope, base: &str, name: &str, r#type: &RustType) {
+    fn write_type_declaration(
+        &self,
+        scope: &mut Scope,
+        base: &str,
+        name: &str,
+        r#type: &RustType,
+        tag_mode: TagMode,
+    ) {
         let combined = Self::combined_field_type_name(base, name);
-        let type_dec = Self::type_declaration(r#type, &Self::constraint_impl_name(&combined));
+        let type_dec = if tag_mode == TagMode::Explicit {
+            Self::explicit_type_declaration(r#type, &Self::constraint_impl_name(&combined))
+        } else {
+            Self::type_declaration(r#type, &Self::constraint_impl_name(&combined))
+        };
         if !cfg!(feature = "generate-internal-docs") {
             scope.raw("#[doc(hidden)]");
         }
@@ -131,6 +259,23 @@ impl AsnDefWriter {
         format!("___asn1rs_{}", combined)
     }
 
+    fn explicit_constraint_name(name: &str) -> String {
+        Self::constraint_impl_name(&format!("{}ExplicitConstraint", name))
+    }
+
+    /// Whether the type needs to be wrapped to be tagged explicitly. CHOICE and open types are
+    /// always tagged explicitly and `Option` and `DefaultValue` only wrap the actual type.
+    fn is_explicit(r#type: &RustType, tag_mode: TagMode) -> bool {
+        tag_mode == TagMode::Explicit
+            && !matches!(
+                r#type,
+                RustType::Option(_)
+                    | RustType::Default(..)
+                    | RustType::OpenType
+                    | RustType::Related(..)
+            )
+    }
+
     #[must_use]
     pub fn combined_field_type_name(base: &str, name: &str) -> String {
         format!(
@@ -140,28 +285,51 @@ impl AsnDefWriter {
         )
     }
 
-    fn write_constraints(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
+    fn write_constraints(
+        &self,
+        scope: &mut Scope,
+        Definition(name, r#type): &Definition<Rust>,
+        tag_default: TagDefault,
+    ) {
         match r#type {
             Rust::Struct {
                 fields,
                 tag,
+                tag_mode,
                 extension_after,
+                extension_group,
+                unknown_extensions,
                 ordering,
             } => {
-                // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (SEQUENCE and SET)
-                let fields = Self::assign_implicit_tags(fields);
+                // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (SEQUENCE and SET), but the components
+                // of a group are numbered together with the surrounding ones instead
+                let fields = if *extension_group {
+                    fields.to_vec()
+                } else {
+                    Self::assign_implicit_tags(fields, tag_default)
+                };
+                let tag = Self::write_explicit_definition_constraint(scope, name, *tag, *tag_mode);
                 self.write_field_constraints(scope, name, &fields);
                 self.write_sequence_or_set_constraint(
                     scope,
                     name,
-                    *tag,
+                    tag,
                     &fields,
                     *extension_after,
                     *ordering,
+                    false,
+                    *extension_group,
+                    *unknown_extensions,
                 );
             }
             Rust::Enum(plain) => {
-                self.write_enumerated_constraint(scope, name, plain);
+                let tag = Self::write_explicit_definition_constraint(
+                    scope,
+                    name,
+                    plain.tag(),
+                    plain.tag_mode(),
+                );
+                self.write_enumerated_constraint(scope, name, tag, plain);
             }
             Rust::DataEnum(data) => {
                 let fields = data
@@ -169,25 +337,46 @@ impl AsnDefWriter {
                     .map(|variant| Field {
                         name_type: (variant.name().to_string(), variant.r#type().clone()),
                         tag: variant.tag(),
+                        tag_mode: variant.tag_mode(),
                         constants: Vec::default(),
+                        identifier: None,
                     })
                     .collect::<Vec<_>>();
 
                 // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (CHOICE)
-                let fields = Self::assign_implicit_tags(&fields);
+                let fields = Self::assign_implicit_tags(&fields, tag_default);
 
                 self.write_field_constraints(scope, name, &fields);
                 self.write_choice_constraint(scope, name, data)
             }
+            Rust::OpenTypeChoice { variants, keys } => {
+                // each alternative is encoded with the tag of its own type
+                let fields = variants
+                    .variants()
+                    .map(|variant| Field {
+                        name_type: (variant.name().to_string(), variant.r#type().clone()),
+                        tag: None,
+                        tag_mode: TagMode::default(),
+                        constants: Vec::default(),
+                        identifier: None,
+                    })
+                    .collect::<Vec<_>>();
+
+                self.write_field_constraints(scope, name, &fields);
+                self.write_open_type_choice_constraint(scope, name, variants, keys)
+            }
             Rust::TupleStruct {
                 r#type,
                 tag,
+                tag_mode,
                 constants,
             } => {
                 let fields = [Field {
                     name_type: ("0".to_string(), r#type.clone()),
                     tag: *tag,
+                    tag_mode: *tag_mode,
                     constants: constants.to_vec(),
+                    identifier: None,
                 }];
                 self.write_field_constraints(scope, name, &fields[..]);
                 self.write_sequence_or_set_constraint(
@@ -197,6 +386,9 @@ impl AsnDefWriter {
                     &fields[..],
                     None,
                     EncodingOrdering::Keep,
+                    true,
+                    false,
+                    false,
                 );
             }
         }
@@ -216,6 +408,9 @@ impl AsnDefWriter {
         field: &Field,
         constraint_type_name: &str,
     ) {
+        if Self::is_explicit(field.r#type(), field.tag_mode()) {
+            return self.write_explicit_field_constraint(scope, name, field, constraint_type_name);
+        }
         match field.r#type() {
             RustType::Bool => {
                 Self::write_common_constraint_type(
@@ -223,6 +418,9 @@ impl AsnDefWriter {
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_BOOLEAN),
                 );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}boolean::Constraint", CRATE_SYN_PREFIX));
             }
             RustType::I8(range) => {
                 Self::write_common_constraint_type(
@@ -328,23 +526,29 @@ impl AsnDefWriter {
                     range,
                 )
             }
-            RustType::String(size, charset) => {
+            RustType::String(size, charset, alphabet) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or_else(|| charset.default_tag()),
                 );
-                Self::write_size_constraint(
+                Self::write_string_constraint(
                     match charset {
                         Charset::Utf8 => "utf8string",
                         Charset::Ia5 => "ia5string",
                         Charset::Numeric => "numericstring",
                         Charset::Printable => "printablestring",
                         Charset::Visible => "visiblestring",
+                        Charset::Teletex => "teletexstring",
+                        Charset::Graphic => "graphicstring",
+                        Charset::General => "generalstring",
+                        Charset::Universal => "universalstring",
+                        Charset::Bmp => "bmpstring",
                     },
                     scope,
                     constraint_type_name,
                     size,
+                    Some(alphabet).filter(|_| charset.is_known_multiplier()),
                 )
             }
             RustType::VecU8(size) => {
@@ -367,7 +571,10 @@ impl AsnDefWriter {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
-                    field.tag.unwrap_or(Tag::DEFAULT_SEQUENCE_OF),
+                    field.tag.unwrap_or(match ordering {
+                        EncodingOrdering::Keep => Tag::DEFAULT_SEQUENCE_OF,
+                        EncodingOrdering::Sort => Tag::DEFAULT_SET_OF,
+                    }),
                 );
                 Self::write_size_constraint(
                     match ordering {
@@ -389,7 +596,9 @@ impl AsnDefWriter {
                     &Field {
                         name_type: (virtual_field_name, *inner.clone()),
                         tag: None,
+                        tag_mode: TagMode::default(),
                         constants: field.constants().to_vec(),
+                        identifier: None,
                     },
                     &constraint_type_name,
                 )
@@ -400,6 +609,62 @@ impl AsnDefWriter {
                     constraint_type_name,
                     field.tag.unwrap_or(Tag::DEFAULT_NULL),
                 );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}null::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::F64 => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_REAL),
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}real::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::ObjectIdentifier => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_OBJECT_IDENTIFIER),
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}objectidentifier::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::RelativeOid => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or(Tag::DEFAULT_RELATIVE_OID),
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}relativeoid::Constraint", CRATE_SYN_PREFIX));
+            }
+            RustType::OpenType => {
+                scope.raw(&format!(
+                    "impl {}opentype::Constraint for {} {{",
+                    CRATE_SYN_PREFIX, constraint_type_name
+                ));
+                if let Some(tag) = field.tag {
+                    scope.raw(&format!(
+                        "const TAG: Option<{}Tag> = Some({}Tag::{:?});",
+                        CRATE_MODEL_PREFIX, CRATE_MODEL_PREFIX, tag
+                    ));
+                }
+                scope.raw("}");
+            }
+            RustType::Time(time) => {
+                Self::write_common_constraint_type(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| time.default_tag()),
+                );
+                scope
+                    .new_impl(constraint_type_name)
+                    .impl_trait(format!("{}time::Constraint", CRATE_SYN_PREFIX));
             }
             RustType::Option(inner) => self.write_field_constraint(
                 scope,
@@ -407,7 +672,9 @@ impl AsnDefWriter {
                 &Field {
                     name_type: (field.name().to_string(), *inner.clone()),
                     tag: field.tag(),
+                    tag_mode: field.tag_mode(),
                     constants: field.constants().to_vec(),
+                    identifier: None,
                 },
                 constraint_type_name,
             ),
@@ -429,7 +696,9 @@ impl AsnDefWriter {
                     &Field {
                         name_type: (virtual_field_name, *inner.clone()),
                         tag: field.tag,
+                        tag_mode: field.tag_mode(),
                         constants: field.constants().to_vec(),
+                        identifier: None,
                     },
                     &constraint_type_name,
                 )
@@ -446,16 +715,105 @@ impl AsnDefWriter {
                             constraint_type_name
                         )
                     }),
+                    field.tag.is_none(),
                 );
             }
+            // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, an open type is always tagged explicitly
+            RustType::Related(..) => {
+                self.write_complex_constraint(
+                    scope,
+                    constraint_type_name,
+                    field.tag.unwrap_or_else(|| {
+                        panic!(
+                            "Open type {}::{} requires a tag for {}",
+                            name,
+                            field.name(),
+                            constraint_type_name
+                        )
+                    }),
+                    false,
+                );
+            }
+        }
+    }
+
+    /// The tag belongs to the explicit constraint, while the wrapped value is written as if it
+    /// were untagged
+    fn write_explicit_field_constraint(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        field: &Field,
+        constraint_type_name: &str,
+    ) {
+        Self::write_explicit_constraint(
+            scope,
+            constraint_type_name,
+            field.tag.unwrap_or_else(|| {
+                panic!(
+                    "Explicitly tagged {}::{} requires a tag for {}",
+                    name,
+                    field.name(),
+                    constraint_type_name
+                )
+            }),
+        );
+
+        let virtual_field_name = Self::explicit_virtual_field_name(field.name());
+        let constraint_type_name = Self::constraint_type_name(name, &virtual_field_name);
+        Self::write_constraint_type_decl(scope, &constraint_type_name);
+
+        self.write_field_constraint(
+            scope,
+            name,
+            &Field {
+                name_type: (virtual_field_name, field.r#type().clone()),
+                tag: None,
+                tag_mode: TagMode::default(),
+                constants: field.constants().to_vec(),
+                identifier: None,
+            },
+            &constraint_type_name,
+        )
+    }
+
+    /// Writes the constraint for the explicit tag of a definition, if there is one, and returns
+    /// the tag that remains for the definition itself
+    fn write_explicit_definition_constraint(
+        scope: &mut Scope,
+        name: &str,
+        tag: Option<Tag>,
+        tag_mode: TagMode,
+    ) -> Option<Tag> {
+        match tag {
+            Some(tag) if tag_mode == TagMode::Explicit => {
+                let constraint_type_name = Self::explicit_constraint_name(name);
+                Self::write_constraint_type_decl(scope, &constraint_type_name);
+                Self::write_explicit_constraint(scope, &constraint_type_name, tag);
+                None
+            }
+            tag => tag,
         }
     }
 
-    fn write_complex_constraint(&self, scope: &mut Scope, name: &str, tag: Tag) {
+    fn write_explicit_constraint(scope: &mut Scope, name: &str, tag: Tag) {
         Self::write_common_constraint_type(scope, name, tag);
         scope
             .new_impl(name)
-            .impl_trait(format!("{}complex::Constraint", CRATE_SYN_PREFIX));
+            .impl_trait(format!("{}explicit::Constraint", CRATE_SYN_PREFIX));
+    }
+
+    fn write_complex_constraint(&self, scope: &mut Scope, name: &str, tag: Tag, untagged: bool) {
+        Self::write_common_constraint_type(scope, name, tag);
+        if untagged {
+            let mut imp = Impl::new(name);
+            imp.impl_trait(format!("{}complex::Constraint", CRATE_SYN_PREFIX));
+            Self::insert_consts(scope, imp, ["const UNTAGGED: bool = true;"]);
+        } else {
+            scope
+                .new_impl(name)
+                .impl_trait(format!("{}complex::Constraint", CRATE_SYN_PREFIX));
+        }
     }
 
     fn vec_virtual_field_name(field_name: &str) -> String {
@@ -466,6 +824,11 @@ impl AsnDefWriter {
         field_name.to_string() + "Value"
     }
 
+    fn explicit_virtual_field_name(field_name: &str) -> String {
+        field_name.to_string() + "Inner"
+    }
+
+    #[allow(clippy::too_many_arguments)] // for now this is fine-ish
     fn write_sequence_or_set_constraint(
         &self,
         scope: &mut Scope,
@@ -474,8 +837,18 @@ impl AsnDefWriter {
         fields: &[Field],
         extension_after_field: Option<usize>,
         ordering: EncodingOrdering,
+        transparent: bool,
+        extension_group: bool,
+        unknown_extensions: bool,
     ) {
-        Self::write_common_constraint_type(scope, name, tag.unwrap_or(Tag::DEFAULT_SEQUENCE));
+        Self::write_common_constraint_type(
+            scope,
+            name,
+            tag.unwrap_or(match ordering {
+                EncodingOrdering::Keep => Tag::DEFAULT_SEQUENCE,
+                EncodingOrdering::Sort => Tag::DEFAULT_SET,
+            }),
+        );
 
         let sorted;
         let (fields, module) = match ordering {
@@ -489,14 +862,24 @@ impl AsnDefWriter {
         let mut imp = Impl::new(name);
         imp.impl_trait(format!("{}{}::Constraint", CRATE_SYN_PREFIX, module));
 
-        self.write_sequence_or_set_constraint_read_fn(&mut imp, name, fields);
+        // the extension additions of a newer version of the type are kept in an additional field
+        self.write_sequence_or_set_constraint_read_fn(&mut imp, name, fields, unknown_extensions);
         self.write_sequence_or_set_constraint_write_fn(&mut imp, name, fields);
+        if unknown_extensions {
+            imp.new_fn("unknown_extensions")
+                .attr("inline")
+                .arg_ref_self()
+                .ret(format!("Option<&{}UnknownExtensions>", CRATE_SYN_PREFIX))
+                .line(format!("Some(&self.{})", UNKNOWN_EXTENSIONS_FIELD));
+        }
 
         Self::write_sequence_constraint_insert_consts(
             scope,
             name,
             fields,
             extension_after_field,
+            transparent,
+            extension_group,
             imp,
         );
     }
@@ -528,12 +911,14 @@ impl AsnDefWriter {
             .line(format!("AsnDef{}::write_value(writer, self)", name));
     }
 
-    fn write_enumerated_constraint(&self, scope: &mut Scope, name: &str, enumerated: &PlainEnum) {
-        Self::write_common_constraint_type(
-            scope,
-            name,
-            enumerated.tag().unwrap_or(Tag::DEFAULT_ENUMERATED),
-        );
+    fn write_enumerated_constraint(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        tag: Option<Tag>,
+        enumerated: &PlainEnum,
+    ) {
+        Self::write_common_constraint_type(scope, name, tag.unwrap_or(Tag::DEFAULT_ENUMERATED));
         let mut imp = Impl::new(name);
         imp.impl_trait(format!("{}enumerated::Constraint", CRATE_SYN_PREFIX));
 
@@ -544,7 +929,7 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("Self::{} => {},", variant, index));
+                    match_block.line(format!("Self::{} => {},", variant.name(), index));
                 }
                 match_block
             });
@@ -556,7 +941,7 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match index");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("{} => Some(Self::{}),", index, variant));
+                    match_block.line(format!("{} => Some(Self::{}),", index, variant.name()));
                 }
                 match_block.line("_ => None,");
                 match_block
@@ -567,6 +952,19 @@ impl AsnDefWriter {
             imp,
             &[
                 format!("const NAME: &'static str = \"{}\";", name),
+                format!(
+                    "const VARIANT_NAMES: &'static [&'static str] = &[{}];",
+                    Self::identifier_list(enumerated.variants().map(PlainVariant::identifier))
+                ),
+                format!(
+                    "const VARIANT_VALUES: &'static [i64] = &[{}];",
+                    enumerated
+                        .variants()
+                        .enumerate()
+                        .map(|(index, variant)| variant.value().unwrap_or(index as i64).to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
                 format!("const VARIANT_COUNT: u64 = {};", enumerated.len()),
                 format!(
                     "const STD_VARIANT_COUNT: u64 = {};",
@@ -591,15 +989,147 @@ impl AsnDefWriter {
         let mut imp = Impl::new(name);
         imp.impl_trait(format!("{}choice::Constraint", CRATE_SYN_PREFIX));
 
+        Self::write_data_enum_content_fns(&mut imp, name, choice, false);
+
+        if choice.has_unknown_extension() {
+            imp.new_fn("unknown_extension")
+                .attr("inline")
+                .arg_ref_self()
+                .ret(format!("Option<&{}OpenType>", CRATE_SYN_PREFIX))
+                .push_block({
+                    let mut match_block = Block::new("match self");
+                    match_block.line(format!(
+                        "Self::{}(_, content) => Some(content),",
+                        UNKNOWN_EXTENSION_VARIANT
+                    ));
+                    match_block.line("_ => None,");
+                    match_block
+                });
+
+            imp.new_fn("from_unknown_extension")
+                .attr("inline")
+                .arg("index", "u64")
+                .arg("content", format!("{}OpenType", CRATE_SYN_PREFIX))
+                .ret("Option<Self>")
+                .line(format!(
+                    "Some(Self::{}(index, content))",
+                    UNKNOWN_EXTENSION_VARIANT
+                ));
+        }
+
+        Self::insert_consts(
+            scope,
+            imp,
+            &[
+                format!("const NAME: &'static str = \"{}\";", name),
+                format!(
+                    "const VARIANT_NAMES: &'static [&'static str] = &[{}];",
+                    Self::identifier_list(choice.variants().map(DataVariant::identifier))
+                ),
+                format!("const VARIANT_COUNT: u64 = {};", choice.len()),
+                format!(
+                    "const STD_VARIANT_COUNT: u64 = {};",
+                    choice
+                        .extension_after_index()
+                        .map(|v| v + 1)
+                        .unwrap_or_else(|| choice.len())
+                ),
+                format!("const EXTENSIBLE: bool = {};", choice.is_extensible()),
+            ],
+        );
+    }
+
+    fn write_open_type_choice_constraint(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        variants: &DataEnum,
+        keys: &[i64],
+    ) {
+        let mut imp = Impl::new(name);
+        imp.impl_trait(format!("{}opentypechoice::Constraint", CRATE_SYN_PREFIX));
+
+        Self::write_data_enum_content_fns(&mut imp, name, variants, true);
+
+        if variants.has_unknown_extension() {
+            imp.new_fn("unknown")
+                .attr("inline")
+                .arg_ref_self()
+                .ret(format!("Option<(i64, &{}OpenType)>", CRATE_SYN_PREFIX))
+                .push_block({
+                    let mut match_block = Block::new("match self");
+                    match_block.line(format!(
+                        "Self::{}(key, content) => Some((*key, content)),",
+                        UNKNOWN_OBJECT_VARIANT
+                    ));
+                    match_block.line("_ => None,");
+                    match_block
+                });
+
+            imp.new_fn("from_unknown")
+                .attr("inline")
+                .arg("key", "i64")
+                .arg("content", format!("{}OpenType", CRATE_SYN_PREFIX))
+                .ret("Option<Self>")
+                .line(format!(
+                    "Some(Self::{}(key, content))",
+                    UNKNOWN_OBJECT_VARIANT
+                ));
+        }
+
+        Self::insert_consts(
+            scope,
+            imp,
+            &[
+                format!("const NAME: &'static str = \"{}\";", name),
+                format!(
+                    "const VARIANT_NAMES: &'static [&'static str] = &[{}];",
+                    Self::identifier_list(variants.variants().map(DataVariant::identifier))
+                ),
+                format!(
+                    "const KEYS: &'static [i64] = &[{}];",
+                    keys.iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                format!(
+                    "const EXTENSIBLE: bool = {};",
+                    variants.has_unknown_extension()
+                ),
+            ],
+        );
+    }
+
+    /// `to_choice_index`, `write_content` and `read_content` shared by CHOICE and open types
+    fn write_data_enum_content_fns(imp: &mut Impl, name: &str, data: &DataEnum, open_type: bool) {
+        let unknown_variant = if open_type {
+            UNKNOWN_OBJECT_VARIANT
+        } else {
+            UNKNOWN_EXTENSION_VARIANT
+        };
+
         imp.new_fn("to_choice_index")
             .attr("inline")
             .arg_ref_self()
             .ret("u64")
             .push_block({
                 let mut match_block = Block::new("match self");
-                for (index, variant) in choice.variants().enumerate() {
+                for (index, variant) in data.variants().enumerate() {
                     match_block.line(format!("Self::{}(_) => {},", variant.name(), index));
                 }
+                if data.has_unknown_extension() && open_type {
+                    // the object is not one of the KEYS
+                    match_block.line(format!(
+                        "Self::{}(..) => Self::KEYS.len() as u64,",
+                        UNKNOWN_OBJECT_VARIANT
+                    ));
+                } else if data.has_unknown_extension() {
+                    match_block.line(format!(
+                        "Self::{}(index, _) => *index,",
+                        UNKNOWN_EXTENSION_VARIANT
+                    ));
+                }
                 match_block
             });
 
@@ -611,7 +1141,7 @@ impl AsnDefWriter {
             .ret("Result<(), W::Error>")
             .push_block({
                 let mut match_block = Block::new("match self");
-                for variant in choice.variants() {
+                for variant in data.variants() {
                     let combined = Self::combined_field_type_name(name, variant.name());
                     match_block.line(format!(
                         "Self::{}(c) => AsnDef{}::write_value(writer, c),",
@@ -619,6 +1149,11 @@ impl AsnDefWriter {
                         combined
                     ));
                 }
+                if data.has_unknown_extension() {
+                    // written as the bytes it was read as and without calling into this, for a
+                    // CHOICE only by the packed encoding rules
+                    match_block.line(format!("Self::{}(..) => Ok(()),", unknown_variant));
+                }
                 match_block
             });
 
@@ -630,7 +1165,7 @@ impl AsnDefWriter {
             .ret("Result<Option<Self>, R::Error>")
             .push_block({
                 let mut match_block = Block::new("match index");
-                for (index, variant) in choice.variants().enumerate() {
+                for (index, variant) in data.variants().enumerate() {
                     let combined = Self::combined_field_type_name(name, variant.name());
                     match_block.line(format!(
                         "{} => Ok(Some(Self::{}(AsnDef{}::read_value(reader)?))),",
@@ -642,23 +1177,6 @@ impl AsnDefWriter {
                 match_block.line("_ => Ok(None),");
                 match_block
             });
-
-        Self::insert_consts(
-            scope,
-            imp,
-            &[
-                format!("const NAME: &'static str = \"{}\";", name),
-                format!("const VARIANT_COUNT: u64 = {};", choice.len()),
-                format!(
-                    "const STD_VARIANT_COUNT: u64 = {};",
-                    choice
-                        .extension_after_index()
-                        .map(|v| v + 1)
-                        .unwrap_or_else(|| choice.len())
-                ),
-                format!("const EXTENSIBLE: bool = {};", choice.is_extensible()),
-            ],
-        );
     }
 
     fn write_common_constraint_type(scope: &mut Scope, constraint_type_name: &str, tag: Tag) {
@@ -721,6 +1239,33 @@ impl AsnDefWriter {
             "impl {}{}::Constraint for {} {{",
             CRATE_SYN_PREFIX, module, constraint_type_name
         ));
+        Self::write_size_constants(scope, size);
+        scope.raw("}");
+    }
+
+    /// The permitted alphabet is only written for the charsets whose `Constraint` supports it
+    fn write_string_constraint(
+        module: &str,
+        scope: &mut Scope,
+        constraint_type_name: &str,
+        size: &Size,
+        alphabet: Option<&PermittedAlphabet>,
+    ) {
+        scope.raw(&format!(
+            "impl {}{}::Constraint for {} {{",
+            CRATE_SYN_PREFIX, module, constraint_type_name
+        ));
+        Self::write_size_constants(scope, size);
+        if let Some(alphabet) = alphabet.filter(|alphabet| !alphabet.is_any()) {
+            scope.raw(&format!(
+                "const PERMITTED_ALPHABET: &'static [(char, char)] = &{:?};",
+                alphabet.ranges()
+            ));
+        }
+        scope.raw("}");
+    }
+
+    fn write_size_constants(scope: &mut Scope, size: &Size) {
         if let Some(min) = size.min() {
             scope.raw(&format!("const MIN: Option<u64> = Some({});", min));
         }
@@ -728,7 +1273,6 @@ impl AsnDefWriter {
             scope.raw(&format!("const MAX: Option<u64> = Some({});", max));
         }
         scope.raw(&format!("const EXTENSIBLE: bool = {};", size.extensible()));
-        scope.raw("}");
     }
 
     fn write_default_constraint(
@@ -782,6 +1326,17 @@ impl AsnDefWriter {
                         .to_string(),
                 ),
             ),
+            RustType::Time(time) => (
+                Cow::Borrowed(time.rust_name()),
+                Cow::Borrowed(time.rust_name()),
+                Cow::Owned(format!(
+                    "{}::from_static({})",
+                    time.rust_name(),
+                    default.as_rust_const_literal_expect(true, |l| {
+                        matches!(l, LiteralValue::String(..))
+                    })
+                )),
+            ),
             t => (
                 Cow::Owned(t.to_string()),
                 t.to_const_lit_string(),
@@ -802,33 +1357,50 @@ impl AsnDefWriter {
         name: &str,
         fields: &[Field],
         extension_after_field: Option<usize>,
+        transparent: bool,
+        extension_group: bool,
         imp: Impl,
     ) {
         Self::insert_consts(
             scope,
             imp,
-            &[
-                format!(
-                    "const EXTENDED_AFTER_FIELD: Option<u64> = {:?};",
-                    extension_after_field
-                ),
-                format!("const FIELD_COUNT: u64 = {};", fields.len()),
-                format!(
-                    "const STD_OPTIONAL_FIELDS: u64 = {};",
-                    fields
-                        .iter()
-                        .enumerate()
-                        .take_while(
-                            |(index, _f)| *index <= extension_after_field.unwrap_or(usize::MAX)
-                        )
-                        .filter(|(_index, f)| f.r#type().is_optional())
-                        .count()
-                ),
-                format!("const NAME: &'static str = \"{}\";", name),
-            ],
+            transparent
+                .then(|| "const TRANSPARENT: bool = true;".to_string())
+                .into_iter()
+                .chain(extension_group.then(|| "const EXTENSION_GROUP: bool = true;".to_string()))
+                .chain([
+                    format!(
+                        "const EXTENDED_AFTER_FIELD: Option<u64> = {:?};",
+                        extension_after_field
+                    ),
+                    format!(
+                        "const FIELD_NAMES: &'static [&'static str] = &[{}];",
+                        Self::identifier_list(fields.iter().map(Field::identifier))
+                    ),
+                    format!("const FIELD_COUNT: u64 = {};", fields.len()),
+                    format!(
+                        "const STD_OPTIONAL_FIELDS: u64 = {};",
+                        fields
+                            .iter()
+                            .enumerate()
+                            .take_while(
+                                |(index, _f)| *index <= extension_after_field.unwrap_or(usize::MAX)
+                            )
+                            .filter(|(_index, f)| f.r#type().is_optional())
+                            .count()
+                    ),
+                    format!("const NAME: &'static str = \"{}\";", name),
+                ]),
         );
     }
 
+    fn identifier_list<'a>(identifiers: impl Iterator<Item = &'a str>) -> String {
+        identifiers
+            .map(|identifier| format!("{:?}", identifier))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     fn insert_consts<S: ToString, I: IntoIterator<Item = S>>(
         scope: &mut Scope,
         imp: Impl,
@@ -849,14 +1421,52 @@ impl AsnDefWriter {
         imp: &mut Impl,
         name: &str,
         fields: &[Field],
+        unknown_extensions: bool,
     ) {
-        imp.new_fn("read_seq")
+        // read last, after all the known fields
+        let read_unknown_extensions = unknown_extensions.then(|| {
+            format!(
+                "{}: reader.read_unknown_extensions::<Self>()?",
+                UNKNOWN_EXTENSIONS_FIELD
+            )
+        });
+
+        let body = imp
+            .new_fn("read_seq")
             .attr("inline")
             .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
             .arg("reader", "&mut R")
             .ret("Result<Self, R::Error>")
-            .bound("Self", "Sized")
-            .push_block({
+            .bound("Self", "Sized");
+
+        if fields
+            .iter()
+            .any(|field| matches!(field.r#type(), RustType::Related(..)))
+        {
+            // the type of a related field depends on the value of another field, therefore all
+            // fields are read in order before the struct is assembled
+            for field in fields {
+                body.line(format!(
+                    "let {} = AsnDef{}::read_value(reader{})?;",
+                    field.name(),
+                    Self::combined_field_type_name(name, field.name()),
+                    match field.r#type() {
+                        RustType::Related(relation, _) => format!(", {}", relation),
+                        _ => String::default(),
+                    }
+                ));
+            }
+            body.line(format!(
+                "Ok(Self {{ {} }})",
+                fields
+                    .iter()
+                    .map(|field| field.name().to_string())
+                    .chain(read_unknown_extensions)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        } else {
+            body.push_block({
                 let mut block = Block::new("Ok(Self");
 
                 for field in fields {
@@ -867,9 +1477,14 @@ impl AsnDefWriter {
                     ));
                 }
 
+                if let Some(read_unknown_extensions) = read_unknown_extensions {
+                    block.line(format!("{},", read_unknown_extensions));
+                }
+
                 block.after(")");
                 block
             });
+        }
     }
 
     fn write_sequence_or_set_constraint_write_fn(
@@ -888,9 +1503,14 @@ impl AsnDefWriter {
 
         for field in fields {
             body.line(format!(
-                "AsnDef{}::write_value(writer, &self.{})?;",
+                "AsnDef{}::write_value(writer, &self.{}{})?;",
                 Self::combined_field_type_name(name, field.name()),
                 field.name(),
+                match field.r#type() {
+                    // the value has to be the one selected by the related field
+                    RustType::Related(relation, _) => format!(", self.{}", relation),
+                    _ => String::default(),
+                }
             ));
         }
 
@@ -902,18 +1522,20 @@ impl AsnDefWriter {
 
         for definition in &model.definitions {
             Self.write_type_definitions(&mut scope, definition);
-            Self.write_constraints(&mut scope, definition);
-            Self.impl_readable(&mut scope, &definition.0);
-            Self.impl_writable(&mut scope, &definition.0);
+            Self.write_constraints(&mut scope, definition, model.tag_default);
+            if !matches!(definition.1, Rust::OpenTypeChoice { .. }) {
+                Self.impl_readable(&mut scope, &definition.0);
+                Self.impl_writable(&mut scope, &definition.0);
+            }
         }
 
         scope.to_string()
     }
 
-    /// ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3
-    fn assign_implicit_tags(fields: &[Field]) -> Vec<Field> {
+    /// ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3, only in modules with `AUTOMATIC TAGS`
+    fn assign_implicit_tags(fields: &[Field], tag_default: TagDefault) -> Vec<Field> {
         let any_explicit = fields.iter().any(|f| f.tag.is_some());
-        if any_explicit {
+        if any_explicit || tag_default != TagDefault::Automatic {
             fields.to_vec()
         } else {
             fields
@@ -956,21 +1578,32 @@ impl AsnDefWriter {
 pub(crate) mod tests {
     use crate::gen::rust::walker::AsnDefWriter;
     use crate::model::rust::{EncodingOrdering, Field};
-    use crate::model::{Charset, Definition, Rust, RustType, Size};
+    use crate::model::{Charset, Definition, PermittedAlphabet, Rust, RustType, Size, TagDefault};
     use codegen::Scope;
 
     fn simple_whatever_sequence() -> Definition<Rust> {
         Definition(
             String::from("Whatever"),
             Rust::struct_from_fields(vec![
-                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                Field::from_name_type(
+                    "name",
+                    RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default()),
+                ),
                 Field::from_name_type(
                     "opt",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(
+                        Size::Any,
+                        Charset::Utf8,
+                        PermittedAlphabet::default(),
+                    ))),
                 ),
                 Field::from_name_type(
                     "some",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(
+                        Size::Any,
+                        Charset::Utf8,
+                        PermittedAlphabet::default(),
+                    ))),
                 ),
             ]),
         )
@@ -982,18 +1615,32 @@ pub(crate) mod tests {
             Rust::Struct {
                 ordering: EncodingOrdering::Keep,
                 fields: vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type(
+                        "name",
+                        RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default()),
+                    ),
                     Field::from_name_type(
                         "opt",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            PermittedAlphabet::default(),
+                        ))),
                     ),
                     Field::from_name_type(
                         "some",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            PermittedAlphabet::default(),
+                        ))),
                     ),
                 ],
                 tag: None,
+                tag_mode: Default::default(),
                 extension_after: Some(1),
+                extension_group: false,
+                unknown_extensions: true,
             },
         )
     }
@@ -1072,7 +1719,7 @@ pub(crate) mod tests {
     pub fn test_whatever_struct_constraint_and_read_write_impl() {
         let def = simple_whatever_sequence();
         let mut scope = Scope::new();
-        AsnDefWriter.write_constraints(&mut scope, &def);
+        AsnDefWriter.write_constraints(&mut scope, &def, TagDefault::Automatic);
         AsnDefWriter.impl_readable(&mut scope, &def.0);
         AsnDefWriter.impl_writable(&mut scope, &def.0);
         let string = scope.to_string();
@@ -1117,6 +1764,7 @@ pub(crate) mod tests {
                 const NAME: &'static str = "Whatever";
                 const STD_OPTIONAL_FIELDS: u64 = 2;
                 const FIELD_COUNT: u64 = 3;
+                const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
                 const EXTENDED_AFTER_FIELD: Option<u64> = None;
                 
                 #[inline]
@@ -1171,7 +1819,7 @@ pub(crate) mod tests {
     pub fn test_potatoe_struct_has_correct_extensible_constraints() {
         let def = extensible_potato_sequence();
         let mut scope = Scope::new();
-        AsnDefWriter.write_constraints(&mut scope, &def);
+        AsnDefWriter.write_constraints(&mut scope, &def, TagDefault::Automatic);
         let string = scope.to_string();
         println!("{}", string);
 
@@ -1213,6 +1861,7 @@ pub(crate) mod tests {
                 const NAME: &'static str = "Potato";
                 const STD_OPTIONAL_FIELDS: u64 = 1;
                 const FIELD_COUNT: u64 = 3;
+                const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
                 const EXTENDED_AFTER_FIELD: Option<u64> = Some(1);
 
                 #[inline]
@@ -1223,6 +1872,7 @@ pub(crate) mod tests {
                         name: AsnDefPotatoFieldName::read_value(reader)?,
                         opt: AsnDefPotatoFieldOpt::read_value(reader)?,
                         some: AsnDefPotatoFieldSome::read_value(reader)?,
+                        unknown_extensions: reader.read_unknown_extensions::<Self>()?,
                     })
                 }
 
@@ -1233,6 +1883,11 @@ pub(crate) mod tests {
                     AsnDefPotatoFieldSome::write_value(writer, &self.some)?;
                     Ok(())
                 }
+
+                #[inline]
+                fn unknown_extensions(&self) -> Option<&::asn1rs::syn::UnknownExtensions> {
+                    Some(&self.unknown_extensions)
+                }
             }
 
             "#
@@ -0,0 +1,214 @@
+use crate::model::{Error, PeekableTokens};
+use crate::parser::{Location, Token};
+use std::convert::TryFrom;
+use std::iter::Peekable;
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 51.7, the characters a restricted character string is limited
+/// to through `FROM`. The characters are kept as sorted and disjoint ranges, no range means that
+/// the alphabet is not constrained.
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
+pub struct PermittedAlphabet(Vec<(char, char)>);
+
+impl PermittedAlphabet {
+    /// Merges the given inclusive ranges into sorted and disjoint ranges
+    /// ```rust
+    /// use asn1rs_model::model::PermittedAlphabet;
+    /// let alphabet = PermittedAlphabet::from_ranges(vec![('a', 'f'), ('0', '9'), ('c', 'z')]);
+    /// assert_eq!(&[('0', '9'), ('a', 'z')][..], alphabet.ranges());
+    /// assert_eq!(36, alphabet.len());
+    /// ```
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (char, char)>) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect::<Vec<_>>();
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if (*last as u32).saturating_add(1) >= min as u32 => {
+                    *last = (*last).max(max);
+                }
+                _ => merged.push((min, max)),
+            }
+        }
+        Self(merged)
+    }
+
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.0
+    }
+
+    /// Whether there is no `FROM` constraint on the string
+    pub fn is_any(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The number of characters in the alphabet, zero if it is not constrained
+    pub fn len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(min, max)| (*max as u32 - *min as u32) as usize + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.is_any()
+    }
+
+    pub fn contains(&self, char: char) -> bool {
+        self.is_any()
+            || self
+                .0
+                .iter()
+                .any(|(min, max)| (*min..=*max).contains(&char))
+    }
+
+    pub fn to_constraint_string(&self) -> Option<String> {
+        if self.is_any() {
+            None
+        } else {
+            Some(format!(
+                "from({})",
+                self.0
+                    .iter()
+                    .map(|(min, max)| if min == max {
+                        format!("{:?}", min)
+                    } else {
+                        format!("{:?}..={:?}", min, max)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        }
+    }
+
+    /// Reads the characters of a quoted string, including its whitespace, which the tokenizer
+    /// only preserves through the locations of the tokens
+    fn read_chars<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Result<Vec<char>, Error> {
+        let opening = iter.next_if_separator_and_eq('"')?;
+        let mut chars = Vec::new();
+        let mut end = Location::at(opening.location().line(), opening.location().column() + 1);
+
+        loop {
+            let token = iter.next_or_err()?;
+            let location = token.location();
+            if location.line() == end.line() {
+                chars.extend((end.column()..location.column()).map(|_| ' '));
+            }
+            match token {
+                t if t.eq_separator('"') => break,
+                Token::Text(_, text) => {
+                    end = Location::at(location.line(), location.column() + text.chars().count());
+                    chars.extend(text.chars());
+                }
+                Token::Separator(_, char) => {
+                    end = Location::at(location.line(), location.column() + 1);
+                    chars.push(char);
+                }
+            }
+        }
+
+        Ok(chars)
+    }
+
+    /// A single character as bound of a range such as `"A".."Z"`
+    fn read_char<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Result<char, Error> {
+        let token = iter.peek_or_err()?.clone();
+        match Self::read_chars(iter)?[..] {
+            [char] => Ok(char),
+            _ => Err(Error::invalid_range_value(token)),
+        }
+    }
+}
+
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for PermittedAlphabet {
+    type Error = Error;
+
+    /// Reads `FROM ("A".."Z" | "0".."9" | "-_")`, where `UNION` is an alias for `|`
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        iter.next_text_eq_ignore_case_or_err("FROM")?;
+        iter.next_separator_eq_or_err('(')?;
+
+        let mut ranges = Vec::new();
+        loop {
+            let token = iter.peek_or_err()?.clone();
+            if iter.peek_is_separator_eq('.') {
+                return Err(Error::unexpected_token(token));
+            }
+
+            let chars = Self::read_chars(iter)?;
+            if iter.peek_is_separator_eq('.') {
+                iter.next_separator_eq_or_err('.')?;
+                iter.next_separator_eq_or_err('.')?;
+                match chars[..] {
+                    [min] => ranges.push((min, Self::read_char(iter)?)),
+                    _ => return Err(Error::invalid_range_value(token)),
+                }
+            } else if chars.is_empty() {
+                return Err(Error::invalid_range_value(token));
+            } else {
+                ranges.extend(chars.into_iter().map(|char| (char, char)));
+            }
+
+            if !iter.next_is_text_and_eq_ignore_case("|")
+                && !iter.next_is_text_and_eq_ignore_case("UNION")
+            {
+                break;
+            }
+        }
+
+        iter.next_separator_eq_or_err(')')?;
+        Ok(Self::from_ranges(ranges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Tokenizer;
+
+    fn parse(asn: &str) -> Result<PermittedAlphabet, Error> {
+        PermittedAlphabet::try_from(&mut Tokenizer.parse(asn).into_iter().peekable())
+    }
+
+    #[test]
+    fn test_ranges_and_single_characters() {
+        assert_eq!(
+            Ok(PermittedAlphabet::from_ranges(vec![
+                ('A', 'Z'),
+                ('0', '9'),
+                (' ', ' '),
+                ('-', '-')
+            ])),
+            parse(r#"FROM ("A".."Z" | "0".."9" UNION " -")"#)
+        );
+    }
+
+    #[test]
+    fn test_whitespace_is_kept() {
+        assert_eq!(
+            &[(' ', ' '), ('a', 'b')][..],
+            parse(r#"FROM ("a b")"#).unwrap().ranges()
+        );
+        assert_eq!(&[(' ', ' ')][..], parse(r#"FROM (" ")"#).unwrap().ranges());
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        assert!(parse(r#"FROM ("AB".."Z")"#).is_err());
+        assert!(parse(r#"FROM ("A".."YZ")"#).is_err());
+        assert!(parse(r#"FROM ("")"#).is_err());
+        assert!(parse(r#"FROM ("A".."Z", ...)"#).is_err());
+    }
+
+    #[test]
+    fn test_constraint_string() {
+        assert_eq!(None, PermittedAlphabet::default().to_constraint_string());
+        assert_eq!(
+            Some("from('\\'', '0'..='9')".to_string()),
+            PermittedAlphabet::from_ranges(vec![('0', '9'), ('\'', '\'')]).to_constraint_string()
+        );
+    }
+}
@@ -1,14 +1,18 @@
 use crate::model::lor::{Error as ResolveError, TryResolve, Unresolved};
-use crate::model::lor::{ResolveState, Resolved, Resolver};
+use crate::model::lor::{Expander, Instantiator, ResolveState, Resolved, Resolver};
 use crate::model::{
     BitString, Charset, Choice, ChoiceVariant, ComponentTypeList, Enumerated, Field, Integer,
-    LitOrRef, LiteralValue, Range, Size, Tag, TagProperty, Target,
+    LitOrRef, LiteralValue, ObjectClass, ObjectSet, OpenTypeChoice, PermittedAlphabet, Range, Real,
+    Size, Tag, TagMode, TagProperty, Target, TimeType,
 };
 use std::fmt::Debug;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Asn<RS: ResolveState = Resolved> {
     pub tag: Option<Tag>,
+    /// The `EXPLICIT` or `IMPLICIT` following the tag, otherwise the mode depends on the
+    /// [`crate::model::TagDefault`] of the module
+    pub tag_mode: Option<TagMode>,
     pub r#type: Type<RS>,
     pub default: Option<RS::ConstType>,
 }
@@ -31,11 +35,17 @@ impl<RS: ResolveState> Asn<RS> {
     pub fn opt_tagged(tag: Option<Tag>, r#type: Type<RS>) -> Self {
         Self {
             tag,
+            tag_mode: None,
             r#type,
             default: None,
         }
     }
 
+    pub fn with_tag_mode(mut self, tag_mode: Option<TagMode>) -> Self {
+        self.tag_mode = tag_mode;
+        self
+    }
+
     pub fn untagged(r#type: Type<RS>) -> Self {
         Self::opt_tagged(None, r#type)
     }
@@ -70,14 +80,41 @@ impl Asn<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
     >(
         &self,
         resolver: &R,
     ) -> Result<Asn<Resolved>, ResolveError> {
-        let r#type = self.r#type.try_resolve(resolver)?;
+        let (tag, tag_mode, r#type) = match &self.r#type {
+            // the tag of the parameterized type applies, unless the instance is tagged itself
+            Type::Instance(instance) if self.tag.is_none() => {
+                let instance = resolver.instantiate(instance)?;
+                (instance.tag, instance.tag_mode, instance.r#type)
+            }
+            Type::Instance(instance) => {
+                let instance = resolver.instantiate(instance)?;
+                (self.tag, self.tag_mode, instance.r#type)
+            }
+            r#type => match r#type.no_optional() {
+                // like for instances, the tag of the alternative applies unless tagged itself
+                Type::Selection(selection) if self.tag.is_none() => {
+                    let selected = resolver.select(selection)?;
+                    (
+                        selected.tag,
+                        selected.tag_mode,
+                        r#type.try_resolve(resolver)?,
+                    )
+                }
+                _ => (self.tag, self.tag_mode, r#type.try_resolve(resolver)?),
+            },
+        };
         Ok(Asn {
-            tag: self.tag,
+            tag,
+            tag_mode,
             default: self
                 .default
                 .as_ref()
@@ -120,13 +157,21 @@ pub enum Type<RS: ResolveState = Resolved> {
     Boolean,
     /// ITU-T X.680 | ISO/IEC 8824-1, 19
     Integer(Integer<RS::RangeType>),
-    String(Size<RS::SizeType>, Charset),
+    /// ITU-T X.680 | ISO/IEC 8824-1, 21
+    Real(Real),
+    String(Size<RS::SizeType>, Charset, PermittedAlphabet),
     /// ITU-T X.680 | ISO/IEC 8824-1, 23
     OctetString(Size<RS::SizeType>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 22
     BitString(BitString<RS::SizeType>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 24
     Null,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 32
+    ObjectIdentifier,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 33
+    RelativeOid,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 38.4.1, 46 and 47
+    Time(TimeType),
 
     Optional(Box<Type<RS>>),
     Default(Box<Type<RS>>, LiteralValue),
@@ -146,6 +191,23 @@ pub enum Type<RS: ResolveState = Resolved> {
 
     /// ITU-T X.680 | ISO/IEC 8824-1, 16
     TypeReference(String, Option<Tag>),
+
+    /// ITU-T X.681 | ISO/IEC 8824-2, 14
+    ObjectClassField(RS::ObjectClassFieldType),
+    /// ITU-T X.682 | ISO/IEC 8824-3, 10.7
+    OpenTypeChoice(OpenTypeChoice<RS>),
+    /// ITU-T X.681 | ISO/IEC 8824-2, 14.2, a value of any type like `TYPE-IDENTIFIER.&Type` or
+    /// the historic `ANY` and `ANY DEFINED BY`, kept in its encoded form
+    OpenType,
+    /// A component whose type depends on the value of the component with the given name
+    Related(String, Box<Type<RS>>),
+    /// ITU-T X.683 | ISO/IEC 8824-4, 9
+    Instance(RS::InstanceType),
+    /// ITU-T X.680 | ISO/IEC 8824-1, 30
+    Selection(RS::SelectionType),
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.4, only valid as the type of a placeholder component
+    /// in the component list of a SEQUENCE or SET
+    ComponentsOf(RS::ComponentsOfType),
 }
 
 impl Type {
@@ -157,13 +219,14 @@ impl Type {
         Self::Sequence(ComponentTypeList {
             fields,
             extension_after: None,
+            extension_groups: Vec::new(),
         })
     }
 }
 
 impl<RS: ResolveState> Type<RS> {
     pub fn unconstrained_utf8string() -> Self {
-        Self::String(Size::Any, Charset::Utf8)
+        Self::String(Size::Any, Charset::Utf8, PermittedAlphabet::default())
     }
 
     pub fn unconstrained_octetstring() -> Self {
@@ -211,6 +274,14 @@ impl<RS: ResolveState> Type<RS> {
         Asn::untagged(self)
     }
 
+    pub fn no_optional(&self) -> &Self {
+        if let Self::Optional(inner) = self {
+            inner.no_optional()
+        } else {
+            self
+        }
+    }
+
     pub fn no_optional_mut(&mut self) -> &mut Self {
         if let Self::Optional(inner) = self {
             inner.no_optional_mut()
@@ -225,7 +296,11 @@ impl Type<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
     >(
         &self,
         resolver: &R,
@@ -233,10 +308,16 @@ impl Type<Unresolved> {
         Ok(match self {
             Type::Boolean => Type::Boolean,
             Type::Integer(integer) => Type::Integer(integer.try_resolve(resolver)?),
-            Type::String(size, charset) => Type::String(size.try_resolve(resolver)?, *charset),
+            Type::Real(real) => Type::Real(real.clone()),
+            Type::String(size, charset, alphabet) => {
+                Type::String(size.try_resolve(resolver)?, *charset, alphabet.clone())
+            }
             Type::OctetString(size) => Type::OctetString(size.try_resolve(resolver)?),
             Type::BitString(string) => Type::BitString(string.try_resolve(resolver)?),
             Type::Null => Type::Null,
+            Type::ObjectIdentifier => Type::ObjectIdentifier,
+            Type::RelativeOid => Type::RelativeOid,
+            Type::Time(time) => Type::Time(*time),
             Type::Optional(inner) => Type::Optional(Box::new(inner.try_resolve(resolver)?)),
             Type::Default(inner, default) => {
                 Type::Default(Box::new(inner.try_resolve(resolver)?), default.clone())
@@ -254,6 +335,20 @@ impl Type<Unresolved> {
             Type::Enumerated(e) => Type::Enumerated(e.clone()),
             Type::Choice(c) => Type::Choice(c.try_resolve(resolver)?),
             Type::TypeReference(name, tag) => Type::TypeReference(name.clone(), *tag),
+            Type::ObjectClassField(field) => field.try_resolve(resolver)?,
+            Type::OpenTypeChoice(choice) => Type::OpenTypeChoice(choice.try_resolve(resolver)?),
+            Type::OpenType => Type::OpenType,
+            Type::Related(relation, inner) => {
+                Type::Related(relation.clone(), Box::new(inner.try_resolve(resolver)?))
+            }
+            Type::Instance(instance) => resolver.instantiate(instance)?.r#type,
+            Type::Selection(selection) => resolver.select(selection)?.r#type,
+            Type::ComponentsOf(name) => {
+                return Err(ResolveError::FailedToResolveType(format!(
+                    "COMPONENTS OF {}",
+                    name
+                )))
+            }
         })
     }
 }
@@ -1,4 +1,6 @@
 use crate::model::Tag;
+use std::borrow::Cow;
+use std::convert::TryFrom;
 
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, EnumString)]
 #[strum(serialize_all = "lowercase")]
@@ -9,16 +11,24 @@ pub enum Charset {
     /// ITU-T X.680 | ISO/IEC 8824-1, 43.3
     Printable,
 
-    // /// (Also T61String)
-    // Teletext,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41 (Also T61String), the octets of the ISO 2022 escape
+    /// sequences and graphic sets are mapped one-to-one to the characters up to U+00FF
+    Teletex,
     // Videotext,
     /// Encoding as in ISO/IEC 646 (??)
     Ia5,
 
-    // GraphicsString,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, mapped to characters like [`Charset::Teletex`]
+    Graphic,
     /// ITU-T X.680 | ISO/IEC 8824-1, 43.3
     /// (Also ISO646String)
     Visible,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, mapped to characters like [`Charset::Teletex`]
+    General,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, any character of ISO/IEC 10646
+    Universal,
+    /// ITU-T X.680 | ISO/IEC 8824-1, 41, the Basic Multilingual Plane of ISO/IEC 10646
+    Bmp,
 }
 
 impl Charset {
@@ -74,6 +84,88 @@ impl Charset {
             Charset::Printable => Tag::DEFAULT_PRINTABLE_STRING,
             Charset::Ia5 => Tag::DEFAULT_IA5_STRING,
             Charset::Visible => Tag::DEFAULT_VISIBLE_STRING,
+            Charset::Teletex => Tag::DEFAULT_TELETEXT_STRING,
+            Charset::Graphic => Tag::DEFAULT_GRAPHIC_STRING,
+            Charset::General => Tag::DEFAULT_GENERAL_STRING,
+            Charset::Universal => Tag::DEFAULT_UNIVERSAL_STRING,
+            Charset::Bmp => Tag::DEFAULT_BMP_STRING,
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2, the known-multiplier character string types have a fixed
+    /// number of octets per character, all others are encoded like an `OCTET STRING` in PER
+    pub const fn is_known_multiplier(self) -> bool {
+        !matches!(
+            self,
+            Charset::Utf8 | Charset::Teletex | Charset::Graphic | Charset::General
+        )
+    }
+
+    /// The number of octets per character in [`Charset::encode_octets`], if fixed
+    pub const fn octets_per_char(self) -> Option<usize> {
+        match self {
+            Charset::Utf8 => None,
+            Charset::Bmp => Some(2),
+            Charset::Universal => Some(4),
+            _ => Some(1),
+        }
+    }
+
+    /// The octets of a string as in the contents octets of BER: big-endian UCS-2 for
+    /// `BMPString`, UCS-4 for `UniversalString`, one octet per character for the 8-bit charsets
+    /// and UTF-8 for all others. The characters are not checked, see [`Charset::find_invalid`].
+    /// ```rust
+    /// use asn1rs_model::model::Charset;
+    /// assert_eq!(&[0x00, 0x41, 0x20, 0xAC][..], &Charset::Bmp.encode_octets("A€")[..]);
+    /// assert_eq!(&[0x00, 0x01, 0xF6, 0x00][..], &Charset::Universal.encode_octets("😀")[..]);
+    /// assert_eq!(&[0xE9][..], &Charset::Teletex.encode_octets("é")[..]);
+    /// assert_eq!(Some("A€".to_string()), Charset::Bmp.decode_octets(vec![0x00, 0x41, 0x20, 0xAC]));
+    /// assert_eq!(None, Charset::Bmp.decode_octets(vec![0xD8, 0x00]));
+    /// ```
+    pub fn encode_octets(self, value: &str) -> Cow<'_, [u8]> {
+        match self {
+            Charset::Bmp => Cow::Owned(
+                value
+                    .chars()
+                    .flat_map(|char| (char as u16).to_be_bytes())
+                    .collect(),
+            ),
+            Charset::Universal => Cow::Owned(
+                value
+                    .chars()
+                    .flat_map(|char| (char as u32).to_be_bytes())
+                    .collect(),
+            ),
+            Charset::Teletex | Charset::Graphic | Charset::General => {
+                Cow::Owned(value.chars().map(|char| char as u8).collect())
+            }
+            _ => Cow::Borrowed(value.as_bytes()),
+        }
+    }
+
+    /// The reverse of [`Charset::encode_octets`], `None` if the octets do not form a sequence of
+    /// characters
+    pub fn decode_octets(self, octets: Vec<u8>) -> Option<String> {
+        match self {
+            Charset::Bmp | Charset::Universal => {
+                let width = self.octets_per_char().unwrap_or(1);
+                if octets.len() % width != 0 {
+                    return None;
+                }
+                octets
+                    .chunks_exact(width)
+                    .map(|octets| {
+                        let char = octets
+                            .iter()
+                            .fold(0_u32, |char, octet| char << 8 | u32::from(*octet));
+                        char::try_from(char).ok()
+                    })
+                    .collect()
+            }
+            Charset::Teletex | Charset::Graphic | Charset::General => {
+                Some(octets.into_iter().map(char::from).collect())
+            }
+            _ => String::from_utf8(octets).ok(),
         }
     }
 
@@ -92,6 +184,9 @@ impl Charset {
             }
             Charset::Ia5 => matches!(char as u32, 0_u32..=127),
             Charset::Visible => matches!(char as u32, 32_u32..=126),
+            Charset::Teletex | Charset::Graphic | Charset::General => char as u32 <= 0xFF,
+            Charset::Universal => true,
+            Charset::Bmp => char as u32 <= 0xFFFF,
         }
     }
 }
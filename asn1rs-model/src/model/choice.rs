@@ -1,5 +1,10 @@
-use crate::model::lor::{Error as ResolveError, ResolveState, Resolved, Resolver, Unresolved};
-use crate::model::{Asn, Error, Model, PeekableTokens, Tag, TagProperty, Type};
+use crate::model::lor::{
+    Error as ResolveError, Expander, Instantiator, ResolveState, Resolved, Resolver, Unresolved,
+};
+use crate::model::{
+    Asn, Error, ExtensionAdditionGroup, Model, ObjectClass, ObjectSet, PeekableTokens, Tag,
+    TagMode, TagProperty, Type,
+};
 use crate::parser::Token;
 use std::convert::TryFrom;
 
@@ -9,6 +14,7 @@ use std::iter::Peekable;
 pub struct Choice<RS: ResolveState = Resolved> {
     variants: Vec<ChoiceVariant<RS>>,
     extension_after: Option<usize>,
+    extension_groups: Vec<ExtensionAdditionGroup>,
 }
 
 impl<RS: ResolveState> From<Vec<ChoiceVariant<RS>>> for Choice<RS> {
@@ -16,6 +22,7 @@ impl<RS: ResolveState> From<Vec<ChoiceVariant<RS>>> for Choice<RS> {
         Self {
             variants,
             extension_after: None,
+            extension_groups: Vec::default(),
         }
     }
 }
@@ -25,6 +32,7 @@ impl<RS: ResolveState> Choice<RS> {
         Self {
             variants: variants.collect(),
             extension_after: None,
+            extension_groups: Vec::default(),
         }
     }
 
@@ -50,6 +58,10 @@ impl<RS: ResolveState> Choice<RS> {
         self.variants.iter()
     }
 
+    pub fn variants_mut(&mut self) -> impl Iterator<Item = &mut ChoiceVariant<RS>> {
+        self.variants.iter_mut()
+    }
+
     pub fn is_extensible(&self) -> bool {
         self.extension_after.is_some()
     }
@@ -57,6 +69,12 @@ impl<RS: ResolveState> Choice<RS> {
     pub fn extension_after_index(&self) -> Option<usize> {
         self.extension_after
     }
+
+    /// The groups of extension alternatives, which are encoded like any other extension
+    /// alternative
+    pub fn extension_groups(&self) -> &[ExtensionAdditionGroup] {
+        &self.extension_groups
+    }
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved> {
@@ -67,6 +85,7 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved>
         let mut choice = Choice {
             variants: Vec::new(),
             extension_after: None,
+            extension_groups: Vec::new(),
         };
 
         loop {
@@ -80,14 +99,29 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved>
                     iter.next_separator_eq_or_err('.')?;
                     choice.extension_after = Some(choice.variants.len() - 1);
                 }
+            } else if let Ok(bracket) = iter.next_if_separator_and_eq('[') {
+                if choice.extension_after.is_none() {
+                    return Err(Error::invalid_position_for_extension_marker(bracket));
+                }
+                iter.next_separator_eq_or_err('[')?;
+                let version = ExtensionAdditionGroup::read_version(iter)?;
+                let start = choice.variants.len();
+                loop {
+                    choice.variants.push(Self::read_variant(iter)?);
+                    match iter.next_or_err()? {
+                        token if token.eq_separator(',') => continue,
+                        token if token.eq_separator(']') => break,
+                        token => return Err(Error::unexpected_token(token)),
+                    }
+                }
+                iter.next_separator_eq_or_err(']')?;
+                choice.extension_groups.push(ExtensionAdditionGroup {
+                    version,
+                    start,
+                    end: choice.variants.len(),
+                });
             } else {
-                let name = iter.next_text_or_err()?;
-                let (token, tag) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
-                let r#type = Model::<Asn<Unresolved>>::read_role_given_text(
-                    iter,
-                    token.into_text_or_else(Error::no_text)?,
-                )?;
-                choice.variants.push(ChoiceVariant { name, tag, r#type });
+                choice.variants.push(Self::read_variant(iter)?);
             }
 
             loop_ctrl_separator!(iter.next_or_err()?);
@@ -98,11 +132,33 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved>
 }
 
 impl Choice<Unresolved> {
+    fn read_variant<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<ChoiceVariant<Unresolved>, Error> {
+        let name = iter.next_text_or_err()?;
+        let (token, tag, tag_mode) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
+        let r#type = Model::<Asn<Unresolved>>::read_role_given_text(
+            iter,
+            token.into_text_or_else(Error::no_text)?,
+        )?;
+        Ok(ChoiceVariant {
+            name,
+            identifier: None,
+            tag,
+            tag_mode,
+            r#type,
+        })
+    }
+
     pub fn try_resolve<
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
     >(
         &self,
         resolver: &R,
@@ -114,6 +170,7 @@ impl Choice<Unresolved> {
                 .map(|v| v.try_resolve(resolver))
                 .collect::<Result<Vec<_>, _>>()?,
             extension_after: self.extension_after,
+            extension_groups: self.extension_groups.clone(),
         })
     }
 }
@@ -121,16 +178,30 @@ impl Choice<Unresolved> {
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct ChoiceVariant<RS: ResolveState = Resolved> {
     pub name: String,
+    /// The identifier in the ASN.1 definition, if it differs from `name`
+    pub identifier: Option<String>,
     pub tag: Option<Tag>,
+    /// See [`Asn::tag_mode`]
+    pub tag_mode: Option<TagMode>,
     pub r#type: Type<RS>,
 }
 
+/// ITU-T X.680 | ISO/IEC 8824-1, 30, the type of an alternative of a CHOICE, such as
+/// `alternative < Choice`
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct Selection {
+    pub alternative: String,
+    pub choice: String,
+}
+
 impl<RS: ResolveState> ChoiceVariant<RS> {
     #[cfg(test)]
     pub fn name_type<I: ToString>(name: I, r#type: Type<RS>) -> Self {
         ChoiceVariant {
             name: name.to_string(),
+            identifier: None,
             tag: None,
+            tag_mode: None,
             r#type,
         }
     }
@@ -139,6 +210,11 @@ impl<RS: ResolveState> ChoiceVariant<RS> {
         &self.name
     }
 
+    /// The identifier of this variant in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+
     pub fn r#type(&self) -> &Type<RS> {
         &self.r#type
     }
@@ -163,14 +239,20 @@ impl ChoiceVariant<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
     >(
         &self,
         resolver: &R,
     ) -> Result<ChoiceVariant<Resolved>, ResolveError> {
         Ok(ChoiceVariant {
             name: self.name.clone(),
+            identifier: self.identifier.clone(),
             tag: self.tag,
+            tag_mode: self.tag_mode,
             r#type: self.r#type.try_resolve(resolver)?,
         })
     }
@@ -1,15 +1,61 @@
-use crate::model::lor::{Error as ResolveError, Resolved, Resolver};
+use crate::model::lor::{Error as ResolveError, Expander, Instantiator, Resolved, Resolver};
 use crate::model::lor::{ResolveState, Unresolved};
-use crate::model::{Asn, Error, Field, Model, PeekableTokens, Type};
+use crate::model::{Asn, Error, Field, Model, ObjectClass, ObjectSet, PeekableTokens, Type};
 use crate::parser::Token;
 use std::convert::TryFrom;
 use std::iter::Peekable;
+use std::ops::Range;
 
 /// ITU-T X.680 | ISO/IEC 8824-1:2015, Annex L
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct ComponentTypeList<RS: ResolveState = Unresolved> {
     pub fields: Vec<Field<Asn<RS>>>,
     pub extension_after: Option<usize>,
+    /// The extension addition groups, which hold some of the `fields` after the extension marker.
+    /// Without an extension marker, a single group of all `fields` describes the content of such
+    /// a group on its own.
+    pub extension_groups: Vec<ExtensionAdditionGroup>,
+}
+
+impl<RS: ResolveState> ComponentTypeList<RS> {
+    /// Whether this only describes the content of an extension addition group
+    pub fn is_extension_group(&self) -> bool {
+        self.extension_after.is_none()
+            && matches!(
+                &self.extension_groups[..],
+                [group] if group.range() == (0..self.fields.len())
+            )
+    }
+}
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 25.1 and 29.1, the components (or alternatives) between `[[` and
+/// `]]`, which are encoded as a single extension addition by PER and OER
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub struct ExtensionAdditionGroup {
+    pub version: Option<u64>,
+    /// The index of the first component in the group
+    pub start: usize,
+    /// The index after the last component in the group
+    pub end: usize,
+}
+
+impl ExtensionAdditionGroup {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Reads the optional version number after the opening `[[`
+    pub(crate) fn read_version<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<Option<u64>, Error> {
+        if iter.peek_is_text_and_satisfies(|text| text.parse::<u64>().is_ok()) {
+            let version = iter.next_text_or_err()?.parse::<u64>().ok();
+            iter.next_separator_eq_or_err(':')?;
+            Ok(version)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<Unresolved> {
@@ -20,6 +66,7 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
         let mut sequence = Self {
             fields: Vec::default(),
             extension_after: None,
+            extension_groups: Vec::default(),
         };
 
         loop {
@@ -31,15 +78,63 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
                 let field_len = sequence.fields.len();
                 sequence.extension_after = Some(field_len.saturating_sub(1));
 
+                match iter.next_or_err()? {
+                    token if token.eq_separator(',') => true,
+                    token if token.eq_separator('}') => false,
+                    token => return Err(Error::unexpected_token(token)),
+                }
+            } else if let Ok(bracket) = iter.next_if_separator_and_eq('[') {
+                if sequence.extension_after.is_none() {
+                    return Err(Error::invalid_position_for_extension_marker(bracket));
+                }
+                iter.next_separator_eq_or_err('[')?;
+                let version = ExtensionAdditionGroup::read_version(iter)?;
+                let start = sequence.fields.len();
+                loop {
+                    let (field, token) = Model::<Asn<Unresolved>>::read_field(iter)?;
+                    sequence.fields.push(field);
+                    match token {
+                        token if token.eq_separator(',') => continue,
+                        token if token.eq_separator(']') => break,
+                        token => return Err(Error::unexpected_token(token)),
+                    }
+                }
+                iter.next_separator_eq_or_err(']')?;
+                sequence.extension_groups.push(ExtensionAdditionGroup {
+                    version,
+                    start,
+                    end: sequence.fields.len(),
+                });
+
+                match iter.next_or_err()? {
+                    token if token.eq_separator(',') => true,
+                    token if token.eq_separator('}') => false,
+                    token => return Err(Error::unexpected_token(token)),
+                }
+            } else if iter.peek_is_text_eq("COMPONENTS") {
+                let _ = iter.next();
+                iter.next_text_eq_ignore_case_or_err("OF")?;
+                let name = iter.next_text_or_err()?;
+                // replaced by the components of the referenced type when resolved
+                sequence.fields.push(Field {
+                    name: name.clone(),
+                    identifier: None,
+                    role: Type::ComponentsOf(name).untagged(),
+                });
+
                 match iter.next_or_err()? {
                     token if token.eq_separator(',') => true,
                     token if token.eq_separator('}') => false,
                     token => return Err(Error::unexpected_token(token)),
                 }
             } else {
-                let (field, continues) = Model::<Asn<Unresolved>>::read_field(iter)?;
+                let (field, token) = Model::<Asn<Unresolved>>::read_field(iter)?;
                 sequence.fields.push(field);
-                continues
+                match token {
+                    token if token.eq_separator(',') => true,
+                    token if token.eq_separator('}') => false,
+                    token => return Err(Error::unexpected_token(token)),
+                }
             };
 
             if !continues {
@@ -47,27 +142,78 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
             }
         }
 
+        sequence.assign_relation_fields();
         Ok(sequence)
     }
 }
 
 impl ComponentTypeList<Unresolved> {
+    /// For each component relation constraint, remembers the class field that the referenced
+    /// component refers to, because that field is the key for selecting the type
+    fn assign_relation_fields(&mut self) {
+        let referenced_fields = self
+            .fields
+            .iter()
+            .map(|f| match f.role.r#type.no_optional() {
+                Type::ObjectClassField(ocf) => Some((f.name.clone(), ocf.field.clone())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for field in &mut self.fields {
+            if let Type::ObjectClassField(ocf) = field.role.r#type.no_optional_mut() {
+                ocf.relation_field = ocf.relation.as_ref().and_then(|relation| {
+                    referenced_fields
+                        .iter()
+                        .flatten()
+                        .find(|(name, _)| name.eq(relation))
+                        .map(|(_, field)| field.clone())
+                });
+            }
+        }
+    }
+
     pub fn try_resolve<
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
     >(
         &self,
         resolver: &R,
     ) -> Result<ComponentTypeList<Resolved>, ResolveError> {
+        let mut fields = Vec::with_capacity(self.fields.len());
+        let mut extension_after = None;
+        // where each component starts after the expansion, to move the groups along
+        let mut starts = Vec::with_capacity(self.fields.len() + 1);
+        for (index, field) in self.fields.iter().enumerate() {
+            starts.push(fields.len());
+            match &field.role.r#type {
+                Type::ComponentsOf(name) => fields.extend(resolver.components_of(name)?),
+                _ => fields.push(field.try_resolve(resolver)?),
+            }
+            // the expanded components shift the extension marker
+            if self.extension_after == Some(index) {
+                extension_after = Some(fields.len().saturating_sub(1));
+            }
+        }
+        starts.push(fields.len());
         Ok(ComponentTypeList {
-            fields: self
-                .fields
+            fields,
+            extension_after,
+            extension_groups: self
+                .extension_groups
                 .iter()
-                .map(|f| f.try_resolve(resolver))
-                .collect::<Result<Vec<_>, _>>()?,
-            extension_after: self.extension_after,
+                .map(|group| ExtensionAdditionGroup {
+                    version: group.version,
+                    start: starts[group.start],
+                    end: starts[group.end],
+                })
+                .collect(),
         })
     }
 }
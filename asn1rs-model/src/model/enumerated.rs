@@ -62,6 +62,39 @@ impl Enumerated {
     pub fn extension_after_index(&self) -> Option<usize> {
         self.extension_after
     }
+
+    /// The values of the variants, where a variant without a number has the least value that is
+    /// not used by another root variant or, for an extension addition, the least unused value
+    /// greater than the one of the previous addition (ITU-T X.680 | ISO/IEC 8824-1, 20.3 and 20.4)
+    pub fn values(&self) -> Vec<i64> {
+        let root_len = self
+            .extension_after
+            .map_or(self.variants.len(), |index| index + 1);
+        let mut values = Vec::with_capacity(self.variants.len());
+        let mut used = self.variants[..root_len]
+            .iter()
+            .filter_map(|variant| variant.number)
+            .map(|number| number as i64)
+            .collect::<Vec<_>>();
+        let mut next = 0;
+        for (index, variant) in self.variants.iter().enumerate() {
+            let value = match variant.number {
+                Some(number) => number as i64,
+                None => {
+                    while used.contains(&next) {
+                        next += 1;
+                    }
+                    next
+                }
+            };
+            if index >= root_len {
+                next = next.max(value + 1);
+            }
+            used.push(value);
+            values.push(value);
+        }
+        values
+    }
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
@@ -120,6 +153,8 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
 pub struct EnumeratedVariant {
     pub(crate) name: String,
     pub(crate) number: Option<usize>,
+    /// The identifier in the ASN.1 definition, if it differs from `name`
+    pub(crate) identifier: Option<String>,
 }
 
 #[cfg(test)]
@@ -134,6 +169,7 @@ impl EnumeratedVariant {
         Self {
             name: name.to_string(),
             number: None,
+            identifier: None,
         }
     }
 
@@ -141,6 +177,7 @@ impl EnumeratedVariant {
         Self {
             name: name.to_string(),
             number: Some(number),
+            identifier: None,
         }
     }
 
@@ -153,6 +190,11 @@ impl EnumeratedVariant {
         self
     }
 
+    pub fn with_identifier_opt(mut self, identifier: Option<String>) -> Self {
+        self.identifier = identifier;
+        self
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -160,4 +202,29 @@ impl EnumeratedVariant {
     pub fn number(&self) -> Option<usize> {
         self.number
     }
+
+    /// The identifier of this variant in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_values_of_unnumbered_variants() {
+        let enumerated = Enumerated::from(vec![
+            EnumeratedVariant::from_name("a"),
+            EnumeratedVariant::from_name_number("b", 0),
+            EnumeratedVariant::from_name("c"),
+            EnumeratedVariant::from_name_number("d", 5),
+            EnumeratedVariant::from_name("e"),
+            EnumeratedVariant::from_name_number("f", 9),
+            EnumeratedVariant::from_name("g"),
+        ])
+        .with_extension_after(3);
+        assert_eq!(vec![1, 0, 2, 5, 3, 9, 10], enumerated.values());
+    }
 }
@@ -0,0 +1,809 @@
+//! Information object classes, information object sets and the table constraints referring to
+//! them, see ITU-T X.681 | ISO/IEC 8824-2 and ITU-T X.682 | ISO/IEC 8824-3
+
+use crate::model::lor::{
+    Error as ResolveError, Expander, Instantiator, ResolveState, Resolved, Resolver, Unresolved,
+};
+use crate::model::{Asn, Error, ErrorKind, LitOrRef, LiteralValue, Model, PeekableTokens, Type};
+use crate::parser::{Token, Tokenizer};
+use std::convert::TryFrom;
+use std::iter::Peekable;
+
+/// ITU-T X.681 | ISO/IEC 8824-2, 9
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ObjectClass {
+    pub fields: Vec<FieldSpec>,
+    /// The syntax for defining objects of this class, ITU-T X.681 | ISO/IEC 8824-2, 10. Without
+    /// it, objects are defined by listing the field names and their settings separated by comma.
+    pub syntax: Option<Vec<SyntaxElement>>,
+}
+
+impl ObjectClass {
+    /// The name of the only predefined class, see [`ObjectClass::type_identifier`]
+    pub const TYPE_IDENTIFIER: &'static str = "TYPE-IDENTIFIER";
+
+    /// ITU-T X.681 | ISO/IEC 8824-2, A.2, the class that relates a type to an object identifier
+    pub fn type_identifier() -> Self {
+        let tokens = Tokenizer::default().parse(
+            "{ &id OBJECT IDENTIFIER UNIQUE, &Type } WITH SYNTAX { &Type IDENTIFIED BY &id }",
+        );
+        Self::try_from(&mut tokens.into_iter().peekable())
+            .expect("The definition of TYPE-IDENTIFIER is valid")
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldSpec> {
+        self.fields.iter().find(|f| f.name.eq(name))
+    }
+}
+
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ObjectClass {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        iter.next_separator_eq_or_err('{')?;
+        let mut fields = Vec::new();
+
+        loop {
+            fields.push(FieldSpec::try_from(&mut *iter)?);
+            loop_ctrl_separator!(iter.next_or_err()?);
+        }
+
+        let syntax = if iter.next_is_text_and_eq_ignore_case("WITH") {
+            iter.next_text_eq_ignore_case_or_err("SYNTAX")?;
+            iter.next_separator_eq_or_err('{')?;
+            Some(SyntaxElement::read_until(iter, '}')?)
+        } else {
+            None
+        };
+
+        Ok(Self { fields, syntax })
+    }
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2, 9.2
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct FieldSpec {
+    /// The name including the leading `&`
+    pub name: String,
+    pub kind: FieldKind,
+    pub unique: bool,
+    /// Whether the field is `OPTIONAL` or has a `DEFAULT` setting
+    pub optional: bool,
+}
+
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for FieldSpec {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        let token = iter.next_or_err()?;
+        let is_type_like = match token.text().and_then(|t| t.strip_prefix('&')) {
+            Some(name) => name.starts_with(|c: char| c.is_ascii_uppercase()),
+            None => return Err(Error::unexpected_token(token)),
+        };
+        let name = token.into_text_or_else(Error::no_text)?;
+
+        let has_type = !iter.peek_is_separator_eq(',')
+            && !iter.peek_is_separator_eq('}')
+            && !iter.peek_is_text_eq_ignore_case("UNIQUE")
+            && !iter.peek_is_text_eq_ignore_case("OPTIONAL")
+            && !iter.peek_is_text_eq_ignore_case("DEFAULT");
+
+        let kind = match (is_type_like, has_type) {
+            (true, false) => FieldKind::Type,
+            (true, true) => FieldKind::ValueSet(Model::<Asn<Unresolved>>::read_role(iter)?),
+            (false, true) => FieldKind::Value(Model::<Asn<Unresolved>>::read_role(iter)?),
+            (false, false) => return Err(Error::unexpected_token(iter.next_or_err()?)),
+        };
+
+        let mut spec = Self {
+            name,
+            kind,
+            unique: false,
+            optional: false,
+        };
+
+        loop {
+            if iter.next_is_text_and_eq_ignore_case("UNIQUE") {
+                spec.unique = true;
+            } else if iter.next_is_text_and_eq_ignore_case("OPTIONAL") {
+                spec.optional = true;
+            } else if iter.next_is_text_and_eq_ignore_case("DEFAULT") {
+                // the default setting is not needed to determine the alternatives of open types
+                spec.optional = true;
+                skip_setting(iter)?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(spec)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum FieldKind {
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9.5
+    Type,
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9.6
+    Value(Type<Unresolved>),
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9.8
+    ValueSet(Type<Unresolved>),
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2, 10.5
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub enum SyntaxElement {
+    /// A word or a comma that needs to be present literally
+    Literal(String),
+    /// The setting of the field with the given name
+    Field(String),
+    /// A group of elements that may be omitted as a whole
+    Optional(Vec<SyntaxElement>),
+}
+
+impl SyntaxElement {
+    fn read_until<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        end: char,
+    ) -> Result<Vec<Self>, Error> {
+        let mut elements = Vec::new();
+        loop {
+            let token = iter.next_or_err()?;
+            if token.eq_separator(end) {
+                return Ok(elements);
+            } else if token.eq_separator('[') {
+                elements.push(SyntaxElement::Optional(Self::read_until(iter, ']')?));
+            } else if token.eq_separator(',') {
+                elements.push(SyntaxElement::Literal(",".to_string()));
+            } else if let Some(text) = token.text() {
+                if text.starts_with('&') {
+                    elements.push(SyntaxElement::Field(text.to_string()));
+                } else {
+                    elements.push(SyntaxElement::Literal(text.to_string()));
+                }
+            } else {
+                return Err(Error::unexpected_token(token));
+            }
+        }
+    }
+
+    fn peek_is_start<T: Iterator<Item = Token>>(&self, iter: &mut Peekable<T>) -> bool {
+        match self {
+            SyntaxElement::Literal(literal) if literal == "," => iter.peek_is_separator_eq(','),
+            SyntaxElement::Literal(literal) => iter.peek_is_text_eq(literal),
+            SyntaxElement::Field(_) => iter.peek().is_some(),
+            SyntaxElement::Optional(group) => group
+                .first()
+                .map(|element| element.peek_is_start(iter))
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// ITU-T X.681 | ISO/IEC 8824-2, 12
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ObjectSet {
+    /// The name of the class of the objects in this set
+    pub class: String,
+    pub elements: Vec<ObjectSetElement>,
+    pub extensible: bool,
+}
+
+impl ObjectSet {
+    pub(crate) fn read<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        class: String,
+    ) -> Result<Self, Error> {
+        iter.next_separator_eq_or_err('{')?;
+        let mut set = Self {
+            class,
+            elements: Vec::new(),
+            extensible: false,
+        };
+
+        loop {
+            let token = iter.next_or_err()?;
+            if token.eq_separator('}') {
+                return Ok(set);
+            } else if token.eq_separator(',')
+                || token.eq_text("|")
+                || token.eq_text_ignore_ascii_case("UNION")
+            {
+                continue;
+            } else if token.eq_separator('.') {
+                iter.next_separator_eq_or_err('.')?;
+                iter.next_separator_eq_or_err('.')?;
+                set.extensible = true;
+            } else if token.eq_separator('{') {
+                set.elements
+                    .push(ObjectSetElement::Object(read_until_closing_brace(iter)?));
+            } else if let Some(text) = token.text() {
+                set.elements
+                    .push(ObjectSetElement::Reference(text.to_string()));
+            } else {
+                return Err(Error::unexpected_token(token));
+            }
+        }
+    }
+
+    /// Collects the objects of this set and all sets it refers to, in order of appearance, and
+    /// returns whether any of these sets is extensible
+    fn collect_objects<R: Resolver<ObjectSet>>(
+        &self,
+        resolver: &R,
+        visited: &mut Vec<String>,
+        objects: &mut Vec<Vec<Token>>,
+    ) -> Result<bool, ResolveError> {
+        let mut extensible = self.extensible;
+        for element in &self.elements {
+            match element {
+                ObjectSetElement::Object(tokens) => objects.push(tokens.clone()),
+                ObjectSetElement::Reference(name) if !visited.contains(name) => {
+                    visited.push(name.clone());
+                    extensible |= resolver
+                        .resolve(&LitOrRef::Ref(name.clone()))?
+                        .collect_objects(resolver, visited, objects)?;
+                }
+                ObjectSetElement::Reference(_) => {}
+            }
+        }
+        Ok(extensible)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub enum ObjectSetElement {
+    /// An object that is defined in place. It is kept as tokens, because it can only be parsed
+    /// with the syntax of its class, which might be defined in another module.
+    Object(Vec<Token>),
+    /// A reference to another object set
+    Reference(String),
+}
+
+/// The setting of a single field of an object
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+enum Setting {
+    Type(Type<Unresolved>),
+    Value(LitOrRef<LiteralValue>),
+    ValueSet,
+}
+
+impl Setting {
+    fn read<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        kind: &FieldKind,
+    ) -> Result<Self, Error> {
+        Ok(match kind {
+            FieldKind::Type => Setting::Type(Model::<Asn<Unresolved>>::read_role(iter)?),
+            FieldKind::Value(_) => match Model::<Asn<Unresolved>>::read_literal(iter) {
+                Ok(value) => Setting::Value(LitOrRef::Lit(value)),
+                Err(ErrorKind::UnsupportedLiteral(token)) if token.is_text() => {
+                    Setting::Value(LitOrRef::Ref(iter.next_text_or_err()?))
+                }
+                Err(e) => return Err(e.into()),
+            },
+            FieldKind::ValueSet(_) => {
+                iter.next_separator_eq_or_err('{')?;
+                let _ = read_until_closing_brace(iter)?;
+                Setting::ValueSet
+            }
+        })
+    }
+
+    /// Parses the settings of an object according to the syntax of its class
+    fn read_object(
+        tokens: Vec<Token>,
+        class: &ObjectClass,
+    ) -> Result<Vec<(String, Setting)>, Error> {
+        let mut iter = tokens.into_iter().peekable();
+        let mut settings = Vec::new();
+
+        if let Some(syntax) = &class.syntax {
+            Self::read_with_syntax(&mut iter, class, syntax, &mut settings)?;
+        } else {
+            while iter.peek().is_some() {
+                let token = iter.next_or_err()?;
+                let spec = token
+                    .text()
+                    .and_then(|name| class.field(name))
+                    .ok_or_else(|| Error::unexpected_token(token.clone()))?;
+                settings.push((spec.name.clone(), Self::read(&mut iter, &spec.kind)?));
+                if iter.peek().is_some() {
+                    iter.next_separator_eq_or_err(',')?;
+                }
+            }
+        }
+
+        match iter.next() {
+            None => Ok(settings),
+            Some(token) => Err(Error::unexpected_token(token)),
+        }
+    }
+
+    fn read_with_syntax<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        class: &ObjectClass,
+        syntax: &[SyntaxElement],
+        settings: &mut Vec<(String, Setting)>,
+    ) -> Result<(), Error> {
+        for element in syntax {
+            match element {
+                SyntaxElement::Literal(literal) if literal == "," => {
+                    iter.next_separator_eq_or_err(',')?;
+                }
+                SyntaxElement::Literal(literal) => {
+                    iter.next_text_eq_ignore_case_or_err(literal)?;
+                }
+                SyntaxElement::Field(name) => {
+                    let spec = class
+                        .field(name)
+                        .ok_or_else(|| Error::unexpected_token(Token::from(name.to_string())))?;
+                    settings.push((name.clone(), Self::read(iter, &spec.kind)?));
+                }
+                SyntaxElement::Optional(group) => {
+                    if element.peek_is_start(iter) {
+                        Self::read_with_syntax(iter, class, group, settings)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A reference to a field of an information object class, like `CLASS.&id` (ITU-T X.681 |
+/// ISO/IEC 8824-2, 14), optionally restricted by a table constraint like `({Set}{@id})`
+/// (ITU-T X.682 | ISO/IEC 8824-3, 10)
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct ObjectClassField {
+    pub class: String,
+    /// The name of the field including the leading `&`
+    pub field: String,
+    /// The object set of the table constraint
+    pub set: Option<String>,
+    /// The name of the component that is referred to by the component relation constraint
+    pub relation: Option<String>,
+    /// The field of the class that the component in `relation` refers to
+    pub relation_field: Option<String>,
+}
+
+impl ObjectClassField {
+    pub(crate) fn read<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        class: String,
+    ) -> Result<Self, Error> {
+        let field = iter.next_text_or_err()?;
+        let mut result = Self {
+            class,
+            field,
+            set: None,
+            relation: None,
+            relation_field: None,
+        };
+
+        if iter.next_is_separator_and_eq('(') {
+            iter.next_separator_eq_or_err('{')?;
+            result.set = Some(iter.next_text_or_err()?);
+            iter.next_separator_eq_or_err('}')?;
+
+            if iter.next_is_separator_and_eq('{') {
+                let path = read_until_closing_brace(iter)?
+                    .into_iter()
+                    .filter_map(Token::into_text)
+                    .collect::<String>();
+                // only components at the same level (`@id` or `@.id`) are supported for now
+                result.relation = Some(path.trim_start_matches('@').to_string());
+            }
+
+            iter.next_separator_eq_or_err(')')?;
+        }
+
+        Ok(result)
+    }
+
+    pub fn try_resolve<
+        R: Resolver<<Resolved as ResolveState>::SizeType>
+            + Resolver<<Resolved as ResolveState>::RangeType>
+            + Resolver<<Resolved as ResolveState>::ConstType>
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
+    >(
+        &self,
+        resolver: &R,
+    ) -> Result<Type<Resolved>, ResolveError> {
+        let class: ObjectClass = resolver.resolve(&LitOrRef::Ref(self.class.clone()))?;
+        let spec = class.field(&self.field).ok_or_else(|| {
+            ResolveError::FailedToResolveReference(format!("{}.{}", self.class, self.field))
+        })?;
+
+        match &spec.kind {
+            FieldKind::Value(r#type) | FieldKind::ValueSet(r#type) => {
+                // keys need to be integers in the generated code, not wrappers around them
+                if let Type::TypeReference(name, None) = r#type {
+                    if let Ok(integer @ Type::Integer(_)) =
+                        resolver.resolve(&LitOrRef::<Type<Unresolved>>::Ref(name.clone()))
+                    {
+                        return integer.try_resolve(resolver);
+                    }
+                }
+                r#type.try_resolve(resolver)
+            }
+            FieldKind::Type => match (&self.set, &self.relation, &self.relation_field) {
+                (Some(set), Some(relation), Some(relation_field)) => Ok(Type::Related(
+                    relation.clone(),
+                    Box::new(Type::OpenTypeChoice(self.resolve_open_type_choice(
+                        set,
+                        relation_field,
+                        &class,
+                        resolver,
+                    )?)),
+                )),
+                (_, Some(relation), None) => Err(ResolveError::FailedToResolveReference(format!(
+                    "@{}",
+                    relation
+                ))),
+                // ITU-T X.681 | ISO/IEC 8824-2, 14.2, without a table constraint, the value can be
+                // of any type
+                _ => Ok(Type::OpenType),
+            },
+        }
+    }
+
+    fn resolve_open_type_choice<
+        R: Resolver<<Resolved as ResolveState>::SizeType>
+            + Resolver<<Resolved as ResolveState>::RangeType>
+            + Resolver<<Resolved as ResolveState>::ConstType>
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
+    >(
+        &self,
+        set: &str,
+        key_field: &str,
+        class: &ObjectClass,
+        resolver: &R,
+    ) -> Result<OpenTypeChoice, ResolveError> {
+        let mut objects = Vec::new();
+        let extensible = resolver
+            .resolve(&LitOrRef::<ObjectSet>::Ref(set.to_string()))?
+            .collect_objects(resolver, &mut vec![set.to_string()], &mut objects)?;
+
+        let mut alternatives: Vec<(i64, Option<String>, Type<Unresolved>)> =
+            Vec::with_capacity(objects.len());
+        for tokens in objects {
+            let mut settings = Setting::read_object(tokens, class)
+                .map_err(|e| ResolveError::FailedToParseObject(format!("{} in {}", e, set)))?;
+
+            let key = match settings.iter().position(|(name, _)| name.eq(key_field)) {
+                Some(index) => settings.swap_remove(index).1,
+                None => continue,
+            };
+            let r#type = match settings.into_iter().find(|(name, _)| self.field.eq(name)) {
+                Some((_, Setting::Type(r#type))) => r#type,
+                _ => continue,
+            };
+            let (key, key_name) = match key {
+                Setting::Value(lor) => {
+                    let name = match &lor {
+                        LitOrRef::Ref(name) => Some(name.clone()),
+                        LitOrRef::Lit(_) => None,
+                    };
+                    let value: LiteralValue = resolver.resolve(&lor)?;
+                    let key = value.to_integer().ok_or_else(|| {
+                        ResolveError::UnsupportedObjectClassField(format!(
+                            "Only INTEGER keys are supported, but {}.{} is {:?}",
+                            self.class, key_field, value
+                        ))
+                    })?;
+                    (key, name)
+                }
+                _ => continue,
+            };
+
+            if alternatives.iter().all(|(k, ..)| *k != key) {
+                alternatives.push((key, key_name, r#type));
+            }
+        }
+
+        let type_name = |r#type: &Type<Unresolved>| match r#type {
+            Type::TypeReference(name, _) => Some(name.clone()),
+            _ => None,
+        };
+
+        let mut variants = Vec::with_capacity(alternatives.len());
+        for (key, key_name, r#type) in &alternatives {
+            let name = type_name(r#type)
+                .filter(|name| {
+                    alternatives
+                        .iter()
+                        .filter(|(_, _, t)| type_name(t).as_ref() == Some(name))
+                        .count()
+                        == 1
+                })
+                .or_else(|| key_name.clone())
+                .unwrap_or_else(|| format!("{}-{}", self.field.trim_start_matches('&'), key));
+            variants.push(OpenTypeVariant {
+                key: *key,
+                name,
+                identifier: None,
+                r#type: r#type.try_resolve(resolver)?,
+            });
+        }
+
+        Ok(OpenTypeChoice {
+            variants,
+            extensible,
+        })
+    }
+}
+
+/// The alternatives of an open type, whose actual type is selected by the value of another
+/// component, ITU-T X.682 | ISO/IEC 8824-3, 10.7
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct OpenTypeChoice<RS: ResolveState = Resolved> {
+    pub variants: Vec<OpenTypeVariant<RS>>,
+    /// Whether the object set is extensible, so that the related field might select an object
+    /// unknown to this type
+    pub extensible: bool,
+}
+
+impl<RS: ResolveState> OpenTypeChoice<RS> {
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    pub fn variants(&self) -> impl Iterator<Item = &OpenTypeVariant<RS>> {
+        self.variants.iter()
+    }
+}
+
+impl OpenTypeChoice<Unresolved> {
+    pub fn try_resolve<
+        R: Resolver<<Resolved as ResolveState>::SizeType>
+            + Resolver<<Resolved as ResolveState>::RangeType>
+            + Resolver<<Resolved as ResolveState>::ConstType>
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
+    >(
+        &self,
+        resolver: &R,
+    ) -> Result<OpenTypeChoice<Resolved>, ResolveError> {
+        Ok(OpenTypeChoice {
+            variants: self
+                .variants
+                .iter()
+                .map(|v| {
+                    Ok(OpenTypeVariant {
+                        key: v.key,
+                        name: v.name.clone(),
+                        identifier: v.identifier.clone(),
+                        r#type: v.r#type.try_resolve(resolver)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            extensible: self.extensible,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct OpenTypeVariant<RS: ResolveState = Resolved> {
+    /// The value of the related component that selects this variant
+    pub key: i64,
+    pub name: String,
+    /// The identifier in the ASN.1 definition, if it differs from `name`
+    pub identifier: Option<String>,
+    pub r#type: Type<RS>,
+}
+
+impl<RS: ResolveState> OpenTypeVariant<RS> {
+    /// The identifier of this variant in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// Reads all tokens until the closing brace that matches an already consumed opening brace
+pub(crate) fn read_until_closing_brace<T: Iterator<Item = Token>>(
+    iter: &mut Peekable<T>,
+) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut depth = 0_usize;
+    loop {
+        let token = iter.next_or_err()?;
+        if token.eq_separator('{') {
+            depth += 1;
+        } else if token.eq_separator('}') {
+            if depth == 0 {
+                return Ok(tokens);
+            }
+            depth -= 1;
+        }
+        tokens.push(token);
+    }
+}
+
+/// Skips a setting, stopping before the `,` or `}` that ends it
+fn skip_setting<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Result<(), Error> {
+    while !iter.peek_is_separator_eq(',') && !iter.peek_is_separator_eq('}') {
+        if iter.next_or_err()?.eq_separator('{') {
+            let _ = read_until_closing_brace(iter)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(asn: &str) -> Peekable<std::vec::IntoIter<Token>> {
+        Tokenizer::default().parse(asn).into_iter().peekable()
+    }
+
+    #[test]
+    fn test_class_with_syntax() {
+        let class = ObjectClass::try_from(&mut tokens(
+            r"{
+                &id         ProtocolIE-ID   UNIQUE,
+                &criticality Criticality    DEFAULT ignore,
+                &Value,
+                &Values     INTEGER         OPTIONAL
+            }
+            WITH SYNTAX {
+                ID &id [CRITICALITY &criticality] TYPE &Value
+            }",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            class,
+            ObjectClass {
+                fields: vec![
+                    FieldSpec {
+                        name: "&id".to_string(),
+                        kind: FieldKind::Value(Type::TypeReference(
+                            "ProtocolIE-ID".to_string(),
+                            None
+                        )),
+                        unique: true,
+                        optional: false,
+                    },
+                    FieldSpec {
+                        name: "&criticality".to_string(),
+                        kind: FieldKind::Value(Type::TypeReference(
+                            "Criticality".to_string(),
+                            None
+                        )),
+                        unique: false,
+                        optional: true,
+                    },
+                    FieldSpec {
+                        name: "&Value".to_string(),
+                        kind: FieldKind::Type,
+                        unique: false,
+                        optional: false,
+                    },
+                    FieldSpec {
+                        name: "&Values".to_string(),
+                        kind: FieldKind::ValueSet(Type::Integer(crate::model::Integer::default())),
+                        unique: false,
+                        optional: true,
+                    },
+                ],
+                syntax: Some(vec![
+                    SyntaxElement::Literal("ID".to_string()),
+                    SyntaxElement::Field("&id".to_string()),
+                    SyntaxElement::Optional(vec![
+                        SyntaxElement::Literal("CRITICALITY".to_string()),
+                        SyntaxElement::Field("&criticality".to_string()),
+                    ]),
+                    SyntaxElement::Literal("TYPE".to_string()),
+                    SyntaxElement::Field("&Value".to_string()),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_object_settings_with_and_without_syntax() {
+        let mut class = ObjectClass::try_from(&mut tokens(
+            r"{ &id INTEGER UNIQUE, &criticality Criticality, &Value }
+            WITH SYNTAX { ID &id [CRITICALITY &criticality] TYPE &Value }",
+        ))
+        .unwrap();
+
+        let object = |asn: &str| tokens(asn).collect::<Vec<_>>();
+        let settings = |class: &ObjectClass, asn: &str| Setting::read_object(object(asn), class);
+
+        assert_eq!(
+            settings(&class, "ID id-Name CRITICALITY reject TYPE Name").unwrap(),
+            vec![
+                (
+                    "&id".to_string(),
+                    Setting::Value(LitOrRef::Ref("id-Name".to_string()))
+                ),
+                (
+                    "&criticality".to_string(),
+                    Setting::Value(LitOrRef::Ref("reject".to_string()))
+                ),
+                (
+                    "&Value".to_string(),
+                    Setting::Type(Type::TypeReference("Name".to_string(), None))
+                ),
+            ]
+        );
+        assert_eq!(
+            settings(&class, "ID 7 TYPE BOOLEAN").unwrap(),
+            vec![
+                (
+                    "&id".to_string(),
+                    Setting::Value(LitOrRef::Lit(LiteralValue::Integer(7)))
+                ),
+                ("&Value".to_string(), Setting::Type(Type::Boolean)),
+            ]
+        );
+        assert!(settings(&class, "ID 7 TYPE BOOLEAN PRESENCE optional").is_err());
+        assert!(settings(&class, "TYPE BOOLEAN ID 7").is_err());
+
+        class.syntax = None;
+        assert_eq!(
+            settings(&class, "&Value NULL, &id 3").unwrap(),
+            vec![
+                ("&Value".to_string(), Setting::Type(Type::Null)),
+                (
+                    "&id".to_string(),
+                    Setting::Value(LitOrRef::Lit(LiteralValue::Integer(3)))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_object_set_elements() {
+        let set = ObjectSet::read(
+            &mut tokens("{ { ID 1 TYPE A } | { ID 2 TYPE SEQUENCE { a BOOLEAN } } | Other, ... }"),
+            "CLASS".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!("CLASS", set.class);
+        assert!(set.extensible);
+        assert_eq!(3, set.elements.len());
+        assert!(matches!(&set.elements[0], ObjectSetElement::Object(t) if t.len() == 4));
+        assert!(matches!(&set.elements[1], ObjectSetElement::Object(t) if t.len() == 8));
+        assert_eq!(
+            ObjectSetElement::Reference("Other".to_string()),
+            set.elements[2]
+        );
+    }
+
+    #[test]
+    fn test_object_class_field_with_component_relation() {
+        let field =
+            ObjectClassField::read(&mut tokens("&Value ({Set}{@.id})"), "CLASS".to_string())
+                .unwrap();
+        assert_eq!(
+            ObjectClassField {
+                class: "CLASS".to_string(),
+                field: "&Value".to_string(),
+                set: Some("Set".to_string()),
+                relation: Some("id".to_string()),
+                relation_field: None,
+            },
+            field
+        );
+    }
+}
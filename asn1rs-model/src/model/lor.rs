@@ -1,11 +1,22 @@
+use crate::model::ioc::ObjectClassField;
+use crate::model::param::Instance;
 use crate::model::rs::ResolveScope;
-use crate::model::{Asn, LiteralValue, Model};
+use crate::model::{Asn, Field, LiteralValue, Model, Selection};
+use std::convert::Infallible;
 use std::fmt::{Debug, Display, Formatter};
 
 pub trait ResolveState: Clone {
     type SizeType: Display + Debug + Clone + PartialOrd + PartialEq;
     type RangeType: Display + Debug + Clone + PartialOrd + PartialEq;
     type ConstType: Debug + Clone + PartialOrd + PartialEq;
+    /// References to fields of information object classes only exist until they are resolved
+    type ObjectClassFieldType: Debug + Clone + PartialOrd + PartialEq;
+    /// Instances of parameterized types are replaced by the type they stand for when resolved
+    type InstanceType: Debug + Clone + PartialOrd + PartialEq;
+    /// Selection types are replaced by the type of the selected alternative when resolved
+    type SelectionType: Debug + Clone + PartialOrd + PartialEq;
+    /// `COMPONENTS OF` is replaced by the components of the referenced type when resolved
+    type ComponentsOfType: Debug + Clone + PartialOrd + PartialEq;
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
@@ -14,6 +25,10 @@ impl ResolveState for Resolved {
     type SizeType = usize;
     type RangeType = i64;
     type ConstType = LiteralValue;
+    type ObjectClassFieldType = Infallible;
+    type InstanceType = Infallible;
+    type SelectionType = Infallible;
+    type ComponentsOfType = Infallible;
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
@@ -22,6 +37,11 @@ impl ResolveState for Unresolved {
     type SizeType = LitOrRef<usize>;
     type RangeType = LitOrRef<i64>;
     type ConstType = LitOrRef<LiteralValue>;
+    type ObjectClassFieldType = ObjectClassField;
+    type InstanceType = Instance;
+    type SelectionType = Selection;
+    /// The name of the referenced type
+    type ComponentsOfType = String;
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
@@ -56,6 +76,13 @@ pub enum Error {
     FailedToResolveType(String),
     FailedToResolveReference(String),
     FailedToParseLiteral(String),
+    FailedToParseObject(String),
+    UnsupportedObjectClassField(String),
+    FailedToInstantiate(String),
+    /// The symbol (first) is imported from a module (second) that does not export it
+    SymbolNotExported(String, String),
+    /// The symbols (first) are imported from a module (second) that is not loaded
+    UnresolvedImport(String, String),
 }
 
 impl std::error::Error for Error {}
@@ -71,6 +98,25 @@ impl std::fmt::Display for Error {
             Error::FailedToParseLiteral(literal) => {
                 write!(f, "Failed to parse literal: {}", literal)
             }
+            Error::FailedToParseObject(object) => {
+                write!(f, "Failed to parse information object: {}", object)
+            }
+            Error::UnsupportedObjectClassField(field) => {
+                write!(f, "Unsupported information object class field: {}", field)
+            }
+            Error::FailedToInstantiate(instance) => {
+                write!(f, "Failed to instantiate parameterized type: {}", instance)
+            }
+            Error::SymbolNotExported(symbol, module) => {
+                write!(f, "Symbol {} is not exported by module {}", symbol, module)
+            }
+            Error::UnresolvedImport(symbols, module) => {
+                write!(
+                    f,
+                    "Failed to import {} from unknown module {}",
+                    symbols, module
+                )
+            }
         }
     }
 }
@@ -79,6 +125,21 @@ pub trait Resolver<T> {
     fn resolve(&self, lor: &LitOrRef<T>) -> Result<T, Error>;
 }
 
+/// Replaces instances of parameterized types by the types they stand for
+pub trait Instantiator {
+    fn instantiate(&self, instance: &Instance) -> Result<Asn<Resolved>, Error>;
+}
+
+/// Replaces the types that refer to parts of other types, which are the selection types and
+/// `COMPONENTS OF`
+pub trait Expander {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 30
+    fn select(&self, selection: &Selection) -> Result<Asn<Resolved>, Error>;
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.5, only the root components are included
+    fn components_of(&self, name: &str) -> Result<Vec<Field<Asn<Resolved>>>, Error>;
+}
+
 pub trait TryResolve<T, R: Sized> {
     fn try_resolve(&self, resolver: &impl Resolver<T>) -> Result<R, Error>;
 }
@@ -24,6 +24,7 @@ macro_rules! loop_ctrl_separator {
     };
 }
 
+mod alphabet;
 mod asn;
 mod bit_string;
 mod charset;
@@ -33,49 +34,73 @@ mod definition;
 mod enumerated;
 mod err;
 mod int;
+mod ioc;
 mod itc;
 pub mod lor;
 mod oid;
+mod param;
 mod parse;
 mod range;
+mod real;
 mod rs;
 mod size;
 mod tag;
 mod tag_resolver;
+mod time;
 
 use crate::model::itc::InnerTypeConstraints;
-use crate::model::lor::{ResolveState, Resolved, Resolver, Unresolved};
+use crate::model::lor::{Expander, Instantiator, ResolveState, Resolved, Resolver, Unresolved};
+pub use alphabet::PermittedAlphabet;
 pub use asn::Asn;
 pub use asn::Type;
 pub use bit_string::BitString;
 pub use charset::Charset;
 pub use choice::Choice;
 pub use choice::ChoiceVariant;
+pub use choice::Selection;
 pub use components::ComponentTypeList;
+pub use components::ExtensionAdditionGroup;
 pub use definition::Definition;
 pub use enumerated::Enumerated;
 pub use enumerated::EnumeratedVariant;
 pub use err::Error;
 pub use err::ErrorKind;
 pub use int::Integer;
+pub use ioc::{
+    FieldKind, FieldSpec, ObjectClass, ObjectClassField, ObjectSet, ObjectSetElement,
+    OpenTypeChoice, OpenTypeVariant, SyntaxElement,
+};
 pub use lor::Error as ResolveError;
 pub use lor::LitOrRef;
 pub use oid::{ObjectIdentifier, ObjectIdentifierComponent};
+pub use param::{Instance, Parameter, ParameterizedType};
 pub use parse::PeekableTokens;
 pub use range::Range;
+pub use real::Real;
 pub use rs::MultiModuleResolver;
 pub use size::Size;
 pub use tag::Tag;
+pub use tag::TagDefault;
+pub use tag::TagMode;
 pub use tag::TagProperty;
 pub use tag_resolver::TagResolver;
+pub use time::TimeType;
 
 #[derive(Debug, Clone)]
 pub struct Model<T: Target> {
     pub name: String,
     pub oid: Option<ObjectIdentifier>,
+    pub tag_default: TagDefault,
+    pub exports: Exports,
     pub imports: Vec<Import>,
     pub definitions: Vec<Definition<T::DefinitionType>>,
     pub value_references: Vec<ValueReference<T::ValueReferenceType>>,
+    /// ITU-T X.681 | ISO/IEC 8824-2, 9
+    pub object_classes: Vec<Definition<ObjectClass>>,
+    /// ITU-T X.681 | ISO/IEC 8824-2, 12
+    pub object_sets: Vec<Definition<ObjectSet>>,
+    /// ITU-T X.683 | ISO/IEC 8824-4, 8
+    pub parameterized_types: Vec<Definition<ParameterizedType>>,
 }
 
 pub trait Target {
@@ -88,9 +113,15 @@ impl<T: Target> Default for Model<T> {
         Model {
             name: Default::default(),
             oid: None,
+            // like the definitions of the #[asn] attribute, which are tagged automatically
+            tag_default: TagDefault::Automatic,
+            exports: Exports::default(),
             imports: Default::default(),
             definitions: Default::default(),
             value_references: Vec::default(),
+            object_classes: Vec::default(),
+            object_sets: Vec::default(),
+            parameterized_types: Vec::default(),
         }
     }
 }
@@ -102,21 +133,57 @@ impl Model<Asn<Unresolved>> {
 
         model.name = Self::read_name(&mut iter)?;
         model.oid = Self::maybe_read_oid(&mut iter)?;
-        Self::skip_until_after_text_ignore_ascii_case(&mut iter, "BEGIN")?;
+        model.tag_default = Self::read_tag_default(&mut iter)?;
 
         while let Some(token) = iter.next() {
             if token.eq_text_ignore_ascii_case("END") {
                 model.make_names_nice();
                 return Ok(model);
+            } else if token.eq_text_ignore_ascii_case("EXPORTS") {
+                model.exports = Self::read_exports(&mut iter)?;
             } else if token.eq_text_ignore_ascii_case("IMPORTS") {
                 Self::read_imports(&mut iter)?
                     .into_iter()
                     .for_each(|i| model.imports.push(i));
+            } else if iter.peek_is_separator_eq('{') {
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                model
+                    .parameterized_types
+                    .push(Definition(name, ParameterizedType::read(&mut iter)?));
             } else if iter.peek_is_separator_eq(':') {
-                model.definitions.push(Self::read_definition(
-                    &mut iter,
-                    token.into_text_or_else(Error::unexpected_token)?,
-                )?);
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                Self::read_assignment(&mut iter)?;
+                if iter.next_is_text_and_eq_ignore_case("CLASS") {
+                    model
+                        .object_classes
+                        .push(Definition(name, ObjectClass::try_from(&mut iter)?));
+                } else {
+                    model
+                        .definitions
+                        .push(Self::read_definition(&mut iter, name)?);
+                }
+            } else if token
+                .text()
+                .map_or(false, |t| t.starts_with(|c: char| c.is_ascii_uppercase()))
+            {
+                // object sets have an uppercase name followed by the class and a '{',
+                // but value references may have an uppercase name as well
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                let class = iter.next_text_or_err()?;
+                let role = Self::read_role_given_text(&mut iter, class)?;
+                Self::read_assignment(&mut iter)?;
+                match role {
+                    Type::TypeReference(class, None) if iter.peek_is_separator_eq('{') => {
+                        model
+                            .object_sets
+                            .push(Definition(name, ObjectSet::read(&mut iter, class)?));
+                    }
+                    role => model.value_references.push(ValueReference {
+                        name,
+                        value: Self::read_literal(&mut iter)?,
+                        role: role.untagged(),
+                    }),
+                }
             } else {
                 model.value_references.push(Self::read_value_reference(
                     &mut iter,
@@ -143,7 +210,9 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
-    fn read_oid(iter: &mut Peekable<IntoIter<Token>>) -> Result<ObjectIdentifier, Error> {
+    fn read_oid<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<ObjectIdentifier, ErrorKind> {
         let mut vec = Vec::default();
         while let Some(token) = iter.next() {
             if token.eq_separator('}') {
@@ -153,12 +222,12 @@ impl Model<Asn<Unresolved>> {
                     vec.push(ObjectIdentifierComponent::NumberForm(
                         identifier
                             .parse()
-                            .map_err(|_| Error::invalid_int_value(token))?,
+                            .map_err(|_| ErrorKind::InvalidIntText(token.clone()))?,
                     ));
                 } else if iter.next_is_separator_and_eq('(') {
                     let number = match iter.next_text_or_err()?.parse::<u64>() {
                         Ok(number) => number,
-                        Err(_) => return Err(Error::invalid_int_value(token)),
+                        Err(_) => return Err(ErrorKind::InvalidIntText(token)),
                     };
                     iter.next_separator_eq_or_err(')')?;
                     vec.push(ObjectIdentifierComponent::NameAndNumberForm(
@@ -169,19 +238,44 @@ impl Model<Asn<Unresolved>> {
                     vec.push(ObjectIdentifierComponent::NameForm(identifier.to_string()));
                 }
             } else {
-                return Err(Error::unexpected_token(token));
+                return Err(ErrorKind::UnexpectedToken(token));
             }
         }
         Ok(ObjectIdentifier(vec))
     }
 
-    fn skip_until_after_text_ignore_ascii_case(
-        iter: &mut Peekable<IntoIter<Token>>,
-        text: &str,
-    ) -> Result<(), Error> {
-        for t in iter {
-            if t.eq_text_ignore_ascii_case(text) {
-                return Ok(());
+    /// Reads the remaining module header up to and including `BEGIN`, of which only the
+    /// `TagDefault` is of interest (ITU-T X.680 | ISO/IEC 8824-1, 13.1)
+    fn read_tag_default(iter: &mut Peekable<IntoIter<Token>>) -> Result<TagDefault, Error> {
+        let mut tag_default = TagDefault::Explicit;
+        while let Some(token) = iter.next() {
+            if token.eq_text_ignore_ascii_case("BEGIN") {
+                return Ok(tag_default);
+            } else if let Some(default) = token.text().and_then(TagDefault::try_from_text) {
+                if iter.peek_is_text_eq_ignore_case("TAGS") {
+                    tag_default = default;
+                }
+            }
+        }
+        Err(Error::unexpected_end_of_stream())
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 13.13
+    fn read_exports(iter: &mut Peekable<IntoIter<Token>>) -> Result<Exports, Error> {
+        if iter.next_is_text_and_eq_ignore_case("ALL") {
+            iter.next_separator_eq_or_err(';')?;
+            return Ok(Exports::All);
+        }
+        let mut symbols = Vec::new();
+        while let Some(token) = iter.next() {
+            if token.eq_separator(';') {
+                return Ok(Exports::Symbols(symbols));
+            } else if !token.eq_separator(',') {
+                symbols.push(token.into_text_or_else(Error::unexpected_token)?);
+                // like for IMPORTS, parameterized types are referred to as `Name{}`
+                if iter.next_is_separator_and_eq('{') {
+                    iter.next_separator_eq_or_err('}')?;
+                }
             }
         }
         Err(Error::unexpected_end_of_stream())
@@ -196,6 +290,10 @@ impl Model<Asn<Unresolved>> {
             } else {
                 let text = token.into_text_or_else(Error::unexpected_token)?;
                 import.what.push(text);
+                // parameterized types may be imported as `Name{}`, see ITU-T X.683 9.1
+                if iter.next_is_separator_and_eq('{') {
+                    iter.next_separator_eq_or_err('}')?;
+                }
                 let token = iter.next_or_err()?;
                 if token.eq_separator(',') {
                     // ignore separator
@@ -209,44 +307,39 @@ impl Model<Asn<Unresolved>> {
         }
         Err(Error::unexpected_end_of_stream())
     }
+    fn read_assignment<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Result<(), Error> {
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err(':')?;
+        iter.next_separator_eq_or_err('=')?;
+        Ok(())
+    }
+
     fn read_definition(
         iter: &mut Peekable<IntoIter<Token>>,
         name: String,
     ) -> Result<Definition<Asn<Unresolved>>, Error> {
-        iter.next_separator_eq_or_err(':')?;
-        iter.next_separator_eq_or_err(':')?;
-        iter.next_separator_eq_or_err('=')?;
+        Ok(Definition(name, Self::read_type(iter)?))
+    }
 
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
+    fn read_type<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<Asn<Unresolved>, Error> {
+        let (token, tag, tag_mode) = Self::next_with_opt_tag(iter)?;
 
-        if token.eq_text_ignore_ascii_case("SEQUENCE") {
-            Ok(Definition(
-                name,
-                Self::read_sequence_or_sequence_of(iter)?.opt_tagged(tag),
-            ))
+        let r#type = if token.eq_text_ignore_ascii_case("SEQUENCE") {
+            Self::read_sequence_or_sequence_of(iter)?
         } else if token.eq_text_ignore_ascii_case("SET") {
-            Ok(Definition(
-                name,
-                Self::read_set_or_set_of(iter)?.opt_tagged(tag),
-            ))
+            Self::read_set_or_set_of(iter)?
         } else if token.eq_text_ignore_ascii_case("ENUMERATED") {
-            Ok(Definition(
-                name,
-                Type::Enumerated(Enumerated::try_from(iter)?).opt_tagged(tag),
-            ))
+            Type::Enumerated(Enumerated::try_from(iter)?)
         } else if token.eq_text_ignore_ascii_case("CHOICE") {
-            Ok(Definition(
-                name,
-                Type::Choice(Choice::try_from(iter)?).opt_tagged(tag),
-            ))
+            Type::Choice(Choice::try_from(iter)?)
         } else if let Some(text) = token.text() {
-            Ok(Definition(
-                name,
-                Self::read_role_given_text(iter, text.to_string())?.opt_tagged(tag),
-            ))
+            Self::read_role_given_text(iter, text.to_string())?
         } else {
-            Err(Error::unexpected_token(token))
-        }
+            return Err(Error::unexpected_token(token));
+        };
+        Ok(r#type.opt_tagged(tag).with_tag_mode(tag_mode))
     }
 
     fn read_value_reference<T: Iterator<Item = Token>>(
@@ -257,13 +350,12 @@ impl Model<Asn<Unresolved>> {
         Ok(ValueReference {
             name,
             value: {
-                iter.next_separator_eq_or_err(':')?;
-                iter.next_separator_eq_or_err(':')?;
-                iter.next_separator_eq_or_err('=')?;
+                Self::read_assignment(iter)?;
                 Self::read_literal(iter)?
             },
             role: Asn {
                 tag: None,
+                tag_mode: None,
                 r#type,
                 default: None,
             },
@@ -291,6 +383,8 @@ impl Model<Asn<Unresolved>> {
                 Self::read_string_literal(iter, '"')?
             } else if iter.peek_is_separator_eq('\'') {
                 Self::read_hex_or_bit_string_literal(iter)?
+            } else if iter.next_is_separator_and_eq('{') {
+                return Ok(LiteralValue::ObjectIdentifier(Self::read_oid(iter)?));
             } else {
                 return Err(ErrorKind::UnsupportedLiteral(iter.peek_or_err()?.clone()));
             }
@@ -352,15 +446,16 @@ impl Model<Asn<Unresolved>> {
 
     fn next_with_opt_tag<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
-    ) -> Result<(Token, Option<Tag>), Error> {
+    ) -> Result<(Token, Option<Tag>, Option<TagMode>), Error> {
         let token = iter.next_or_err()?;
         if token.eq_separator('[') {
             let tag = Tag::try_from(&mut *iter)?;
             iter.next_separator_eq_or_err(']')?;
+            let tag_mode = TagMode::read_opt(iter);
             let token = iter.next_or_err()?;
-            Ok((token, Some(tag)))
+            Ok((token, Some(tag), tag_mode))
         } else {
-            Ok((token, None))
+            Ok((token, None, None))
         }
     }
 
@@ -375,15 +470,38 @@ impl Model<Asn<Unresolved>> {
         iter: &mut Peekable<T>,
         text: String,
     ) -> Result<Type<Unresolved>, Error> {
+        if let Some(time) = TimeType::from_asn_name(&text) {
+            return Ok(Type::Time(time));
+        }
+        // the historic ANY of ITU-T X.208, whose keyword is case sensitive unlike the others here
+        if text == "ANY" {
+            if iter.peek_is_text_eq("DEFINED") {
+                let _ = iter.next();
+                iter.next_text_eq_ignore_case_or_err("BY")?;
+                let _ = iter.next_text_or_err()?;
+            }
+            return Ok(Type::OpenType);
+        }
         Ok(match text.to_ascii_lowercase().as_ref() {
             "integer" => Type::Integer(Integer::try_from(iter)?),
             "boolean" => Type::Boolean,
             "null" => Type::Null,
-            "utf8string" => Type::String(Self::maybe_read_size(iter)?, Charset::Utf8),
-            "ia5string" => Type::String(Self::maybe_read_size(iter)?, Charset::Ia5),
-            "numericstring" => Type::String(Self::maybe_read_size(iter)?, Charset::Numeric),
-            "printablestring" => Type::String(Self::maybe_read_size(iter)?, Charset::Printable),
-            "visiblestring" => Type::String(Self::maybe_read_size(iter)?, Charset::Visible),
+            "real" => Type::Real(Real::try_from(iter)?),
+            "object" => {
+                iter.next_text_eq_ignore_case_or_err("IDENTIFIER")?;
+                Type::ObjectIdentifier
+            }
+            "relative-oid" => Type::RelativeOid,
+            "utf8string" => Self::read_string(iter, Charset::Utf8)?,
+            "ia5string" => Self::read_string(iter, Charset::Ia5)?,
+            "numericstring" => Self::read_string(iter, Charset::Numeric)?,
+            "printablestring" => Self::read_string(iter, Charset::Printable)?,
+            "visiblestring" => Self::read_string(iter, Charset::Visible)?,
+            "teletexstring" | "t61string" => Self::read_string(iter, Charset::Teletex)?,
+            "graphicstring" => Self::read_string(iter, Charset::Graphic)?,
+            "generalstring" => Self::read_string(iter, Charset::General)?,
+            "universalstring" => Self::read_string(iter, Charset::Universal)?,
+            "bmpstring" => Self::read_string(iter, Charset::Bmp)?,
             "octet" => {
                 iter.next_text_eq_ignore_case_or_err("STRING")?;
                 Type::OctetString(Self::maybe_read_size(iter)?)
@@ -396,6 +514,14 @@ impl Model<Asn<Unresolved>> {
             "choice" => Type::Choice(Choice::try_from(iter)?),
             "sequence" => Self::read_sequence_or_sequence_of(iter)?,
             "set" => Self::read_set_or_set_of(iter)?,
+            _ if iter.next_is_separator_and_eq('.') => {
+                Type::ObjectClassField(ObjectClassField::read(iter, text)?)
+            }
+            _ if iter.peek_is_separator_eq('{') => Type::Instance(Instance::read(iter, text)?),
+            _ if iter.next_is_separator_and_eq('<') => Type::Selection(Selection {
+                alternative: text,
+                choice: iter.next_text_or_err()?,
+            }),
             _ => {
                 // TODO use InnerTypeConstraints to flatten TypeReference to an actual type and
                 //      prevent tuple-type nesting in the generated rust and other code by copying
@@ -418,6 +544,39 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
+    /// Reads the `SIZE` and `FROM` constraints of a restricted character string, which are
+    /// either in parentheses of their own or intersected through `^` or `INTERSECTION`
+    fn read_string<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        charset: Charset,
+    ) -> Result<Type<Unresolved>, Error> {
+        let mut size = if iter.peek_is_text_eq_ignore_case("SIZE") {
+            Size::try_from(&mut *iter)?
+        } else {
+            Size::Any
+        };
+        let mut alphabet = PermittedAlphabet::default();
+
+        while iter.next_is_separator_and_eq('(') {
+            loop {
+                if iter.peek_is_text_eq_ignore_case("FROM") {
+                    alphabet = PermittedAlphabet::try_from(&mut *iter)?;
+                } else {
+                    size = Size::try_from(&mut *iter)?;
+                }
+
+                if !iter.next_is_text_and_eq_ignore_case("^")
+                    && !iter.next_is_text_and_eq_ignore_case("INTERSECTION")
+                {
+                    break;
+                }
+            }
+            iter.next_separator_eq_or_err(')')?;
+        }
+
+        Ok(Type::String(size, charset, alphabet))
+    }
+
     fn maybe_read_size<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<Size<<Unresolved as ResolveState>::SizeType>, Error> {
@@ -456,15 +615,18 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
+    /// Reads a component and returns it together with the token that follows it
     fn read_field<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
-    ) -> Result<(Field<Asn<Unresolved>>, bool), Error> {
+    ) -> Result<(Field<Asn<Unresolved>>, Token), Error> {
         let name = iter.next_text_or_err()?;
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
+        let (token, tag, tag_mode) = Self::next_with_opt_tag(iter)?;
         let mut field = Field {
             name,
+            identifier: None,
             role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
-                .opt_tagged(tag),
+                .opt_tagged(tag)
+                .with_tag_mode(tag_mode),
         };
 
         let token = {
@@ -492,15 +654,7 @@ impl Model<Asn<Unresolved>> {
             }
         };
 
-        let (continues, ends) = token
-            .separator()
-            .map_or((false, false), |s| (s == ',', s == '}'));
-
-        if continues || ends {
-            Ok((field, continues))
-        } else {
-            Err(Error::unexpected_token(token))
-        }
+        Ok((field, token))
     }
 }
 
@@ -592,6 +746,8 @@ pub enum LiteralValue {
     Integer(i64),
     OctetString(Vec<u8>),
     EnumeratedVariant(String, String),
+    /// The components of an `OBJECT IDENTIFIER` or `RELATIVE-OID` value
+    ObjectIdentifier(ObjectIdentifier),
 }
 
 impl LiteralValue {
@@ -604,6 +760,25 @@ impl LiteralValue {
     }
 }
 
+/// The symbols a module makes available to other modules, see ITU-T X.680 | ISO/IEC 8824-1, 13.13
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
+pub enum Exports {
+    /// `EXPORTS ALL;` or no `EXPORTS` clause at all
+    #[default]
+    All,
+    /// `EXPORTS a, b;`, which might also be empty to export nothing at all
+    Symbols(Vec<String>),
+}
+
+impl Exports {
+    pub fn contains(&self, symbol: &str) -> bool {
+        match self {
+            Exports::All => true,
+            Exports::Symbols(symbols) => symbols.iter().any(|s| s == symbol),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
 pub struct Import {
     pub what: Vec<String>,
@@ -611,12 +786,40 @@ pub struct Import {
     pub from_oid: Option<ObjectIdentifier>,
 }
 
+impl Import {
+    /// Modules are identified by their OID if both, the import and the module, name one, so
+    /// that different versions of a module with the same name can be told apart
+    pub fn refers_to<T: Target>(&self, model: &Model<T>) -> bool {
+        match (&self.from_oid, &model.oid) {
+            (Some(from_oid), Some(oid)) => from_oid.is_equivalent(oid),
+            _ => self.from == model.name,
+        }
+    }
+
+    /// The name of the module and its OID, if any
+    pub fn module_description(&self) -> String {
+        match &self.from_oid {
+            Some(oid) => format!("{} {}", self.from, oid),
+            None => self.from.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 pub struct Field<T> {
     pub name: String,
+    /// The identifier in the ASN.1 definition, if it differs from `name`
+    pub identifier: Option<String>,
     pub role: T,
 }
 
+impl<T> Field<T> {
+    /// The identifier of this field in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+}
+
 impl<T: TagProperty> TagProperty for Field<T> {
     fn tag(&self) -> Option<Tag> {
         self.role.tag()
@@ -636,13 +839,18 @@ impl Field<Asn<Unresolved>> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ObjectClass>
+            + Resolver<ObjectSet>
+            + Instantiator
+            + Expander,
     >(
         &self,
         resolver: &R,
     ) -> Result<Field<Asn<Resolved>>, ResolveError> {
         Ok(Field {
             name: self.name.clone(),
+            identifier: self.identifier.clone(),
             role: self.role.try_resolve(resolver)?,
         })
     }
@@ -683,21 +891,25 @@ pub(crate) mod tests {
                 Type::sequence_from_fields(vec![
                     Field {
                         name: "small".into(),
+                        identifier: None,
                         role: Type::integer_with_range(Range::inclusive(Some(0), Some(255)))
                             .untagged(),
                     },
                     Field {
                         name: "bigger".into(),
+                        identifier: None,
                         role: Type::integer_with_range(Range::inclusive(Some(0), Some(65535)))
                             .untagged(),
                     },
                     Field {
                         name: "negative".into(),
+                        identifier: None,
                         role: Type::integer_with_range(Range::inclusive(Some(-1), Some(255)))
                             .untagged(),
                     },
                     Field {
                         name: "unlimited".into(),
+                        identifier: None,
                         role: Type::unconstrained_integer().optional().untagged(),
                     }
                 ])
@@ -738,6 +950,7 @@ pub(crate) mod tests {
                 "Woah".into(),
                 Type::sequence_from_fields(vec![Field {
                     name: "decision".into(),
+                    identifier: None,
                     role: Type::Enumerated(Enumerated::from_names(
                         ["ABORT", "RETURN", "CONFIRM", "MAYDAY", "THE_CAKE_IS_A_LIE",].iter()
                     ))
@@ -807,6 +1020,7 @@ pub(crate) mod tests {
                 Type::sequence_from_fields(vec![
                     Field {
                         name: "also-ones".into(),
+                        identifier: None,
                         role: Type::SequenceOf(
                             Box::new(Type::integer_with_range(Range::inclusive(Some(0), Some(1)))),
                             Size::Any,
@@ -815,6 +1029,7 @@ pub(crate) mod tests {
                     },
                     Field {
                         name: "nesteds".into(),
+                        identifier: None,
                         role: Type::SequenceOf(
                             Box::new(Type::SequenceOf(
                                 Box::new(Type::integer_with_range(Range::inclusive(
@@ -829,6 +1044,7 @@ pub(crate) mod tests {
                     },
                     Field {
                         name: "optionals".into(),
+                        identifier: None,
                         role: Type::SequenceOf(
                             Box::new(Type::SequenceOf(
                                 Box::new(Type::unconstrained_integer()),
@@ -916,6 +1132,7 @@ pub(crate) mod tests {
                 "Woah".into(),
                 Type::sequence_from_fields(vec![Field {
                     name: "decision".into(),
+                    identifier: None,
                     role: Type::choice_from_variants(vec![
                         ChoiceVariant::name_type("this", Type::TypeReference("This".into(), None)),
                         ChoiceVariant::name_type("that", Type::TypeReference("That".into(), None)),
@@ -961,14 +1178,17 @@ pub(crate) mod tests {
                 "Woah".into(),
                 Type::sequence_from_fields(vec![Field {
                     name: "complex".into(),
+                    identifier: None,
                     role: Type::sequence_from_fields(vec![
                         Field {
                             name: "ones".into(),
+                            identifier: None,
                             role: Type::integer_with_range(Range::inclusive(Some(0), Some(1)))
                                 .untagged(),
                         },
                         Field {
                             name: "list-ones".into(),
+                            identifier: None,
                             role: Type::SequenceOf(
                                 Box::new(Type::integer_with_range(Range::inclusive(
                                     Some(0),
@@ -980,6 +1200,7 @@ pub(crate) mod tests {
                         },
                         Field {
                             name: "optional-ones".into(),
+                            identifier: None,
                             role: Type::SequenceOf(
                                 Box::new(Type::integer_with_range(Range::inclusive(
                                     Some(0),
@@ -1073,6 +1294,75 @@ pub(crate) mod tests {
         )
     }
 
+    #[test]
+    pub fn test_real_type_with_components() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"
+            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Unconstrained ::= REAL
+            Binary64 ::= REAL (WITH COMPONENTS {
+                mantissa (-9007199254740991..9007199254740991),
+                base (2),
+                exponent (-1022..1023)
+            })
+            Decimal ::= REAL (WITH COMPONENTS { base (10), exponent (MIN..0, ...) })
+
+            END
+        ",
+        ))
+        .expect("Failed to parse")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(
+            &[
+                Definition(
+                    "Unconstrained".to_string(),
+                    Type::Real(Real::default()).untagged(),
+                ),
+                Definition(
+                    "Binary64".to_string(),
+                    Type::Real(Real {
+                        mantissa: Range::inclusive(
+                            Some(-9_007_199_254_740_991),
+                            Some(9_007_199_254_740_991)
+                        ),
+                        base: Some(2),
+                        exponent: Range::inclusive(Some(-1022), Some(1023)),
+                    })
+                    .untagged(),
+                ),
+                Definition(
+                    "Decimal".to_string(),
+                    Type::Real(Real {
+                        mantissa: Range::none(),
+                        base: Some(10),
+                        exponent: Range::inclusive(None, Some(0)).with_extensible(true),
+                    })
+                    .untagged(),
+                ),
+            ][..],
+            &model.definitions[..]
+        )
+    }
+
+    #[test]
+    pub fn test_real_type_with_invalid_base() {
+        let result = Model::try_from(Tokenizer::default().parse(
+            r"
+            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Invalid ::= REAL (WITH COMPONENTS { base (8) })
+
+            END
+        ",
+        ));
+        assert!(result.is_err());
+    }
+
     #[test]
     pub fn test_enumerated_advanced() {
         let model = Model::try_from(Tokenizer::default().parse(
@@ -1248,10 +1538,12 @@ pub(crate) mod tests {
                     Type::sequence_from_fields(vec![
                         Field {
                             name: "abc".to_string(),
+                            identifier: None,
                             role: Type::unconstrained_integer().tagged(Tag::ContextSpecific(1)),
                         },
                         Field {
                             name: "def".to_string(),
+                            identifier: None,
                             role: Type::integer_with_range(Range::inclusive(Some(0), Some(255)))
                                 .tagged(Tag::ContextSpecific(2)),
                         }
@@ -1277,6 +1569,119 @@ pub(crate) mod tests {
         )
     }
 
+    #[test]
+    pub fn test_parsing_tag_default_of_module() {
+        for (header, expected) in [
+            ("SimpleSchema DEFINITIONS ::=", TagDefault::Explicit),
+            (
+                "SimpleSchema DEFINITIONS EXPLICIT TAGS ::=",
+                TagDefault::Explicit,
+            ),
+            (
+                "SimpleSchema DEFINITIONS IMPLICIT TAGS ::=",
+                TagDefault::Implicit,
+            ),
+            (
+                "SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=",
+                TagDefault::Automatic,
+            ),
+            (
+                "SimpleSchema { iso(1) 2 } DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::=",
+                TagDefault::Implicit,
+            ),
+        ] {
+            let model =
+                Model::try_from(Tokenizer::default().parse(&format!("{}\nBEGIN\nEND", header)))
+                    .expect("Failed to parse");
+            assert_eq!(expected, model.tag_default, "{}", header);
+        }
+    }
+
+    #[test]
+    pub fn test_parsing_tag_modes() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SimpleSchema DEFINITIONS IMPLICIT TAGS ::=
+            BEGIN
+
+            Wrapped ::= [APPLICATION 3] EXPLICIT INTEGER
+
+            Fields ::= SEQUENCE {
+                abc [1] IMPLICIT INTEGER,
+                def [2] EXPLICIT BOOLEAN,
+                ghi [3] BOOLEAN
+            }
+
+            Decision ::= CHOICE {
+                abc [1] EXPLICIT INTEGER,
+                def [2] BOOLEAN
+            }
+
+            END
+        ",
+        ))
+        .expect("Failed to parse")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(TagDefault::Implicit, model.tag_default);
+        assert_eq!(
+            &[
+                Definition(
+                    "Wrapped".to_string(),
+                    Type::unconstrained_integer()
+                        .tagged(Tag::Application(3))
+                        .with_tag_mode(Some(TagMode::Explicit)),
+                ),
+                Definition(
+                    "Fields".to_string(),
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "abc".to_string(),
+                            identifier: None,
+                            role: Type::unconstrained_integer()
+                                .tagged(Tag::ContextSpecific(1))
+                                .with_tag_mode(Some(TagMode::Implicit)),
+                        },
+                        Field {
+                            name: "def".to_string(),
+                            identifier: None,
+                            role: Type::Boolean
+                                .tagged(Tag::ContextSpecific(2))
+                                .with_tag_mode(Some(TagMode::Explicit)),
+                        },
+                        Field {
+                            name: "ghi".to_string(),
+                            identifier: None,
+                            role: Type::Boolean.tagged(Tag::ContextSpecific(3)),
+                        },
+                    ])
+                    .untagged(),
+                ),
+                Definition(
+                    "Decision".to_string(),
+                    Type::choice_from_variants(vec![
+                        ChoiceVariant {
+                            name: "abc".to_string(),
+                            identifier: None,
+                            tag: Some(Tag::ContextSpecific(1)),
+                            tag_mode: Some(TagMode::Explicit),
+                            r#type: Type::unconstrained_integer(),
+                        },
+                        ChoiceVariant {
+                            name: "def".to_string(),
+                            identifier: None,
+                            tag: Some(Tag::ContextSpecific(2)),
+                            tag_mode: None,
+                            r#type: Type::Boolean,
+                        },
+                    ])
+                    .untagged(),
+                ),
+            ][..],
+            &model.definitions[..]
+        )
+    }
+
     #[test]
     pub fn test_parsing_of_extensible_choices() {
         let model = Model::try_from(Tokenizer::default().parse(
@@ -1486,6 +1891,7 @@ pub(crate) mod tests {
                     Type::sequence_from_fields(vec![
                         Field {
                             name: "inline".to_string(),
+                            identifier: None,
                             role: Type::Integer(Integer {
                                 range: Range::none(),
                                 constants: vec![
@@ -1498,6 +1904,7 @@ pub(crate) mod tests {
                         },
                         Field {
                             name: "eff-u8".to_string(),
+                            identifier: None,
                             role: Type::Integer(Integer {
                                 range: Range::inclusive(Some(0), Some(255)),
                                 constants: vec![
@@ -1510,6 +1917,7 @@ pub(crate) mod tests {
                         },
                         Field {
                             name: "tagged".to_string(),
+                            identifier: None,
                             role: Type::Integer(Integer {
                                 range: Range::inclusive(Some(0), Some(255)),
                                 constants: vec![
@@ -1566,6 +1974,7 @@ pub(crate) mod tests {
                 "RangedOptional".to_string(),
                 Type::sequence_from_fields(vec![Field {
                     name: "value".to_string(),
+                    identifier: None,
                     role: Type::Integer(Integer {
                         range: Range::inclusive(Some(0), Some(255)).with_extensible(true),
                         constants: vec![
@@ -1631,7 +2040,10 @@ pub(crate) mod tests {
             ordering: _,
             fields,
             tag,
+            tag_mode: _,
             extension_after: _,
+            extension_group: _,
+            unknown_extensions: _,
         } = rust.definitions[0].value()
         {
             assert_eq!("Implicit", rust.definitions[0].0.as_str());
@@ -1760,83 +2172,330 @@ pub(crate) mod tests {
     }
 
     #[test]
-    pub fn test_value_reference_octet_string() {
+    pub fn test_value_reference_object_identifier() {
         let model = Model::try_from(Tokenizer::default().parse(
             r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
 
-                answers OCTET STRING ::= '42'h
+                id-rsa OBJECT IDENTIFIER ::= { iso member-body(2) us(840) 113549 }
+                id-pkcs-1 OBJECT IDENTIFIER ::= { id-rsa 1 1 }
+                id-relative RELATIVE-OID ::= { 3 4 }
+
+                Identified ::= SEQUENCE {
+                    id OBJECT IDENTIFIER,
+                    relative RELATIVE-OID
+                }
 
                 END",
         ))
         .expect("Failed to load model");
         assert_eq!(
             ValueReference {
-                name: "answers".to_string(),
-                role: Type::OctetString(Size::Any).untagged(),
-                value: LiteralValue::OctetString(vec![0x42])
+                name: "id-pkcs-1".to_string(),
+                role: Type::ObjectIdentifier.untagged(),
+                value: LiteralValue::ObjectIdentifier(ObjectIdentifier(vec![
+                    ObjectIdentifierComponent::NameForm("id-rsa".to_string()),
+                    ObjectIdentifierComponent::NumberForm(1),
+                    ObjectIdentifierComponent::NumberForm(1),
+                ]))
             },
-            model.value_references[0]
-        )
+            model.value_references[1]
+        );
+
+        let model = model.try_resolve().expect("Failed to resolve");
+        assert_eq!(
+            &LiteralValue::ObjectIdentifier(ObjectIdentifier(vec![
+                ObjectIdentifierComponent::NameAndNumberForm("iso".to_string(), 1),
+                ObjectIdentifierComponent::NameAndNumberForm("member-body".to_string(), 2),
+                ObjectIdentifierComponent::NameAndNumberForm("us".to_string(), 840),
+                ObjectIdentifierComponent::NumberForm(113_549),
+                ObjectIdentifierComponent::NumberForm(1),
+                ObjectIdentifierComponent::NumberForm(1),
+            ])),
+            &model.value_references[1].value
+        );
+        assert_eq!(Type::RelativeOid.untagged(), model.value_references[2].role);
+        assert_eq!(
+            &[Definition(
+                "Identified".to_string(),
+                Type::Sequence(ComponentTypeList {
+                    extension_after: None,
+                    extension_groups: Vec::default(),
+                    fields: vec![
+                        Field {
+                            name: "id".to_string(),
+                            identifier: None,
+                            role: Type::ObjectIdentifier.untagged(),
+                        },
+                        Field {
+                            name: "relative".to_string(),
+                            identifier: None,
+                            role: Type::RelativeOid.untagged(),
+                        },
+                    ],
+                })
+                .untagged(),
+            )][..],
+            &model.definitions[..]
+        );
     }
 
     #[test]
-    pub fn test_value_reference_string() {
+    pub fn test_value_reference_object_identifier_unresolvable() {
         let model = Model::try_from(Tokenizer::default().parse(
-            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
 
-                utf8 UTF8String ::= "häw äre yöu .. .. doing"
-                ia5 IA5String ::= "how are you"
+                id-cyclic OBJECT IDENTIFIER ::= { id-cyclic 1 }
+                id-unknown OBJECT IDENTIFIER ::= { iso unknown 1 }
 
-                END"#,
+                END",
         ))
         .expect("Failed to load model");
         assert_eq!(
-            &[
-                ValueReference {
-                    name: "utf8".to_string(),
-                    role: Type::String(Size::Any, Charset::Utf8).untagged(),
-                    value: LiteralValue::String("häw äre yöu .. .. doing".to_string())
-                },
-                ValueReference {
-                    name: "ia5".to_string(),
-                    role: Type::String(Size::Any, Charset::Ia5).untagged(),
-                    value: LiteralValue::String("how are you".to_string())
+            ResolveError::FailedToResolveReference("id-cyclic".to_string()),
+            model.try_resolve().unwrap_err()
+        );
+    }
+
+    #[test]
+    pub fn test_legacy_string_types() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Subscriber ::= SEQUENCE {
+                    name BMPString,
+                    alias UniversalString,
+                    teletex TeletexString,
+                    t61 T61String,
+                    graphic GraphicString,
+                    general GeneralString
                 }
+
+                END",
+        ))
+        .expect("Failed to load model");
+        let fields = match &model.definitions[0].1.r#type {
+            Type::Sequence(list) => &list.fields,
+            other => panic!("Unexpected type {:?}", other),
+        };
+        assert_eq!(
+            vec![
+                Type::String(Size::Any, Charset::Bmp, PermittedAlphabet::default()),
+                Type::String(Size::Any, Charset::Universal, PermittedAlphabet::default()),
+                Type::String(Size::Any, Charset::Teletex, PermittedAlphabet::default()),
+                Type::String(Size::Any, Charset::Teletex, PermittedAlphabet::default()),
+                Type::String(Size::Any, Charset::Graphic, PermittedAlphabet::default()),
+                Type::String(Size::Any, Charset::General, PermittedAlphabet::default()),
             ],
-            &model.value_references[..]
+            fields
+                .iter()
+                .map(|field| field.role.r#type.clone())
+                .collect::<Vec<_>>()
         );
     }
 
     #[test]
-    pub fn test_value_reference_in_size() {
+    pub fn test_permitted_alphabet() {
         let model = Model::try_from(Tokenizer::default().parse(
             r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
 
-                se_min INTEGER ::= 42
-                se_max INTEGER ::= 1337
-                
-                seq-fix         ::= SEQUENCE (SIZE(se_min)) OF INTEGER
-                seq-min-max     ::= SEQUENCE (SIZE(se_min..se_max)) OF INTEGER
-                seq-min-max-ext ::= SEQUENCE (SIZE(se_min..se_max,...)) OF INTEGER
-                
-                mixed-min-max     ::= SEQUENCE (SIZE(se_min..4711)) OF INTEGER
-                mixed-min-max-ext ::= SEQUENCE (SIZE(420..se_max,...)) OF INTEGER
+                Callsign ::= SEQUENCE {
+                    code IA5String (FROM ("A".."Z" | "0".."9")),
+                    sized PrintableString (SIZE(1..8)) (FROM ("ABC")),
+                    intersected VisibleString (SIZE(4) ^ FROM (" " | "a".."z")),
+                    reversed NumericString (FROM ("0".."7") INTERSECTION SIZE(2..3,...))
+                }
 
                 END"#,
         ))
-        .expect("Failed to load model")
-        .try_resolve()
-        .expect("Failed to resolve");
+        .expect("Failed to load model");
+        let fields = match &model.definitions[0].1.r#type {
+            Type::Sequence(list) => &list.fields,
+            other => panic!("Unexpected type {:?}", other),
+        };
         assert_eq!(
-            &[
-                Definition(
-                    "seq-fix".to_string(),
-                    Type::<Resolved>::SequenceOf(
-                        Box::new(Type::Integer(Integer::default())),
-                        Size::Fix(42_usize, false)
-                    )
-                    .untagged()
-                ),
+            vec![
+                Type::String(
+                    Size::Any,
+                    Charset::Ia5,
+                    PermittedAlphabet::from_ranges(vec![('A', 'Z'), ('0', '9')])
+                ),
+                Type::String(
+                    Size::Range(LitOrRef::Lit(1), LitOrRef::Lit(8), false),
+                    Charset::Printable,
+                    PermittedAlphabet::from_ranges(vec![('A', 'C')])
+                ),
+                Type::String(
+                    Size::Fix(LitOrRef::Lit(4), false),
+                    Charset::Visible,
+                    PermittedAlphabet::from_ranges(vec![(' ', ' '), ('a', 'z')])
+                ),
+                Type::String(
+                    Size::Range(LitOrRef::Lit(2), LitOrRef::Lit(3), true),
+                    Charset::Numeric,
+                    PermittedAlphabet::from_ranges(vec![('0', '7')])
+                ),
+            ],
+            fields
+                .iter()
+                .map(|field| field.role.r#type.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn test_time_types() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                LogEntry ::= SEQUENCE {
+                    at GeneralizedTime,
+                    legacy UTCTime,
+                    day DATE,
+                    time TIME-OF-DAY,
+                    local DATE-TIME,
+                    lasting DURATION OPTIONAL,
+                    date Date
+                }
+
+                Date ::= DATE
+
+                END",
+        ))
+        .expect("Failed to load model");
+        assert_eq!(
+            &[
+                Definition(
+                    "LogEntry".to_string(),
+                    Type::Sequence(ComponentTypeList {
+                        extension_after: None,
+                        extension_groups: Vec::default(),
+                        fields: vec![
+                            Field {
+                                name: "at".to_string(),
+                                identifier: None,
+                                role: Type::Time(TimeType::GeneralizedTime).untagged(),
+                            },
+                            Field {
+                                name: "legacy".to_string(),
+                                identifier: None,
+                                role: Type::Time(TimeType::UtcTime).untagged(),
+                            },
+                            Field {
+                                name: "day".to_string(),
+                                identifier: None,
+                                role: Type::Time(TimeType::Date).untagged(),
+                            },
+                            Field {
+                                name: "time".to_string(),
+                                identifier: None,
+                                role: Type::Time(TimeType::TimeOfDay).untagged(),
+                            },
+                            Field {
+                                name: "local".to_string(),
+                                identifier: None,
+                                role: Type::Time(TimeType::DateTime).untagged(),
+                            },
+                            Field {
+                                name: "lasting".to_string(),
+                                identifier: None,
+                                role: Type::Optional(Box::new(Type::Time(TimeType::Duration)))
+                                    .untagged(),
+                            },
+                            Field {
+                                name: "date".to_string(),
+                                identifier: None,
+                                role: Type::TypeReference("Date".to_string(), None).untagged(),
+                            },
+                        ],
+                    })
+                    .untagged(),
+                ),
+                Definition("Date".to_string(), Type::Time(TimeType::Date).untagged()),
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_value_reference_octet_string() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                answers OCTET STRING ::= '42'h
+
+                END",
+        ))
+        .expect("Failed to load model");
+        assert_eq!(
+            ValueReference {
+                name: "answers".to_string(),
+                role: Type::OctetString(Size::Any).untagged(),
+                value: LiteralValue::OctetString(vec![0x42])
+            },
+            model.value_references[0]
+        )
+    }
+
+    #[test]
+    pub fn test_value_reference_string() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                utf8 UTF8String ::= "häw äre yöu .. .. doing"
+                ia5 IA5String ::= "how are you"
+
+                END"#,
+        ))
+        .expect("Failed to load model");
+        assert_eq!(
+            &[
+                ValueReference {
+                    name: "utf8".to_string(),
+                    role: Type::String(Size::Any, Charset::Utf8, PermittedAlphabet::default())
+                        .untagged(),
+                    value: LiteralValue::String("häw äre yöu .. .. doing".to_string())
+                },
+                ValueReference {
+                    name: "ia5".to_string(),
+                    role: Type::String(Size::Any, Charset::Ia5, PermittedAlphabet::default())
+                        .untagged(),
+                    value: LiteralValue::String("how are you".to_string())
+                }
+            ],
+            &model.value_references[..]
+        );
+    }
+
+    #[test]
+    pub fn test_value_reference_in_size() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                se_min INTEGER ::= 42
+                se_max INTEGER ::= 1337
+                
+                seq-fix         ::= SEQUENCE (SIZE(se_min)) OF INTEGER
+                seq-min-max     ::= SEQUENCE (SIZE(se_min..se_max)) OF INTEGER
+                seq-min-max-ext ::= SEQUENCE (SIZE(se_min..se_max,...)) OF INTEGER
+                
+                mixed-min-max     ::= SEQUENCE (SIZE(se_min..4711)) OF INTEGER
+                mixed-min-max-ext ::= SEQUENCE (SIZE(420..se_max,...)) OF INTEGER
+
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+        assert_eq!(
+            &[
+                Definition(
+                    "seq-fix".to_string(),
+                    Type::<Resolved>::SequenceOf(
+                        Box::new(Type::Integer(Integer::default())),
+                        Size::Fix(42_usize, false)
+                    )
+                    .untagged()
+                ),
                 Definition(
                     "seq-min-max".to_string(),
                     Type::<Resolved>::SequenceOf(
@@ -1929,4 +2588,670 @@ pub(crate) mod tests {
             &model.definitions[..]
         );
     }
+
+    #[test]
+    pub fn test_table_constraint_resolves_to_open_type_choice() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                IE-ID ::= INTEGER (0..255)
+
+                IES ::= CLASS { &id IE-ID UNIQUE, &Value }
+                WITH SYNTAX { ID &id TYPE &Value }
+
+                id-name IE-ID ::= 2
+
+                Name ::= UTF8String
+
+                SomeIEs IES ::= { { ID 1 TYPE BOOLEAN } | { ID id-name TYPE Name }, ... }
+
+                Field ::= SEQUENCE {
+                    id      IES.&id     ({SomeIEs}),
+                    value   IES.&Value  ({SomeIEs}{@id})
+                }
+
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(1, model.object_classes.len());
+        assert_eq!(1, model.object_sets.len());
+        assert_eq!(
+            Definition(
+                "Field".to_string(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "id".to_string(),
+                        identifier: None,
+                        role: Type::<Resolved>::Integer(Integer::with_range(Range::inclusive(
+                            Some(0),
+                            Some(255)
+                        )))
+                        .untagged(),
+                    },
+                    Field {
+                        name: "value".to_string(),
+                        identifier: None,
+                        role: Type::Related(
+                            "id".to_string(),
+                            Box::new(Type::OpenTypeChoice(OpenTypeChoice {
+                                variants: vec![
+                                    OpenTypeVariant {
+                                        key: 1,
+                                        name: "Value-1".to_string(),
+                                        identifier: None,
+                                        r#type: Type::Boolean,
+                                    },
+                                    OpenTypeVariant {
+                                        key: 2,
+                                        name: "Name".to_string(),
+                                        identifier: None,
+                                        r#type: Type::TypeReference("Name".to_string(), None),
+                                    },
+                                ],
+                                extensible: true,
+                            }))
+                        )
+                        .untagged(),
+                    },
+                ])
+                .untagged()
+            ),
+            model.definitions[2]
+        );
+    }
+
+    #[test]
+    pub fn test_open_types_without_table_constraint() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Any ::= ANY
+
+                Defined ::= SEQUENCE {
+                    id      INTEGER (0..255),
+                    value   ANY DEFINED BY id
+                }
+
+                Typed ::= SEQUENCE {
+                    id      TYPE-IDENTIFIER.&id,
+                    value   TYPE-IDENTIFIER.&Type
+                }
+
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(
+            Definition("Any".to_string(), Type::OpenType.untagged()),
+            model.definitions[0]
+        );
+        assert_eq!(
+            Definition(
+                "Defined".to_string(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "id".to_string(),
+                        identifier: None,
+                        role: Type::<Resolved>::Integer(Integer::with_range(Range::inclusive(
+                            Some(0),
+                            Some(255)
+                        )))
+                        .untagged(),
+                    },
+                    Field {
+                        name: "value".to_string(),
+                        identifier: None,
+                        role: Type::OpenType.untagged(),
+                    },
+                ])
+                .untagged()
+            ),
+            model.definitions[1]
+        );
+        assert_eq!(
+            Definition(
+                "Typed".to_string(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "id".to_string(),
+                        identifier: None,
+                        role: Type::ObjectIdentifier.untagged(),
+                    },
+                    Field {
+                        name: "value".to_string(),
+                        identifier: None,
+                        role: Type::OpenType.untagged(),
+                    },
+                ])
+                .untagged()
+            ),
+            model.definitions[2]
+        );
+    }
+
+    #[test]
+    pub fn test_parameterized_types_across_modules() {
+        let containers = Model::try_from(Tokenizer::default().parse(
+            r#"Containers DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                maxProtocolIEs INTEGER ::= 8
+
+                PROTOCOL-IES ::= CLASS { &id INTEGER (0..255) UNIQUE, &Value }
+                WITH SYNTAX { ID &id TYPE &Value }
+
+                ProtocolIE-Container {PROTOCOL-IES : IEsSetParam} ::=
+                    SEQUENCE (SIZE (0..maxProtocolIEs)) OF ProtocolIE-Field {{IEsSetParam}}
+
+                ProtocolIE-Field {PROTOCOL-IES : IEsSetParam} ::= SEQUENCE {
+                    id      PROTOCOL-IES.&id    ({IEsSetParam}),
+                    value   PROTOCOL-IES.&Value ({IEsSetParam}{@id})
+                }
+
+                END"#,
+        ))
+        .expect("Failed to load model");
+        let contents = Model::try_from(Tokenizer::default().parse(
+            r#"Contents DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                IMPORTS ProtocolIE-Container{}, PROTOCOL-IES FROM Containers;
+
+                Request ::= SEQUENCE {
+                    protocolIEs ProtocolIE-Container { {RequestIEs} }
+                }
+
+                RequestIEs PROTOCOL-IES ::= { { ID 3 TYPE BOOLEAN }, ... }
+
+                END"#,
+        ))
+        .expect("Failed to load model");
+
+        assert_eq!(2, containers.parameterized_types.len());
+        assert_eq!(
+            vec![Parameter {
+                governor: Some("PROTOCOL-IES".to_string()),
+                name: "IEsSetParam".to_string(),
+            }],
+            containers.parameterized_types[0].1.parameters
+        );
+
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(containers);
+        resolver.push(contents);
+        let models = resolver.try_resolve_all().expect("Failed to resolve");
+
+        assert_eq!(
+            Definition(
+                "Request".to_string(),
+                Type::sequence_from_fields(vec![Field {
+                    name: "protocolIEs".to_string(),
+                    identifier: None,
+                    role: Type::<Resolved>::SequenceOf(
+                        Box::new(Type::sequence_from_fields(vec![
+                            Field {
+                                name: "id".to_string(),
+                                identifier: None,
+                                role: Type::<Resolved>::Integer(Integer::with_range(
+                                    Range::inclusive(Some(0), Some(255))
+                                ))
+                                .untagged(),
+                            },
+                            Field {
+                                name: "value".to_string(),
+                                identifier: None,
+                                role: Type::Related(
+                                    "id".to_string(),
+                                    Box::new(Type::OpenTypeChoice(OpenTypeChoice {
+                                        variants: vec![OpenTypeVariant {
+                                            key: 3,
+                                            name: "Value-3".to_string(),
+                                            identifier: None,
+                                            r#type: Type::Boolean,
+                                        }],
+                                        extensible: true,
+                                    }))
+                                )
+                                .untagged(),
+                            },
+                        ])),
+                        Size::Range(0, 8, false),
+                    )
+                    .untagged(),
+                }])
+                .untagged()
+            ),
+            models[1].definitions[0]
+        );
+    }
+
+    #[test]
+    fn test_parsing_exports() {
+        let parse = |exports: &str| {
+            Model::try_from(Tokenizer::default().parse(&format!(
+                r"Exporting DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                {}
+                Visible ::= INTEGER
+                END",
+                exports
+            )))
+            .expect("Failed to load model")
+            .exports
+        };
+
+        assert_eq!(Exports::All, parse(""));
+        assert_eq!(Exports::All, parse("EXPORTS ALL;"));
+        assert_eq!(Exports::Symbols(Vec::default()), parse("EXPORTS;"));
+        assert_eq!(
+            Exports::Symbols(vec![
+                "Visible".to_string(),
+                "value".to_string(),
+                "Container".to_string()
+            ]),
+            parse("EXPORTS Visible, value, Container{};")
+        );
+    }
+
+    #[test]
+    fn test_importing_not_exported_symbol_fails() {
+        let exporting = Model::try_from(Tokenizer::default().parse(
+            r"Exporting DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            EXPORTS Visible;
+            Visible ::= INTEGER
+            Hidden ::= BOOLEAN
+            END",
+        ))
+        .expect("Failed to load model");
+        let importing = |what: &str| {
+            Model::try_from(Tokenizer::default().parse(&format!(
+                r"Importing DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS {} FROM Exporting;
+                Wrapper ::= SEQUENCE {{ inner {} }}
+                END",
+                what, what
+            )))
+            .expect("Failed to load model")
+        };
+
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(exporting.clone());
+        resolver.push(importing("Visible"));
+        assert!(resolver.try_resolve_all().is_ok());
+
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(exporting);
+        resolver.push(importing("Hidden"));
+        assert_eq!(
+            Err(ResolveError::SymbolNotExported(
+                "Hidden".to_string(),
+                "Exporting".to_string()
+            )),
+            resolver.try_resolve_all().map(drop)
+        );
+    }
+
+    #[test]
+    fn test_resolving_imports_by_module_oid() {
+        let versioned = |version: u64| {
+            Model::try_from(Tokenizer::default().parse(&format!(
+                r"Versioned {{ 1 2 {} }} DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                max-value INTEGER ::= {}
+                END",
+                version, version
+            )))
+            .expect("Failed to load model")
+        };
+        let importing = |oid: &str| {
+            Model::try_from(Tokenizer::default().parse(&format!(
+                r"Importing DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS max-value FROM Versioned {};
+                Limited ::= INTEGER (0..max-value)
+                END",
+                oid
+            )))
+            .expect("Failed to load model")
+        };
+        let resolve = |oid: &str| {
+            let mut resolver = MultiModuleResolver::default();
+            resolver.set_imported_modules_required(true);
+            resolver.push(versioned(1));
+            resolver.push(versioned(2));
+            resolver.push(importing(oid));
+            resolver
+                .try_resolve_all()
+                .map(|models| models[2].definitions[0].1.r#type.clone())
+        };
+
+        assert_eq!(
+            Ok(Type::integer_with_range(Range::inclusive(Some(0), Some(1)))),
+            resolve("{ 1 2 1 }")
+        );
+        assert_eq!(
+            Ok(Type::integer_with_range(Range::inclusive(Some(0), Some(2)))),
+            resolve("{ iso member-body(2) 2 }")
+        );
+        assert_eq!(
+            Err(ResolveError::UnresolvedImport(
+                "max-value".to_string(),
+                "Versioned { 1 2 3 }".to_string()
+            )),
+            resolve("{ 1 2 3 }")
+        );
+    }
+
+    #[test]
+    fn test_import_from_unknown_module_fails_if_required() {
+        let importing = || {
+            Model::try_from(Tokenizer::default().parse(
+                r"Importing DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Some, Other FROM Unknown;
+                Wrapper ::= SEQUENCE { inner Some }
+                END",
+            ))
+            .expect("Failed to load model")
+        };
+
+        // modules are resolved one at a time by default
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(importing());
+        let models = resolver.try_resolve_all().expect("Failed to resolve");
+        assert_eq!(models[0].imports, importing().imports);
+
+        let mut resolver = MultiModuleResolver::default();
+        resolver.set_imported_modules_required(true);
+        resolver.push(importing());
+        let error = resolver.try_resolve_all().map(drop).unwrap_err();
+        assert_eq!(
+            ResolveError::UnresolvedImport("Some, Other".to_string(), "Unknown".to_string()),
+            error
+        );
+        assert_eq!(
+            "Failed to import Some, Other from unknown module Unknown",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_components_of_expands_root_components() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"ComponentsOf DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Base ::= SEQUENCE { a INTEGER, b BOOLEAN, ..., c NULL }
+
+            Extended ::= SEQUENCE { COMPONENTS OF Base, d NULL, ..., e BOOLEAN }
+
+            Aliased ::= Base
+
+            Nested ::= SET { x BOOLEAN, COMPONENTS OF Aliased }
+
+            END",
+        ))
+        .expect("Failed to parse")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        let field = |name: &str, r#type: Type| Field {
+            name: name.to_string(),
+            identifier: None,
+            role: r#type.untagged(),
+        };
+
+        assert_eq!(
+            Definition(
+                "Extended".to_string(),
+                Type::Sequence(ComponentTypeList {
+                    fields: vec![
+                        field("a", Type::unconstrained_integer()),
+                        field("b", Type::Boolean),
+                        field("d", Type::Null),
+                        field("e", Type::Boolean),
+                    ],
+                    extension_after: Some(2),
+                    extension_groups: Vec::default(),
+                })
+                .untagged(),
+            ),
+            model.definitions[1]
+        );
+        assert_eq!(
+            Definition(
+                "Nested".to_string(),
+                Type::Set(ComponentTypeList {
+                    fields: vec![
+                        field("x", Type::Boolean),
+                        field("a", Type::unconstrained_integer()),
+                        field("b", Type::Boolean),
+                    ],
+                    extension_after: None,
+                    extension_groups: Vec::default(),
+                })
+                .untagged(),
+            ),
+            model.definitions[3]
+        );
+    }
+
+    #[test]
+    fn test_components_of_resolves_in_defining_module() {
+        let base = Model::try_from(Tokenizer::default().parse(
+            r"Base DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            max-id INTEGER ::= 10
+            Header ::= SEQUENCE { id INTEGER (0..max-id) }
+            END",
+        ))
+        .expect("Failed to load model");
+        let message = Model::try_from(Tokenizer::default().parse(
+            r"Message DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Header FROM Base;
+            Message ::= SEQUENCE { COMPONENTS OF Header, flag BOOLEAN }
+            END",
+        ))
+        .expect("Failed to load model");
+
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(base);
+        resolver.push(message);
+        let models = resolver.try_resolve_all().expect("Failed to resolve");
+
+        assert_eq!(
+            Definition(
+                "Message".to_string(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "id".to_string(),
+                        identifier: None,
+                        role: Type::integer_with_range(Range::inclusive(Some(0), Some(10)))
+                            .untagged(),
+                    },
+                    Field {
+                        name: "flag".to_string(),
+                        identifier: None,
+                        role: Type::Boolean.untagged(),
+                    },
+                ])
+                .untagged(),
+            ),
+            models[1].definitions[0]
+        );
+    }
+
+    #[test]
+    fn test_selection_types() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Selection DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Decision ::= CHOICE { number [5] INTEGER (0..255), flag BOOLEAN }
+
+            Selected ::= number < Decision
+
+            Holder ::= SEQUENCE {
+                number number < Decision OPTIONAL,
+                flag [1] flag<Decision
+            }
+
+            END",
+        ))
+        .expect("Failed to parse")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(
+            Definition(
+                "Selected".to_string(),
+                Type::integer_with_range(Range::inclusive(Some(0), Some(255)))
+                    .tagged(Tag::ContextSpecific(5)),
+            ),
+            model.definitions[1]
+        );
+        assert_eq!(
+            Definition(
+                "Holder".to_string(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "number".to_string(),
+                        identifier: None,
+                        role: Type::integer_with_range(Range::inclusive(Some(0), Some(255)))
+                            .optional()
+                            .tagged(Tag::ContextSpecific(5)),
+                    },
+                    Field {
+                        name: "flag".to_string(),
+                        identifier: None,
+                        role: Type::Boolean.tagged(Tag::ContextSpecific(1)),
+                    },
+                ])
+                .untagged(),
+            ),
+            model.definitions[2]
+        );
+    }
+
+    #[test]
+    fn test_selection_of_unknown_alternative_fails() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Selection DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Decision ::= CHOICE { number INTEGER, flag BOOLEAN }
+            Selected ::= text < Decision
+            END",
+        ))
+        .expect("Failed to parse");
+
+        assert_eq!(
+            ResolveError::FailedToResolveType("text < Decision".to_string()),
+            model.try_resolve().map(drop).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parsing_extension_addition_groups() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Groups DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Versioned ::= SEQUENCE {
+                id INTEGER,
+                ...,
+                [[ 2: a INTEGER, b BOOLEAN OPTIONAL ]],
+                c NULL,
+                [[ d BOOLEAN ]]
+            }
+
+            Decision ::= CHOICE {
+                number INTEGER,
+                ...,
+                [[ 3: flag BOOLEAN, text UTF8String ]]
+            }
+
+            END",
+        ))
+        .expect("Failed to parse");
+
+        if let Type::Sequence(components) = &model.definitions[0].value().r#type {
+            assert_eq!(
+                vec!["id", "a", "b", "c", "d"],
+                components
+                    .fields
+                    .iter()
+                    .map(|f| f.name.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(Some(0), components.extension_after);
+            assert_eq!(
+                vec![
+                    ExtensionAdditionGroup {
+                        version: Some(2),
+                        start: 1,
+                        end: 3,
+                    },
+                    ExtensionAdditionGroup {
+                        version: None,
+                        start: 4,
+                        end: 5,
+                    },
+                ],
+                components.extension_groups
+            );
+        } else {
+            panic!("Versioned is not a SEQUENCE");
+        }
+
+        if let Type::Choice(choice) = &model.definitions[1].value().r#type {
+            assert_eq!(3, choice.len());
+            assert_eq!(
+                &[ExtensionAdditionGroup {
+                    version: Some(3),
+                    start: 1,
+                    end: 3,
+                }],
+                choice.extension_groups()
+            );
+        } else {
+            panic!("Decision is not a CHOICE");
+        }
+    }
+
+    #[test]
+    fn test_extension_addition_group_before_extension_marker_fails() {
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Separator(
+                Location::at(2, 50),
+                '[',
+            )),
+            Model::try_from(Tokenizer::default().parse(
+                r"Groups DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Versioned ::= SEQUENCE { id INTEGER, [[ a INTEGER ]] }
+            END",
+            ))
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    fn test_resolving_moves_extension_addition_groups_along() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"Groups DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Header ::= SEQUENCE { id INTEGER, flag BOOLEAN }
+            Versioned ::= SEQUENCE { COMPONENTS OF Header, ..., [[ a INTEGER ]] }
+            END",
+        ))
+        .expect("Failed to parse")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        if let Type::Sequence(components) = &model.definitions[1].value().r#type {
+            assert_eq!(Some(1), components.extension_after);
+            assert_eq!(
+                vec![ExtensionAdditionGroup {
+                    version: None,
+                    start: 2,
+                    end: 3,
+                }],
+                components.extension_groups
+            );
+        } else {
+            panic!("Versioned is not a SEQUENCE");
+        }
+    }
 }
@@ -1,6 +1,8 @@
+use std::fmt::{Display, Formatter};
+
 /// The object-identifier is described in ITU-T X.680 | ISO/IEC 8824-1:2015
-/// in chapter 32. The XML-related definitions as well as'DefinedValue' is
-/// ignored by this implementation.
+/// in chapter 32. The XML-related definitions are ignored by this implementation
+/// and a 'DefinedValue' is kept in the name-form until it is resolved.
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 pub struct ObjectIdentifier(pub Vec<ObjectIdentifierComponent>);
 
@@ -8,6 +10,51 @@ impl ObjectIdentifier {
     pub fn iter(&self) -> impl Iterator<Item = &ObjectIdentifierComponent> {
         self.0.iter()
     }
+
+    /// The numbers of all components or `None` if at least one component has only a name
+    pub fn arcs(&self) -> Option<Vec<u64>> {
+        self.iter().map(ObjectIdentifierComponent::number).collect()
+    }
+
+    /// Whether both identify the same object, even if written in different forms, such as
+    /// `{ iso member-body(2) }` and `{ 1 2 }`
+    pub fn is_equivalent(&self, other: &ObjectIdentifier) -> bool {
+        match (self.well_known_arcs(), other.well_known_arcs()) {
+            (Some(arcs), Some(other_arcs)) => arcs == other_arcs,
+            _ => self == other,
+        }
+    }
+
+    /// Like [`ObjectIdentifier::arcs`], but also considers the well-known names
+    fn well_known_arcs(&self) -> Option<Vec<u64>> {
+        let mut arcs = Vec::with_capacity(self.0.len());
+        for component in self.iter() {
+            let number = match component {
+                ObjectIdentifierComponent::NameForm(name) => {
+                    ObjectIdentifierComponent::well_known_number(arcs.last().copied(), name)?
+                }
+                component => component.number()?,
+            };
+            arcs.push(number);
+        }
+        Some(arcs)
+    }
+}
+
+impl Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for component in self.iter() {
+            match component {
+                ObjectIdentifierComponent::NameForm(name) => write!(f, " {}", name)?,
+                ObjectIdentifierComponent::NumberForm(number) => write!(f, " {}", number)?,
+                ObjectIdentifierComponent::NameAndNumberForm(name, number) => {
+                    write!(f, " {}({})", name, number)?
+                }
+            }
+        }
+        write!(f, " }}")
+    }
 }
 
 /// The object-identifier is described in ITU-T X.680 | ISO/IEC 8824-1:2015
@@ -19,3 +66,33 @@ pub enum ObjectIdentifierComponent {
     NumberForm(u64),
     NameAndNumberForm(String, u64),
 }
+
+impl ObjectIdentifierComponent {
+    pub fn number(&self) -> Option<u64> {
+        match self {
+            ObjectIdentifierComponent::NameForm(_) => None,
+            ObjectIdentifierComponent::NumberForm(number)
+            | ObjectIdentifierComponent::NameAndNumberForm(_, number) => Some(*number),
+        }
+    }
+
+    /// The number of an arc that can be referred to by its name only: the root arcs and the
+    /// arcs beneath `itu-t` and `iso` that are assigned in ITU-T X.660 | ISO/IEC 9834-1
+    pub fn well_known_number(parent: Option<u64>, name: &str) -> Option<u64> {
+        Some(match (parent, name) {
+            (None, "itu-t") | (None, "ccitt") => 0,
+            (None, "iso") => 1,
+            (None, "joint-iso-itu-t") | (None, "joint-iso-ccitt") => 2,
+            (Some(0), "recommendation") => 0,
+            (Some(0), "question") => 1,
+            (Some(0), "administration") => 2,
+            (Some(0), "network-operator") => 3,
+            (Some(0), "identified-organization") => 4,
+            (Some(1), "standard") => 0,
+            (Some(1), "registration-authority") => 1,
+            (Some(1), "member-body") => 2,
+            (Some(1), "identified-organization") => 3,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,271 @@
+//! Parameterized types and their instances, see ITU-T X.683 | ISO/IEC 8824-4
+
+use crate::model::ioc::read_until_closing_brace;
+use crate::model::lor::{Error as ResolveError, Unresolved};
+use crate::model::{Asn, Error, Model, PeekableTokens};
+use crate::parser::Token;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// A parameterized type assignment like `Container {Type, INTEGER:size} ::= ...` (ITU-T X.683 |
+/// ISO/IEC 8824-4, 8.2). The type is kept as tokens, because what the parameters stand for is
+/// only known once they are replaced by the actual parameters of an [`Instance`].
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ParameterizedType {
+    pub parameters: Vec<Parameter>,
+    /// The tokens of the type on the right hand side of the assignment
+    pub body: Vec<Token>,
+}
+
+/// ITU-T X.683 | ISO/IEC 8824-4, 8.3
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct Parameter {
+    /// The type of a value or value set parameter or the class of an object set parameter
+    pub governor: Option<String>,
+    pub name: String,
+}
+
+impl ParameterizedType {
+    /// Reads the parameter list and the type of the assignment, starting after the name
+    pub(crate) fn read(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Error> {
+        iter.next_separator_eq_or_err('{')?;
+        let parameters = Parameter::read_list(iter)?;
+        Model::<Asn<Unresolved>>::read_assignment(iter)?;
+
+        // the type is parsed once to find its end and to report syntax errors early
+        let mut probe = iter.clone();
+        let _ = Model::<Asn<Unresolved>>::read_type(&mut probe)?;
+        let len = iter.len() - probe.len();
+
+        Ok(Self {
+            parameters,
+            body: iter.by_ref().take(len).collect(),
+        })
+    }
+
+    /// Replaces the parameters in the body by the actual parameters of the given instance and
+    /// parses the result
+    pub fn instantiate(&self, instance: &Instance) -> Result<Asn<Unresolved>, ResolveError> {
+        if self.parameters.len() != instance.arguments.len() {
+            return Err(ResolveError::FailedToInstantiate(format!(
+                "{} expects {} parameters, but got {}",
+                instance.name,
+                self.parameters.len(),
+                instance.arguments.len()
+            )));
+        }
+
+        let mut tokens = Vec::with_capacity(self.body.len());
+        for (index, token) in self.body.iter().enumerate() {
+            let parameter = token.text().and_then(|text| {
+                self.parameters
+                    .iter()
+                    .position(|p| p.name.eq(text))
+                    .filter(|_| !self.is_component_name(index))
+            });
+            match parameter {
+                Some(parameter) => tokens.extend(
+                    self.parameters[parameter]
+                        .actual(&instance.arguments[parameter])
+                        .iter()
+                        .cloned(),
+                ),
+                None => tokens.push(token.clone()),
+            }
+        }
+
+        Model::<Asn<Unresolved>>::read_type(&mut tokens.into_iter().peekable())
+            .map_err(|e| ResolveError::FailedToInstantiate(format!("{} of {}", e, instance.name)))
+    }
+
+    /// Whether the token at the given index names a component, which must not be replaced even
+    /// if a parameter has the same name
+    fn is_component_name(&self, index: usize) -> bool {
+        let follows_separator =
+            index > 0 && matches!(self.body[index - 1].separator(), Some('{') | Some(','));
+        let precedes_type = self
+            .body
+            .get(index + 1)
+            .map_or(false, |next| next.is_text() || next.eq_separator('['));
+        follows_separator && precedes_type
+    }
+}
+
+impl Parameter {
+    fn read_list(iter: &mut Peekable<IntoIter<Token>>) -> Result<Vec<Self>, Error> {
+        let mut parameters = Vec::new();
+        loop {
+            let mut texts = vec![iter.next_text_or_err()?];
+            while iter.peek_or_err()?.is_text() {
+                texts.push(iter.next_text_or_err()?);
+            }
+            parameters.push(if iter.next_is_separator_and_eq(':') {
+                Parameter {
+                    governor: Some(texts.join(" ")),
+                    name: iter.next_text_or_err()?,
+                }
+            } else if texts.len() == 1 {
+                Parameter {
+                    governor: None,
+                    name: texts.remove(0),
+                }
+            } else {
+                return Err(Error::unexpected_token(iter.next_or_err()?));
+            });
+
+            loop_ctrl_separator!(iter.next_or_err()?);
+        }
+        Ok(parameters)
+    }
+
+    /// An object set is passed as `{Set}`, but it replaces the parameter in places like
+    /// `({Parameter}{@id})` where the braces are already given
+    fn actual<'a>(&self, argument: &'a [Token]) -> &'a [Token] {
+        match argument {
+            [open, reference, close]
+                if self.governor.is_some()
+                    && open.eq_separator('{')
+                    && reference.is_text()
+                    && close.eq_separator('}') =>
+            {
+                core::slice::from_ref(reference)
+            }
+            argument => argument,
+        }
+    }
+}
+
+/// The use of a parameterized type like `Container {INTEGER}` (ITU-T X.683 | ISO/IEC 8824-4, 9)
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct Instance {
+    pub name: String,
+    /// The tokens of each actual parameter
+    pub arguments: Vec<Vec<Token>>,
+}
+
+impl Instance {
+    /// Reads the actual parameters, starting after the name of the parameterized type
+    pub(crate) fn read<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        name: String,
+    ) -> Result<Self, Error> {
+        iter.next_separator_eq_or_err('{')?;
+        let mut arguments = vec![Vec::new()];
+        let mut tokens = read_until_closing_brace(iter)?.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            let argument = arguments.last_mut().unwrap();
+            if token.eq_separator(',') {
+                arguments.push(Vec::new());
+            } else if token.eq_separator('{') {
+                argument.push(token);
+                argument.extend(read_until_closing_brace(&mut tokens)?);
+                argument.push(Token::from('}'));
+            } else {
+                argument.push(token);
+            }
+        }
+        Ok(Self { name, arguments })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ComponentTypeList, Field, Integer, LitOrRef, Range, Type};
+    use crate::parser::Tokenizer;
+
+    fn tokens(asn: &str) -> Peekable<IntoIter<Token>> {
+        Tokenizer::default().parse(asn).into_iter().peekable()
+    }
+
+    #[test]
+    fn test_parameter_list() {
+        let mut iter = tokens("{ Type, INTEGER : size, CLASS-NAME : Set } ::= BOOLEAN next");
+        let parameterized = ParameterizedType::read(&mut iter).unwrap();
+        assert_eq!(
+            vec![
+                Parameter {
+                    governor: None,
+                    name: "Type".to_string(),
+                },
+                Parameter {
+                    governor: Some("INTEGER".to_string()),
+                    name: "size".to_string(),
+                },
+                Parameter {
+                    governor: Some("CLASS-NAME".to_string()),
+                    name: "Set".to_string(),
+                },
+            ],
+            parameterized.parameters
+        );
+        assert_eq!(1, parameterized.body.len());
+        assert!(iter.next().unwrap().eq_text("next"));
+    }
+
+    #[test]
+    fn test_instantiate_replaces_parameters_but_not_components() {
+        let parameterized = ParameterizedType::read(&mut tokens(
+            "{ INTEGER : value, Value } ::= SEQUENCE { value Value, other INTEGER (0..value) }",
+        ))
+        .unwrap();
+        let instance = Instance::read(&mut tokens("{ 7, BOOLEAN }"), "Name".to_string()).unwrap();
+
+        assert_eq!(
+            Type::Sequence(ComponentTypeList {
+                fields: vec![
+                    Field {
+                        name: "value".to_string(),
+                        identifier: None,
+                        role: Type::Boolean.untagged(),
+                    },
+                    Field {
+                        name: "other".to_string(),
+                        identifier: None,
+                        role: Type::<Unresolved>::Integer(Integer::with_range(Range::inclusive(
+                            Some(LitOrRef::Lit(0)),
+                            Some(LitOrRef::Lit(7)),
+                        )))
+                        .untagged(),
+                    },
+                ],
+                extension_after: None,
+                extension_groups: Vec::default(),
+            })
+            .untagged(),
+            parameterized.instantiate(&instance).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_instance_arguments() {
+        let instance = Instance::read(
+            &mut tokens("{ {Set}, INTEGER (0..1), { a, b } } next"),
+            "Name".to_string(),
+        )
+        .unwrap();
+        assert_eq!(3, instance.arguments.len());
+        assert_eq!(3, instance.arguments[0].len());
+        assert!(instance.arguments[1][0].eq_text("INTEGER"));
+        assert!(instance.arguments[1].last().unwrap().eq_separator(')'));
+        assert_eq!(5, instance.arguments[2].len());
+
+        let parameter = Parameter {
+            governor: Some("CLASS".to_string()),
+            name: "Set".to_string(),
+        };
+        assert_eq!(1, parameter.actual(&instance.arguments[0]).len());
+        assert_eq!(5, parameter.actual(&instance.arguments[2]).len());
+    }
+
+    #[test]
+    fn test_wrong_number_of_arguments() {
+        let parameterized =
+            ParameterizedType::read(&mut tokens("{ Type } ::= SEQUENCE OF Type")).unwrap();
+        let instance = Instance::read(&mut tokens("{ A, B }"), "Name".to_string()).unwrap();
+        assert!(matches!(
+            parameterized.instantiate(&instance),
+            Err(ResolveError::FailedToInstantiate(_))
+        ));
+    }
+}
@@ -17,6 +17,7 @@ pub enum ProtobufType {
     UInt64,
     SInt32,
     SInt64,
+    Double,
     String,
     Bytes,
     BitsReprByBytesAndBitsLen,
@@ -42,7 +43,10 @@ impl ProtobufType {
             ProtobufType::UInt64 => RustType::U64(Range::none()),
             ProtobufType::SInt32 => RustType::I32(Range::inclusive(0, i32::MAX)),
             ProtobufType::SInt64 => RustType::I64(Range::inclusive(0, i64::MAX)),
-            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8),
+            ProtobufType::Double => RustType::F64,
+            ProtobufType::String => {
+                RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default())
+            }
             ProtobufType::Bytes => RustType::VecU8(Size::Any),
             ProtobufType::BitsReprByBytesAndBitsLen => RustType::BitVec(Size::Any),
             ProtobufType::Repeated(inner) => {
@@ -63,6 +67,7 @@ impl ProtobufType {
             ProtobufType::UInt64 => true,
             ProtobufType::SInt32 => true,
             ProtobufType::SInt64 => true,
+            ProtobufType::Double => true,
             ProtobufType::String => true,
             ProtobufType::Bytes | ProtobufType::BitsReprByBytesAndBitsLen => true,
             ProtobufType::OneOf(_) => false,
@@ -82,6 +87,7 @@ impl ToString for ProtobufType {
             ProtobufType::UInt64 => "uint64",
             ProtobufType::SInt32 => "sint32",
             ProtobufType::SInt64 => "sint64",
+            ProtobufType::Double => "double",
             ProtobufType::String => "string",
             ProtobufType::Bytes => "bytes",
             ProtobufType::BitsReprByBytesAndBitsLen => "bytes",
@@ -119,8 +125,13 @@ impl Model<Protobuf> {
         let mut model = Model {
             name: rust_model.name.clone(),
             oid: rust_model.oid.clone(),
+            tag_default: rust_model.tag_default,
+            exports: rust_model.exports.clone(),
             imports: rust_model.imports.clone(),
             definitions: Vec::with_capacity(rust_model.definitions.len()),
+            object_classes: Vec::default(),
+            object_sets: Vec::default(),
+            parameterized_types: Vec::default(),
             value_references: Vec::default(),
         };
         for Definition(name, rust) in &rust_model.definitions {
@@ -137,7 +148,10 @@ impl Model<Protobuf> {
             Rust::Struct {
                 fields,
                 tag: _,
+                tag_mode: _,
                 extension_after: _,
+                extension_group: _,
+                unknown_extensions: _,
                 ordering: _,
             } => {
                 let mut proto_fields = Vec::with_capacity(fields.len());
@@ -150,10 +164,17 @@ impl Model<Protobuf> {
 
                 Protobuf::Message(proto_fields)
             }
-            Rust::Enum(r_enum) => {
-                Protobuf::Enum(r_enum.variants().map(|v| proto_variant_name(v)).collect())
-            }
-            Rust::DataEnum(enumeration) => {
+            Rust::Enum(r_enum) => Protobuf::Enum(
+                r_enum
+                    .variants()
+                    .map(|v| proto_variant_name(v.name()))
+                    .collect(),
+            ),
+            Rust::DataEnum(enumeration)
+            | Rust::OpenTypeChoice {
+                variants: enumeration,
+                ..
+            } => {
                 let mut proto_enum = Vec::with_capacity(enumeration.len());
                 for variant in enumeration.variants() {
                     proto_enum.push((
@@ -185,12 +206,17 @@ impl Model<Protobuf> {
             RustType::I32(_) => ProtobufType::SInt32,
             RustType::U64(_) => ProtobufType::UInt64,
             RustType::I64(_) => ProtobufType::SInt64,
+            RustType::F64 => ProtobufType::Double,
+            RustType::ObjectIdentifier | RustType::RelativeOid => ProtobufType::String,
+            RustType::Time(_) => ProtobufType::String,
             RustType::String(..) => ProtobufType::String,
             RustType::VecU8(_) => ProtobufType::Bytes,
             RustType::BitVec(_) => ProtobufType::BitsReprByBytesAndBitsLen,
             RustType::Null => ProtobufType::Bytes,
+            RustType::OpenType => ProtobufType::Bytes,
 
             RustType::Complex(complex, _) => ProtobufType::Complex(complex.clone()),
+            RustType::Related(_, inner) => Self::definition_type_to_protobuf_type(inner),
 
             RustType::Option(inner) => {
                 // in protobuf everything is optional...
@@ -266,6 +292,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rust_real_to_protobuf_double() {
+        test_model_definition_conversion(
+            &[Definition(
+                "Measurement".into(),
+                Rust::struct_from_fields(vec![Field::from_name_type("value", RustType::F64)]),
+            )],
+            &[Definition(
+                "Measurement".into(),
+                Protobuf::Message(vec![("value".into(), ProtobufType::Double)]),
+            )],
+        );
+    }
+
     #[test]
     fn test_simple_rust_tuple_to_protobuf() {
         test_model_definition_conversion(
@@ -304,7 +344,11 @@ mod tests {
                 "SuchStruct".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "very_optional",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(
+                        Size::Any,
+                        Charset::Utf8,
+                        PermittedAlphabet::default(),
+                    ))),
                 )]),
             )],
             &[Definition(
@@ -322,7 +366,7 @@ mod tests {
                 Rust::DataEnum(
                     vec![DataVariant::from_name_type(
                         "MuchVariant",
-                        RustType::String(Size::Any, Charset::Utf8),
+                        RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default()),
                     )]
                     .into(),
                 ),
@@ -0,0 +1,94 @@
+use crate::model::{Error, PeekableTokens, Range};
+use crate::parser::Token;
+use std::convert::TryFrom;
+use std::iter::Peekable;
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 21, with the components of the associated type (21.5)
+/// optionally constrained through `WITH COMPONENTS`
+#[derive(Default, Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct Real {
+    pub mantissa: Range<Option<i64>>,
+    pub base: Option<u8>,
+    pub exponent: Range<Option<i64>>,
+}
+
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Real {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        let mut real = Self::default();
+        if !iter.next_is_separator_and_eq('(') {
+            return Ok(real);
+        }
+
+        iter.next_text_eq_ignore_case_or_err("WITH")?;
+        iter.next_text_eq_ignore_case_or_err("COMPONENTS")?;
+        iter.next_separator_eq_or_err('{')?;
+
+        while !iter.peek_is_separator_eq('}') {
+            let name = iter.next_or_err()?;
+            let range = Self::read_range(iter)?;
+            match name.text() {
+                Some(text) if text.eq_ignore_ascii_case("mantissa") => real.mantissa = range,
+                Some(text) if text.eq_ignore_ascii_case("exponent") => real.exponent = range,
+                Some(text) if text.eq_ignore_ascii_case("base") => {
+                    real.base = match range {
+                        Range(Some(base @ 2), Some(2), false)
+                        | Range(Some(base @ 10), Some(10), false) => Some(base as u8),
+                        _ => return Err(Error::invalid_range_value(name)),
+                    }
+                }
+                _ => return Err(Error::unexpected_token(name)),
+            }
+
+            if !iter.next_is_separator_and_eq(',') {
+                break;
+            }
+        }
+
+        iter.next_separator_eq_or_err('}')?;
+        iter.next_separator_eq_or_err(')')?;
+        Ok(real)
+    }
+}
+
+impl Real {
+    /// Reads a single value `(2)` or a range `(-125..128)` where `MIN` and `MAX` are unbounded
+    fn read_range<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<Range<Option<i64>>, Error> {
+        iter.next_separator_eq_or_err('(')?;
+        let min = Self::read_bound(iter, "MIN")?;
+        let max = if iter.next_is_separator_and_eq('.') {
+            iter.next_separator_eq_or_err('.')?;
+            Self::read_bound(iter, "MAX")?
+        } else {
+            min
+        };
+        let extensible = if iter.next_is_separator_and_eq(',') {
+            iter.next_separator_eq_or_err('.')?;
+            iter.next_separator_eq_or_err('.')?;
+            iter.next_separator_eq_or_err('.')?;
+            true
+        } else {
+            false
+        };
+        iter.next_separator_eq_or_err(')')?;
+        Ok(Range(min, max, extensible))
+    }
+
+    fn read_bound<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        unbounded: &str,
+    ) -> Result<Option<i64>, Error> {
+        let token = iter.next_or_err()?;
+        match token.text() {
+            Some(text) if text.eq_ignore_ascii_case(unbounded) => Ok(None),
+            Some(text) => match text.parse::<i64>() {
+                Ok(value) => Ok(Some(value)),
+                Err(_) => Err(Error::invalid_range_value(token)),
+            },
+            None => Err(Error::invalid_range_value(token)),
+        }
+    }
+}
@@ -1,9 +1,14 @@
-use crate::model::lor::{Error, Resolved, Resolver, Unresolved};
-use crate::model::{Asn, Definition, LitOrRef, LiteralValue, Model, Target, Type, ValueReference};
+use crate::model::lor::{Error, Expander, Instantiator, Resolved, Resolver, Unresolved};
+use crate::model::{
+    Asn, Definition, Field, Import, Instance, LitOrRef, LiteralValue, Model, ObjectClass,
+    ObjectIdentifier, ObjectIdentifierComponent, ObjectSet, ParameterizedType, Selection, Target,
+    Type, ValueReference,
+};
 
 #[derive(Default)]
 pub struct MultiModuleResolver {
     models: Vec<Model<Asn<Unresolved>>>,
+    imported_modules_required: bool,
 }
 
 impl MultiModuleResolver {
@@ -11,15 +16,26 @@ impl MultiModuleResolver {
         self.models.push(model);
     }
 
+    /// Whether resolving fails with [`Error::UnresolvedImport`] if a module imports from a module
+    /// that is not pushed. Otherwise, such imports are kept unresolved, so that modules can be
+    /// resolved one at a time, which is the default.
+    pub fn set_imported_modules_required(&mut self, required: bool) {
+        self.imported_modules_required = required;
+    }
+
     pub fn try_resolve_all(&self) -> Result<Vec<Model<Asn<Resolved>>>, Error> {
         self.models
             .iter()
             .map(|model| {
-                ResolveScope {
+                let scope = ResolveScope {
                     model,
                     scope: &self.models,
+                    caller: None,
+                };
+                if self.imported_modules_required {
+                    scope.check_imported_modules_are_loaded()?;
                 }
-                .try_resolve()
+                scope.try_resolve()
             })
             .collect::<_>()
     }
@@ -28,6 +44,9 @@ impl MultiModuleResolver {
 pub(crate) struct ResolveScope<'a> {
     model: &'a Model<Asn<Unresolved>>,
     scope: &'a [Model<Asn<Unresolved>>],
+    /// The scope of the instance of a parameterized type, whose actual parameters refer to names
+    /// that are not necessarily known to the module defining the parameterized type
+    caller: Option<&'a ResolveScope<'a>>,
 }
 
 impl<'a> From<&'a Model<Asn<Unresolved>>> for ResolveScope<'a> {
@@ -35,6 +54,7 @@ impl<'a> From<&'a Model<Asn<Unresolved>>> for ResolveScope<'a> {
         Self {
             model,
             scope: core::slice::from_ref(model),
+            caller: None,
         }
     }
 }
@@ -44,17 +64,24 @@ impl<'a> ResolveScope<'a> {
         let mut result = Model::<Asn<Resolved>> {
             name: self.model.name.clone(),
             oid: self.model.oid.clone(),
+            tag_default: self.model.tag_default,
+            exports: self.model.exports.clone(),
             imports: self.model.imports.clone(),
             definitions: Vec::with_capacity(self.model.definitions.len()),
             value_references: Vec::with_capacity(self.model.value_references.len()),
+            object_classes: self.model.object_classes.clone(),
+            object_sets: self.model.object_sets.clone(),
+            parameterized_types: self.model.parameterized_types.clone(),
         };
 
+        self.check_imports_are_exported()?;
+
         // copy over all value references
         for vr in &self.model.value_references {
             result.value_references.push(ValueReference {
                 name: vr.name.clone(),
                 role: vr.role.try_resolve(self)?,
-                value: vr.value.clone(),
+                value: self.resolve_literal(&vr.value)?,
             })
         }
 
@@ -67,16 +94,44 @@ impl<'a> ResolveScope<'a> {
         Ok(result)
     }
 
+    /// See [`MultiModuleResolver::set_imported_modules_required`]
+    fn check_imported_modules_are_loaded(&self) -> Result<(), Error> {
+        match self
+            .model
+            .imports
+            .iter()
+            .find(|import| self.imported_module(import).is_none())
+        {
+            Some(import) => Err(Error::UnresolvedImport(
+                import.what.join(", "),
+                import.module_description(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Modules that are not part of the scope cannot be checked and are therefore ignored
+    fn check_imports_are_exported(&self) -> Result<(), Error> {
+        for import in &self.model.imports {
+            if let Some(module) = self.imported_module(import) {
+                if let Some(what) = import.what.iter().find(|w| !module.exports.contains(w)) {
+                    return Err(Error::SymbolNotExported(what.clone(), module.name.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn model_with_imported_item(&self, item: &str) -> Option<&'a Model<Asn<Unresolved>>> {
         self.model
             .imports
             .iter()
             .find(|i| i.what.iter().any(|what| what.eq(item)))
-            .and_then(|import| {
-                self.scope.iter().find(|m| {
-                    (m.oid.is_some() && m.oid.eq(&import.from_oid)) || m.name.eq(&import.from)
-                })
-            })
+            .and_then(|import| self.imported_module(import))
+    }
+
+    fn imported_module(&self, import: &Import) -> Option<&'a Model<Asn<Unresolved>>> {
+        self.scope.iter().find(|m| import.refers_to(m))
     }
 
     fn value_reference(
@@ -92,26 +147,184 @@ impl<'a> ResolveScope<'a> {
                     ResolveScope {
                         model,
                         scope: self.scope,
+                        caller: None,
                     }
                     .value_reference(name)
                 })
             })
+            .or_else(|| self.caller.and_then(|caller| caller.value_reference(name)))
+    }
+
+    fn resolve_literal(&self, value: &LiteralValue) -> Result<LiteralValue, Error> {
+        match value {
+            LiteralValue::ObjectIdentifier(oid) => Ok(LiteralValue::ObjectIdentifier(
+                self.resolve_object_identifier(oid, &mut Vec::new())?,
+            )),
+            value => Ok(value.clone()),
+        }
+    }
+
+    /// Replaces all components in the name form by their numbers. The first component might
+    /// refer to another object identifier value, that is then inserted in place, while all other
+    /// names need to be well-known arcs.
+    fn resolve_object_identifier(
+        &self,
+        oid: &ObjectIdentifier,
+        resolving: &mut Vec<String>,
+    ) -> Result<ObjectIdentifier, Error> {
+        let mut components = Vec::with_capacity(oid.0.len());
+        for (index, component) in oid.iter().enumerate() {
+            let name = match component {
+                ObjectIdentifierComponent::NameForm(name) => name,
+                component => {
+                    components.push(component.clone());
+                    continue;
+                }
+            };
+
+            let referenced = self
+                .value_reference(name)
+                .filter(|_| index == 0 && !resolving.contains(name));
+
+            if let Some(ValueReference {
+                value: LiteralValue::ObjectIdentifier(referenced),
+                ..
+            }) = referenced
+            {
+                resolving.push(name.clone());
+                let resolved = self.resolve_object_identifier(referenced, resolving)?;
+                resolving.pop();
+                components.extend(resolved.0);
+            } else {
+                // only the first two arcs can be well-known, see `well_known_number`
+                let parent = match components.as_slice() {
+                    [] => None,
+                    [parent] => parent.number(),
+                    _ => return Err(Error::FailedToResolveReference(name.clone())),
+                };
+                let number = ObjectIdentifierComponent::well_known_number(parent, name)
+                    .ok_or_else(|| Error::FailedToResolveReference(name.clone()))?;
+                components.push(ObjectIdentifierComponent::NameAndNumberForm(
+                    name.clone(),
+                    number,
+                ));
+            }
+        }
+        Ok(ObjectIdentifier(components))
     }
 
     fn definition(&self, name: &str) -> Option<&'a Definition<Asn<Unresolved>>> {
+        self.definition_in_scope(name).map(|(_scope, def)| def)
+    }
+
+    /// Returns the definition together with the scope of the module defining it
+    fn definition_in_scope(
+        &self,
+        name: &str,
+    ) -> Option<(ResolveScope<'a>, &'a Definition<Asn<Unresolved>>)> {
         self.model
             .definitions
             .iter()
             .find(|def| def.name().eq(name))
+            .map(|def| {
+                let scope = ResolveScope {
+                    model: self.model,
+                    scope: self.scope,
+                    caller: None,
+                };
+                (scope, def)
+            })
+            .or_else(|| {
+                self.model_with_imported_item(name).and_then(|model| {
+                    ResolveScope {
+                        model,
+                        scope: self.scope,
+                        caller: None,
+                    }
+                    .definition_in_scope(name)
+                })
+            })
+            .or_else(|| {
+                self.caller
+                    .and_then(|caller| caller.definition_in_scope(name))
+            })
+    }
+
+    /// Follows references to other definitions up to the actual type, which is returned in the
+    /// scope of the module defining it
+    fn dereference(&self, name: &str) -> Result<(ResolveScope<'a>, &'a Type<Unresolved>), Error> {
+        match self.definition_in_scope(name) {
+            Some((scope, Definition(_name, asn))) => match &asn.r#type {
+                Type::TypeReference(other, _tag) => scope.dereference(other),
+                r#type => Ok((scope, r#type)),
+            },
+            None => Err(Error::FailedToResolveType(name.to_string())),
+        }
+    }
+
+    fn object_class(&self, name: &str) -> Option<&'a Definition<ObjectClass>> {
+        self.model
+            .object_classes
+            .iter()
+            .find(|def| def.name().eq(name))
+            .or_else(|| {
+                self.model_with_imported_item(name).and_then(|model| {
+                    ResolveScope {
+                        model,
+                        scope: self.scope,
+                        caller: None,
+                    }
+                    .object_class(name)
+                })
+            })
+            .or_else(|| self.caller.and_then(|caller| caller.object_class(name)))
+    }
+
+    fn object_set(&self, name: &str) -> Option<&'a Definition<ObjectSet>> {
+        self.model
+            .object_sets
+            .iter()
+            .find(|def| def.name().eq(name))
             .or_else(|| {
                 self.model_with_imported_item(name).and_then(|model| {
                     ResolveScope {
                         model,
                         scope: self.scope,
+                        caller: None,
                     }
-                    .definition(name)
+                    .object_set(name)
                 })
             })
+            .or_else(|| self.caller.and_then(|caller| caller.object_set(name)))
+    }
+
+    /// Returns the parameterized type together with the module defining it
+    fn parameterized_type(
+        &self,
+        name: &str,
+    ) -> Option<(
+        &'a Model<Asn<Unresolved>>,
+        &'a Definition<ParameterizedType>,
+    )> {
+        self.model
+            .parameterized_types
+            .iter()
+            .find(|def| def.name().eq(name))
+            .map(|def| (self.model, def))
+            .or_else(|| {
+                self.model_with_imported_item(name).and_then(|model| {
+                    ResolveScope {
+                        model,
+                        scope: self.scope,
+                        caller: None,
+                    }
+                    .parameterized_type(name)
+                })
+            })
+            .or_else(|| {
+                self.caller
+                    .and_then(|caller| caller.parameterized_type(name))
+            })
     }
 }
 
@@ -147,11 +360,11 @@ impl Resolver<i64> for ResolveScope<'_> {
 impl Resolver<LiteralValue> for ResolveScope<'_> {
     fn resolve(&self, lor: &LitOrRef<LiteralValue>) -> Result<LiteralValue, Error> {
         match lor {
-            LitOrRef::Lit(lit) => Ok(lit.clone()),
+            LitOrRef::Lit(lit) => self.resolve_literal(lit),
             LitOrRef::Ref(name) => self
                 .value_reference(name)
-                .map(|vr| vr.value.clone())
-                .ok_or_else(|| Error::FailedToResolveReference(name.clone())),
+                .ok_or_else(|| Error::FailedToResolveReference(name.clone()))
+                .and_then(|vr| self.resolve_literal(&vr.value)),
         }
     }
 }
@@ -167,3 +380,82 @@ impl Resolver<Type<Unresolved>> for ResolveScope<'_> {
         }
     }
 }
+
+impl Resolver<ObjectClass> for ResolveScope<'_> {
+    fn resolve(&self, lor: &LitOrRef<ObjectClass>) -> Result<ObjectClass, Error> {
+        match lor {
+            LitOrRef::Lit(lit) => Ok(lit.clone()),
+            LitOrRef::Ref(name) => match self.object_class(name) {
+                Some(def) => Ok(def.1.clone()),
+                None if name == ObjectClass::TYPE_IDENTIFIER => Ok(ObjectClass::type_identifier()),
+                None => Err(Error::FailedToResolveType(name.clone())),
+            },
+        }
+    }
+}
+
+impl Resolver<ObjectSet> for ResolveScope<'_> {
+    fn resolve(&self, lor: &LitOrRef<ObjectSet>) -> Result<ObjectSet, Error> {
+        match lor {
+            LitOrRef::Lit(lit) => Ok(lit.clone()),
+            LitOrRef::Ref(name) => self
+                .object_set(name)
+                .map(|def| def.1.clone())
+                .ok_or_else(|| Error::FailedToResolveReference(name.clone())),
+        }
+    }
+}
+
+impl Expander for ResolveScope<'_> {
+    fn select(&self, selection: &Selection) -> Result<Asn<Resolved>, Error> {
+        let (scope, r#type) = self.dereference(&selection.choice)?;
+        let variant = match r#type {
+            Type::Choice(choice) => choice
+                .variants()
+                .find(|variant| variant.name().eq(&selection.alternative)),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            Error::FailedToResolveType(format!("{} < {}", selection.alternative, selection.choice))
+        })?;
+        Ok(Asn {
+            tag: variant.tag,
+            tag_mode: variant.tag_mode,
+            r#type: variant.r#type.try_resolve(&scope)?,
+            default: None,
+        })
+    }
+
+    fn components_of(&self, name: &str) -> Result<Vec<Field<Asn<Resolved>>>, Error> {
+        match self.dereference(name)? {
+            (scope, Type::Sequence(components)) | (scope, Type::Set(components)) => {
+                let mut components = components.try_resolve(&scope)?;
+                if let Some(extension_after) = components.extension_after {
+                    components.fields.truncate(extension_after + 1);
+                }
+                Ok(components.fields)
+            }
+            _ => Err(Error::FailedToResolveType(format!(
+                "COMPONENTS OF {}",
+                name
+            ))),
+        }
+    }
+}
+
+impl Instantiator for ResolveScope<'_> {
+    fn instantiate(&self, instance: &Instance) -> Result<Asn<Resolved>, Error> {
+        let (model, parameterized) = self
+            .parameterized_type(&instance.name)
+            .ok_or_else(|| Error::FailedToResolveType(instance.name.clone()))?;
+        // the type is resolved in the module defining it, the actual parameters in this one
+        parameterized
+            .1
+            .instantiate(instance)?
+            .try_resolve(&ResolveScope {
+                model,
+                scope: self.scope,
+                caller: Some(self),
+            })
+    }
+}
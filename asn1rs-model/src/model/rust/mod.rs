@@ -1,12 +1,12 @@
 use crate::model::lor::{ResolveState, Resolved};
 use crate::model::rust::Field as RustField;
 use crate::model::{Asn, ChoiceVariant, Integer, LiteralValue, Target};
-use crate::model::{Charset, Range};
-use crate::model::{ComponentTypeList, ValueReference};
-use crate::model::{Definition, Type};
-use crate::model::{Import, Tag, TagProperty};
+use crate::model::{Charset, PermittedAlphabet, Range, Real};
+use crate::model::{ComponentTypeList, ExtensionAdditionGroup, ValueReference};
+use crate::model::{Definition, Exports, Type};
+use crate::model::{Import, Tag, TagDefault, TagMode, TagProperty};
 use crate::model::{Model, Size};
-use crate::model::{TagResolver, Type as AsnType};
+use crate::model::{TagResolver, TimeType, Type as AsnType};
 use std::borrow::Cow;
 
 const I8_MAX: i64 = i8::MAX as i64;
@@ -19,7 +19,19 @@ const U16_MAX: u64 = u16::MAX as u64;
 const U32_MAX: u64 = u32::MAX as u64;
 //const U64_MAX: u64 = u64::MAX as u64;
 
-pub type PlainVariant = String;
+/// The name of the field of an extensible SEQUENCE or SET that keeps the extension additions
+/// which are unknown to the struct when reading a value of a newer version of the type
+pub const UNKNOWN_EXTENSIONS_FIELD: &str = "unknown_extensions";
+
+/// The name of the variant of an extensible CHOICE that keeps an alternative which is unknown to
+/// the enum when reading a value of a newer version of the type
+pub const UNKNOWN_EXTENSION_VARIANT: &str = "UnknownExtension";
+
+/// The name of the variant of an open type constrained by an extensible object set that keeps
+/// the value of an object which is unknown to the enum, together with the value of the related
+/// field that selected it
+pub const UNKNOWN_OBJECT_VARIANT: &str = "Unknown";
+
 pub type PlainEnum = Enumeration<PlainVariant>;
 pub type DataEnum = Enumeration<DataVariant>;
 
@@ -39,11 +51,17 @@ pub enum RustType {
     U32(Range<u32>),
     I64(Range<i64>),
     U64(Range<Option<u64>>),
-    String(Size, Charset),
+    F64,
+    String(Size, Charset, PermittedAlphabet),
     VecU8(Size),
     BitVec(Size),
     Vec(Box<RustType>, Size, EncodingOrdering),
     Null,
+    ObjectIdentifier,
+    RelativeOid,
+    Time(TimeType),
+    /// The encoded value of an open type
+    OpenType,
 
     Option(Box<RustType>),
     Default(Box<RustType>, LiteralValue),
@@ -53,12 +71,17 @@ pub enum RustType {
     /// thought of as a "ReferenceType"; declaring usage,
     /// but not being declared here
     Complex(String, Option<Tag>),
+
+    /// A value whose type is selected by the value of the field with the given name
+    Related(String, Box<RustType>),
 }
 
 impl RustType {
     pub fn as_inner_type(&self) -> &RustType {
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) | RustType::Default(inner, ..) =
-            self
+        if let RustType::Vec(inner, ..)
+        | RustType::Option(inner)
+        | RustType::Default(inner, ..)
+        | RustType::Related(_, inner) = self
         {
             inner.as_inner_type()
         } else {
@@ -67,8 +90,10 @@ impl RustType {
     }
 
     pub fn into_inner_type(self) -> RustType {
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) | RustType::Default(inner, ..) =
-            self
+        if let RustType::Vec(inner, ..)
+        | RustType::Option(inner)
+        | RustType::Default(inner, ..)
+        | RustType::Related(_, inner) = self
         {
             inner.into_inner_type()
         } else {
@@ -121,7 +146,8 @@ impl RustType {
                 | RustType::U32(_)
                 | RustType::I32(_)
                 | RustType::U64(_)
-                | RustType::I64(_),
+                | RustType::I64(_)
+                | RustType::F64,
         ) || matches!(self, RustType::Default(inner, ..) if inner.is_primitive())
     }
 
@@ -155,6 +181,10 @@ impl RustType {
             RustType::I64(Range(min, max, extensible)) => {
                 Some(Range(min.to_string(), max.to_string(), *extensible))
             }
+            RustType::F64 => None,
+            RustType::ObjectIdentifier | RustType::RelativeOid => None,
+            RustType::Time(_) => None,
+            RustType::OpenType => None,
             RustType::String(..) => None,
             RustType::VecU8(_) => None,
             RustType::BitVec(_) => None,
@@ -163,6 +193,7 @@ impl RustType {
             RustType::Option(inner) => inner.integer_range_str(),
             RustType::Default(inner, ..) => inner.integer_range_str(),
             RustType::Complex(_, _) => None,
+            RustType::Related(..) => None,
         }
     }
 
@@ -207,7 +238,12 @@ impl RustType {
                 range.max().map(|v| v as i64),
                 range.extensible(),
             )),
-            RustType::String(size, charset) => AsnType::String(size, charset),
+            RustType::F64 => AsnType::Real(Real::default()),
+            RustType::ObjectIdentifier => AsnType::ObjectIdentifier,
+            RustType::RelativeOid => AsnType::RelativeOid,
+            RustType::Time(time) => AsnType::Time(time),
+            RustType::OpenType => AsnType::OpenType,
+            RustType::String(size, charset, alphabet) => AsnType::String(size, charset, alphabet),
             RustType::VecU8(size) => AsnType::OctetString(size),
             RustType::BitVec(size) => AsnType::bit_vec_with_size(size),
             RustType::Vec(inner, size, EncodingOrdering::Keep) => {
@@ -222,6 +258,9 @@ impl RustType {
                 AsnType::Default(Box::new(value.into_asn()), default)
             }
             RustType::Complex(name, tag) => AsnType::TypeReference(name, tag),
+            RustType::Related(relation, inner) => {
+                AsnType::Related(relation, Box::new(inner.into_asn()))
+            }
         }
     }
 
@@ -236,6 +275,11 @@ impl RustType {
             RustType::I32(_) => matches!(other, RustType::I32(_)),
             RustType::U64(_) => matches!(other, RustType::U64(_)),
             RustType::I64(_) => matches!(other, RustType::I64(_)),
+            RustType::F64 => RustType::F64 == *other,
+            RustType::ObjectIdentifier => RustType::ObjectIdentifier == *other,
+            RustType::RelativeOid => RustType::RelativeOid == *other,
+            RustType::Time(time) => RustType::Time(*time) == *other,
+            RustType::OpenType => RustType::OpenType == *other,
             RustType::String(..) => matches!(other, RustType::String(..)),
             RustType::VecU8(_) => matches!(other, RustType::VecU8(_)),
             RustType::BitVec(_) => matches!(other, RustType::BitVec(_)),
@@ -263,6 +307,9 @@ impl RustType {
                     false
                 }
             }
+            RustType::Related(_, inner_a) => {
+                matches!(other, RustType::Related(_, inner_b) if inner_a.similar(inner_b))
+            }
         }
     }
 
@@ -278,9 +325,13 @@ impl RustType {
             | RustType::U32(_)
             | RustType::I64(_)
             | RustType::U64(_) => Tag::DEFAULT_INTEGER,
+            RustType::F64 => Tag::DEFAULT_REAL,
+            RustType::ObjectIdentifier => Tag::DEFAULT_OBJECT_IDENTIFIER,
+            RustType::RelativeOid => Tag::DEFAULT_RELATIVE_OID,
+            RustType::Time(time) => time.default_tag(),
             RustType::BitVec(_) => Tag::DEFAULT_BIT_STRING,
             RustType::VecU8(_) => Tag::DEFAULT_OCTET_STRING,
-            RustType::String(_, charset) => charset.default_tag(),
+            RustType::String(_, charset, _) => charset.default_tag(),
             RustType::Vec(_, _, EncodingOrdering::Keep) => Tag::DEFAULT_SEQUENCE_OF,
             RustType::Vec(_, _, EncodingOrdering::Sort) => Tag::DEFAULT_SET_OF,
             RustType::Null => Tag::DEFAULT_NULL,
@@ -288,6 +339,8 @@ impl RustType {
             RustType::Default(inner, ..) => return inner.tag(),
             // TODO this is wrong. This should resolve the tag from the referenced type instead, but atm the infrastructure is missing to do such a thing, see github#13
             RustType::Complex(_, tag) => return *tag,
+            // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, an open type has no tag of its own
+            RustType::Related(..) | RustType::OpenType => return None,
         })
     }
 }
@@ -306,28 +359,57 @@ pub enum Rust {
         ordering: EncodingOrdering,
         fields: Vec<Field>,
         tag: Option<Tag>,
+        tag_mode: TagMode,
         extension_after: Option<usize>,
+        /// Whether this holds the components of an extension addition group of another struct
+        /// (see [`crate::model::ExtensionAdditionGroup`])
+        extension_group: bool,
+        /// Whether the extension additions unknown to the struct are kept in the additional
+        /// field [`UNKNOWN_EXTENSIONS_FIELD`]
+        unknown_extensions: bool,
     },
     Enum(PlainEnum),
     DataEnum(DataEnum),
 
+    /// The alternatives of an open type, each selected by the key of the same index
+    OpenTypeChoice {
+        variants: DataEnum,
+        keys: Vec<i64>,
+    },
+
     /// Used to represent a single, unnamed inner type
     // TODO inline the referred type!?
     TupleStruct {
         r#type: RustType,
         tag: Option<Tag>,
+        tag_mode: TagMode,
         constants: Vec<(String, String)>,
     },
 }
 
 impl Rust {
+    /// Extension additions unknown to an extensible struct or enum are skipped instead of being
+    /// kept in the field [`UNKNOWN_EXTENSIONS_FIELD`] or the variant [`UNKNOWN_EXTENSION_VARIANT`]
+    pub fn skip_unknown_extensions(&mut self) {
+        match self {
+            Rust::Struct {
+                unknown_extensions, ..
+            } => *unknown_extensions = false,
+            Rust::DataEnum(enumeration) => enumeration.unknown_extension = false,
+            Rust::Enum(_) | Rust::OpenTypeChoice { .. } | Rust::TupleStruct { .. } => {}
+        }
+    }
+
     #[cfg(test)]
     pub fn struct_from_fields(fields: Vec<Field>) -> Self {
         Self::Struct {
             ordering: EncodingOrdering::Keep,
             fields,
             tag: None,
+            tag_mode: TagMode::default(),
             extension_after: None,
+            extension_group: false,
+            unknown_extensions: false,
         }
     }
 
@@ -335,9 +417,29 @@ impl Rust {
         Self::TupleStruct {
             r#type,
             tag: None,
+            tag_mode: TagMode::default(),
             constants: Vec::default(),
         }
     }
+
+    pub fn tag_mode(&self) -> TagMode {
+        match self {
+            Rust::Struct { tag_mode, .. } | Rust::TupleStruct { tag_mode, .. } => *tag_mode,
+            Rust::Enum(e) => e.tag_mode(),
+            Rust::DataEnum(c) => c.tag_mode(),
+            Rust::OpenTypeChoice { variants, .. } => variants.tag_mode(),
+        }
+    }
+
+    pub fn with_tag_mode(mut self, mode: TagMode) -> Self {
+        match &mut self {
+            Rust::Struct { tag_mode, .. } | Rust::TupleStruct { tag_mode, .. } => *tag_mode = mode,
+            Rust::Enum(e) => e.tag_mode = mode,
+            Rust::DataEnum(c) => c.tag_mode = mode,
+            Rust::OpenTypeChoice { variants, .. } => variants.tag_mode = mode,
+        }
+        self
+    }
 }
 
 impl Target for Rust {
@@ -351,6 +453,7 @@ impl TagProperty for Rust {
             Rust::Struct { tag, .. } => *tag,
             Rust::Enum(e) => e.tag(),
             Rust::DataEnum(c) => c.tag(),
+            Rust::OpenTypeChoice { variants, .. } => variants.tag(),
             Rust::TupleStruct { tag, .. } => *tag,
         }
     }
@@ -360,6 +463,7 @@ impl TagProperty for Rust {
             Rust::Struct { tag, .. } => *tag = Some(new_tag),
             Rust::Enum(e) => e.set_tag(new_tag),
             Rust::DataEnum(c) => c.set_tag(new_tag),
+            Rust::OpenTypeChoice { variants, .. } => variants.set_tag(new_tag),
             Rust::TupleStruct { tag, .. } => *tag = Some(new_tag),
         }
     }
@@ -369,6 +473,7 @@ impl TagProperty for Rust {
             Rust::Struct { tag, .. } => *tag = None,
             Rust::Enum(e) => e.reset_tag(),
             Rust::DataEnum(c) => c.reset_tag(),
+            Rust::OpenTypeChoice { variants, .. } => variants.reset_tag(),
             Rust::TupleStruct { tag, .. } => *tag = None,
         }
     }
@@ -389,6 +494,11 @@ impl RustType {
             RustType::I32(_) => "i32",
             RustType::U64(_) => "u64",
             RustType::I64(_) => "i64",
+            RustType::F64 => "f64",
+            RustType::ObjectIdentifier => "ObjectIdentifier",
+            RustType::RelativeOid => "RelativeOid",
+            RustType::Time(time) => time.rust_name(),
+            RustType::OpenType => "OpenType",
             RustType::String(..) => "&'static str",
             RustType::VecU8(_) => "&'static [u8]",
             RustType::BitVec(_) => "u64",
@@ -401,6 +511,7 @@ impl RustType {
             }
             RustType::Default(inner, ..) => return inner.to_const_lit_string(),
             RustType::Complex(name, _) => return Cow::Owned(name.clone()),
+            RustType::Related(_, inner) => return inner.to_const_lit_string(),
         })
     }
 }
@@ -417,6 +528,11 @@ impl ToString for RustType {
             RustType::I32(_) => "i32",
             RustType::U64(_) => "u64",
             RustType::I64(_) => "i64",
+            RustType::F64 => "f64",
+            RustType::ObjectIdentifier => "ObjectIdentifier",
+            RustType::RelativeOid => "RelativeOid",
+            RustType::Time(time) => time.rust_name(),
+            RustType::OpenType => "OpenType",
             RustType::String(..) => "String",
             RustType::VecU8(_) => "Vec<u8>",
             RustType::BitVec(_) => "BitVec",
@@ -425,6 +541,7 @@ impl ToString for RustType {
             RustType::Option(inner) => return format!("Option<{}>", inner.to_string()),
             RustType::Default(inner, ..) => return inner.to_string(),
             RustType::Complex(name, _) => return name.clone(),
+            RustType::Related(_, inner) => return inner.to_string(),
         }
         .into()
     }
@@ -434,7 +551,10 @@ impl ToString for RustType {
 pub struct Field {
     pub(crate) name_type: (String, RustType),
     pub(crate) tag: Option<Tag>,
+    pub(crate) tag_mode: TagMode,
     pub(crate) constants: Vec<(String, String)>,
+    /// The identifier in the ASN.1 definition, if it differs from the name
+    pub(crate) identifier: Option<String>,
 }
 
 impl Field {
@@ -442,7 +562,9 @@ impl Field {
         Self {
             name_type: (name.to_string(), r#type),
             tag: None,
+            tag_mode: TagMode::default(),
             constants: Vec::default(),
+            identifier: None,
         }
     }
 
@@ -466,6 +588,25 @@ impl Field {
         self.constants = constants;
         self
     }
+
+    /// The identifier of this field in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    pub fn with_identifier_opt(mut self, identifier: Option<String>) -> Self {
+        self.identifier = identifier;
+        self
+    }
+
+    pub fn tag_mode(&self) -> TagMode {
+        self.tag_mode
+    }
+
+    pub fn with_tag_mode(mut self, tag_mode: TagMode) -> Self {
+        self.tag_mode = tag_mode;
+        self
+    }
 }
 
 impl TagProperty for Field {
@@ -486,7 +627,11 @@ impl TagProperty for Field {
 pub struct Enumeration<T> {
     variants: Vec<T>,
     tag: Option<Tag>,
+    tag_mode: TagMode,
     extended_after_index: Option<usize>,
+    /// Whether an alternative unknown to the enum is kept in the additional variant
+    /// [`UNKNOWN_EXTENSION_VARIANT`] or, for an open type, [`UNKNOWN_OBJECT_VARIANT`]
+    unknown_extension: bool,
 }
 
 impl<T> From<Vec<T>> for Enumeration<T> {
@@ -494,7 +639,9 @@ impl<T> From<Vec<T>> for Enumeration<T> {
         Enumeration {
             variants,
             tag: None,
+            tag_mode: TagMode::default(),
             extended_after_index: None,
+            unknown_extension: false,
         }
     }
 }
@@ -505,6 +652,11 @@ impl<T> Enumeration<T> {
         self
     }
 
+    pub fn with_unknown_extension(mut self, unknown_extension: bool) -> Self {
+        self.unknown_extension = unknown_extension;
+        self
+    }
+
     pub fn len(&self) -> usize {
         self.variants.len()
     }
@@ -529,6 +681,17 @@ impl<T> Enumeration<T> {
     pub fn is_extensible(&self) -> bool {
         self.extended_after_index.is_some()
     }
+
+    /// Whether an alternative unknown to the enum is kept in the additional variant
+    /// [`UNKNOWN_EXTENSION_VARIANT`] or, for an open type, [`UNKNOWN_OBJECT_VARIANT`] instead of
+    /// being rejected
+    pub fn has_unknown_extension(&self) -> bool {
+        self.unknown_extension
+    }
+
+    pub fn tag_mode(&self) -> TagMode {
+        self.tag_mode
+    }
 }
 
 impl<T> TagProperty for Enumeration<T> {
@@ -547,7 +710,61 @@ impl<T> TagProperty for Enumeration<T> {
 
 impl PlainEnum {
     pub fn from_names(names: impl Iterator<Item = impl ToString>) -> Self {
-        Self::from(names.map(|n| n.to_string()).collect::<Vec<_>>())
+        Self::from(names.map(PlainVariant::from_name).collect::<Vec<_>>())
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+pub struct PlainVariant {
+    name: String,
+    identifier: Option<String>,
+    /// The value in the ASN.1 definition, if it is known
+    value: Option<i64>,
+}
+
+impl PlainVariant {
+    pub fn from_name<T: ToString>(name: T) -> Self {
+        Self {
+            name: name.to_string(),
+            identifier: None,
+            value: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The identifier of this variant in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+
+    pub fn with_identifier_opt(mut self, identifier: Option<String>) -> Self {
+        self.identifier = identifier;
+        self
+    }
+
+    /// The value of this variant in the ASN.1 definition, if it is known
+    pub fn value(&self) -> Option<i64> {
+        self.value
+    }
+
+    pub fn with_value(mut self, value: i64) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+impl From<&str> for PlainVariant {
+    fn from(name: &str) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl From<String> for PlainVariant {
+    fn from(name: String) -> Self {
+        Self::from_name(name)
     }
 }
 
@@ -555,6 +772,8 @@ impl PlainEnum {
 pub struct DataVariant {
     name_type: (String, RustType),
     tag: Option<Tag>,
+    tag_mode: TagMode,
+    identifier: Option<String>,
 }
 
 impl DataVariant {
@@ -562,9 +781,30 @@ impl DataVariant {
         Self {
             name_type: (name.to_string(), r#type),
             tag: None,
+            tag_mode: TagMode::default(),
+            identifier: None,
         }
     }
 
+    pub fn tag_mode(&self) -> TagMode {
+        self.tag_mode
+    }
+
+    pub fn with_tag_mode(mut self, tag_mode: TagMode) -> Self {
+        self.tag_mode = tag_mode;
+        self
+    }
+
+    /// The identifier of this variant in the ASN.1 definition
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    pub fn with_identifier_opt(mut self, identifier: Option<String>) -> Self {
+        self.identifier = identifier;
+        self
+    }
+
     pub fn fallback_representation(&self) -> &(String, RustType) {
         &self.name_type
     }
@@ -601,12 +841,15 @@ impl Model<Rust> {
         let mut definitions = Vec::with_capacity(asn_model.definitions.len());
         let mut ctxt = Context {
             resolver: TagResolver::new(asn_model, scope),
+            tag_default: asn_model.tag_default,
             target: &mut definitions,
             make_names_nice,
         };
         let mut model = Model {
             name: ctxt.module_name(&asn_model.name),
             oid: asn_model.oid.clone(),
+            tag_default: asn_model.tag_default,
+            exports: Exports::All,
             imports: asn_model
                 .imports
                 .iter()
@@ -618,16 +861,34 @@ impl Model<Rust> {
                 .collect(),
             definitions: Vec::default(),
             value_references: Vec::with_capacity(asn_model.value_references.len()),
+            object_classes: Vec::default(),
+            object_sets: Vec::default(),
+            parameterized_types: Vec::default(),
         };
+        // inlined types of an exported definition need to be exported as well
+        let mut exported = Vec::new();
         for Definition(name, asn) in &asn_model.definitions {
             let rust_name = ctxt.struct_or_enum_name(name);
-            Self::definition_to_rust(&rust_name, &asn.r#type, asn.tag, &mut ctxt);
+            let tag_mode = ctxt.tag_mode(asn.tag, asn.tag_mode, &asn.r#type);
+            let first_extracted = ctxt.target.len();
+            Self::definition_to_rust(&rust_name, &asn.r#type, asn.tag, tag_mode, &mut ctxt);
+            if asn_model.exports.contains(name) {
+                exported.extend(ctxt.target[first_extracted..].iter().map(|d| d.0.clone()));
+            }
         }
         for vref in &asn_model.value_references {
             if let Some(rust_type) = Self::map_asn_type_to_rust_type_flat(&vref.role.r#type) {
+                if asn_model.exports.contains(&vref.name) {
+                    exported.push(ctxt.constant_name(&vref.name));
+                }
                 model.value_references.push(ValueReference {
                     name: ctxt.constant_name(&vref.name),
-                    role: rust_type,
+                    role: match rust_type {
+                        RustType::Complex(name, tag) => {
+                            RustType::Complex(ctxt.struct_or_enum_name(&name), tag)
+                        }
+                        rust_type => rust_type,
+                    },
                     value: vref.value.clone(),
                 });
             } else {
@@ -635,6 +896,9 @@ impl Model<Rust> {
                 println!("Ignoring ValueReference {}", vref.name);
             }
         }
+        if let Exports::Symbols(_) = asn_model.exports {
+            model.exports = Exports::Symbols(exported);
+        }
         model.definitions = definitions;
         model
     }
@@ -646,7 +910,14 @@ impl Model<Rust> {
                 Self::asn_extensible_integer_to_rust(int)
             }
             Type::Integer(int) => Self::asn_fixed_integer_to_rust_type(int),
-            Type::String(size, charset) => RustType::String(size.clone(), *charset),
+            Type::Real(_) => RustType::F64,
+            Type::ObjectIdentifier => RustType::ObjectIdentifier,
+            Type::RelativeOid => RustType::RelativeOid,
+            Type::Time(time) => RustType::Time(*time),
+            Type::OpenType => RustType::OpenType,
+            Type::String(size, charset, alphabet) => {
+                RustType::String(size.clone(), *charset, alphabet.clone())
+            }
             Type::OctetString(size) => RustType::VecU8(size.clone()),
             Type::BitString(bs) => RustType::BitVec(bs.size.clone()),
             Type::Null => RustType::Null,
@@ -658,12 +929,18 @@ impl Model<Rust> {
                 default.clone(),
             ),
             Type::TypeReference(name, tag) => RustType::Complex(name.clone(), *tag),
+            Type::ObjectClassField(never)
+            | Type::Instance(never)
+            | Type::Selection(never)
+            | Type::ComponentsOf(never) => match *never {},
             Type::Sequence(_)
             | Type::SequenceOf(_, _)
             | Type::Set(_)
             | Type::SetOf(_, _)
             | Type::Enumerated(_)
-            | Type::Choice(_) => return None,
+            | Type::Choice(_)
+            | Type::OpenTypeChoice(_)
+            | Type::Related(..) => return None,
         })
     }
 
@@ -674,35 +951,66 @@ impl Model<Rust> {
     /// and can therefore be used to be inserted in the parent element.
     ///
     /// The name is expected in a valid and rusty way
-    fn definition_to_rust(name: &str, asn: &AsnType, tag: Option<Tag>, ctxt: &mut Context<'_>) {
+    fn definition_to_rust(
+        name: &str,
+        asn: &AsnType,
+        tag: Option<Tag>,
+        tag_mode: TagMode,
+        ctxt: &mut Context<'_>,
+    ) {
         match asn {
             AsnType::Boolean
             | AsnType::Null
+            | AsnType::Real(_)
+            | AsnType::ObjectIdentifier
+            | AsnType::RelativeOid
+            | AsnType::Time(_)
+            | AsnType::OpenType
             | AsnType::String(..)
             | AsnType::OctetString(_)
             | AsnType::BitString(_) => {
-                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt);
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, tag_mode, ctxt);
+                ctxt.add_definition(Definition(
+                    name.to_string(),
+                    Rust::tuple_struct_from_type(rust_type)
+                        .with_tag_opt(tag)
+                        .with_tag_mode(tag_mode),
+                ));
+            }
+            AsnType::Related(..) => {
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, tag_mode, ctxt);
                 ctxt.add_definition(Definition(
                     name.to_string(),
-                    Rust::tuple_struct_from_type(rust_type).with_tag_opt(tag),
+                    Rust::tuple_struct_from_type(rust_type)
+                        .with_tag_opt(tag)
+                        .with_tag_mode(tag_mode),
                 ));
             }
-            AsnType::TypeReference(_, tag) => {
-                let rust_type = Self::definition_type_to_rust_type(name, asn, *tag, ctxt);
+            AsnType::ObjectClassField(never)
+            | AsnType::Instance(never)
+            | AsnType::Selection(never)
+            | AsnType::ComponentsOf(never) => match *never {},
+            AsnType::TypeReference(_, reference_tag) => {
+                // the tag of the definition replaces or wraps the tag of the referenced type
+                let tag = tag.or(*reference_tag);
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, tag_mode, ctxt);
                 ctxt.add_definition(Definition(
                     name.to_string(),
-                    Rust::tuple_struct_from_type(rust_type).with_tag_opt(*tag),
+                    Rust::tuple_struct_from_type(rust_type)
+                        .with_tag_opt(tag)
+                        .with_tag_mode(tag_mode),
                 ));
             }
 
             me @ AsnType::Integer(_) => {
-                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt);
+                let rust_type = Self::definition_type_to_rust_type(name, asn, tag, tag_mode, ctxt);
                 let constants = ctxt.to_rust_constants(me);
                 ctxt.add_definition(Definition(
                     name.into(),
                     Rust::TupleStruct {
                         r#type: rust_type,
                         tag,
+                        tag_mode,
                         constants,
                     },
                 ));
@@ -710,60 +1018,72 @@ impl Model<Rust> {
 
             AsnType::Optional(inner) => {
                 let inner = RustType::Option(Box::new(Self::definition_type_to_rust_type(
-                    name, inner, tag, ctxt,
+                    name, inner, tag, tag_mode, ctxt,
                 )));
                 ctxt.add_definition(Definition(
                     name.into(),
-                    Rust::tuple_struct_from_type(inner).with_tag_opt(tag),
+                    Rust::tuple_struct_from_type(inner)
+                        .with_tag_opt(tag)
+                        .with_tag_mode(tag_mode),
                 ))
             }
 
             AsnType::Default(inner, default) => {
                 let inner = RustType::Default(
-                    Box::new(Self::definition_type_to_rust_type(name, inner, tag, ctxt)),
+                    Box::new(Self::definition_type_to_rust_type(
+                        name, inner, tag, tag_mode, ctxt,
+                    )),
                     default.clone(),
                 );
                 ctxt.add_definition(Definition(
                     name.into(),
-                    Rust::tuple_struct_from_type(inner).with_tag_opt(tag),
+                    Rust::tuple_struct_from_type(inner)
+                        .with_tag_opt(tag)
+                        .with_tag_mode(tag_mode),
                 ))
             }
 
-            AsnType::Sequence(ComponentTypeList {
-                fields,
-                extension_after,
-            }) => {
-                let fields = Self::asn_fields_to_rust_fields(name, fields, *extension_after, ctxt);
+            AsnType::Sequence(components) => {
+                let fields = Self::asn_components_to_rust_fields(name, components, ctxt);
                 ctxt.add_definition(Definition(
                     name.into(),
                     Rust::Struct {
                         ordering: EncodingOrdering::Keep,
                         fields,
                         tag,
-                        extension_after: *extension_after,
+                        tag_mode,
+                        extension_after: components.extension_after,
+                        extension_group: components.is_extension_group(),
+                        unknown_extensions: components.extension_after.is_some(),
                     },
                 ));
             }
 
-            AsnType::Set(ComponentTypeList {
-                fields,
-                extension_after,
-            }) => {
-                let fields = Self::asn_fields_to_rust_fields(name, fields, *extension_after, ctxt);
+            AsnType::Set(components) => {
+                let fields = Self::asn_components_to_rust_fields(name, components, ctxt);
                 ctxt.add_definition(Definition(
                     name.into(),
                     Rust::Struct {
                         ordering: EncodingOrdering::Sort,
                         fields,
                         tag,
-                        extension_after: *extension_after,
+                        tag_mode,
+                        extension_after: components.extension_after,
+                        extension_group: components.is_extension_group(),
+                        unknown_extensions: components.extension_after.is_some(),
                     },
                 ));
             }
 
             AsnType::SequenceOf(asn, size) => {
                 let inner = RustType::Vec(
-                    Box::new(Self::definition_type_to_rust_type(name, asn, tag, ctxt)),
+                    Box::new(Self::definition_type_to_rust_type(
+                        name,
+                        asn,
+                        tag,
+                        TagMode::default(),
+                        ctxt,
+                    )),
                     size.clone(),
                     EncodingOrdering::Keep,
                 );
@@ -772,13 +1092,21 @@ impl Model<Rust> {
 
             AsnType::SetOf(asn, size) => {
                 let inner = RustType::Vec(
-                    Box::new(Self::definition_type_to_rust_type(name, asn, tag, ctxt)),
+                    Box::new(Self::definition_type_to_rust_type(
+                        name,
+                        asn,
+                        tag,
+                        TagMode::default(),
+                        ctxt,
+                    )),
                     size.clone(),
                     EncodingOrdering::Sort,
                 );
                 ctxt.add_definition(Definition(
                     name.into(),
-                    Rust::tuple_struct_from_type(inner).with_tag_opt(tag),
+                    Rust::tuple_struct_from_type(inner)
+                        .with_tag_opt(tag)
+                        .with_tag_mode(tag_mode),
                 ));
             }
 
@@ -786,36 +1114,91 @@ impl Model<Rust> {
                 let mut enumeration = Enumeration {
                     variants: Vec::with_capacity(choice.len()),
                     tag,
+                    tag_mode,
                     extended_after_index: choice.extension_after_index(),
+                    unknown_extension: choice.is_extensible(),
                 };
 
-                for ChoiceVariant {
+                for variant @ ChoiceVariant {
                     name: variant_name,
                     r#type,
                     tag,
+                    tag_mode,
+                    ..
                 } in choice.variants()
                 {
                     let rust_name = format!("{}{}", name, ctxt.struct_or_enum_name(variant_name));
-                    let rust_role =
-                        Self::definition_type_to_rust_type(&rust_name, r#type, *tag, ctxt);
+                    let tag_mode = ctxt.tag_mode(*tag, *tag_mode, r#type);
+                    let rust_role = Self::definition_type_to_rust_type(
+                        &rust_name, r#type, *tag, tag_mode, ctxt,
+                    );
                     let rust_field_name = ctxt.variant_name(variant_name);
+                    let identifier = Context::identifier(variant.identifier(), &rust_field_name);
                     enumeration.variants.push(
-                        DataVariant::from_name_type(rust_field_name, rust_role).with_tag_opt(*tag),
+                        DataVariant::from_name_type(rust_field_name, rust_role)
+                            .with_identifier_opt(identifier)
+                            .with_tag_opt(*tag)
+                            .with_tag_mode(Context::outer_tag_mode(r#type, tag_mode)),
                     );
                 }
 
                 ctxt.add_definition(Definition(name.into(), Rust::DataEnum(enumeration)));
             }
 
+            AsnType::OpenTypeChoice(choice) => {
+                let mut enumeration = Enumeration {
+                    variants: Vec::with_capacity(choice.len()),
+                    tag,
+                    tag_mode,
+                    extended_after_index: None,
+                    unknown_extension: choice.extensible,
+                };
+                let mut keys = Vec::with_capacity(choice.len());
+
+                for variant in choice.variants() {
+                    let rust_name = format!("{}{}", name, ctxt.struct_or_enum_name(&variant.name));
+                    let rust_role = Self::definition_type_to_rust_type(
+                        &rust_name,
+                        &variant.r#type,
+                        None,
+                        TagMode::default(),
+                        ctxt,
+                    );
+                    let rust_variant_name = ctxt.variant_name(&variant.name);
+                    let identifier = Context::identifier(variant.identifier(), &rust_variant_name);
+                    enumeration.variants.push(
+                        DataVariant::from_name_type(rust_variant_name, rust_role)
+                            .with_identifier_opt(identifier),
+                    );
+                    keys.push(variant.key);
+                }
+
+                ctxt.add_definition(Definition(
+                    name.into(),
+                    Rust::OpenTypeChoice {
+                        variants: enumeration,
+                        keys,
+                    },
+                ));
+            }
+
             AsnType::Enumerated(enumerated) => {
                 let mut rust_enum = Enumeration {
                     variants: Vec::with_capacity(enumerated.len()),
                     tag,
+                    tag_mode,
                     extended_after_index: enumerated.extension_after_index(),
+                    unknown_extension: false,
                 };
 
-                for variant in enumerated.variants() {
-                    rust_enum.variants.push(ctxt.variant_name(variant.name()));
+                for (variant, value) in enumerated.variants().zip(enumerated.values()) {
+                    let rust_name = ctxt.variant_name(variant.name());
+                    let identifier = Context::identifier(variant.identifier(), &rust_name);
+                    rust_enum.variants.push(
+                        PlainVariant::from_name(rust_name)
+                            .with_identifier_opt(identifier)
+                            .with_value(value),
+                    );
                 }
 
                 ctxt.add_definition(Definition(name.into(), Rust::Enum(rust_enum)));
@@ -823,6 +1206,49 @@ impl Model<Rust> {
         }
     }
 
+    /// Each extension addition group becomes a single optional field, with a struct of its own for
+    /// the components of the group
+    fn asn_components_to_rust_fields(
+        name: &str,
+        components: &ComponentTypeList<Resolved>,
+        ctxt: &mut Context<'_>,
+    ) -> Vec<Field> {
+        if components.extension_groups.is_empty() || components.is_extension_group() {
+            return Self::asn_fields_to_rust_fields(
+                name,
+                &components.fields,
+                components.extension_after,
+                ctxt,
+            );
+        }
+
+        let fields = ctxt.tag_automatically(&components.fields);
+        let mut grouped = Vec::with_capacity(fields.len());
+        let mut next = 0;
+        for (index, group) in components.extension_groups.iter().enumerate() {
+            grouped.extend_from_slice(&fields[next..group.start]);
+            let number = group.version.unwrap_or(index as u64 + 1);
+            grouped.push(crate::model::Field {
+                name: format!("extension_group_{}", number),
+                identifier: None,
+                role: AsnType::Optional(Box::new(AsnType::Sequence(ComponentTypeList {
+                    fields: fields[group.range()].to_vec(),
+                    extension_after: None,
+                    extension_groups: vec![ExtensionAdditionGroup {
+                        version: group.version,
+                        start: 0,
+                        end: group.end - group.start,
+                    }],
+                })))
+                .untagged(),
+            });
+            next = group.end;
+        }
+        grouped.extend_from_slice(&fields[next..]);
+
+        Self::asn_fields_to_rust_fields(name, &grouped, components.extension_after, ctxt)
+    }
+
     fn asn_fields_to_rust_fields(
         name: &str,
         fields: &[crate::model::Field<Asn>],
@@ -834,8 +1260,14 @@ impl Model<Rust> {
         for (index, field) in fields.iter().enumerate() {
             let rust_name = format!("{}{}", name, ctxt.struct_or_enum_name(&field.name));
             let tag = field.role.tag;
-            let rust_role =
-                Self::definition_type_to_rust_type(&rust_name, &field.role.r#type, tag, ctxt);
+            let tag_mode = ctxt.tag_mode(tag, field.role.tag_mode, &field.role.r#type);
+            let rust_role = Self::definition_type_to_rust_type(
+                &rust_name,
+                &field.role.r#type,
+                tag,
+                tag_mode,
+                ctxt,
+            );
             let rust_role = if let Some(def) = &field.role.default {
                 RustType::Default(Box::new(rust_role.no_option()), def.clone())
             } else if extension_after.map(|e| index > e).unwrap_or(false)
@@ -846,11 +1278,14 @@ impl Model<Rust> {
                 rust_role
             };
             let rust_field_name = ctxt.field_name(&field.name);
+            let identifier = Context::identifier(field.identifier(), &rust_field_name);
             let constants = ctxt.to_rust_constants(&field.role.r#type);
             rust_fields.push(
                 RustField::from_name_type(rust_field_name, rust_role)
                     .with_constants(constants)
-                    .with_tag_opt(tag),
+                    .with_identifier_opt(identifier)
+                    .with_tag_opt(tag)
+                    .with_tag_mode(Context::outer_tag_mode(&field.role.r#type, tag_mode)),
             );
         }
 
@@ -861,6 +1296,7 @@ impl Model<Rust> {
         name: &str,
         asn: &AsnType,
         tag: Option<Tag>,
+        tag_mode: TagMode,
         ctxt: &mut Context<'_>,
     ) -> RustType {
         match asn {
@@ -870,8 +1306,15 @@ impl Model<Rust> {
                 Self::asn_extensible_integer_to_rust(int)
             }
             AsnType::Integer(int) => Self::asn_fixed_integer_to_rust_type(int),
-
-            AsnType::String(size, charset) => RustType::String(size.clone(), *charset),
+            AsnType::Real(_) => RustType::F64,
+            AsnType::ObjectIdentifier => RustType::ObjectIdentifier,
+            AsnType::RelativeOid => RustType::RelativeOid,
+            AsnType::Time(time) => RustType::Time(*time),
+            AsnType::OpenType => RustType::OpenType,
+
+            AsnType::String(size, charset, alphabet) => {
+                RustType::String(size.clone(), *charset, alphabet.clone())
+            }
             AsnType::OctetString(size) => RustType::VecU8(size.clone()),
             AsnType::BitString(bitstring) => RustType::BitVec(bitstring.size.clone()),
             Type::Optional(inner) => {
@@ -879,6 +1322,7 @@ impl Model<Rust> {
                     name,
                     inner,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(inner)),
+                    tag_mode,
                     ctxt,
                 )))
             }
@@ -887,6 +1331,7 @@ impl Model<Rust> {
                     name,
                     inner,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(inner)),
+                    tag_mode,
                     ctxt,
                 )),
                 default.clone(),
@@ -896,6 +1341,7 @@ impl Model<Rust> {
                     name,
                     asn,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(asn)),
+                    TagMode::default(),
                     ctxt,
                 )),
                 size.clone(),
@@ -906,6 +1352,7 @@ impl Model<Rust> {
                     name,
                     asn,
                     tag.or_else(|| ctxt.resolver().resolve_no_default(asn)),
+                    TagMode::default(),
                     ctxt,
                 )),
                 size.clone(),
@@ -914,15 +1361,30 @@ impl Model<Rust> {
             ty @ AsnType::Sequence(_)
             | ty @ AsnType::Set(_)
             | ty @ AsnType::Enumerated(_)
-            | ty @ AsnType::Choice(_) => {
+            | ty @ AsnType::Choice(_)
+            | ty @ AsnType::OpenTypeChoice(_) => {
                 let name = ctxt.struct_or_enum_name(name);
-                Self::definition_to_rust(&name, asn, tag, ctxt);
+                Self::definition_to_rust(&name, asn, tag, tag_mode, ctxt);
                 RustType::Complex(name, tag.or_else(|| ctxt.resolver().resolve_type_tag(ty)))
             }
             AsnType::TypeReference(name, tag) => RustType::Complex(
                 ctxt.struct_or_enum_name(name),
                 (*tag).or_else(|| ctxt.resolver().resolve_tag(name)),
             ),
+            AsnType::ObjectClassField(never)
+            | AsnType::Instance(never)
+            | AsnType::Selection(never)
+            | AsnType::ComponentsOf(never) => match *never {},
+            AsnType::Related(relation, inner) => RustType::Related(
+                ctxt.field_name(relation),
+                Box::new(Self::definition_type_to_rust_type(
+                    name,
+                    inner,
+                    None,
+                    TagMode::default(),
+                    ctxt,
+                )),
+            ),
         }
     }
 
@@ -982,11 +1444,72 @@ impl Model<Rust> {
 
 struct Context<'a> {
     resolver: TagResolver<'a>,
+    tag_default: TagDefault,
     target: &'a mut Vec<Definition<Rust>>,
     make_names_nice: bool,
 }
 
 impl Context<'_> {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, the tag of a CHOICE or an open type is always
+    /// explicit, otherwise the mode depends on the keyword or the [`TagDefault`] of the module
+    fn tag_mode(&self, tag: Option<Tag>, tag_mode: Option<TagMode>, asn: &AsnType) -> TagMode {
+        match asn {
+            _ if tag.is_none() => TagMode::default(),
+            AsnType::Optional(inner) | AsnType::Default(inner, _) => {
+                self.tag_mode(tag, tag_mode, inner)
+            }
+            AsnType::Choice(_)
+            | AsnType::OpenTypeChoice(_)
+            | AsnType::OpenType
+            | AsnType::Related(..) => TagMode::Explicit,
+            _ => self.tag_default.tag_mode(tag_mode),
+        }
+    }
+
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.3, with `AUTOMATIC TAGS` the components of extension
+    /// addition groups are numbered as if they were not grouped, which is why the tags cannot be
+    /// assigned per struct later on
+    fn tag_automatically(
+        &self,
+        fields: &[crate::model::Field<Asn>],
+    ) -> Vec<crate::model::Field<Asn>> {
+        let any_tagged = fields.iter().any(|f| f.role.tag.is_some());
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let mut field = field.clone();
+                if self.tag_default == TagDefault::Automatic && !any_tagged {
+                    field.role.tag = Some(Tag::ContextSpecific(index));
+                }
+                field
+            })
+            .collect()
+    }
+
+    /// Inlined SEQUENCE, SET, ENUMERATED and CHOICE types are extracted into their own
+    /// definitions, which then carry the explicit tag instead of the field or variant
+    fn outer_tag_mode(asn: &AsnType, tag_mode: TagMode) -> TagMode {
+        match asn {
+            AsnType::Optional(inner) | AsnType::Default(inner, _) => {
+                Self::outer_tag_mode(inner, tag_mode)
+            }
+            AsnType::Sequence(_)
+            | AsnType::Set(_)
+            | AsnType::Enumerated(_)
+            | AsnType::Choice(_)
+            | AsnType::OpenTypeChoice(_) => TagMode::default(),
+            _ => tag_mode,
+        }
+    }
+
+    /// The ASN.1 identifier needs only be remembered, if the rust name differs from it
+    fn identifier(identifier: &str, rust_name: &str) -> Option<String> {
+        Some(identifier)
+            .filter(|identifier| *identifier != rust_name)
+            .map(ToString::to_string)
+    }
+
     fn to_rust_constants(&self, asn: &AsnType) -> Vec<(String, String)> {
         match asn {
             AsnType::Integer(integer) => integer
@@ -1002,6 +1525,11 @@ impl Context<'_> {
 
             Type::Boolean
             | Type::Null
+            | Type::Real(_)
+            | Type::ObjectIdentifier
+            | Type::RelativeOid
+            | Type::Time(_)
+            | Type::OpenType
             | Type::String(..)
             | Type::OctetString(_)
             | Type::Optional(_)
@@ -1012,7 +1540,13 @@ impl Context<'_> {
             | Type::SetOf(..)
             | Type::Enumerated(_)
             | Type::Choice(_)
-            | Type::TypeReference(_, _) => Vec::default(),
+            | Type::TypeReference(_, _)
+            | Type::OpenTypeChoice(_)
+            | Type::Related(..) => Vec::default(),
+            Type::ObjectClassField(never)
+            | Type::Instance(never)
+            | Type::Selection(never)
+            | Type::ComponentsOf(never) => match *never {},
         }
     }
 
@@ -1191,6 +1725,19 @@ impl LiteralValue {
                             }
                         )
                     }
+                    LiteralValue::ObjectIdentifier(oid) => {
+                        write!(f, "&[")?;
+                        for (index, component) in oid.iter().enumerate() {
+                            if index > 0 {
+                                write!(f, ", ")?;
+                            }
+                            match component.number() {
+                                Some(number) => write!(f, "{}", number)?,
+                                None => panic!("Unresolved object identifier {:?}", oid),
+                            }
+                        }
+                        write!(f, "]")
+                    }
                 }
             }
         }
@@ -1312,11 +1859,21 @@ mod tests {
                 "WoahDecision".into(),
                 Rust::Enum(
                     vec![
-                        "Abort".into(),
-                        "Return".into(),
-                        "Confirm".into(),
-                        "Mayday".into(),
-                        "TheCakeIsALie".into()
+                        PlainVariant::from("Abort")
+                            .with_identifier_opt(Some("ABORT".into()))
+                            .with_value(0),
+                        PlainVariant::from("Return")
+                            .with_identifier_opt(Some("RETURN".into()))
+                            .with_value(1),
+                        PlainVariant::from("Confirm")
+                            .with_identifier_opt(Some("CONFIRM".into()))
+                            .with_value(2),
+                        PlainVariant::from("Mayday")
+                            .with_identifier_opt(Some("MAYDAY".into()))
+                            .with_value(3),
+                        PlainVariant::from("TheCakeIsALie")
+                            .with_identifier_opt(Some("THE_CAKE_IS_A_LIE".into()))
+                            .with_value(4),
                     ]
                     .into()
                 ),
@@ -1386,7 +1943,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ),
-                    ),
+                    )
+                    .with_identifier_opt(Some("also-ones".into())),
                     RustField::from_name_type(
                         "nesteds",
                         RustType::Vec(
@@ -1457,7 +2015,17 @@ mod tests {
         assert_eq!(
             Definition(
                 "Neither".into(),
-                Rust::Enum(vec!["Abc".into(), "Def".into(),].into()),
+                Rust::Enum(
+                    vec![
+                        PlainVariant::from("Abc")
+                            .with_identifier_opt(Some("ABC".into()))
+                            .with_value(0),
+                        PlainVariant::from("Def")
+                            .with_identifier_opt(Some("DEF".into()))
+                            .with_value(1),
+                    ]
+                    .into()
+                ),
             ),
             model_rust.definitions[2]
         );
@@ -1469,15 +2037,18 @@ mod tests {
                         DataVariant::from_name_type(
                             "This",
                             RustType::Complex("This".into(), Some(Tag::DEFAULT_SEQUENCE_OF))
-                        ),
+                        )
+                        .with_identifier_opt(Some("this".into())),
                         DataVariant::from_name_type(
                             "That",
                             RustType::Complex("That".into(), Some(Tag::DEFAULT_SEQUENCE_OF))
-                        ),
+                        )
+                        .with_identifier_opt(Some("that".into())),
                         DataVariant::from_name_type(
                             "Neither",
                             RustType::Complex("Neither".into(), Some(Tag::DEFAULT_ENUMERATED))
-                        ),
+                        )
+                        .with_identifier_opt(Some("neither".into())),
                     ]
                     .into()
                 )
@@ -1489,7 +2060,8 @@ mod tests {
                 "Woah".into(),
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "decision",
-                    RustType::Complex("WoahDecision".into(), Some(Tag::DEFAULT_ENUMERATED)),
+                    // ITU-T X.680 | ISO/IEC 8824-1, 29.3, the alternatives are tagged from [0]
+                    RustType::Complex("WoahDecision".into(), Some(Tag::ContextSpecific(0))),
                 )])
             ),
             model_rust.definitions[4]
@@ -1519,7 +2091,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ),
-                    ),
+                    )
+                    .with_identifier_opt(Some("list-ones".into())),
                     RustField::from_name_type(
                         "optional_ones",
                         RustType::Option(Box::new(RustType::Vec(
@@ -1527,7 +2100,8 @@ mod tests {
                             Size::Any,
                             EncodingOrdering::Keep
                         ))),
-                    ),
+                    )
+                    .with_identifier_opt(Some("optional-ones".into())),
                 ]),
             ),
             model_rust.definitions[0]
@@ -1564,7 +2138,16 @@ mod tests {
         assert_eq!(
             Definition(
                 "SimpleEnumTest".into(),
-                Rust::Enum(vec!["Bernd".into(), "DasVerdammte".into(), "Brooot".into(),].into()),
+                Rust::Enum(
+                    vec![
+                        PlainVariant::from("Bernd").with_value(0),
+                        PlainVariant::from("DasVerdammte")
+                            .with_identifier_opt(Some("Das-Verdammte".into()))
+                            .with_value(1),
+                        PlainVariant::from("Brooot").with_value(2),
+                    ]
+                    .into()
+                ),
             ),
             model_rust.definitions[0]
         );
@@ -1592,9 +2175,15 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "BerndDasBrot",
-                            RustType::String(Size::Any, Charset::Utf8),
-                        ),
-                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any)),
+                            RustType::String(
+                                Size::Any,
+                                Charset::Utf8,
+                                PermittedAlphabet::default()
+                            ),
+                        )
+                        .with_identifier_opt(Some("bernd-das-brot".into())),
+                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any))
+                            .with_identifier_opt(Some("nochSoEinBrot".into())),
                     ]
                     .into()
                 ),
@@ -1638,11 +2227,16 @@ mod tests {
                         DataVariant::from_name_type(
                             "NormalList",
                             RustType::Vec(
-                                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                                Box::new(RustType::String(
+                                    Size::Any,
+                                    Charset::Utf8,
+                                    PermittedAlphabet::default()
+                                )),
                                 Size::Any,
                                 EncodingOrdering::Keep
                             ),
-                        ),
+                        )
+                        .with_identifier_opt(Some("normal-List".into())),
                         DataVariant::from_name_type(
                             "NestedList",
                             RustType::Vec(
@@ -1654,7 +2248,8 @@ mod tests {
                                 Size::Any,
                                 EncodingOrdering::Keep
                             ),
-                        ),
+                        )
+                        .with_identifier_opt(Some("NESTED-List".into())),
                     ]
                     .into()
                 ),
@@ -1680,7 +2275,11 @@ mod tests {
             Definition(
                 "TupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
-                    Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                    Box::new(RustType::String(
+                        Size::Any,
+                        Charset::Utf8,
+                        PermittedAlphabet::default()
+                    )),
                     Size::Any,
                     EncodingOrdering::Keep
                 )),
@@ -1713,7 +2312,11 @@ mod tests {
                 "NestedTupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
                     Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            PermittedAlphabet::default()
+                        )),
                         Size::Any,
                         EncodingOrdering::Keep
                     )),
@@ -1733,6 +2336,7 @@ mod tests {
             "OptionalStructListTest".into(),
             AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
+                identifier: None,
                 role: AsnType::SequenceOf(Box::new(AsnType::unconstrained_utf8string()), Size::Any)
                     .optional()
                     .untagged(),
@@ -1749,7 +2353,11 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Option(Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            PermittedAlphabet::default()
+                        )),
                         Size::Any,
                         EncodingOrdering::Keep
                     ))),
@@ -1767,6 +2375,7 @@ mod tests {
             "StructListTest".into(),
             AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
+                identifier: None,
                 role: AsnType::SequenceOf(Box::new(AsnType::unconstrained_utf8string()), Size::Any)
                     .untagged(),
             }])
@@ -1782,7 +2391,11 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            PermittedAlphabet::default()
+                        )),
                         Size::Any,
                         EncodingOrdering::Keep
                     ),
@@ -1800,6 +2413,7 @@ mod tests {
             "NestedStructListTest".into(),
             AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
+                identifier: None,
                 role: AsnType::SequenceOf(
                     Box::new(AsnType::SequenceOf(
                         Box::new(AsnType::unconstrained_utf8string()),
@@ -1822,7 +2436,11 @@ mod tests {
                     "strings",
                     RustType::Vec(
                         Box::new(RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(
+                                Size::Any,
+                                Charset::Utf8,
+                                PermittedAlphabet::default()
+                            )),
                             Size::Any,
                             EncodingOrdering::Keep
                         )),
@@ -1858,8 +2476,18 @@ mod tests {
             &[Definition(
                 "Extensible".into(),
                 Rust::Enum(
-                    PlainEnum::from_names(["Abc", "Def", "Ghi"].iter())
-                        .with_extension_after(Some(2))
+                    PlainEnum::from(vec![
+                        PlainVariant::from("Abc")
+                            .with_identifier_opt(Some("abc".into()))
+                            .with_value(0),
+                        PlainVariant::from("Def")
+                            .with_identifier_opt(Some("def".into()))
+                            .with_value(1),
+                        PlainVariant::from("Ghi")
+                            .with_identifier_opt(Some("ghi".into()))
+                            .with_value(42),
+                    ])
+                    .with_extension_after(Some(2))
                 ),
             )],
             &model_rust.definitions[..]
@@ -1878,7 +2506,9 @@ mod tests {
                     ChoiceVariant::name_type("def", Type::unconstrained_integer()),
                     ChoiceVariant {
                         name: "ghi".to_string(),
+                        identifier: None,
                         tag: Some(Tag::Universal(4)),
+                        tag_mode: None,
                         r#type: Type::Boolean,
                     },
                 ])
@@ -1895,15 +2525,19 @@ mod tests {
                 "Extensible".into(),
                 Rust::DataEnum(
                     DataEnum::from(vec![
-                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any)),
+                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any))
+                            .with_identifier_opt(Some("abc".into())),
                         DataVariant::from_name_type(
                             "Def".to_string(),
                             RustType::U64(Range::none()),
-                        ),
+                        )
+                        .with_identifier_opt(Some("def".into())),
                         DataVariant::from_name_type("Ghi".to_string(), RustType::Bool)
+                            .with_identifier_opt(Some("ghi".into()))
                             .with_tag(Tag::Universal(4)),
                     ])
                     .with_extension_after(Some(2))
+                    .with_unknown_extension(true)
                 ),
             )],
             &model_rust.definitions[..]
@@ -1916,7 +2550,10 @@ mod tests {
             ordering: EncodingOrdering::Keep,
             fields: Vec::default(),
             tag: None,
+            tag_mode: TagMode::default(),
             extension_after: None,
+            extension_group: false,
+            unknown_extensions: false,
         });
     }
 
@@ -1932,7 +2569,7 @@ mod tests {
         test_property(Rust::DataEnum(DataEnum::from(vec![
             DataVariant::from_name_type(
                 "SomeName".to_string(),
-                RustType::String(Size::Any, Charset::Visible),
+                RustType::String(Size::Any, Charset::Visible, PermittedAlphabet::default()),
             ),
         ])));
     }
@@ -1942,6 +2579,7 @@ mod tests {
         test_property(Rust::TupleStruct {
             r#type: RustType::VecU8(Size::Any),
             tag: None,
+            tag_mode: TagMode::default(),
             constants: Vec::default(),
         });
     }
@@ -1972,8 +2610,13 @@ mod tests {
         let asn = Model::<Asn<Resolved>> {
             name: "SomeGreatName".to_string(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            exports: Exports::All,
             imports: Vec::default(),
             definitions: Vec::default(),
+            object_classes: Vec::default(),
+            object_sets: Vec::default(),
+            parameterized_types: Vec::default(),
             value_references: vec![
                 ValueReference {
                     name: "local-http".to_string(),
@@ -2009,11 +2652,44 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_value_reference_object_identifier_to_rust() {
+        let asn = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                id-rsa OBJECT IDENTIFIER ::= { iso(1) member-body(2) us(840) 113549 }
+                id-relative RELATIVE-OID ::= { 3 4 }
+
+                END",
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            pub const ID_RSA: ObjectIdentifier = ObjectIdentifier::from_static(&[1, 2, 840, 113549]);
+            pub const ID_RELATIVE: RelativeOid = RelativeOid::from_static(&[3, 4]);
+
+        "#,
+            &RustCodeGenerator::from(asn.to_rust())
+                .to_string_without_generators()
+                .into_iter()
+                .map(|(_f, c)| c)
+                .next()
+                .unwrap(),
+        );
+    }
+
     #[test]
     fn test_to_rust_coherent_complex_reference_renaming() {
         let asn = Model::<Asn<Resolved>> {
             name: "CoherentComplexRenaming".to_string(),
             oid: None,
+            tag_default: TagDefault::Automatic,
+            exports: Exports::All,
             imports: vec![],
             definitions: vec![
                 Definition("Some-Name-WithID".to_string(), Type::Boolean.untagged()),
@@ -2023,19 +2699,25 @@ mod tests {
                         fields: vec![
                             Field {
                                 name: "some-internal".to_string(),
+                                identifier: None,
                                 role: Type::Boolean.untagged(),
                             },
                             Field {
                                 name: "id".to_string(),
+                                identifier: None,
                                 role: Type::TypeReference("Some-Name-WithID".to_string(), None)
                                     .untagged(),
                             },
                         ],
                         extension_after: None,
+                        extension_groups: Vec::default(),
                     })
                     .untagged(),
                 ),
             ],
+            object_classes: Vec::default(),
+            object_sets: Vec::default(),
+            parameterized_types: Vec::default(),
             value_references: vec![],
         };
         assert_eq!(
@@ -2045,6 +2727,7 @@ mod tests {
                     Rust::TupleStruct {
                         r#type: RustType::Bool,
                         tag: None,
+                        tag_mode: TagMode::default(),
                         constants: vec![]
                     }
                 ),
@@ -2052,11 +2735,13 @@ mod tests {
                     "ComplexContainer".to_string(),
                     Rust::Struct {
                         ordering: EncodingOrdering::Keep,
+                        tag_mode: TagMode::default(),
                         fields: vec![
                             crate::model::rust::Field::from_name_type(
                                 "some_internal".to_string(),
                                 RustType::Bool
-                            ),
+                            )
+                            .with_identifier_opt(Some("some-internal".into())),
                             crate::model::rust::Field::from_name_type(
                                 "id".to_string(),
                                 RustType::Complex(
@@ -2066,7 +2751,9 @@ mod tests {
                             ),
                         ],
                         tag: None,
-                        extension_after: None
+                        extension_after: None,
+                        extension_group: false,
+                        unknown_extensions: false,
                     }
                 ),
             ],
@@ -3,9 +3,9 @@ use crate::model::rust::{DataEnum, DataVariant, EncodingOrdering};
 use crate::model::rust::{Field, PlainEnum};
 use crate::model::Rust;
 use crate::model::RustType;
-use crate::model::{Charset, Model};
+use crate::model::{Charset, Model, PermittedAlphabet};
 use crate::model::{Definition, Size};
-use crate::model::{Range, Target};
+use crate::model::{Range, Target, TimeType};
 use std::collections::HashMap;
 use std::convert::Infallible;
 
@@ -23,6 +23,7 @@ pub enum SqlType {
     SmallInt, // 2byte
     Integer,  // 4byte
     BigInt,   // 8byte
+    Double,   // 8byte
     Serial,   // 4byte
     Boolean,
     Text,
@@ -31,6 +32,10 @@ pub enum SqlType {
     ByteArray,
     NullByteArray,
     BitsReprByByteArrayAndBitsLen,
+    OpenTypeReprByByteArray,
+    ObjectIdentifierReprByText,
+    RelativeOidReprByText,
+    TimeReprByText(TimeType),
     References(String, String, Option<Action>, Option<Action>),
 }
 
@@ -75,9 +80,12 @@ impl SqlType {
             SqlType::SmallInt => RustType::I16(Range::inclusive(0, i16::MAX)),
             SqlType::Integer => RustType::I32(Range::inclusive(0, i32::MAX)),
             SqlType::BigInt => RustType::I64(Range::inclusive(0, i64::MAX)),
+            SqlType::Double => RustType::F64,
             SqlType::Serial => RustType::I32(Range::inclusive(0, i32::MAX)),
             SqlType::Boolean => RustType::Bool,
-            SqlType::Text => RustType::String(Size::Any, Charset::Utf8),
+            SqlType::Text => {
+                RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default())
+            }
             SqlType::Array(inner) => {
                 RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
             }
@@ -85,6 +93,10 @@ impl SqlType {
             SqlType::ByteArray => RustType::VecU8(Size::Any),
             SqlType::NullByteArray => return RustType::Null,
             SqlType::BitsReprByByteArrayAndBitsLen => RustType::BitVec(Size::Any),
+            SqlType::OpenTypeReprByByteArray => RustType::OpenType,
+            SqlType::ObjectIdentifierReprByText => RustType::ObjectIdentifier,
+            SqlType::RelativeOidReprByText => RustType::RelativeOid,
+            SqlType::TimeReprByText(time) => RustType::Time(*time),
             SqlType::References(name, _, _, _) => RustType::Complex(name.clone(), None),
         }))
     }
@@ -96,14 +108,19 @@ impl ToString for SqlType {
             SqlType::SmallInt => "SMALLINT".into(),
             SqlType::Integer => "INTEGER".into(),
             SqlType::BigInt => "BIGINT".into(),
+            SqlType::Double => "DOUBLE PRECISION".into(),
             SqlType::Serial => "SERIAL".into(),
             SqlType::Boolean => "BOOLEAN".into(),
-            SqlType::Text => "TEXT".into(),
+            SqlType::Text
+            | SqlType::ObjectIdentifierReprByText
+            | SqlType::RelativeOidReprByText
+            | SqlType::TimeReprByText(_) => "TEXT".into(),
             SqlType::Array(inner) => format!("{}[]", inner.to_string()),
             SqlType::NotNull(inner) => format!("{} NOT NULL", inner.to_string()),
             SqlType::ByteArray
             | SqlType::NullByteArray
-            | SqlType::BitsReprByByteArrayAndBitsLen => "BYTEA".into(),
+            | SqlType::BitsReprByByteArrayAndBitsLen
+            | SqlType::OpenTypeReprByByteArray => "BYTEA".into(),
             SqlType::References(table, column, on_delete, on_update) => format!(
                 "INTEGER REFERENCES {}({}){}{}",
                 Model::<Sql>::sql_definition_name(table),
@@ -156,8 +173,13 @@ impl Model<Sql> {
         let mut model = Model {
             name: rust_model.name.clone(),
             oid: rust_model.oid.clone(),
+            tag_default: rust_model.tag_default,
+            exports: Default::default(), // ignored in SQL,
             imports: Default::default(), // ignored in SQL
             definitions: Vec::with_capacity(rust_model.definitions.len()),
+            object_classes: Vec::default(),
+            object_sets: Vec::default(),
+            parameterized_types: Vec::default(),
             value_references: Vec::default(),
         };
         for Definition(name, rust) in &rust_model.definitions {
@@ -220,13 +242,18 @@ impl Model<Sql> {
             Rust::Struct {
                 fields,
                 tag: _,
+                tag_mode: _,
                 extension_after: _,
+                extension_group: _,
+                unknown_extensions: _,
                 ordering: _,
             } => Self::rust_struct_to_sql_table(name, fields, definitions),
             Rust::Enum(rust_enum) => Self::rust_enum_to_sql_enum(name, rust_enum, definitions),
-            Rust::DataEnum(enumeration) => {
-                Self::rust_data_enum_to_sql_table(name, enumeration, definitions)
-            }
+            Rust::DataEnum(enumeration)
+            | Rust::OpenTypeChoice {
+                variants: enumeration,
+                ..
+            } => Self::rust_data_enum_to_sql_table(name, enumeration, definitions),
             Rust::TupleStruct { r#type: rust, .. } => {
                 Self::rust_tuple_struct_to_sql_table(name, rust, definitions)
             }
@@ -341,7 +368,10 @@ impl Model<Sql> {
         enumeration: &PlainEnum,
         definitions: &mut Vec<Definition<Sql>>,
     ) {
-        let variants = enumeration.variants().map(String::clone).collect();
+        let variants = enumeration
+            .variants()
+            .map(|v| v.name().to_string())
+            .collect();
         definitions.push(Definition(name.into(), Sql::Enum(variants)));
         Self::add_silently_prevent_any_delete(name, definitions);
     }
@@ -508,6 +538,10 @@ impl Model<Sql> {
             RustType::VecU8(_) => true,
             RustType::BitVec(_) => true,
             RustType::Null => true,
+            RustType::ObjectIdentifier => true,
+            RustType::RelativeOid => true,
+            RustType::Time(_) => true,
+            RustType::OpenType => true,
             r => r.is_primitive(),
         }
     }
@@ -547,12 +581,17 @@ impl ToSql for RustType {
             RustType::U32(Range(_, upper, _)) if *upper <= i32::MAX as u32 => SqlType::Integer,
             RustType::U16(_) | RustType::I32(_) => SqlType::Integer,
             RustType::U32(_) | RustType::U64(_) | RustType::I64(_) => SqlType::BigInt,
-            RustType::String(_size, _charset) => SqlType::Text,
+            RustType::F64 => SqlType::Double,
+            RustType::ObjectIdentifier => SqlType::ObjectIdentifierReprByText,
+            RustType::RelativeOid => SqlType::RelativeOidReprByText,
+            RustType::Time(time) => SqlType::TimeReprByText(*time),
+            RustType::OpenType => SqlType::OpenTypeReprByByteArray,
+            RustType::String(_size, _charset, _alphabet) => SqlType::Text,
             RustType::VecU8(_) => SqlType::ByteArray,
             RustType::BitVec(_) => SqlType::BitsReprByByteArrayAndBitsLen,
             RustType::Vec(inner, _size, _ordering) => SqlType::Array(inner.to_sql().into()),
             RustType::Option(inner) => return inner.to_sql().nullable(),
-            RustType::Default(inner, ..) => return inner.to_sql(),
+            RustType::Default(inner, ..) | RustType::Related(_, inner) => return inner.to_sql(),
             RustType::Complex(name, _tag) => SqlType::References(
                 name.clone(),
                 FOREIGN_KEY_DEFAULT_COLUMN.into(),
@@ -605,7 +644,10 @@ mod tests {
             definitions: vec![Definition(
                 "Person".into(),
                 Rust::struct_from_fields(vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type(
+                        "name",
+                        RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default()),
+                    ),
                     Field::from_name_type("birth", RustType::Complex("City".into(), None)),
                 ]),
             )],
@@ -678,7 +720,11 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "DeadSince",
-                            RustType::String(Size::Any, Charset::Utf8),
+                            RustType::String(
+                                Size::Any,
+                                Charset::Utf8,
+                                PermittedAlphabet::default(),
+                            ),
                         ),
                         DataVariant::from_name_type(
                             "Alive",
@@ -786,7 +832,11 @@ mod tests {
                     Field::from_name_type(
                         "list_of_primitive",
                         RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(
+                                Size::Any,
+                                Charset::Utf8,
+                                PermittedAlphabet::default(),
+                            )),
                             Size::Any,
                             EncodingOrdering::Keep,
                         ),
@@ -922,7 +972,11 @@ mod tests {
             definitions: vec![
                 Definition(
                     "Whatever".into(),
-                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8)),
+                    Rust::tuple_struct_from_type(RustType::String(
+                        Size::Any,
+                        Charset::Utf8,
+                        PermittedAlphabet::default(),
+                    )),
                 ),
                 Definition(
                     "Whatelse".into(),
@@ -1084,7 +1138,7 @@ mod tests {
                 "City".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "id",
-                    RustType::String(Size::Any, Charset::Utf8),
+                    RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default()),
                 )]),
             )],
             ..Default::default()
@@ -1184,10 +1238,10 @@ mod tests {
         );
 
         assert_eq!(
-            RustType::String(Size::Any, Charset::Utf8)
+            RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default())
                 .to_sql()
                 .to_rust(),
-            RustType::String(Size::Any, Charset::Utf8),
+            RustType::String(Size::Any, Charset::Utf8, PermittedAlphabet::default()),
         );
         assert_eq!(
             RustType::VecU8(Size::Any).to_sql().to_rust(),
@@ -1195,14 +1249,22 @@ mod tests {
         );
         assert_eq!(
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(
+                    Size::Any,
+                    Charset::Utf8,
+                    PermittedAlphabet::default()
+                )),
                 Size::Any,
                 EncodingOrdering::Keep
             )
             .to_sql()
             .to_rust(),
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(
+                    Size::Any,
+                    Charset::Utf8,
+                    PermittedAlphabet::default()
+                )),
                 Size::Any,
                 EncodingOrdering::Keep
             ),
@@ -1228,6 +1290,10 @@ mod tests {
             SqlType::NotNull(SqlType::Serial.into()).to_rust(),
             RustType::I32(Range::inclusive(0, i32::MAX))
         );
+        assert_eq!(
+            SqlType::NotNull(SqlType::Double.into()).to_rust(),
+            RustType::F64
+        );
     }
 
     #[test]
@@ -1246,7 +1312,10 @@ mod tests {
         assert_eq!("BIGINT", &SqlType::BigInt.to_string());
         assert_eq!("SERIAL", &SqlType::Serial.to_string());
         assert_eq!("BOOLEAN", &SqlType::Boolean.to_string());
+        assert_eq!("DOUBLE PRECISION", &SqlType::Double.to_string());
         assert_eq!("TEXT", &SqlType::Text.to_string());
+        assert_eq!("TEXT", &SqlType::ObjectIdentifierReprByText.to_string());
+        assert_eq!("BYTEA", &SqlType::OpenTypeReprByByteArray.to_string());
         assert_eq!(
             "SMALLINT[]",
             &SqlType::Array(SqlType::SmallInt.into()).to_string()
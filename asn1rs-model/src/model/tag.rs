@@ -44,8 +44,11 @@ impl Tag {
     pub const DEFAULT_BIT_STRING: Tag = Tag::Universal(3);
     pub const DEFAULT_OCTET_STRING: Tag = Tag::Universal(4);
     pub const DEFAULT_NULL: Tag = Tag::Universal(5);
+    pub const DEFAULT_OBJECT_IDENTIFIER: Tag = Tag::Universal(6);
+    pub const DEFAULT_REAL: Tag = Tag::Universal(9);
     pub const DEFAULT_ENUMERATED: Tag = Tag::Universal(10);
     pub const DEFAULT_UTF8_STRING: Tag = Tag::Universal(12);
+    pub const DEFAULT_RELATIVE_OID: Tag = Tag::Universal(13);
     pub const DEFAULT_SEQUENCE: Tag = Tag::Universal(16);
     pub const DEFAULT_SEQUENCE_OF: Tag = Tag::Universal(16);
     pub const DEFAULT_SET: Tag = Tag::Universal(17);
@@ -71,6 +74,19 @@ impl Tag {
     pub const DEFAULT_UNIVERSAL_STRING: Tag = Tag::Universal(28);
     /// ITU-T Rec. X.680, 41
     pub const DEFAULT_BMP_STRING: Tag = Tag::Universal(30);
+
+    /// ITU-T Rec. X.680, 47
+    pub const DEFAULT_UTC_TIME: Tag = Tag::Universal(23);
+    /// ITU-T Rec. X.680, 46
+    pub const DEFAULT_GENERALIZED_TIME: Tag = Tag::Universal(24);
+    /// ITU-T Rec. X.680, 38.4.1
+    pub const DEFAULT_DATE: Tag = Tag::Universal(31);
+    /// ITU-T Rec. X.680, 38.4.1
+    pub const DEFAULT_TIME_OF_DAY: Tag = Tag::Universal(32);
+    /// ITU-T Rec. X.680, 38.4.1
+    pub const DEFAULT_DATE_TIME: Tag = Tag::Universal(33);
+    /// ITU-T Rec. X.680, 38.4.1
+    pub const DEFAULT_DURATION: Tag = Tag::Universal(34);
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Tag {
@@ -101,6 +117,63 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Tag {
     }
 }
 
+/// The tagging environment of a module (ITU-T X.680 | ISO/IEC 8824-1, 13.1), which decides
+/// the mode of tags without an explicit `EXPLICIT` or `IMPLICIT` and whether the components of
+/// `SEQUENCE`, `SET` and `CHOICE` types are tagged automatically (ITU-T X.680 | ISO/IEC 8824-1,
+/// 25.3, 27.3 and 29.3)
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Ord, Eq, Hash)]
+pub enum TagDefault {
+    /// Also the tagging environment of a module without `TagDefault`
+    Explicit,
+    Implicit,
+    Automatic,
+}
+
+impl TagDefault {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 31.2.7
+    pub const fn tag_mode(self, mode: Option<TagMode>) -> TagMode {
+        match (mode, self) {
+            (Some(mode), _) => mode,
+            (None, TagDefault::Explicit) => TagMode::Explicit,
+            (None, TagDefault::Implicit) | (None, TagDefault::Automatic) => TagMode::Implicit,
+        }
+    }
+
+    pub(crate) fn try_from_text(text: &str) -> Option<Self> {
+        match text {
+            t if t.eq_ignore_ascii_case("EXPLICIT") => Some(TagDefault::Explicit),
+            t if t.eq_ignore_ascii_case("IMPLICIT") => Some(TagDefault::Implicit),
+            t if t.eq_ignore_ascii_case("AUTOMATIC") => Some(TagDefault::Automatic),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a tag replaces the tag of the underlying type or is added to it (ITU-T X.680 |
+/// ISO/IEC 8824-1, 31.2). Tagging a `CHOICE` or an open type is always explicit, regardless of
+/// the mode of the tag.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Ord, Eq, Hash, Default)]
+pub enum TagMode {
+    #[default]
+    Implicit,
+    Explicit,
+}
+
+impl TagMode {
+    /// Reads the optional `IMPLICIT` or `EXPLICIT` that follows a tag
+    pub(crate) fn read_opt<T: Iterator<Item = Token>>(iter: &mut Peekable<T>) -> Option<Self> {
+        if iter.peek_is_text_eq_ignore_case("IMPLICIT") {
+            let _ = iter.next();
+            Some(TagMode::Implicit)
+        } else if iter.peek_is_text_eq_ignore_case("EXPLICIT") {
+            let _ = iter.next();
+            Some(TagMode::Explicit)
+        } else {
+            None
+        }
+    }
+}
+
 pub trait TagProperty {
     fn tag(&self) -> Option<Tag>;
 
@@ -1,5 +1,5 @@
 use crate::model::charset::Charset;
-use crate::model::{Asn, Definition, Model, Tag, TagProperty, Type};
+use crate::model::{Asn, Definition, Model, Tag, TagDefault, TagProperty, Type};
 
 pub struct TagResolver<'a> {
     model: &'a Model<Asn>,
@@ -12,7 +12,16 @@ impl TagResolver<'_> {
     }
 
     pub fn resolve_default(ty: &Type) -> Option<Tag> {
-        let model = Model::<Asn>::default();
+        Self::resolve_default_in(TagDefault::Automatic, ty)
+    }
+
+    /// Like [`TagResolver::resolve_default`], but for a type in a module with the given
+    /// tagging environment
+    pub fn resolve_default_in(tag_default: TagDefault, ty: &Type) -> Option<Tag> {
+        let model = Model::<Asn> {
+            tag_default,
+            ..Model::default()
+        };
         TagResolver {
             model: &model,
             scope: &[],
@@ -27,8 +36,7 @@ impl TagResolver<'_> {
             .imports
             .iter()
             .find(|import| import.what.iter().any(|what| what.eq(ty)))
-            .map(|import| &import.from)
-            .and_then(|model_name| self.scope.iter().find(|model| model.name.eq(model_name)))
+            .and_then(|import| self.scope.iter().find(|model| import.refers_to(model)))
             .and_then(|model| {
                 TagResolver {
                     model,
@@ -46,7 +54,7 @@ impl TagResolver<'_> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_no_default(&self, ty: &Type) -> Option<Tag> {
-        let default = Self::resolve_default(ty);
+        let default = Self::resolve_default_in(self.model.tag_default, ty);
         let resolved = self.resolve_type_tag(ty);
         resolved.filter(|r| default.ne(&Some(*r)))
     }
@@ -60,18 +68,34 @@ impl TagResolver<'_> {
             Type::BitString(_) => Some(Tag::DEFAULT_BIT_STRING),
             Type::OctetString(_) => Some(Tag::DEFAULT_OCTET_STRING),
             Type::Enumerated(_) => Some(Tag::DEFAULT_ENUMERATED),
-            Type::String(_, Charset::Numeric) => Some(Tag::DEFAULT_NUMERIC_STRING),
-            Type::String(_, Charset::Printable) => Some(Tag::DEFAULT_PRINTABLE_STRING),
-            Type::String(_, Charset::Visible) => Some(Tag::DEFAULT_VISIBLE_STRING),
-            Type::String(_, Charset::Utf8) => Some(Tag::DEFAULT_UTF8_STRING),
-            Type::String(_, Charset::Ia5) => Some(Tag::DEFAULT_IA5_STRING),
+            Type::String(_, Charset::Numeric, _) => Some(Tag::DEFAULT_NUMERIC_STRING),
+            Type::String(_, Charset::Printable, _) => Some(Tag::DEFAULT_PRINTABLE_STRING),
+            Type::String(_, Charset::Visible, _) => Some(Tag::DEFAULT_VISIBLE_STRING),
+            Type::String(_, Charset::Utf8, _) => Some(Tag::DEFAULT_UTF8_STRING),
+            Type::String(_, Charset::Ia5, _) => Some(Tag::DEFAULT_IA5_STRING),
+            Type::String(_, Charset::Teletex, _) => Some(Tag::DEFAULT_TELETEXT_STRING),
+            Type::String(_, Charset::Graphic, _) => Some(Tag::DEFAULT_GRAPHIC_STRING),
+            Type::String(_, Charset::General, _) => Some(Tag::DEFAULT_GENERAL_STRING),
+            Type::String(_, Charset::Universal, _) => Some(Tag::DEFAULT_UNIVERSAL_STRING),
+            Type::String(_, Charset::Bmp, _) => Some(Tag::DEFAULT_BMP_STRING),
             Type::Null => Some(Tag::DEFAULT_NULL),
+            Type::Real(_) => Some(Tag::DEFAULT_REAL),
+            Type::ObjectIdentifier => Some(Tag::DEFAULT_OBJECT_IDENTIFIER),
+            Type::RelativeOid => Some(Tag::DEFAULT_RELATIVE_OID),
+            Type::Time(time) => Some(time.default_tag()),
             Type::Optional(inner) => self.resolve_type_tag(inner),
             Type::Default(inner, ..) => self.resolve_type_tag(inner),
             Type::Sequence(_) => Some(Tag::DEFAULT_SEQUENCE),
             Type::SequenceOf(_, _) => Some(Tag::DEFAULT_SEQUENCE_OF),
             Type::Set(_) => Some(Tag::DEFAULT_SET),
             Type::SetOf(_, _) => Some(Tag::DEFAULT_SET_OF),
+            // ITU-T X.680 | ISO/IEC 8824-1, 29.3, the alternatives are tagged automatically from [0]
+            Type::Choice(choice)
+                if self.model.tag_default == TagDefault::Automatic
+                    && choice.variants().all(|v| v.tag().is_none()) =>
+            {
+                Some(Tag::ContextSpecific(0))
+            }
             Type::Choice(choice) => {
                 let mut tags = choice
                     .variants()
@@ -89,6 +113,12 @@ impl TagResolver<'_> {
                 }
                 tags.into_iter().next()
             }
+            // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, open types are always tagged explicitly
+            Type::OpenTypeChoice(_) | Type::Related(..) | Type::OpenType => None,
+            Type::ObjectClassField(never)
+            | Type::Instance(never)
+            | Type::Selection(never)
+            | Type::ComponentsOf(never) => match *never {},
             Type::TypeReference(inner, tag) => {
                 let tag = (*tag).or_else(|| self.resolve_tag(inner.as_str()));
                 if cfg!(feature = "debug-proc-macro") {
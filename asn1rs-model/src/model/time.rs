@@ -0,0 +1,102 @@
+use crate::model::Tag;
+
+/// The useful time types of ITU-T X.680 | ISO/IEC 8824-1, 46 and 47, as well as the time types
+/// of 38.4.1 that have no further property settings
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum TimeType {
+    /// `GeneralizedTime`: `YYYYMMDDHH[MM[SS]][.f][Z|+-hhmm]`
+    GeneralizedTime,
+    /// `UTCTime`: `YYMMDDhhmm[ss](Z|+-hhmm)`
+    UtcTime,
+    /// `DATE`: `YYYY-MM-DD`
+    Date,
+    /// `TIME-OF-DAY`: `hh:mm:ss`
+    TimeOfDay,
+    /// `DATE-TIME`: `YYYY-MM-DDThh:mm:ss`
+    DateTime,
+    /// `DURATION`: ISO 8601 duration like `P1Y2M3DT4H5M6.5S` or `P2W`
+    Duration,
+}
+
+impl TimeType {
+    /// The type names are case sensitive, so that `Date ::= ...` is still a type reference
+    pub fn from_asn_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "GeneralizedTime" => TimeType::GeneralizedTime,
+            "UTCTime" => TimeType::UtcTime,
+            "DATE" => TimeType::Date,
+            "TIME-OF-DAY" => TimeType::TimeOfDay,
+            "DATE-TIME" => TimeType::DateTime,
+            "DURATION" => TimeType::Duration,
+            _ => return None,
+        })
+    }
+
+    pub const fn asn_name(self) -> &'static str {
+        match self {
+            TimeType::GeneralizedTime => "GeneralizedTime",
+            TimeType::UtcTime => "UTCTime",
+            TimeType::Date => "DATE",
+            TimeType::TimeOfDay => "TIME-OF-DAY",
+            TimeType::DateTime => "DATE-TIME",
+            TimeType::Duration => "DURATION",
+        }
+    }
+
+    /// The name of the type and of its attribute in `#[asn(...)]`
+    pub const fn rust_name(self) -> &'static str {
+        match self {
+            TimeType::GeneralizedTime => "GeneralizedTime",
+            TimeType::UtcTime => "UtcTime",
+            TimeType::Date => "Date",
+            TimeType::TimeOfDay => "TimeOfDay",
+            TimeType::DateTime => "DateTime",
+            TimeType::Duration => "Duration",
+        }
+    }
+
+    pub const fn attribute_name(self) -> &'static str {
+        match self {
+            TimeType::GeneralizedTime => "generalized_time",
+            TimeType::UtcTime => "utc_time",
+            TimeType::Date => "date",
+            TimeType::TimeOfDay => "time_of_day",
+            TimeType::DateTime => "date_time",
+            TimeType::Duration => "duration",
+        }
+    }
+
+    pub const fn default_tag(self) -> Tag {
+        match self {
+            TimeType::GeneralizedTime => Tag::DEFAULT_GENERALIZED_TIME,
+            TimeType::UtcTime => Tag::DEFAULT_UTC_TIME,
+            TimeType::Date => Tag::DEFAULT_DATE,
+            TimeType::TimeOfDay => Tag::DEFAULT_TIME_OF_DAY,
+            TimeType::DateTime => Tag::DEFAULT_DATE_TIME,
+            TimeType::Duration => Tag::DEFAULT_DURATION,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_names_are_consistent() {
+        for time in [
+            TimeType::GeneralizedTime,
+            TimeType::UtcTime,
+            TimeType::Date,
+            TimeType::TimeOfDay,
+            TimeType::DateTime,
+            TimeType::Duration,
+        ] {
+            assert_eq!(Some(time), TimeType::from_asn_name(time.asn_name()));
+            assert_eq!(Ok(time), TimeType::from_str(time.attribute_name()));
+        }
+        assert_eq!(None, TimeType::from_asn_name("Date"));
+    }
+}
@@ -148,7 +148,7 @@ impl Tokenizer {
                     (
                         false,
                         ':' | ';' | '=' | '(' | ')' | '{' | '}' | '.' | ',' | '[' | ']' | '\''
-                        | '"',
+                        | '"' | '<',
                         _,
                     ) => {
                         token = Some(Token::Separator(
@@ -17,6 +17,14 @@ const ARG_RUST_GETTER_AND_SETTER: [&str; 5] = [
     "Whether to generate getter and setter for the fields of the generated rust structs",
 ];
 
+const ARG_RUST_KEEP_UNKNOWN_EXTENSIONS: [&str; 5] = [
+    "RUST_KEEP_UNKNOWN_EXTENSIONS",
+    "RUST_KEEP_UNKNOWN_EXTENSIONS",
+    "k",
+    "rust-keep-unknown-extensions",
+    "Whether extensible rust structs and enums keep extension additions unknown to them",
+];
+
 const ARG_CONVERSION_TARGET: [&str; 5] = [
     "CONVERT_TO",
     "CONVERT_TO",
@@ -38,6 +46,7 @@ pub const CONVERSION_TARGET_POSSIBLE_VALUES: [&str; 3] = [
 pub struct Parameters {
     pub rust_fields_not_public: bool,
     pub rust_getter_and_setter: bool,
+    pub rust_keep_unknown_extensions: bool,
     pub conversion_target: String,
     pub source_files: Vec<String>,
     pub destination_dir: String,
@@ -67,6 +76,7 @@ pub fn create_argument_parser<'a, 'b>() -> App<'a, 'b> {
         .setting(AppSettings::ColoredHelp)
         .arg(arg(ARG_RUST_FIELDS_NOT_PUBLIC, None).takes_value(false))
         .arg(arg(ARG_RUST_GETTER_AND_SETTER, None).takes_value(false))
+        .arg(arg(ARG_RUST_KEEP_UNKNOWN_EXTENSIONS, None).takes_value(false))
         .arg(
             arg(ARG_CONVERSION_TARGET, Some(CONVERSION_TARGET_RUST))
                 .possible_values(&CONVERSION_TARGET_POSSIBLE_VALUES)
@@ -92,6 +102,7 @@ pub fn parse_parameters() -> Parameters {
     Parameters {
         rust_fields_not_public: matches.is_present(ARG_RUST_FIELDS_NOT_PUBLIC[0]),
         rust_getter_and_setter: matches.is_present(ARG_RUST_GETTER_AND_SETTER[0]),
+        rust_keep_unknown_extensions: matches.is_present(ARG_RUST_KEEP_UNKNOWN_EXTENSIONS[0]),
         conversion_target: matches
             .value_of_lossy(ARG_CONVERSION_TARGET[0])
             .expect("Missing conversion target")
@@ -60,6 +60,12 @@ pub struct Converter {
 }
 
 impl Converter {
+    /// See [`MultiModuleResolver::set_imported_modules_required`], by default the loaded files
+    /// may import from modules that are converted separately
+    pub fn set_imported_modules_required(&mut self, required: bool) {
+        self.models.set_imported_modules_required(required);
+    }
+
     pub fn load_file<F: AsRef<Path>>(&mut self, file: F) -> Result<(), Error> {
         let input = ::std::fs::read_to_string(file)?;
         let tokens = Tokenizer::default().parse(&input);
@@ -238,3 +244,39 @@ pub fn convert_to_sql_with<F: AsRef<Path>, D: AsRef<Path>>(
     }
     Ok(files)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMPORTING: &str = r"Importing DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        IMPORTS Inner FROM Exporting;
+        Wrapper ::= SEQUENCE { inner Inner }
+        END";
+
+    #[test]
+    fn test_files_are_converted_one_at_a_time() {
+        let dir = std::env::temp_dir().join(format!("asn1rs-converter-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("importing.asn1");
+        std::fs::write(&file, IMPORTING).unwrap();
+
+        let mut converter = Converter::default();
+        converter.load_file(&file).unwrap();
+        let files = converter.to_rust(&dir, |_| {}).unwrap();
+        assert_eq!(
+            Some(&vec!["importing.rs".to_string()]),
+            files.get("Importing")
+        );
+
+        let mut converter = Converter::default();
+        converter.set_imported_modules_required(true);
+        converter.load_file(&file).unwrap();
+        assert!(matches!(
+            converter.to_rust(&dir, |_| {}),
+            Err(Error::ResolveError(ResolveError::UnresolvedImport(..)))
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
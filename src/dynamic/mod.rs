@@ -0,0 +1,388 @@
+//! Values of types that are only known at runtime. A [`Schema`] holds the resolved definitions of
+//! one or more ASN.1 modules and reads and writes [`Value`]s by the name of a definition, without
+//! generating any Rust types for them.
+//!
+//! ```rust
+//! use asn1rs::dynamic::{Schema, Value};
+//! use asn1rs::model::Model;
+//! use asn1rs::parser::Tokenizer;
+//! use std::convert::TryFrom;
+//!
+//! let model = Model::try_from(Tokenizer::default().parse(
+//!     r"Inspection DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+//!         Packet ::= SEQUENCE {
+//!             id INTEGER (0..255),
+//!             label UTF8String OPTIONAL
+//!         }
+//!     END",
+//! ))
+//! .unwrap()
+//! .try_resolve()
+//! .unwrap();
+//!
+//! let schema = Schema::from(model);
+//! let bytes = [0x85, 0x81, 0x34, 0x34, 0x80];
+//! let value = schema.decode_uper("Packet", &bytes).unwrap();
+//! assert_eq!(Some(&Value::Integer(11)), value.component("id"));
+//! assert_eq!(Some(&Value::String("hi".to_string())), value.component("label"));
+//! assert_eq!(&bytes[..], &schema.encode_uper("Packet", &value).unwrap()[..]);
+//! ```
+
+mod uper;
+
+use crate::io::per::Error as PerError;
+use crate::io::per::ErrorKind as PerErrorKind;
+use crate::model::{Asn, Definition, Field, LiteralValue, Model, Type};
+use crate::syn::{ObjectIdentifier, OpenType, RelativeOid, UnknownExtensions};
+use std::collections::{HashMap, HashSet};
+
+/// A value of any ASN.1 type, shaped by the [`Type`] it is read as or written as
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Boolean(bool),
+    Integer(i64),
+    Real(f64),
+    /// The value of any character string type and of the time types
+    String(String),
+    OctetString(Vec<u8>),
+    /// The bytes and the number of bits of a `BIT STRING`
+    BitString(Vec<u8>, u64),
+    Null,
+    ObjectIdentifier(ObjectIdentifier),
+    RelativeOid(RelativeOid),
+    /// The identifier of the variant of an `ENUMERATED`
+    Enumerated(String),
+    /// The present components of a `SEQUENCE` or `SET` by their identifier and in the order of
+    /// their definition. Components with a `DEFAULT` value are always present when read. The
+    /// extension additions unknown to the type are kept like for the generated types.
+    Sequence(Vec<(String, Value)>, UnknownExtensions),
+    /// The elements of a `SEQUENCE OF` or `SET OF`
+    SequenceOf(Vec<Value>),
+    /// The identifier and the value of the chosen alternative of a `CHOICE` or of the variant of
+    /// an open type that is selected by a table constraint
+    Choice(String, Box<Value>),
+    /// An alternative of an extensible `CHOICE` that is unknown to the type, by its index and in
+    /// its encoded form
+    UnknownAlternative(u64, OpenType),
+    /// The encoded form of an open type without a table constraint
+    OpenType(OpenType),
+}
+
+impl Value {
+    /// The value of the component with the given identifier, if this is a present component of a
+    /// `SEQUENCE` or `SET`
+    pub fn component(&self, identifier: &str) -> Option<&Value> {
+        if let Value::Sequence(components, _) = self {
+            components
+                .iter()
+                .find(|(name, _)| name == identifier)
+                .map(|(_, value)| value)
+        } else {
+            None
+        }
+    }
+
+    /// A short name of the kind of this value for error messages
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Value::Boolean(_) => "BOOLEAN",
+            Value::Integer(_) => "INTEGER",
+            Value::Real(_) => "REAL",
+            Value::String(_) => "string",
+            Value::OctetString(_) => "OCTET STRING",
+            Value::BitString(..) => "BIT STRING",
+            Value::Null => "NULL",
+            Value::ObjectIdentifier(_) => "OBJECT IDENTIFIER",
+            Value::RelativeOid(_) => "RELATIVE-OID",
+            Value::Enumerated(_) => "ENUMERATED",
+            Value::Sequence(..) => "SEQUENCE",
+            Value::SequenceOf(_) => "SEQUENCE OF",
+            Value::Choice(..) | Value::UnknownAlternative(..) => "CHOICE",
+            Value::OpenType(_) => "open type",
+        }
+    }
+
+    /// The value of a `DEFAULT`, `None` for object identifiers with components that have no
+    /// number
+    pub fn from_literal(literal: &LiteralValue) -> Option<Self> {
+        Some(match literal {
+            LiteralValue::Boolean(value) => Value::Boolean(*value),
+            LiteralValue::String(value) => Value::String(value.clone()),
+            LiteralValue::Integer(value) => Value::Integer(*value),
+            LiteralValue::OctetString(value) => Value::OctetString(value.clone()),
+            LiteralValue::EnumeratedVariant(_, variant) => Value::Enumerated(variant.clone()),
+            LiteralValue::ObjectIdentifier(oid) => {
+                Value::ObjectIdentifier(ObjectIdentifier::from(oid.arcs()?))
+            }
+        })
+    }
+}
+
+/// The definitions of resolved ASN.1 modules, by which [`Value`]s are read and written
+#[derive(Debug, Default, Clone)]
+pub struct Schema {
+    /// The definitions by the name of their module and their own name, like `Module.Name`, or
+    /// like `Module { 1 2 3 }.Name` if several versions of the module are loaded
+    definitions: HashMap<String, Asn>,
+    /// The keys of the definitions by their own name and by the name of their module and their
+    /// own name
+    aliases: HashMap<String, Vec<String>>,
+}
+
+impl Schema {
+    /// Collects the definitions of all given modules. A type reference in a definition refers to
+    /// the definition of the same module or of the module it is imported from, so that several
+    /// modules may define the same name. Several versions of a module can be loaded if they are
+    /// told apart by their OID, see [`crate::model::Import::refers_to`].
+    pub fn new(models: impl IntoIterator<Item = Model<Asn>>) -> Result<Self, Error> {
+        let models = models.into_iter().collect::<Vec<_>>();
+        for (index, model) in models.iter().enumerate() {
+            if models[..index]
+                .iter()
+                .any(|other| is_same_module(other, model))
+            {
+                return Err(Error::DuplicateModule(module_description(model)));
+            }
+        }
+
+        let modules = models
+            .iter()
+            .map(|model| {
+                if models
+                    .iter()
+                    .filter(|other| other.name == model.name)
+                    .count()
+                    > 1
+                {
+                    module_description(model)
+                } else {
+                    model.name.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        let scopes = models
+            .iter()
+            .map(|model| Scope::new(model, &models))
+            .collect::<Vec<_>>();
+
+        let mut schema = Self::default();
+        for (index, model) in models.into_iter().enumerate() {
+            let module = &modules[index];
+            for Definition(name, mut asn) in model.definitions {
+                qualify_references(&mut asn.r#type, &|reference| {
+                    qualified_name(&scopes, &modules, index, reference)
+                });
+                let key = format!("{module}.{name}");
+                for alias in [name.clone(), format!("{}.{name}", model.name)] {
+                    if alias != key {
+                        schema.aliases.entry(alias).or_default().push(key.clone());
+                    }
+                }
+                schema.definitions.insert(key, asn);
+            }
+        }
+        Ok(schema)
+    }
+
+    /// The definition with the given name, which needs to be qualified by the name of its module
+    /// like `Module.Name` if several modules define the name, and by the OID of the module like
+    /// `Module { 1 2 3 }.Name` if several versions of the module are loaded
+    pub fn definition(&self, name: &str) -> Option<&Asn> {
+        self.definitions
+            .get(name)
+            .or_else(|| match self.aliases.get(name)?.as_slice() {
+                [key] => self.definitions.get(key),
+                _ => None,
+            })
+    }
+
+    fn definition_or_err(&self, name: &str) -> Result<&Asn, Error> {
+        self.definition(name).ok_or_else(|| {
+            if self.aliases.get(name).is_some_and(|keys| keys.len() > 1) {
+                Error::AmbiguousType(name.to_string())
+            } else {
+                Error::UnknownType(name.to_string())
+            }
+        })
+    }
+}
+
+/// Modules with the same name are only told apart by their OIDs
+fn is_same_module(a: &Model<Asn>, b: &Model<Asn>) -> bool {
+    a.name == b.name
+        && match (&a.oid, &b.oid) {
+            (Some(a), Some(b)) => a.is_equivalent(b),
+            _ => true,
+        }
+}
+
+/// The name of the module and its OID, if any
+fn module_description(model: &Model<Asn>) -> String {
+    match &model.oid {
+        Some(oid) => format!("{} {}", model.name, oid),
+        None => model.name.clone(),
+    }
+}
+
+/// The names a module defines and the names it imports by the index of the module they are
+/// imported from
+struct Scope {
+    defined: HashSet<String>,
+    imported: HashMap<String, usize>,
+}
+
+impl Scope {
+    fn new(model: &Model<Asn>, models: &[Model<Asn>]) -> Self {
+        Self {
+            defined: model.definitions.iter().map(|d| d.0.clone()).collect(),
+            imported: model
+                .imports
+                .iter()
+                .filter_map(|import| {
+                    let from = models.iter().position(|other| import.refers_to(other))?;
+                    Some(import.what.iter().map(move |name| (name.clone(), from)))
+                })
+                .flatten()
+                .collect(),
+        }
+    }
+}
+
+/// The key of the definition a reference in the module with the given index refers to, qualified
+/// by the module that defines it. `None` if the name is neither defined nor imported from one of
+/// the given modules.
+fn qualified_name(
+    scopes: &[Scope],
+    modules: &[String],
+    index: usize,
+    name: &str,
+) -> Option<String> {
+    let mut index = index;
+    // an import might be re-exported by another module, but never more often than there are modules
+    for _ in 0..scopes.len() {
+        let scope = &scopes[index];
+        if scope.defined.contains(name) {
+            return Some(format!("{}.{name}", modules[index]));
+        }
+        index = *scope.imported.get(name)?;
+    }
+    None
+}
+
+fn qualify_references(r#type: &mut Type, qualified: &impl Fn(&str) -> Option<String>) {
+    match r#type {
+        Type::TypeReference(name, _) => {
+            if let Some(qualified) = qualified(name) {
+                *name = qualified;
+            }
+        }
+        Type::Optional(inner)
+        | Type::Default(inner, _)
+        | Type::SequenceOf(inner, _)
+        | Type::SetOf(inner, _)
+        | Type::Related(_, inner) => qualify_references(inner, qualified),
+        Type::Sequence(components) | Type::Set(components) => {
+            for field in &mut components.fields {
+                qualify_references(&mut field.role.r#type, qualified);
+            }
+        }
+        Type::Choice(choice) => {
+            for variant in choice.variants_mut() {
+                qualify_references(&mut variant.r#type, qualified);
+            }
+        }
+        Type::OpenTypeChoice(choice) => {
+            for variant in &mut choice.variants {
+                qualify_references(&mut variant.r#type, qualified);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl From<Model<Asn>> for Schema {
+    fn from(model: Model<Asn>) -> Self {
+        Self::new(Some(model)).expect("A single module is never a duplicate")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Per(PerError),
+    /// There is no definition with the given name
+    UnknownType(String),
+    /// Several modules define the given name, which needs to be qualified like `Module.Name`
+    AmbiguousType(String),
+    /// The module with the given name and OID is given more than once
+    DuplicateModule(String),
+    /// The given number of bits follow the value, which are more than the zero padding to the
+    /// last octet
+    TrailingBits(usize),
+    /// The value does not fit the type, by the expected and the found kind of value
+    UnexpectedValue(&'static str, &'static str),
+    /// A component that is neither optional nor has a default value is missing
+    MissingComponent(String),
+    /// The `SEQUENCE` or `SET` has no component with the given identifier
+    UnknownComponent(String),
+    /// The `CHOICE` or open type has no alternative with the given identifier
+    UnknownAlternative(String),
+    /// The `ENUMERATED` has no variant with the given identifier
+    UnknownVariant(String),
+}
+
+impl Error {
+    fn unexpected(expected: &'static str, found: &Value) -> Self {
+        Error::UnexpectedValue(expected, found.kind_name())
+    }
+}
+
+impl From<PerError> for Error {
+    fn from(e: PerError) -> Self {
+        Error::Per(e)
+    }
+}
+
+impl From<PerErrorKind> for Error {
+    fn from(kind: PerErrorKind) -> Self {
+        Error::Per(kind.into())
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Per(e) => write!(f, "{e}"),
+            Error::UnknownType(name) => write!(f, "There is no type named {name}"),
+            Error::AmbiguousType(name) => {
+                write!(f, "Several modules define a type named {name}")
+            }
+            Error::DuplicateModule(module) => {
+                write!(f, "The module {module} is given more than once")
+            }
+            Error::TrailingBits(len) => write!(f, "There are {len} bits after the value"),
+            Error::UnexpectedValue(expected, found) => {
+                write!(f, "Expected a {expected} value but found a {found} value")
+            }
+            Error::MissingComponent(name) => write!(f, "The component {name} is missing"),
+            Error::UnknownComponent(name) => write!(f, "There is no component named {name}"),
+            Error::UnknownAlternative(name) => write!(f, "There is no alternative named {name}"),
+            Error::UnknownVariant(name) => write!(f, "There is no variant named {name}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Whether the component may be absent in a value
+fn is_optional(field: &Field<Asn>) -> bool {
+    field.role.default.is_some()
+        || matches!(field.role.r#type, Type::Optional(_) | Type::Default(..))
+}
+
+/// The value of the component if it is absent in a value
+fn default_value(field: &Field<Asn>) -> Option<Value> {
+    match (&field.role.default, &field.role.r#type) {
+        (Some(literal), _) | (None, Type::Default(_, literal)) => Value::from_literal(literal),
+        _ => None,
+    }
+}
@@ -0,0 +1,874 @@
+//! Reads and writes [`Value`]s like the [`crate::syn::io::UperReader`] and the
+//! [`crate::syn::io::UperWriter`] read and write the generated types of the same definitions.
+
+use super::{default_value, is_optional, Error, Schema, Value};
+use crate::io::per::alphabet::EffectiveAlphabet;
+use crate::io::per::unaligned::buffer::{BitBuffer, Bits};
+use crate::io::per::unaligned::{BitRead, BitWrite, ScopedBitRead, BYTE_LEN};
+use crate::io::per::{Error as PerError, ErrorKind, PackedRead, PackedWrite};
+use crate::model::lor::Resolved;
+use crate::model::{
+    Asn, Charset, Choice, ComponentTypeList, Enumerated, Field, Integer, PermittedAlphabet, Size,
+    Type,
+};
+use crate::syn::time;
+use crate::syn::{ObjectIdentifier, OpenType, RelativeOid, UnknownExtensions};
+use std::ops::Range;
+
+impl Schema {
+    /// Reads a value of the type with the given name in the unaligned packed encoding rules
+    pub fn read_uper(&self, type_name: &str, bits: &mut impl BitRead) -> Result<Value, Error> {
+        let asn = self.definition_or_err(type_name)?;
+        self.read_type(&asn.r#type, bits)
+    }
+
+    /// Writes a value of the type with the given name in the unaligned packed encoding rules
+    pub fn write_uper(
+        &self,
+        type_name: &str,
+        value: &Value,
+        bits: &mut impl BitWrite,
+    ) -> Result<(), Error> {
+        let asn = self.definition_or_err(type_name)?;
+        self.write_type(&asn.r#type, value, bits)
+    }
+
+    /// Reads a value of the type with the given name from its complete encoding, which must not
+    /// be followed by more than the zero bits that pad it to an octet
+    pub fn decode_uper(&self, type_name: &str, bytes: &[u8]) -> Result<Value, Error> {
+        let mut bits = Bits::from(bytes);
+        let value = self.read_uper(type_name, &mut bits)?;
+        // ITU-T X.691 | ISO/IEC 8825-2, 11.1, the complete encoding of an empty value is a single
+        // zero octet
+        let padding = if bits.pos() == 0 {
+            BYTE_LEN
+        } else {
+            (BYTE_LEN - bits.pos() % BYTE_LEN) % BYTE_LEN
+        };
+        let trailing = bits.remaining();
+        if trailing > padding || (0..trailing).any(|_| !matches!(bits.read_bit(), Ok(false))) {
+            return Err(Error::TrailingBits(trailing));
+        }
+        Ok(value)
+    }
+
+    pub fn encode_uper(&self, type_name: &str, value: &Value) -> Result<Vec<u8>, Error> {
+        let mut buffer = BitBuffer::default();
+        self.write_uper(type_name, value, &mut buffer)?;
+        Ok(buffer.into())
+    }
+
+    fn read_type(&self, r#type: &Type, bits: &mut impl BitRead) -> Result<Value, Error> {
+        Ok(match r#type {
+            Type::Boolean => Value::Boolean(bits.read_boolean()?),
+            Type::Integer(integer) => Value::Integer(read_integer(integer, bits)?),
+            Type::Real(_) => Value::Real(bits.read_real()?),
+            Type::String(size, charset, alphabet) => {
+                Value::String(read_string(size, *charset, alphabet, bits)?)
+            }
+            Type::OctetString(size) => Value::OctetString(bits.read_octetstring(
+                min(size),
+                max(size),
+                size.extensible(),
+            )?),
+            Type::BitString(bit_string) => {
+                let size = &bit_string.size;
+                let (bytes, len) = bits.read_bitstring(min(size), max(size), size.extensible())?;
+                Value::BitString(bytes, len)
+            }
+            Type::Null => Value::Null,
+            Type::ObjectIdentifier => {
+                Value::ObjectIdentifier(ObjectIdentifier::from(bits.read_object_identifier()?))
+            }
+            Type::RelativeOid => Value::RelativeOid(RelativeOid::from(bits.read_relative_oid()?)),
+            Type::Time(time_type) => {
+                // encoded as an unconstrained VisibleString
+                let len = bits.read_length_determinant(None, None)?;
+                let string = read_7bit_chars(len, bits)?;
+                if time::is_valid(*time_type, &string) {
+                    Value::String(string)
+                } else {
+                    return Err(ErrorKind::InvalidTime(*time_type, string).into());
+                }
+            }
+            Type::Optional(inner) | Type::Default(inner, _) => return self.read_type(inner, bits),
+            Type::Sequence(components) | Type::Set(components) => {
+                self.read_components(components, bits)?
+            }
+            Type::SequenceOf(inner, size) | Type::SetOf(inner, size) => {
+                let len = read_length(size, bits)?;
+                Value::SequenceOf(
+                    (0..len)
+                        .map(|_| self.read_type(inner, bits))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Type::Enumerated(enumerated) => {
+                let index = bits.read_enumeration_index(
+                    std_variants(enumerated.extension_after_index(), enumerated.len()),
+                    enumerated.is_extensible(),
+                )?;
+                let variant = enumerated.variants().nth(index as usize).ok_or(
+                    ErrorKind::InvalidChoiceIndex(index, enumerated.len() as u64),
+                )?;
+                Value::Enumerated(variant.identifier().to_string())
+            }
+            Type::Choice(choice) => self.read_choice(choice, bits)?,
+            Type::TypeReference(name, _) => {
+                return self.read_type(&self.definition_or_err(name)?.r#type, bits)
+            }
+            Type::OpenType => {
+                Value::OpenType(OpenType::from(bits.read_octetstring(None, None, false)?))
+            }
+            Type::OpenTypeChoice(_) | Type::Related(..) => {
+                return Err(unrelated_open_type_choice().into())
+            }
+            Type::ObjectClassField(never)
+            | Type::Instance(never)
+            | Type::Selection(never)
+            | Type::ComponentsOf(never) => match *never {},
+        })
+    }
+
+    fn read_choice(&self, choice: &Choice, bits: &mut impl BitRead) -> Result<Value, Error> {
+        let std_variants = std_variants(choice.extension_after_index(), choice.len());
+        let index = bits.read_choice_index(std_variants, choice.is_extensible())?;
+        match choice.variants().nth(index as usize) {
+            Some(variant) if index < std_variants => Ok(Value::Choice(
+                variant.identifier().to_string(),
+                Box::new(self.read_type(variant.r#type(), bits)?),
+            )),
+            Some(variant) => {
+                let bytes = bits.read_octetstring(None, None, false)?;
+                Ok(Value::Choice(
+                    variant.identifier().to_string(),
+                    Box::new(self.read_type(variant.r#type(), &mut Bits::from(&bytes[..]))?),
+                ))
+            }
+            None => Ok(Value::UnknownAlternative(
+                index,
+                OpenType::from(bits.read_octetstring(None, None, false)?),
+            )),
+        }
+    }
+
+    fn read_components(
+        &self,
+        components: &ComponentTypeList<Resolved>,
+        bits: &mut impl BitRead,
+    ) -> Result<Value, Error> {
+        let fields = &components.fields[..];
+        let root = root_range(components);
+        let mut values = vec![None; fields.len()];
+
+        let extended = components.extension_after.is_some() && bits.read_bit()?;
+        self.read_plain_components(fields, root.clone(), &mut values, bits)?;
+
+        let mut unknown = Vec::new();
+        if extended {
+            let units = addition_units(components, root.end);
+            let len = bits.read_normally_small_length()? as usize + 1;
+            let presence = (0..len)
+                .map(|_| bits.read_bit())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (index, present) in presence.into_iter().enumerate() {
+                match (present, units.get(index)) {
+                    (false, Some(_)) => {}
+                    (false, None) => unknown.push(None),
+                    (true, Some(unit)) => {
+                        let bytes = bits.read_octetstring(None, None, false)?;
+                        let bits = &mut Bits::from(&bytes[..]);
+                        if is_group(components, unit) {
+                            self.read_plain_components(fields, unit.clone(), &mut values, bits)?;
+                        } else {
+                            values[unit.start] =
+                                Some(self.read_component(fields, unit.start, &values, bits)?);
+                        }
+                    }
+                    (true, None) => {
+                        let bytes = bits.read_octetstring(None, None, false)?;
+                        unknown.push(Some(OpenType::from(bytes)));
+                    }
+                }
+            }
+        }
+
+        Ok(Value::Sequence(
+            fields
+                .iter()
+                .zip(values)
+                .filter_map(|(field, value)| {
+                    value
+                        .or_else(|| default_value(field))
+                        .map(|value| (field.identifier().to_string(), value))
+                })
+                .collect(),
+            UnknownExtensions::new(unknown),
+        ))
+    }
+
+    /// Reads the components in the given range like a `SEQUENCE` without extension marker, which
+    /// is either the root of a `SEQUENCE` or an extension addition group
+    fn read_plain_components(
+        &self,
+        fields: &[Field<Asn>],
+        range: Range<usize>,
+        values: &mut [Option<Value>],
+        bits: &mut impl BitRead,
+    ) -> Result<(), Error> {
+        let presence = fields[range.clone()]
+            .iter()
+            .map(|field| Ok(!is_optional(field) || bits.read_bit()?))
+            .collect::<Result<Vec<_>, PerError>>()?;
+
+        for (index, present) in range.zip(presence) {
+            if present {
+                values[index] = Some(self.read_component(fields, index, values, bits)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_component(
+        &self,
+        fields: &[Field<Asn>],
+        index: usize,
+        values: &[Option<Value>],
+        bits: &mut impl BitRead,
+    ) -> Result<Value, Error> {
+        match related(&fields[index].role.r#type) {
+            Some((relation, Type::OpenTypeChoice(choice))) => {
+                let key = related_key(fields, values, relation)?;
+                let variant = choice
+                    .variants
+                    .iter()
+                    .find(|variant| variant.key == key)
+                    .ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+                let bytes = bits.read_octetstring(None, None, false)?;
+                Ok(Value::Choice(
+                    variant.identifier().to_string(),
+                    Box::new(self.read_type(&variant.r#type, &mut Bits::from(&bytes[..]))?),
+                ))
+            }
+            Some((_, inner)) => self.read_type(inner, bits),
+            None => self.read_type(&fields[index].role.r#type, bits),
+        }
+    }
+
+    fn write_type(
+        &self,
+        r#type: &Type,
+        value: &Value,
+        bits: &mut impl BitWrite,
+    ) -> Result<(), Error> {
+        match (r#type, value) {
+            (Type::Boolean, Value::Boolean(value)) => bits.write_boolean(*value)?,
+            (Type::Integer(integer), Value::Integer(value)) => {
+                write_integer(integer, *value, bits)?
+            }
+            (Type::Real(_), Value::Real(value)) => bits.write_real(*value)?,
+            (Type::String(size, charset, alphabet), Value::String(value)) => {
+                write_string(size, *charset, alphabet, value, bits)?
+            }
+            (Type::OctetString(size), Value::OctetString(value)) => {
+                bits.write_octetstring(min(size), max(size), size.extensible(), value)?
+            }
+            (Type::BitString(bit_string), Value::BitString(value, len)) => {
+                let size = &bit_string.size;
+                bits.write_bitstring(min(size), max(size), size.extensible(), value, 0, *len)?
+            }
+            (Type::Null, Value::Null) => {}
+            (Type::ObjectIdentifier, Value::ObjectIdentifier(value)) => {
+                bits.write_object_identifier(value.arcs())?
+            }
+            (Type::RelativeOid, Value::RelativeOid(value)) => {
+                bits.write_relative_oid(value.arcs())?
+            }
+            (Type::Time(_), Value::String(value)) => {
+                // encoded as an unconstrained VisibleString
+                PerError::ensure_string_valid(Charset::Visible, value)?;
+                write_length(
+                    false,
+                    None,
+                    None,
+                    u64::MAX,
+                    value.chars().count() as u64,
+                    bits,
+                )?;
+                write_7bit_chars(value, bits)?;
+            }
+            (Type::Optional(inner) | Type::Default(inner, _), value) => {
+                self.write_type(inner, value, bits)?
+            }
+            (Type::Sequence(components) | Type::Set(components), value) => {
+                self.write_components(components, value, bits)?
+            }
+            (
+                Type::SequenceOf(inner, size) | Type::SetOf(inner, size),
+                Value::SequenceOf(values),
+            ) => {
+                write_length(
+                    size.extensible(),
+                    min(size),
+                    max(size),
+                    i64::MAX as u64,
+                    values.len() as u64,
+                    bits,
+                )?;
+                for value in values {
+                    self.write_type(inner, value, bits)?;
+                }
+            }
+            (Type::Enumerated(enumerated), Value::Enumerated(identifier)) => {
+                write_enumerated(enumerated, identifier, bits)?
+            }
+            (Type::Choice(choice), value) => self.write_choice(choice, value, bits)?,
+            (Type::TypeReference(name, _), value) => {
+                self.write_type(&self.definition_or_err(name)?.r#type, value, bits)?
+            }
+            (Type::OpenType, Value::OpenType(value)) => {
+                bits.write_octetstring(None, None, false, value.as_bytes())?
+            }
+            (Type::OpenTypeChoice(_) | Type::Related(..), _) => {
+                return Err(unrelated_open_type_choice().into())
+            }
+            (r#type, value) => return Err(Error::unexpected(expected(r#type), value)),
+        }
+        Ok(())
+    }
+
+    fn write_choice(
+        &self,
+        choice: &Choice,
+        value: &Value,
+        bits: &mut impl BitWrite,
+    ) -> Result<(), Error> {
+        let std_variants = std_variants(choice.extension_after_index(), choice.len());
+        match value {
+            Value::Choice(identifier, value) => {
+                let (index, variant) = choice
+                    .variants()
+                    .enumerate()
+                    .find(|(_, variant)| variant.identifier() == identifier)
+                    .ok_or_else(|| Error::UnknownAlternative(identifier.clone()))?;
+                let index = index as u64;
+                bits.write_choice_index(std_variants, choice.is_extensible(), index)?;
+                if index < std_variants {
+                    self.write_type(variant.r#type(), value, bits)
+                } else {
+                    let mut buffer = BitBuffer::default();
+                    self.write_type(variant.r#type(), value, &mut buffer)?;
+                    Ok(bits.write_octetstring(None, None, false, buffer.content())?)
+                }
+            }
+            Value::UnknownAlternative(index, content) => {
+                bits.write_choice_index(std_variants, choice.is_extensible(), *index)?;
+                Ok(bits.write_octetstring(None, None, false, content.as_bytes())?)
+            }
+            value => Err(Error::unexpected("CHOICE", value)),
+        }
+    }
+
+    fn write_components(
+        &self,
+        components: &ComponentTypeList<Resolved>,
+        value: &Value,
+        bits: &mut impl BitWrite,
+    ) -> Result<(), Error> {
+        let (values, unknown) = match value {
+            Value::Sequence(values, unknown) => (values, unknown.additions()),
+            value => return Err(Error::unexpected("SEQUENCE", value)),
+        };
+        if let Some((identifier, _)) = values.iter().find(|(identifier, _)| {
+            !components
+                .fields
+                .iter()
+                .any(|field| field.identifier() == identifier)
+        }) {
+            return Err(Error::UnknownComponent(identifier.clone()));
+        }
+
+        let fields = &components.fields[..];
+        let values = fields
+            .iter()
+            .map(|field| {
+                values
+                    .iter()
+                    .find(|(identifier, _)| identifier == field.identifier())
+                    .map(|(_, value)| value)
+                    // a value equal to the default is not encoded
+                    .filter(|value| default_value(field).as_ref() != Some(*value))
+            })
+            .collect::<Vec<_>>();
+
+        let root = root_range(components);
+        let units = addition_units(components, root.end);
+        let present = units
+            .iter()
+            .map(|unit| unit.clone().any(|index| values[index].is_some()))
+            .collect::<Vec<_>>();
+        let extended =
+            present.iter().any(|present| *present) || unknown.iter().any(Option::is_some);
+
+        if components.extension_after.is_some() {
+            bits.write_bit(extended)?;
+        }
+        self.write_plain_components(fields, root, &values, bits)?;
+
+        if extended {
+            // ITU-T X.691 | ISO/IEC 8825-2, 19.8, the presence of every addition is written, even
+            // if the last ones are absent
+            let len = units.len() + unknown.len();
+            bits.write_normally_small_non_negative_whole_number(len as u64 - 1)?;
+            for present in present
+                .iter()
+                .copied()
+                .chain(unknown.iter().map(Option::is_some))
+            {
+                bits.write_bit(present)?;
+            }
+
+            for (unit, _) in units.iter().zip(present).filter(|(_, present)| *present) {
+                let mut buffer = BitBuffer::default();
+                if is_group(components, unit) {
+                    self.write_plain_components(fields, unit.clone(), &values, &mut buffer)?;
+                } else if let Some(value) = values[unit.start] {
+                    self.write_component(fields, unit.start, value, &values, &mut buffer)?;
+                }
+                bits.write_octetstring(None, None, false, buffer.content())?;
+            }
+
+            for content in unknown.iter().flatten() {
+                bits.write_octetstring(None, None, false, content.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the components in the given range like a `SEQUENCE` without extension marker, see
+    /// [`Schema::read_plain_components`]
+    fn write_plain_components(
+        &self,
+        fields: &[Field<Asn>],
+        range: Range<usize>,
+        values: &[Option<&Value>],
+        bits: &mut impl BitWrite,
+    ) -> Result<(), Error> {
+        for index in range.clone() {
+            if is_optional(&fields[index]) {
+                bits.write_bit(values[index].is_some())?;
+            }
+        }
+
+        for index in range {
+            match values[index] {
+                Some(value) => self.write_component(fields, index, value, values, bits)?,
+                None if is_optional(&fields[index]) => {}
+                None => return Err(Error::MissingComponent(fields[index].identifier().into())),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_component(
+        &self,
+        fields: &[Field<Asn>],
+        index: usize,
+        value: &Value,
+        values: &[Option<&Value>],
+        bits: &mut impl BitWrite,
+    ) -> Result<(), Error> {
+        match related(&fields[index].role.r#type) {
+            Some((relation, Type::OpenTypeChoice(choice))) => {
+                let (identifier, value) = match value {
+                    Value::Choice(identifier, value) => (identifier, value),
+                    value => return Err(Error::unexpected("CHOICE", value)),
+                };
+                let variant = choice
+                    .variants
+                    .iter()
+                    .find(|variant| variant.identifier() == identifier)
+                    .ok_or_else(|| Error::UnknownAlternative(identifier.clone()))?;
+
+                let key = match values[fields_index(fields, relation)?] {
+                    Some(Value::Integer(key)) => *key,
+                    Some(value) => return Err(Error::unexpected("INTEGER", value)),
+                    None => return Err(Error::MissingComponent(relation.clone())),
+                };
+                if key != variant.key {
+                    return Err(ErrorKind::InvalidOpenTypeKey(key).into());
+                }
+
+                // ITU-T X.691 | ISO/IEC 8825-2, 11.2, the complete encoding as octet string
+                let mut buffer = BitBuffer::default();
+                self.write_type(&variant.r#type, value, &mut buffer)?;
+                if buffer.bit_len() == 0 {
+                    Ok(bits.write_octetstring(None, None, false, &[0x00])?)
+                } else {
+                    Ok(bits.write_octetstring(None, None, false, buffer.content())?)
+                }
+            }
+            Some((_, inner)) => self.write_type(inner, value, bits),
+            None => self.write_type(&fields[index].role.r#type, value, bits),
+        }
+    }
+}
+
+/// The indices of the root components
+fn root_range(components: &ComponentTypeList<Resolved>) -> Range<usize> {
+    let len = components
+        .extension_after
+        .map_or(components.fields.len(), |after| after + 1)
+        .min(components.fields.len());
+    0..len
+}
+
+/// The components of each extension addition, which is either a single component or an
+/// extension addition group
+fn addition_units(components: &ComponentTypeList<Resolved>, root_end: usize) -> Vec<Range<usize>> {
+    let mut units = Vec::new();
+    let mut index = root_end;
+    while index < components.fields.len() {
+        let unit = components
+            .extension_groups
+            .iter()
+            .find(|group| group.start == index)
+            .map_or(index..index + 1, |group| group.range());
+        index = unit.end;
+        units.push(unit);
+    }
+    units
+}
+
+fn is_group(components: &ComponentTypeList<Resolved>, unit: &Range<usize>) -> bool {
+    components
+        .extension_groups
+        .iter()
+        .any(|group| group.range() == *unit)
+}
+
+/// The name of the component that selects the variant and the open type, if the type of the
+/// component depends on another component
+fn related(r#type: &Type) -> Option<(&String, &Type)> {
+    match r#type.no_optional() {
+        Type::Related(relation, inner) => Some((relation, inner.no_optional())),
+        _ => None,
+    }
+}
+
+fn fields_index(fields: &[Field<Asn>], name: &str) -> Result<usize, Error> {
+    fields
+        .iter()
+        .position(|field| field.name == name || field.identifier() == name)
+        .ok_or_else(|| Error::UnknownComponent(name.to_string()))
+}
+
+fn related_key(fields: &[Field<Asn>], values: &[Option<Value>], name: &str) -> Result<i64, Error> {
+    match &values[fields_index(fields, name)?] {
+        Some(Value::Integer(key)) => Ok(*key),
+        Some(value) => Err(Error::unexpected("INTEGER", value)),
+        None => Err(Error::MissingComponent(name.to_string())),
+    }
+}
+
+fn unrelated_open_type_choice() -> ErrorKind {
+    ErrorKind::UnsupportedOperation(
+        "An open type with a table constraint is only supported as component".to_string(),
+    )
+}
+
+fn std_variants(extension_after: Option<usize>, len: usize) -> u64 {
+    extension_after.map_or(len, |after| after + 1) as u64
+}
+
+fn write_enumerated(
+    enumerated: &Enumerated,
+    identifier: &str,
+    bits: &mut impl BitWrite,
+) -> Result<(), Error> {
+    let index = enumerated
+        .variants()
+        .position(|variant| variant.identifier() == identifier)
+        .ok_or_else(|| Error::UnknownVariant(identifier.to_string()))?;
+    Ok(bits.write_enumeration_index(
+        std_variants(enumerated.extension_after_index(), enumerated.len()),
+        enumerated.is_extensible(),
+        index as u64,
+    )?)
+}
+
+fn expected(r#type: &Type) -> &'static str {
+    match r#type {
+        Type::Boolean => "BOOLEAN",
+        Type::Integer(_) => "INTEGER",
+        Type::Real(_) => "REAL",
+        Type::String(..) | Type::Time(_) => "string",
+        Type::OctetString(_) => "OCTET STRING",
+        Type::BitString(_) => "BIT STRING",
+        Type::Null => "NULL",
+        Type::ObjectIdentifier => "OBJECT IDENTIFIER",
+        Type::RelativeOid => "RELATIVE-OID",
+        Type::SequenceOf(..) | Type::SetOf(..) => "SEQUENCE OF",
+        Type::Enumerated(_) => "ENUMERATED",
+        Type::OpenType => "open type",
+        _ => "other",
+    }
+}
+
+fn min(size: &Size) -> Option<u64> {
+    size.min().map(|min| *min as u64)
+}
+
+fn max(size: &Size) -> Option<u64> {
+    size.max().map(|max| *max as u64)
+}
+
+/// The bounds of the integer as they are encoded, which are those of the Rust type that is
+/// generated for it
+fn integer_bounds(integer: &Integer) -> (Option<i64>, Option<i64>) {
+    match (*integer.range.min(), *integer.range.max()) {
+        (None, None) | (Some(0), None) | (Some(0), Some(i64::MAX)) | (None, Some(i64::MAX)) => {
+            (None, None)
+        }
+        (min, max) if integer.range.extensible() => {
+            if min.unwrap_or_default() >= 0 && max.unwrap_or_default() >= 0 {
+                (min, max)
+            } else {
+                (Some(min.unwrap_or(i64::MIN)), Some(max.unwrap_or(i64::MAX)))
+            }
+        }
+        (min, max) => (Some(min.unwrap_or_default()), Some(max.unwrap_or(i64::MAX))),
+    }
+}
+
+fn read_integer(integer: &Integer, bits: &mut impl BitRead) -> Result<i64, PerError> {
+    let (min, max) = integer_bounds(integer);
+    let unconstrained = if integer.range.extensible() {
+        bits.read_bit()?
+    } else {
+        min.is_none() && max.is_none()
+    };
+
+    if unconstrained {
+        bits.read_unconstrained_whole_number()
+    } else {
+        bits.read_constrained_whole_number(min.unwrap_or(0), max.unwrap_or(i64::MAX))
+    }
+}
+
+fn write_integer(integer: &Integer, value: i64, bits: &mut impl BitWrite) -> Result<(), PerError> {
+    let (min, max) = integer_bounds(integer);
+    let unconstrained = if integer.range.extensible() {
+        let out_of_range = value < min.unwrap_or(0) || value > max.unwrap_or(i64::MAX);
+        bits.write_bit(out_of_range)?;
+        out_of_range
+    } else {
+        min.is_none() && max.is_none()
+    };
+
+    if unconstrained {
+        bits.write_unconstrained_whole_number(value)
+    } else {
+        bits.write_constrained_whole_number(min.unwrap_or(0), max.unwrap_or(i64::MAX), value)
+    }
+}
+
+fn read_length(size: &Size, bits: &mut impl BitRead) -> Result<u64, PerError> {
+    if size.extensible() && bits.read_bit()? {
+        bits.read_length_determinant(None, None)
+    } else {
+        bits.read_length_determinant(min(size), max(size))
+    }
+}
+
+/// Writes the length like [`crate::syn::io::UperWriter::write_extensible_bit_and_length_or_err`]
+fn write_length(
+    extensible: bool,
+    min: Option<u64>,
+    max: Option<u64>,
+    upper_limit: u64,
+    len: u64,
+    bits: &mut impl BitWrite,
+) -> Result<(), PerError> {
+    let unwrapped_min = min.unwrap_or(0);
+    let unwrapped_max = max.unwrap_or(upper_limit);
+    let out_of_range = len < unwrapped_min || len > unwrapped_max;
+
+    if extensible {
+        bits.write_bit(out_of_range)?;
+    }
+
+    if out_of_range {
+        if !extensible {
+            return Err(ErrorKind::SizeNotInRange(len, unwrapped_min, unwrapped_max).into());
+        }
+        bits.write_length_determinant(None, None, len)?;
+    } else {
+        bits.write_length_determinant(min, max, len)?;
+    }
+    Ok(())
+}
+
+fn ensure_size(size: &Size, value: &str) -> Result<(), PerError> {
+    if !size.extensible() {
+        let chars = value.chars().count() as u64;
+        let min = min(size).unwrap_or(0);
+        let max = max(size).unwrap_or(u64::MAX);
+        if chars < min || chars > max {
+            return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+        }
+    }
+    Ok(())
+}
+
+/// The alphabet of a string whose characters only take as many bits as needed for it, which
+/// only the known-multiplier character strings other than `UTF8String` support
+fn effective_alphabet(
+    charset: Charset,
+    alphabet: &PermittedAlphabet,
+) -> Option<EffectiveAlphabet<'_>> {
+    match charset {
+        Charset::Ia5
+        | Charset::Numeric
+        | Charset::Printable
+        | Charset::Visible
+        | Charset::Bmp
+        | Charset::Universal => EffectiveAlphabet::new(alphabet.ranges(), false),
+        Charset::Utf8 | Charset::Teletex | Charset::Graphic | Charset::General => None,
+    }
+}
+
+fn read_string(
+    size: &Size,
+    charset: Charset,
+    alphabet: &PermittedAlphabet,
+    bits: &mut impl BitRead,
+) -> Result<String, PerError> {
+    match charset {
+        Charset::Utf8 => String::from_utf8(bits.read_octetstring(None, None, false)?)
+            .map_err(|e| ErrorKind::FromUtf8Error(e).into()),
+        Charset::Teletex | Charset::Graphic | Charset::General => {
+            let octets = bits.read_octetstring(None, None, false)?;
+            let string = PerError::decode_string(charset, octets)?;
+            PerError::ensure_string_valid(charset, &string)?;
+            Ok(string)
+        }
+        Charset::Ia5
+        | Charset::Numeric
+        | Charset::Printable
+        | Charset::Visible
+        | Charset::Bmp
+        | Charset::Universal => {
+            let len = read_length(size, bits)?;
+            if let Some(alphabet) = effective_alphabet(charset, alphabet) {
+                let offset = core::mem::size_of::<u32>() * BYTE_LEN - alphabet.bits_per_char();
+                return (0..len)
+                    .map(|_| {
+                        let mut value = [0_u8; 4];
+                        bits.read_bits_with_offset(&mut value, offset)?;
+                        alphabet
+                            .decode(u32::from_be_bytes(value))
+                            .ok_or_else(|| ErrorKind::InvalidStringEncoding(charset).into())
+                    })
+                    .collect();
+            }
+            match charset {
+                Charset::Numeric => {
+                    let mut buffer = vec![0_u8; len as usize];
+                    for char in buffer.chunks_exact_mut(1) {
+                        bits.read_bits_with_offset(char, 4)?;
+                        char[0] = match char[0] {
+                            0 => 32,
+                            c => 32 + 15 + c,
+                        };
+                    }
+                    String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+                }
+                Charset::Bmp | Charset::Universal => {
+                    let octets_per_char = charset.octets_per_char().unwrap_or(1);
+                    let mut buffer = vec![0_u8; len as usize * octets_per_char];
+                    bits.read_bits(&mut buffer)?;
+                    PerError::decode_string(charset, buffer)
+                }
+                _ => read_7bit_chars(len, bits),
+            }
+        }
+    }
+}
+
+fn write_string(
+    size: &Size,
+    charset: Charset,
+    alphabet: &PermittedAlphabet,
+    value: &str,
+    bits: &mut impl BitWrite,
+) -> Result<(), PerError> {
+    match charset {
+        Charset::Utf8 => {
+            ensure_size(size, value)?;
+            bits.write_octetstring(None, None, false, value.as_bytes())
+        }
+        Charset::Teletex | Charset::Graphic | Charset::General => {
+            PerError::ensure_string_valid(charset, value)?;
+            ensure_size(size, value)?;
+            bits.write_octetstring(None, None, false, &charset.encode_octets(value))
+        }
+        Charset::Ia5
+        | Charset::Numeric
+        | Charset::Printable
+        | Charset::Visible
+        | Charset::Bmp
+        | Charset::Universal => {
+            PerError::ensure_string_valid(charset, value)?;
+            write_length(
+                size.extensible(),
+                min(size),
+                max(size),
+                u64::MAX,
+                value.chars().count() as u64,
+                bits,
+            )?;
+
+            if let Some(alphabet) = effective_alphabet(charset, alphabet) {
+                let offset = core::mem::size_of::<u32>() * BYTE_LEN - alphabet.bits_per_char();
+                for (index, char) in value.chars().enumerate() {
+                    let value = alphabet
+                        .encode(char)
+                        .ok_or(ErrorKind::InvalidString(charset, char, index))?;
+                    bits.write_bits_with_offset(&value.to_be_bytes(), offset)?;
+                }
+                return Ok(());
+            }
+            match charset {
+                Charset::Numeric => {
+                    for char in value.chars().map(|c| c as u8) {
+                        let char = match char - 32 {
+                            0 => 0,
+                            c => c - 15,
+                        };
+                        bits.write_bits_with_offset(&[char], 4)?;
+                    }
+                    Ok(())
+                }
+                Charset::Bmp | Charset::Universal => bits.write_bits(&charset.encode_octets(value)),
+                _ => write_7bit_chars(value, bits),
+            }
+        }
+    }
+}
+
+fn read_7bit_chars(len: u64, bits: &mut impl BitRead) -> Result<String, PerError> {
+    let mut buffer = vec![0_u8; len as usize];
+    buffer
+        .chunks_exact_mut(1)
+        .try_for_each(|chunk| bits.read_bits_with_offset(chunk, 1))?;
+    String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+}
+
+fn write_7bit_chars(value: &str, bits: &mut impl BitWrite) -> Result<(), PerError> {
+    for char in value.chars() {
+        bits.write_bits_with_offset(&[char as u8], 1)?;
+    }
+    Ok(())
+}
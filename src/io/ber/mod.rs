@@ -0,0 +1,620 @@
+//! This module contains the building blocks of the Basic Encoding Rules (ITU-T X.690 | ISO/IEC
+//! 8825-1): the identifier, length and contents octets of a TLV triplet. The generic
+//! [`crate::syn::io::BerWriter`] and [`crate::syn::io::BerReader`] are built on top of them.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use asn1rs_model::model::Tag;
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.2.5, bit 6 of the identifier octets
+pub const CONSTRUCTED: u8 = 0x20;
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.3.6.1, the single length octet of the indefinite form
+pub const INDEFINITE_LENGTH: u8 = 0x80;
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.5, terminates the contents of the indefinite length form
+pub const END_OF_CONTENTS: [u8; 2] = [0x00, 0x00];
+
+const CLASS_UNIVERSAL: u8 = 0x00;
+const CLASS_APPLICATION: u8 = 0x40;
+const CLASS_CONTEXT_SPECIFIC: u8 = 0x80;
+const CLASS_PRIVATE: u8 = 0xC0;
+const CLASS_MASK: u8 = 0xC0;
+const LOW_TAG_NUMBER_MASK: u8 = 0x1F;
+
+/// The identifier and length octets of an encoding
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub tag: Tag,
+    pub constructed: bool,
+    /// `None` for the indefinite length form
+    pub length: Option<usize>,
+    /// The amount of identifier and length octets
+    pub octets: usize,
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.2
+pub fn write_identifier(buffer: &mut Vec<u8>, tag: Tag, constructed: bool) {
+    let (class, number) = match tag {
+        Tag::Universal(number) => (CLASS_UNIVERSAL, number),
+        Tag::Application(number) => (CLASS_APPLICATION, number),
+        Tag::ContextSpecific(number) => (CLASS_CONTEXT_SPECIFIC, number),
+        Tag::Private(number) => (CLASS_PRIVATE, number),
+    };
+    let first = class | if constructed { CONSTRUCTED } else { 0 };
+    if number < usize::from(LOW_TAG_NUMBER_MASK) {
+        buffer.push(first | number as u8);
+    } else {
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.1.2.4, high tag number form
+        buffer.push(first | LOW_TAG_NUMBER_MASK);
+        write_base128(buffer, number);
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.2.4.2, as few base 128 digits as possible, where all but
+/// the last octet have bit 8 set
+fn write_base128(buffer: &mut Vec<u8>, value: usize) {
+    let digits = (usize::BITS - value.leading_zeros()).div_ceil(7).max(1);
+    for digit in (0..digits).rev() {
+        let more = if digit > 0 { 0x80 } else { 0x00 };
+        buffer.push(more | ((value >> (digit * 7)) & 0x7F) as u8);
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.2, returns the tag, whether the encoding is constructed
+/// and the amount of identifier octets
+pub fn read_identifier(bytes: &[u8]) -> Result<(Tag, bool, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+    let constructed = first & CONSTRUCTED != 0;
+    let (number, octets) = if first & LOW_TAG_NUMBER_MASK == LOW_TAG_NUMBER_MASK {
+        let mut number = 0_usize;
+        let mut octets = 1;
+        loop {
+            let byte = *bytes
+                .get(octets)
+                .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+            // ITU-T X.690 | ISO/IEC 8825-1, 8.1.2.4.2 c), the first subsequent octet must not be 0x80
+            if octets == 1 && byte == 0x80 {
+                return Err(ErrorKind::InvalidTagNumber.into());
+            }
+            if number.leading_zeros() < 7 {
+                return Err(ErrorKind::InvalidTagNumber.into());
+            }
+            number = (number << 7) | usize::from(byte & 0x7F);
+            octets += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        (number, octets)
+    } else {
+        (usize::from(first & LOW_TAG_NUMBER_MASK), 1)
+    };
+    let tag = match first & CLASS_MASK {
+        CLASS_UNIVERSAL => Tag::Universal(number),
+        CLASS_APPLICATION => Tag::Application(number),
+        CLASS_CONTEXT_SPECIFIC => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, constructed, octets))
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.3.4 and 8.1.3.5, the definite form with the minimum
+/// number of length octets
+pub fn write_length(buffer: &mut Vec<u8>, length: usize) {
+    if length < 0x80 {
+        buffer.push(length as u8);
+    } else {
+        let bytes = length.to_be_bytes();
+        let skip = (length.leading_zeros() / 8) as usize;
+        buffer.push(0x80 | (bytes.len() - skip) as u8);
+        buffer.extend_from_slice(&bytes[skip..]);
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.1.3, returns the length (`None` for the indefinite form) and
+/// the amount of length octets
+pub fn read_length(bytes: &[u8]) -> Result<(Option<usize>, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+    match first {
+        INDEFINITE_LENGTH => Ok((None, 1)),
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.1.3.5 c), reserved for future extensions
+        0xFF => Err(ErrorKind::InvalidLength.into()),
+        short if short < 0x80 => Ok((Some(usize::from(short)), 1)),
+        long => {
+            let octets = usize::from(long & 0x7F);
+            let bytes = bytes
+                .get(1..=octets)
+                .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+            let mut length = 0_usize;
+            for byte in bytes {
+                if length.leading_zeros() < 8 {
+                    return Err(ErrorKind::InvalidLength.into());
+                }
+                length = (length << 8) | usize::from(*byte);
+            }
+            Ok((Some(length), 1 + octets))
+        }
+    }
+}
+
+/// Reads the identifier and length octets at the beginning of the given bytes
+pub fn read_header(bytes: &[u8]) -> Result<Header, Error> {
+    let (tag, constructed, identifier_octets) = read_identifier(bytes)?;
+    let (length, length_octets) = read_length(&bytes[identifier_octets..])?;
+    if !constructed && length.is_none() {
+        return Err(ErrorKind::IndefiniteLengthOnPrimitive(tag).into());
+    }
+    Ok(Header {
+        tag,
+        constructed,
+        length,
+        octets: identifier_octets + length_octets,
+    })
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.5.9, the contents octets of the special REAL values
+const REAL_PLUS_INFINITY: u8 = 0x40;
+const REAL_MINUS_INFINITY: u8 = 0x41;
+const REAL_NOT_A_NUMBER: u8 = 0x42;
+const REAL_MINUS_ZERO: u8 = 0x43;
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.5.7, bit 8 of the first contents octet selects the binary
+/// encoding
+const REAL_BINARY: u8 = 0x80;
+const REAL_NEGATIVE: u8 = 0x40;
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.3, the contents octets of an INTEGER as two's complement
+/// with the minimum amount of octets
+pub fn write_integer(buffer: &mut Vec<u8>, value: i64) {
+    let bytes = value.to_be_bytes();
+    let mut skip = 0;
+    while skip < bytes.len() - 1
+        && ((bytes[skip] == 0x00 && bytes[skip + 1] & 0x80 == 0)
+            || (bytes[skip] == 0xFF && bytes[skip + 1] & 0x80 != 0))
+    {
+        skip += 1;
+    }
+    buffer.extend_from_slice(&bytes[skip..]);
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.3, the contents octets of an INTEGER (or ENUMERATED) with the
+/// given tag
+pub fn read_integer(tag: Tag, content: &[u8]) -> Result<i64, Error> {
+    if content.is_empty() {
+        return Err(ErrorKind::InvalidContentLength(tag, 0).into());
+    }
+    let negative = content[0] & 0x80 != 0;
+    let sign = if negative { 0xFF } else { 0x00 };
+    let redundant = content
+        .iter()
+        .take(content.len() - 1)
+        .take_while(|byte| **byte == sign)
+        .count();
+    let content = &content[redundant..];
+    if content.len() > core::mem::size_of::<i64>()
+        || (content.len() == core::mem::size_of::<i64>() && (content[0] & 0x80 != 0) != negative)
+    {
+        return Err(ErrorKind::ValueExceedsMaxInt.into());
+    }
+    Ok(content
+        .iter()
+        .fold(if negative { -1_i64 } else { 0 }, |value, byte| {
+            (value << 8) | i64::from(*byte)
+        }))
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.5, the contents octets of a REAL in the canonical form of
+/// 11.3.1: base 2, no scaling factor and an odd mantissa. This is the form PER and OER refer to
+/// as well.
+pub fn write_real(buffer: &mut Vec<u8>, value: f64) {
+    if value == 0.0 {
+        if value.is_sign_negative() {
+            buffer.push(REAL_MINUS_ZERO);
+        }
+        return;
+    } else if value.is_nan() {
+        buffer.push(REAL_NOT_A_NUMBER);
+        return;
+    } else if value.is_infinite() {
+        buffer.push(if value.is_sign_positive() {
+            REAL_PLUS_INFINITY
+        } else {
+            REAL_MINUS_INFINITY
+        });
+        return;
+    }
+
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    let shift = mantissa.trailing_zeros();
+    let (mantissa, exponent) = (mantissa >> shift, exponent + i64::from(shift));
+
+    let mut exponent_octets = Vec::with_capacity(2);
+    write_integer(&mut exponent_octets, exponent);
+    let sign = if value.is_sign_negative() {
+        REAL_NEGATIVE
+    } else {
+        0x00
+    };
+    // bits 2 to 1 are the amount of exponent octets minus one, base and scaling factor are zero
+    buffer.push(REAL_BINARY | sign | (exponent_octets.len() as u8 - 1));
+    buffer.extend_from_slice(&exponent_octets);
+    let mantissa = mantissa.to_be_bytes();
+    let skip = mantissa.iter().take_while(|byte| **byte == 0x00).count();
+    buffer.extend_from_slice(&mantissa[skip..]);
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.5, the contents octets of a REAL in the binary, decimal or
+/// special form
+pub fn read_real(content: &[u8]) -> Result<f64, Error> {
+    let first = match content.first() {
+        None => return Ok(0.0),
+        Some(first) => *first,
+    };
+    let invalid = || Error::from(ErrorKind::InvalidReal(first));
+    if first & REAL_BINARY != 0 {
+        let base_bits = match (first >> 4) & 0x03 {
+            0 => 1,
+            1 => 3,
+            2 => 4,
+            _ => return Err(invalid()),
+        };
+        let scaling = i64::from((first >> 2) & 0x03);
+        let (exponent_octets, offset) = match first & 0x03 {
+            3 => (usize::from(*content.get(1).ok_or_else(invalid)?), 2),
+            n => (usize::from(n) + 1, 1),
+        };
+        let exponent = content
+            .get(offset..offset + exponent_octets)
+            .filter(|octets| !octets.is_empty())
+            .ok_or_else(invalid)?;
+        let exponent = read_integer(Tag::DEFAULT_REAL, exponent)?;
+        let mantissa = &content[offset + exponent_octets..];
+        let skip = mantissa.iter().take_while(|byte| **byte == 0x00).count();
+        let mantissa = &mantissa[skip..];
+        if mantissa.len() > core::mem::size_of::<u64>() {
+            return Err(ErrorKind::ValueExceedsMaxInt.into());
+        }
+        let mantissa = mantissa
+            .iter()
+            .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte));
+        let exponent = exponent
+            .saturating_mul(base_bits)
+            .saturating_add(scaling)
+            .clamp(-4096, 4096) as i32;
+        // split the scaling into two steps, so that a subnormal result is not lost to an
+        // intermediate underflow
+        let value =
+            mantissa as f64 * 2_f64.powi(exponent / 2) * 2_f64.powi(exponent - exponent / 2);
+        Ok(if first & REAL_NEGATIVE != 0 {
+            -value
+        } else {
+            value
+        })
+    } else if first & REAL_NEGATIVE != 0 {
+        match first {
+            REAL_PLUS_INFINITY => Ok(f64::INFINITY),
+            REAL_MINUS_INFINITY => Ok(f64::NEG_INFINITY),
+            REAL_NOT_A_NUMBER => Ok(f64::NAN),
+            REAL_MINUS_ZERO => Ok(-0.0),
+            _ => Err(invalid()),
+        }
+    } else {
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.5.8, the decimal forms NR1, NR2 and NR3 of ISO 6093
+        core::str::from_utf8(&content[1..])
+            .ok()
+            .filter(|_| (1..=3).contains(&first))
+            .map(|text| text.trim().replace(',', "."))
+            .and_then(|text| text.parse::<f64>().ok())
+            .ok_or_else(invalid)
+    }
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.19, the contents octets of an OBJECT IDENTIFIER, where the
+/// first two arcs are combined into the first subidentifier
+pub fn write_object_identifier(buffer: &mut Vec<u8>, arcs: &[u64]) -> Result<(), Error> {
+    let first = match arcs {
+        [first @ 0..=1, second, ..] if *second < 40 => first * 40 + second,
+        [2, second, ..] => second
+            .checked_add(80)
+            .ok_or(ErrorKind::InvalidObjectIdentifier)?,
+        _ => return Err(ErrorKind::InvalidObjectIdentifier.into()),
+    };
+    write_subidentifier(buffer, first);
+    arcs[2..]
+        .iter()
+        .for_each(|arc| write_subidentifier(buffer, *arc));
+    Ok(())
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.20, the contents octets of a RELATIVE-OID, one subidentifier
+/// per arc
+pub fn write_relative_oid(buffer: &mut Vec<u8>, arcs: &[u64]) -> Result<(), Error> {
+    if arcs.is_empty() {
+        return Err(ErrorKind::InvalidObjectIdentifier.into());
+    }
+    arcs.iter()
+        .for_each(|arc| write_subidentifier(buffer, *arc));
+    Ok(())
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.19.2, base 128 with bit 8 set on all but the last octet
+fn write_subidentifier(buffer: &mut Vec<u8>, value: u64) {
+    let mut octets = [0_u8; 10];
+    let mut index = octets.len() - 1;
+    octets[index] = (value & 0x7F) as u8;
+    let mut value = value >> 7;
+    while value > 0 {
+        index -= 1;
+        octets[index] = 0x80 | (value & 0x7F) as u8;
+        value >>= 7;
+    }
+    buffer.extend_from_slice(&octets[index..]);
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.19, the arcs of an OBJECT IDENTIFIER
+pub fn read_object_identifier(content: &[u8]) -> Result<Vec<u64>, Error> {
+    let mut arcs = read_relative_oid(content)?;
+    let (first, second) = match arcs[0] {
+        value @ 0..=39 => (0, value),
+        value @ 40..=79 => (1, value - 40),
+        value => (2, value - 80),
+    };
+    arcs[0] = second;
+    arcs.insert(0, first);
+    Ok(arcs)
+}
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.20, the arcs of a RELATIVE-OID
+pub fn read_relative_oid(content: &[u8]) -> Result<Vec<u64>, Error> {
+    if content.is_empty() {
+        return Err(ErrorKind::InvalidObjectIdentifier.into());
+    }
+    let mut arcs = Vec::new();
+    let mut value = 0_u64;
+    let mut leading = true;
+    for octet in content {
+        // 8.19.2, a subidentifier is encoded in the fewest possible octets
+        if (leading && *octet == 0x80) || value > u64::MAX >> 7 {
+            return Err(ErrorKind::InvalidObjectIdentifier.into());
+        }
+        value = (value << 7) | u64::from(octet & 0x7F);
+        leading = octet & 0x80 == 0;
+        if leading {
+            arcs.push(value);
+            value = 0;
+        }
+    }
+    if leading {
+        Ok(arcs)
+    } else {
+        Err(ErrorKind::InvalidObjectIdentifier.into())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn identifier(tag: Tag, constructed: bool) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_identifier(&mut buffer, tag, constructed);
+        buffer
+    }
+
+    fn length(length: usize) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_length(&mut buffer, length);
+        buffer
+    }
+
+    fn integer(value: i64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_integer(&mut buffer, value);
+        buffer
+    }
+
+    #[test]
+    fn test_identifier_low_tag_number() {
+        assert_eq!(vec![0x02], identifier(Tag::DEFAULT_INTEGER, false));
+        assert_eq!(vec![0x30], identifier(Tag::DEFAULT_SEQUENCE, true));
+        assert_eq!(vec![0x31], identifier(Tag::DEFAULT_SET, true));
+        assert_eq!(vec![0x65], identifier(Tag::Application(5), true));
+        assert_eq!(vec![0x80], identifier(Tag::ContextSpecific(0), false));
+        assert_eq!(vec![0xDE], identifier(Tag::Private(30), false));
+        assert_eq!(
+            (Tag::Application(5), true, 1),
+            read_identifier(&[0x65]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_identifier_high_tag_number() {
+        assert_eq!(
+            vec![0x9F, 0x1F],
+            identifier(Tag::ContextSpecific(31), false)
+        );
+        assert_eq!(
+            vec![0xBF, 0x81, 0x00],
+            identifier(Tag::ContextSpecific(128), true)
+        );
+        assert_eq!(
+            (Tag::ContextSpecific(128), true, 3),
+            read_identifier(&[0xBF, 0x81, 0x00]).unwrap()
+        );
+        assert_eq!(
+            ErrorKind::InvalidTagNumber,
+            read_identifier(&[0x9F, 0x80, 0x01])
+                .unwrap_err()
+                .kind()
+                .clone()
+        );
+    }
+
+    #[test]
+    fn test_length_forms() {
+        assert_eq!(vec![0x7F], length(127));
+        assert_eq!(vec![0x81, 0x80], length(128));
+        assert_eq!(vec![0x82, 0x01, 0x00], length(256));
+        assert_eq!((Some(256), 3), read_length(&[0x82, 0x01, 0x00]).unwrap());
+        assert_eq!((None, 1), read_length(&[0x80]).unwrap());
+        assert_eq!(
+            ErrorKind::InvalidLength,
+            read_length(&[0xFF]).unwrap_err().kind().clone()
+        );
+    }
+
+    #[test]
+    fn test_primitive_indefinite_length_is_rejected() {
+        assert_eq!(
+            ErrorKind::IndefiniteLengthOnPrimitive(Tag::DEFAULT_OCTET_STRING),
+            read_header(&[0x04, 0x80]).unwrap_err().kind().clone()
+        );
+    }
+
+    #[test]
+    fn test_integer_minimal_octets() {
+        for (value, bytes) in [
+            (0_i64, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x00, 0x80][..]),
+            (256, &[0x01, 0x00][..]),
+            (-1, &[0xFF][..]),
+            (-128, &[0x80][..]),
+            (-129, &[0xFF, 0x7F][..]),
+            (
+                i64::MAX,
+                &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF][..],
+            ),
+            (
+                i64::MIN,
+                &[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..],
+            ),
+        ] {
+            assert_eq!(bytes, &integer(value)[..], "encoding {}", value);
+            assert_eq!(
+                value,
+                read_integer(Tag::DEFAULT_INTEGER, bytes).unwrap(),
+                "decoding {:02x?}",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn test_integer_exceeding_i64() {
+        assert_eq!(
+            ErrorKind::ValueExceedsMaxInt,
+            read_integer(
+                Tag::DEFAULT_INTEGER,
+                &[0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+            )
+            .unwrap_err()
+            .kind()
+            .clone()
+        );
+    }
+
+    #[test]
+    fn test_real_canonical_form() {
+        for (value, bytes) in [
+            (0.0, &[][..]),
+            (-0.0, &[0x43][..]),
+            (1.0, &[0x80, 0x00, 0x01][..]),
+            (0.5, &[0x80, 0xFF, 0x01][..]),
+            (-3.0, &[0xC0, 0x00, 0x03][..]),
+            (1024.0, &[0x80, 0x0A, 0x01][..]),
+            (f64::INFINITY, &[0x40][..]),
+            (f64::NEG_INFINITY, &[0x41][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_real(&mut buffer, value);
+            assert_eq!(bytes, &buffer[..], "{}", value);
+            let read = read_real(bytes).unwrap();
+            assert_eq!(value, read);
+            assert_eq!(value.is_sign_negative(), read.is_sign_negative());
+        }
+        for value in [
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -123.456e78,
+            core::f64::consts::PI,
+        ] {
+            let mut buffer = Vec::new();
+            write_real(&mut buffer, value);
+            assert_eq!(value, read_real(&buffer).unwrap());
+        }
+        assert!(read_real(&[0x42]).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_real_other_forms() {
+        // base 16, scaling factor 1: 3 * 2 * 16^1
+        assert_eq!(96.0, read_real(&[0xA4, 0x01, 0x03]).unwrap());
+        // NR2
+        assert_eq!(1.5, read_real(&[0x02, b' ', b'1', b',', b'5']).unwrap());
+        assert_eq!(
+            &ErrorKind::InvalidReal(0xB0),
+            read_real(&[0xB0, 0x00, 0x01]).unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_object_identifier_subidentifiers() {
+        let mut buffer = Vec::new();
+        write_object_identifier(&mut buffer, &[1, 2, 840, 113549]).unwrap();
+        assert_eq!(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D][..], &buffer[..]);
+        assert_eq!(
+            vec![1, 2, 840, 113549],
+            read_object_identifier(&buffer).unwrap()
+        );
+
+        // 8.19.4, the third root arc allows a second arc beyond 39
+        let mut buffer = Vec::new();
+        write_object_identifier(&mut buffer, &[2, 999, 3]).unwrap();
+        assert_eq!(&[0x88, 0x37, 0x03][..], &buffer[..]);
+        assert_eq!(vec![2, 999, 3], read_object_identifier(&buffer).unwrap());
+
+        let mut buffer = Vec::new();
+        write_relative_oid(&mut buffer, &[8571, 3, 2]).unwrap();
+        assert_eq!(&[0xC2, 0x7B, 0x03, 0x02][..], &buffer[..]);
+        assert_eq!(vec![8571, 3, 2], read_relative_oid(&buffer).unwrap());
+    }
+
+    #[test]
+    fn test_object_identifier_invalid() {
+        for arcs in [&[1][..], &[0, 40][..], &[3, 1][..]] {
+            assert_eq!(
+                &ErrorKind::InvalidObjectIdentifier,
+                write_object_identifier(&mut Vec::new(), arcs)
+                    .unwrap_err()
+                    .kind()
+            );
+        }
+        for content in [&[][..], &[0x80, 0x01][..], &[0x2A, 0x86][..]] {
+            assert_eq!(
+                &ErrorKind::InvalidObjectIdentifier,
+                read_object_identifier(content).unwrap_err().kind()
+            );
+        }
+        assert_eq!(
+            &ErrorKind::InvalidObjectIdentifier,
+            read_relative_oid(&[0xFF; 10]).unwrap_err().kind()
+        );
+    }
+}
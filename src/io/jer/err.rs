@@ -0,0 +1,142 @@
+use asn1rs_model::model::{Charset, TimeType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding JER failed"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    InvalidString(Charset, char, usize),
+    InsufficientDataInSourceBuffer,
+    InvalidJson(usize),
+    UnexpectedType {
+        expected: &'static str,
+        found: &'static str,
+    },
+    InvalidHexString,
+    InvalidBitStringLength(u64),
+    InvalidChoiceIndex(u64, u64),
+    InvalidOpenTypeKey(i64),
+    UnknownIdentifier(String),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    InvalidObjectIdentifier(String),
+    InvalidTime(TimeType, String),
+    MissingMember(&'static str),
+    UnexpectedMembers(&'static str),
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InsufficientDataInSourceBuffer => write!(
+                f,
+                "There is insufficient data in the source buffer for this operation"
+            ),
+            Self::InvalidJson(position) => {
+                write!(f, "The source is not valid JSON at position {}", position)
+            }
+            Self::UnexpectedType { expected, found } => {
+                write!(f, "Expected a JSON {} but found a {}", expected, found)
+            }
+            Self::InvalidHexString => {
+                write!(f, "Expected a string of an even amount of hex digits")
+            }
+            Self::InvalidBitStringLength(length) => write!(
+                f,
+                "The length {} does not match the content of the BIT STRING",
+                length
+            ),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidOpenTypeKey(key) => {
+                write!(
+                    f,
+                    "There is no alternative of the open type for the key {}",
+                    key
+                )
+            }
+            Self::UnknownIdentifier(identifier) => {
+                write!(
+                    f,
+                    "There is no alternative with the identifier {}",
+                    identifier
+                )
+            }
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::InvalidObjectIdentifier(text) => {
+                write!(
+                    f,
+                    "Expected a dotted object identifier but found {:?}",
+                    text
+                )
+            }
+            Self::InvalidTime(r#type, text) => write!(
+                f,
+                "Expected a {} value but found {:?}",
+                r#type.asn_name(),
+                text
+            ),
+            Self::MissingMember(name) => {
+                write!(f, "The JSON object is missing the member {}", name)
+            }
+            Self::UnexpectedMembers(name) => write!(
+                f,
+                "{} contains members that are not part of its definition",
+                name
+            ),
+        }
+    }
+}
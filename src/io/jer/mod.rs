@@ -0,0 +1,430 @@
+//! This module contains the building blocks of the JSON Encoding Rules (ITU-T X.697 |
+//! ISO/IEC 8825-8): a minimal JSON [`Value`] with its serialization and parsing as well as the
+//! hex encoding of BIT STRINGs and OCTET STRINGs. The generic [`crate::syn::io::JerWriter`] and
+//! [`crate::syn::io::JerReader`] are built on top of them.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// The number as it appears in the JSON text
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    /// The members in the order of their appearance
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+}
+
+/// Serializes the value without any insignificant whitespace
+pub fn write_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(true) => out.push_str("true"),
+        Value::Bool(false) => out.push_str("false"),
+        Value::Number(number) => out.push_str(number),
+        Value::String(string) => write_string(out, string),
+        Value::Array(values) => {
+            out.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_value(out, value);
+            }
+            out.push(']');
+        }
+        Value::Object(members) => {
+            out.push('{');
+            for (index, (name, value)) in members.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_string(out, name);
+                out.push(':');
+                write_value(out, value);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// RFC 8259, 7, escapes quotation marks, reverse solidi and control characters
+fn write_string(out: &mut String, string: &str) {
+    out.push('"');
+    for char in string.chars() {
+        match char {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            char if char < ' ' => {
+                let _ = write!(out, "\\u{:04x}", char as u32);
+            }
+            char => out.push(char),
+        }
+    }
+    out.push('"');
+}
+
+/// ITU-T X.697 | ISO/IEC 8825-8, 22 and 23, the content of BIT STRINGs and OCTET STRINGs is
+/// written as hex digits
+pub fn write_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{:02X}", byte);
+    }
+    hex
+}
+
+/// Reads hex digits in upper or lower case
+pub fn read_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ErrorKind::InvalidHexString.into());
+    }
+    Ok(hex
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let digit = |b: u8| (b as char).to_digit(16).unwrap_or_default() as u8;
+            (digit(pair[0]) << 4) | digit(pair[1])
+        })
+        .collect())
+}
+
+/// Parses one JSON value after the other from the source
+pub struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> From<&'a str> for Parser<'a> {
+    fn from(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+}
+
+impl Parser<'_> {
+    /// The amount of bytes that have not been parsed yet, excluding trailing whitespace
+    pub fn bytes_remaining(&mut self) -> usize {
+        self.skip_whitespace();
+        self.source.len() - self.pos
+    }
+
+    pub fn parse_value(&mut self) -> Result<Value, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(ErrorKind::InsufficientDataInSourceBuffer.into()),
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => self.parse_string().map(Value::String),
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            Some(_) => self.parse_literal(),
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    #[cold]
+    fn invalid(&self) -> Error {
+        ErrorKind::InvalidJson(self.pos).into()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.invalid())
+        }
+    }
+
+    /// Consumes the byte after whitespace if it is the given one
+    fn consume(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn parse_object(&mut self) -> Result<Value, Error> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        if !self.consume(b'}') {
+            loop {
+                self.skip_whitespace();
+                let name = self.parse_string()?;
+                self.expect(b':')?;
+                members.push((name, self.parse_value()?));
+                if self.consume(b'}') {
+                    break;
+                }
+                self.expect(b',')?;
+            }
+        }
+        Ok(Value::Object(members))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, Error> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if !self.consume(b']') {
+            loop {
+                values.push(self.parse_value()?);
+                if self.consume(b']') {
+                    break;
+                }
+                self.expect(b',')?;
+            }
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn parse_string(&mut self) -> Result<String, Error> {
+        self.expect(b'"')?;
+        let mut string = String::new();
+        loop {
+            let rest = &self.source[self.pos..];
+            let end = rest
+                .find(|c: char| c == '"' || c == '\\' || c < ' ')
+                .ok_or_else(|| self.invalid())?;
+            string.push_str(&rest[..end]);
+            self.pos += end;
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(string);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    string.push(self.parse_escape()?);
+                }
+                _ => return Err(self.invalid()),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, Error> {
+        let escaped = self.peek().ok_or_else(|| self.invalid())?;
+        self.pos += 1;
+        Ok(match escaped {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{08}',
+            b'f' => '\u{0C}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let high = self.parse_code_unit()?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    // RFC 8259, 7, characters outside of the BMP are escaped as surrogate pairs
+                    if !self.source[self.pos..].starts_with("\\u") {
+                        return Err(self.invalid());
+                    }
+                    self.pos += 2;
+                    let low = self.parse_code_unit()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.invalid());
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                char::from_u32(code).ok_or_else(|| self.invalid())?
+            }
+            _ => return Err(self.invalid()),
+        })
+    }
+
+    fn parse_code_unit(&mut self) -> Result<u32, Error> {
+        let digits = self
+            .source
+            .get(self.pos..self.pos + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.invalid())?;
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).map_err(|_| self.invalid())
+    }
+
+    fn parse_number(&mut self) -> Result<Value, Error> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.invalid()),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            self.expect_digits()?;
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            self.expect_digits()?;
+        }
+        Ok(Value::Number(self.source[start..self.pos].to_string()))
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect_digits(&mut self) -> Result<(), Error> {
+        let start = self.pos;
+        self.skip_digits();
+        if self.pos > start {
+            Ok(())
+        } else {
+            Err(self.invalid())
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<Value, Error> {
+        for (literal, value) in [
+            ("null", Value::Null),
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+        ] {
+            if self.source[self.pos..].starts_with(literal) {
+                self.pos += literal.len();
+                return Ok(value);
+            }
+        }
+        Err(self.invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Value {
+        Parser::from(source).parse_value().unwrap()
+    }
+
+    fn write(value: &Value) -> String {
+        let mut out = String::new();
+        write_value(&mut out, value);
+        out
+    }
+
+    #[test]
+    fn test_parse_and_write_nested() {
+        let source = r#"{"a":[1,-2.5e3,null],"b":{"c":true,"d":false},"e":""}"#;
+        let value = parse(source);
+        assert_eq!(
+            Value::Object(vec![
+                (
+                    "a".to_string(),
+                    Value::Array(vec![
+                        Value::Number("1".to_string()),
+                        Value::Number("-2.5e3".to_string()),
+                        Value::Null
+                    ])
+                ),
+                (
+                    "b".to_string(),
+                    Value::Object(vec![
+                        ("c".to_string(), Value::Bool(true)),
+                        ("d".to_string(), Value::Bool(false)),
+                    ])
+                ),
+                ("e".to_string(), Value::String(String::new())),
+            ]),
+            value
+        );
+        assert_eq!(source, write(&value));
+        assert_eq!(
+            value,
+            parse(
+                " {\n\"a\" : [ 1 , -2.5e3 , null ] ,\t\"b\":{\"c\":true,\"d\":false},\"e\":\"\"} "
+            )
+        );
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let value = Value::String("\"\\/\n\u{1}\u{1F600}ä".to_string());
+        assert_eq!(r#""\"\\/\n\u0001😀ä""#, write(&value));
+        assert_eq!(value, parse(r#""\"\\\/\n\u0001\ud83d\ude00\u00e4""#));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        for (source, position) in [
+            ("{\"a\" 1}", 5),
+            ("[1,]", 3),
+            ("\"abc", 1),
+            ("01", 1),
+            ("nul", 0),
+            ("\"\\ud83d\"", 7),
+        ] {
+            let mut parser = Parser::from(source);
+            let value = parser.parse_value().and_then(|value| {
+                if parser.bytes_remaining() > 0 {
+                    Err(parser.invalid())
+                } else {
+                    Ok(value)
+                }
+            });
+            assert_eq!(
+                &ErrorKind::InvalidJson(position),
+                value.unwrap_err().kind(),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!("00A0FF", write_hex(&[0x00, 0xA0, 0xFF]));
+        assert_eq!(vec![0x00, 0xA0, 0xFF], read_hex("00a0Ff").unwrap());
+        assert!(read_hex("A").is_err());
+        assert!(read_hex("GG").is_err());
+        assert!(read_hex("+1").is_err());
+    }
+}
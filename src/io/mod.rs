@@ -1,9 +1,13 @@
 //! ```text
 //! crate::io                       Utils, common io-root
+//!      ::io::ber                  Basic Encoding Rules (BER) building blocks
+//!      ::io::jer                  JSON Encoding Rules (JER) building blocks
+//!      ::io::oer                  Octet Encoding Rules (OER) building blocks
 //!      ::io::per                  Generic Packed Encoding impls and traits
 //!      ::io::per::unaligned       UNALIGNED PER specialization
 //!      ::io::per::aligned         ALIGNED PER specialization
-//!      ::io::...                  Other ASN.1 representations (e.g xer, ber, ...)
+//!      ::io::xer                  XML Encoding Rules (XER) building blocks
+//!      ::io::...                  Other ASN.1 representations
 //!
 //!      ::io::async_psql           Async PSQL io-utils
 //!      ::io::protobuf             Protocol Buffer io-utils
@@ -12,8 +16,12 @@
 //!      ::io::uper                 Deprecated UNALIGNED PER decoder/encoder
 //! ```
 
+pub mod ber;
+pub mod jer;
+pub mod oer;
 pub mod per;
 pub mod protobuf;
+pub mod xer;
 
 #[cfg(feature = "psql")]
 pub mod psql;
@@ -0,0 +1,179 @@
+use asn1rs_model::model::{Charset, Tag, TimeType};
+use std::string::FromUtf8Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+
+    /// Decodes the octets of a string, which are UTF-8 for all but the charsets with a
+    /// dedicated representation in [`Charset::decode_octets`]
+    pub fn decode_string(charset: Charset, octets: Vec<u8>) -> Result<String, Self> {
+        match charset {
+            Charset::Utf8
+            | Charset::Numeric
+            | Charset::Printable
+            | Charset::Ia5
+            | Charset::Visible => {
+                String::from_utf8(octets).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+            }
+            _ => charset
+                .decode_octets(octets)
+                .ok_or_else(|| ErrorKind::InvalidStringEncoding(charset).into()),
+        }
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding OER failed"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    FromUtf8Error(FromUtf8Error),
+    InvalidString(Charset, char, usize),
+    InvalidStringEncoding(Charset),
+    InsufficientDataInSourceBuffer,
+    InvalidLength,
+    InvalidTagNumber,
+    UnexpectedTag { expected: Tag, found: Tag },
+    UnknownAlternative(&'static str, Tag),
+    InvalidContentLength(usize),
+    InvalidUnusedBits(u8),
+    InvalidChoiceIndex(u64, u64),
+    InvalidEnumeratedValue(i64),
+    InvalidOpenTypeKey(i64),
+    SizeNotInRange(u64, u64, u64),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    InvalidReal(u8),
+    InvalidObjectIdentifier,
+    InvalidTime(TimeType, String),
+    MissingExtensionAddition(&'static str),
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FromUtf8Error(err) => {
+                write!(f, "Failed to call String::from_utf8: ")?;
+                err.fmt(f)
+            }
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InvalidStringEncoding(charset) => write!(
+                f,
+                "The octets do not form a string with the charset {:?}",
+                charset
+            ),
+            Self::InsufficientDataInSourceBuffer => write!(
+                f,
+                "There is insufficient data in the source buffer for this operation"
+            ),
+            Self::InvalidLength => write!(f, "The length determinant is invalid or unsupported"),
+            Self::InvalidTagNumber => write!(f, "The tag of the CHOICE alternative is invalid"),
+            Self::UnexpectedTag { expected, found } => {
+                write!(f, "Expected the tag {:?} but found {:?}", expected, found)
+            }
+            Self::UnknownAlternative(name, tag) => {
+                write!(f, "{} has no alternative with the tag {:?}", name, tag)
+            }
+            Self::InvalidContentLength(length) => write!(
+                f,
+                "The content length {} is invalid for the encoded value",
+                length
+            ),
+            Self::InvalidUnusedBits(unused) => write!(
+                f,
+                "The number of unused bits {} is invalid for this BIT STRING",
+                unused
+            ),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidEnumeratedValue(value) => {
+                write!(
+                    f,
+                    "There is no variant of the ENUMERATED with the value {}",
+                    value
+                )
+            }
+            Self::InvalidOpenTypeKey(key) => {
+                write!(
+                    f,
+                    "There is no alternative of the open type for the key {}",
+                    key
+                )
+            }
+            Self::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::InvalidReal(first) => write!(
+                f,
+                "The REAL with the first contents octet {:#04x} is invalid or unsupported",
+                first
+            ),
+            Self::InvalidObjectIdentifier => write!(
+                f,
+                "The OBJECT IDENTIFIER or RELATIVE-OID is invalid or cannot be encoded"
+            ),
+            Self::InvalidTime(r#type, text) => write!(
+                f,
+                "Expected a {} value but found {:?}",
+                r#type.asn_name(),
+                text
+            ),
+            Self::MissingExtensionAddition(name) => write!(
+                f,
+                "{} is missing an extension addition that is not OPTIONAL",
+                name
+            ),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! This module contains the building blocks of the Octet Encoding Rules (ITU-T X.696 |
+//! ISO/IEC 8825-7): length determinants, the tags of CHOICE alternatives and the fixed or
+//! variable sized encoding of integers. The generic [`crate::syn::io::OerWriter`] and
+//! [`crate::syn::io::OerReader`] are built on top of them.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use asn1rs_model::model::Tag;
+
+/// ITU-T X.696 | ISO/IEC 8825-7, 8.6.5, bit 8 of the first octet selects the long form
+const LONG_FORM: u8 = 0x80;
+
+const CLASS_UNIVERSAL: u8 = 0x00;
+const CLASS_APPLICATION: u8 = 0x40;
+const CLASS_CONTEXT_SPECIFIC: u8 = 0x80;
+const CLASS_PRIVATE: u8 = 0xC0;
+const CLASS_MASK: u8 = 0xC0;
+const TAG_NUMBER_MASK: u8 = 0x3F;
+
+/// How an INTEGER is encoded according to its OER-visible constraints (ITU-T X.696 |
+/// ISO/IEC 8825-7, 10)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IntegerEncoding {
+    /// An unsigned value in a fixed amount of octets (10.3 a to d)
+    FixedUnsigned(usize),
+    /// A two's complement value in a fixed amount of octets (10.4 a to d)
+    FixedSigned(usize),
+    /// A length determinant followed by the minimal unsigned value (10.3 e)
+    VariableUnsigned,
+    /// A length determinant followed by the minimal two's complement value (10.4 e)
+    VariableSigned,
+}
+
+impl IntegerEncoding {
+    /// Extensible constraints are not OER-visible (ITU-T X.696 | ISO/IEC 8825-7, 10.1)
+    pub fn from_constraint(min: Option<i64>, max: Option<i64>, extensible: bool) -> Self {
+        match (min, max) {
+            _ if extensible => IntegerEncoding::VariableSigned,
+            (Some(min), Some(max)) if min >= 0 => match max {
+                max if max <= i64::from(u8::MAX) => IntegerEncoding::FixedUnsigned(1),
+                max if max <= i64::from(u16::MAX) => IntegerEncoding::FixedUnsigned(2),
+                max if max <= i64::from(u32::MAX) => IntegerEncoding::FixedUnsigned(4),
+                _ => IntegerEncoding::FixedUnsigned(8),
+            },
+            (Some(min), Some(max)) => {
+                if min >= i64::from(i8::MIN) && max <= i64::from(i8::MAX) {
+                    IntegerEncoding::FixedSigned(1)
+                } else if min >= i64::from(i16::MIN) && max <= i64::from(i16::MAX) {
+                    IntegerEncoding::FixedSigned(2)
+                } else if min >= i64::from(i32::MIN) && max <= i64::from(i32::MAX) {
+                    IntegerEncoding::FixedSigned(4)
+                } else {
+                    IntegerEncoding::FixedSigned(8)
+                }
+            }
+            (Some(min), None) if min >= 0 => IntegerEncoding::VariableUnsigned,
+            _ => IntegerEncoding::VariableSigned,
+        }
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7, 8.6, the short form for lengths up to 127 and the long form
+/// with as few length octets as possible otherwise
+pub fn write_length(buffer: &mut Vec<u8>, length: usize) {
+    if length < usize::from(LONG_FORM) {
+        buffer.push(length as u8);
+    } else {
+        let octets = unsigned_octets(length as u64);
+        buffer.push(LONG_FORM | octets as u8);
+        write_unsigned(buffer, length as u64, octets);
+    }
+}
+
+/// Reads a length determinant and returns the length and the amount of octets it occupied
+pub fn read_length(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+    if first & LONG_FORM == 0 {
+        Ok((usize::from(first), 1))
+    } else {
+        let octets = usize::from(first & !LONG_FORM);
+        if octets == 0 || octets > core::mem::size_of::<usize>() {
+            return Err(ErrorKind::InvalidLength.into());
+        }
+        let length = bytes
+            .get(1..1 + octets)
+            .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+        Ok((read_unsigned(length)? as usize, 1 + octets))
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7, 8.7, the class in bits 8 and 7 and the tag number in the
+/// remaining bits or the following octets
+pub fn write_tag(buffer: &mut Vec<u8>, tag: Tag) {
+    let (class, number) = match tag {
+        Tag::Universal(number) => (CLASS_UNIVERSAL, number),
+        Tag::Application(number) => (CLASS_APPLICATION, number),
+        Tag::ContextSpecific(number) => (CLASS_CONTEXT_SPECIFIC, number),
+        Tag::Private(number) => (CLASS_PRIVATE, number),
+    };
+    if number < usize::from(TAG_NUMBER_MASK) {
+        buffer.push(class | number as u8);
+    } else {
+        buffer.push(class | TAG_NUMBER_MASK);
+        let digits = (usize::BITS - number.leading_zeros()).div_ceil(7).max(1);
+        for digit in (0..digits).rev() {
+            let more = if digit > 0 { 0x80 } else { 0x00 };
+            buffer.push(more | ((number >> (digit * 7)) & 0x7F) as u8);
+        }
+    }
+}
+
+/// Reads the tag of a CHOICE alternative and returns it with the amount of octets it occupied
+pub fn read_tag(bytes: &[u8]) -> Result<(Tag, usize), Error> {
+    let first = *bytes
+        .first()
+        .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+    let (number, octets) = if first & TAG_NUMBER_MASK == TAG_NUMBER_MASK {
+        let mut number = 0_usize;
+        let mut octets = 1;
+        loop {
+            let byte = *bytes
+                .get(octets)
+                .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+            if (octets == 1 && byte == 0x80) || number.leading_zeros() < 7 {
+                return Err(ErrorKind::InvalidTagNumber.into());
+            }
+            number = (number << 7) | usize::from(byte & 0x7F);
+            octets += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        (number, octets)
+    } else {
+        (usize::from(first & TAG_NUMBER_MASK), 1)
+    };
+    let tag = match first & CLASS_MASK {
+        CLASS_UNIVERSAL => Tag::Universal(number),
+        CLASS_APPLICATION => Tag::Application(number),
+        CLASS_CONTEXT_SPECIFIC => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, octets))
+}
+
+/// The minimal amount of octets for the unsigned value, but at least one
+pub fn unsigned_octets(value: u64) -> usize {
+    ((u64::BITS - value.leading_zeros()) as usize)
+        .div_ceil(8)
+        .max(1)
+}
+
+/// The minimal amount of octets for the two's complement value, but at least one
+pub fn signed_octets(value: i64) -> usize {
+    let significant = if value < 0 {
+        i64::BITS - value.leading_ones()
+    } else {
+        i64::BITS - value.leading_zeros()
+    };
+    // one more bit for the sign
+    (significant as usize + 1).div_ceil(8)
+}
+
+/// Writes the lowest `octets` octets of the value in big endian order
+pub fn write_unsigned(buffer: &mut Vec<u8>, value: u64, octets: usize) {
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - octets..]);
+}
+
+/// Writes the lowest `octets` octets of the two's complement value in big endian order
+pub fn write_signed(buffer: &mut Vec<u8>, value: i64, octets: usize) {
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - octets..]);
+}
+
+pub fn read_unsigned(bytes: &[u8]) -> Result<u64, Error> {
+    if bytes.len() > core::mem::size_of::<u64>() {
+        return Err(ErrorKind::ValueExceedsMaxInt.into());
+    }
+    Ok(bytes
+        .iter()
+        .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte)))
+}
+
+pub fn read_signed(bytes: &[u8]) -> Result<i64, Error> {
+    if bytes.is_empty() {
+        return Err(ErrorKind::InvalidContentLength(0).into());
+    }
+    if bytes.len() > core::mem::size_of::<i64>() {
+        return Err(ErrorKind::ValueExceedsMaxInt.into());
+    }
+    let negative = bytes[0] & 0x80 != 0;
+    let value = bytes
+        .iter()
+        .fold(if negative { -1_i64 } else { 0 }, |value, byte| {
+            (value << 8) | i64::from(*byte)
+        });
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_forms() {
+        for (length, bytes) in [
+            (0, &[0x00][..]),
+            (127, &[0x7F][..]),
+            (128, &[0x81, 0x80][..]),
+            (256, &[0x82, 0x01, 0x00][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_length(&mut buffer, length);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!((length, bytes.len()), read_length(bytes).unwrap());
+        }
+        assert_eq!(
+            &ErrorKind::InvalidLength,
+            read_length(&[0x80]).unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_tags() {
+        for (tag, bytes) in [
+            (Tag::ContextSpecific(2), &[0x82][..]),
+            (Tag::Application(62), &[0x7E][..]),
+            (Tag::Universal(63), &[0x3F, 0x3F][..]),
+            (Tag::Private(200), &[0xFF, 0x81, 0x48][..]),
+        ] {
+            let mut buffer = Vec::new();
+            write_tag(&mut buffer, tag);
+            assert_eq!(bytes, &buffer[..]);
+            assert_eq!((tag, bytes.len()), read_tag(bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_integer_encoding() {
+        use IntegerEncoding::*;
+        for (min, max, extensible, encoding) in [
+            (Some(0), Some(255), false, FixedUnsigned(1)),
+            (Some(0), Some(256), false, FixedUnsigned(2)),
+            (Some(1), Some(4_294_967_295), false, FixedUnsigned(4)),
+            (Some(0), Some(i64::MAX), false, FixedUnsigned(8)),
+            (Some(-128), Some(127), false, FixedSigned(1)),
+            (Some(-129), Some(0), false, FixedSigned(2)),
+            (Some(i64::MIN), Some(0), false, FixedSigned(8)),
+            (Some(0), None, false, VariableUnsigned),
+            (Some(-1), None, false, VariableSigned),
+            (None, Some(0), false, VariableSigned),
+            (Some(0), Some(255), true, VariableSigned),
+        ] {
+            assert_eq!(
+                encoding,
+                IntegerEncoding::from_constraint(min, max, extensible)
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimal_octets() {
+        assert_eq!(1, unsigned_octets(0));
+        assert_eq!(1, unsigned_octets(255));
+        assert_eq!(2, unsigned_octets(256));
+        assert_eq!(1, signed_octets(0));
+        assert_eq!(1, signed_octets(127));
+        assert_eq!(2, signed_octets(128));
+        assert_eq!(1, signed_octets(-128));
+        assert_eq!(2, signed_octets(-129));
+        assert_eq!(8, signed_octets(i64::MIN));
+        assert_eq!(-129, read_signed(&[0xFF, 0x7F]).unwrap());
+        assert_eq!(128, read_signed(&[0x00, 0x80]).unwrap());
+    }
+}
@@ -0,0 +1,902 @@
+use crate::io::per::unaligned::buffer::BitBuffer;
+use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::ScopedBitRead;
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::unaligned::{LENGTH_127, LENGTH_16K, LENGTH_64K};
+use crate::io::per::unaligned::{MAX_FRAGMENTS, MIN_FRAGMENT_SIZE, SMALL_NON_NEGATIVE_NUMBER};
+use crate::io::per::{Error, ErrorKind};
+use crate::io::per::{PackedRead, PackedWrite};
+use std::ops::{Deref, DerefMut};
+
+/// Largest range (`ub - lb`) that is encoded as bit-field without padding, see
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5.7.1
+const BIT_FIELD_RANGE: u64 = 254;
+const ONE_OCTET_RANGE: u64 = 255;
+const TWO_OCTETS_RANGE: u64 = 64 * 1024 - 1;
+
+/// Maximum size in bits of a fixed-size bitstring or character string that is not octet-aligned,
+/// see ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16.9 and 30.5.7
+pub(crate) const MAX_UNALIGNED_FIXED_SIZE_BITS: u64 = 16;
+
+/// Maximum size in octets of a fixed-size octetstring that is not octet-aligned,
+/// see ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17.6
+const MAX_UNALIGNED_FIXED_SIZE_OCTETS: u64 = 2;
+
+/// Wraps a [`BitRead`](crate::io::per::unaligned::BitRead) or [`BitWrite`] implementation and
+/// provides [`PackedRead`] and [`PackedWrite`] according to the ALIGNED variant of
+/// ITU-T X.691 | ISO/IEC 8825-2:2015.
+///
+/// Padding bits are inserted relative to the beginning of the wrapped buffer, so the buffer must
+/// start at the beginning of the outermost value or of an open type field (both always start at
+/// an octet boundary). The wrapped buffer is available through [`Deref`] for plain bit access.
+#[derive(Debug, Default)]
+pub struct Aligned<T>(T);
+
+impl<T> Aligned<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Aligned<T> {
+    fn from(inner: T) -> Self {
+        Self(inner)
+    }
+}
+
+impl<T> Deref for Aligned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Aligned<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<B: ScopedBitRead> Aligned<B> {
+    /// Skips the padding bits until the read position is at the next octet boundary
+    #[inline]
+    pub fn skip_padding(&mut self) -> Result<(), Error> {
+        let padding = (BYTE_LEN - self.0.pos() % BYTE_LEN) % BYTE_LEN;
+        if self.0.remaining() < padding {
+            Err(ErrorKind::EndOfStream.into())
+        } else {
+            self.0.set_pos(self.0.pos() + padding);
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.5 - 11.9.3.8, the length is read
+    /// from an octet-aligned bit-field of one or two octets or is a multiple of 16K for fragments
+    #[inline]
+    fn read_unconstrained_length_determinant(&mut self) -> Result<u64, Error> {
+        self.skip_padding()?;
+        let mut octet = [0u8; 1];
+        self.0.read_bits(&mut octet)?;
+        if octet[0] & 0x80 == 0 {
+            // 11.9.3.6: less than or equal to 127
+            Ok(u64::from(octet[0]))
+        } else if octet[0] & 0x40 == 0 {
+            // 11.9.3.7: greater than 127 and less than 16K
+            let mut second = [0u8; 1];
+            self.0.read_bits(&mut second)?;
+            Ok(u64::from(u16::from_be_bytes([octet[0] & 0x3F, second[0]])))
+        } else {
+            // 11.9.3.8: chunks of 16k multiples
+            Ok(LENGTH_16K * u64::from((octet[0] & 0x3F).min(MAX_FRAGMENTS)))
+        }
+    }
+
+    /// Reads the octet-aligned octets following a (possibly fragmented) length determinant
+    #[inline]
+    fn read_octets_fragmented(
+        &mut self,
+        mut byte_len: u64,
+        fragmentation_possible: bool,
+    ) -> Result<Vec<u8>, Error> {
+        let mut buffer = vec![0u8; byte_len as usize];
+        if byte_len > 0 {
+            self.skip_padding()?;
+            self.0.read_bits(&mut buffer[..])?;
+        }
+
+        if fragmentation_possible && byte_len >= LENGTH_16K {
+            loop {
+                let ext_byte_len = self.read_unconstrained_length_determinant()?;
+                buffer.extend(core::iter::repeat_n(0u8, ext_byte_len as usize));
+                self.0.read_bits(&mut buffer[byte_len as usize..])?;
+                byte_len += ext_byte_len;
+
+                if ext_byte_len < LENGTH_16K {
+                    break;
+                }
+            }
+        }
+
+        Ok(buffer)
+    }
+}
+
+impl<B: ScopedBitRead> PackedRead for Aligned<B> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
+    #[inline]
+    fn read_boolean(&mut self) -> Result<bool, Error> {
+        self.0.read_bit()
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
+    #[inline]
+    fn read_2s_compliment_binary_integer(&mut self, bit_len: u64) -> Result<i64, Error> {
+        self.0.read_2s_compliment_binary_integer(bit_len)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn read_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+    ) -> Result<i64, Error> {
+        let range = upper_bound.wrapping_sub(lower_bound) as u64;
+        if upper_bound > lower_bound {
+            let offset = self.read_non_negative_binary_integer(None, Some(range))?;
+            Ok(lower_bound.wrapping_add(offset as i64))
+        } else {
+            Ok(lower_bound)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_length_determinant(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+    ) -> Result<u64, Error> {
+        match upper_bound {
+            Some(upper_bound) if upper_bound < LENGTH_64K => {
+                // 11.9.3.3 / 11.9.4.1: constrained whole number
+                let lower_bound = const_unwrap_or!(lower_bound, 0);
+                self.read_non_negative_binary_integer(Some(lower_bound), Some(upper_bound))
+            }
+            // 11.9.3.5 / 11.9.4.2: unconstrained, the lower bound is not used
+            _ => self.read_unconstrained_length_determinant(),
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.4
+    #[inline]
+    fn read_normally_small_length(&mut self) -> Result<u64, Error> {
+        self.read_normally_small_non_negative_whole_number()
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
+    #[inline]
+    fn read_normally_small_non_negative_whole_number(&mut self) -> Result<u64, Error> {
+        if self.0.read_bit()? {
+            // 11.6.2
+            self.read_semi_constrained_whole_number(0).map(|v| v as u64)
+        } else {
+            // 11.6.1
+            self.0
+                .read_non_negative_binary_integer(None, Some(SMALL_NON_NEGATIVE_NUMBER - 1))
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.3 and 11.5.7
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_non_negative_binary_integer(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+    ) -> Result<u64, Error> {
+        let range = match (lower_bound, upper_bound) {
+            (None, None) => None,
+            (lb, ub) => Some((
+                const_unwrap_or!(lb, 0),
+                const_unwrap_or!(ub, i64::MAX as u64),
+            )),
+        };
+
+        let mut bytes = [0u8; std::mem::size_of::<u64>()];
+        if let Some((lower, upper)) = range {
+            let range = upper.saturating_sub(lower);
+            if range <= BIT_FIELD_RANGE {
+                // 11.5.7.1: bit-field case
+                self.0
+                    .read_bits_with_offset(&mut bytes, range.leading_zeros() as usize)?;
+            } else if range <= ONE_OCTET_RANGE {
+                // 11.5.7.2: one-octet case
+                self.skip_padding()?;
+                self.0.read_bits(&mut bytes[7..])?;
+            } else if range <= TWO_OCTETS_RANGE {
+                // 11.5.7.3: two-octet case
+                self.skip_padding()?;
+                self.0.read_bits(&mut bytes[6..])?;
+            } else {
+                // 11.5.7.4: indefinite length case, the length is a constrained whole number
+                // between 1 and the number of octets required for the range
+                let max_octets = bytes.len() as u64 - u64::from(range.leading_zeros()) / 8;
+                let octets = 1 + self
+                    .0
+                    .read_non_negative_binary_integer(None, Some(max_octets - 1))?;
+                self.skip_padding()?;
+                self.0.read_bits(&mut bytes[(8 - octets) as usize..])?;
+            }
+            Ok(lower + u64::from_be_bytes(bytes))
+        } else {
+            // 11.7: length determinant followed by the octets
+            let length = self.read_length_determinant(None, None)? as usize;
+            if let Some(offset) = bytes.len().checked_sub(length) {
+                self.0.read_bits(&mut bytes[offset..])?;
+                Ok(u64::from_be_bytes(bytes))
+            } else {
+                Err(Error::length_determinant_exceeds_limit(length, bytes.len()))
+            }
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.7
+    #[inline]
+    fn read_semi_constrained_whole_number(&mut self, lower_bound: i64) -> Result<i64, Error> {
+        let n = self.read_non_negative_binary_integer(None, None)?;
+        Ok((n as i64).wrapping_add(lower_bound))
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.8
+    #[inline]
+    fn read_unconstrained_whole_number(&mut self) -> Result<i64, Error> {
+        let octet_len = self.read_length_determinant(None, None)?;
+        self.read_2s_compliment_binary_integer(octet_len * BYTE_LEN as u64)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_bitstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<(Vec<u8>, u64), Error> {
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+
+        let (mut bit_len, fragmentation_possible) = if extensible && self.0.read_bit()? {
+            // 16.6
+            (self.read_length_determinant(None, None)?, true)
+        } else if upper_bound == 0 {
+            // 16.8
+            return Ok((Vec::default(), 0));
+        } else if fixed_size && upper_bound <= MAX_UNALIGNED_FIXED_SIZE_BITS {
+            // 16.9
+            let mut buffer = vec![0u8; upper_bound.div_ceil(BYTE_LEN as u64) as usize];
+            self.0
+                .read_bits_with_len(&mut buffer[..], upper_bound as usize)?;
+            return Ok((buffer, upper_bound));
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 16.10
+            (upper_bound, false)
+        } else {
+            // 16.11
+            (
+                self.read_length_determinant(lower_bound_size, upper_bound_size)?,
+                upper_bound >= LENGTH_64K,
+            )
+        };
+
+        let mut buffer = vec![0u8; bit_len.div_ceil(BYTE_LEN as u64) as usize];
+        if bit_len > 0 {
+            self.skip_padding()?;
+            self.0
+                .read_bits_with_len(&mut buffer[..], bit_len as usize)?;
+        }
+
+        if fragmentation_possible && bit_len >= LENGTH_16K {
+            loop {
+                let ext_bit_len = self.read_unconstrained_length_determinant()?;
+                let total_byte_len = (bit_len + ext_bit_len).div_ceil(BYTE_LEN as u64);
+                buffer.resize(total_byte_len as usize, 0x00);
+                self.0.read_bits_with_offset_len(
+                    &mut buffer[..],
+                    bit_len as usize,
+                    ext_bit_len as usize,
+                )?;
+                bit_len += ext_bit_len;
+
+                if ext_bit_len < LENGTH_16K {
+                    break;
+                }
+            }
+        }
+
+        Ok((buffer, bit_len))
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn read_octetstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+    ) -> Result<Vec<u8>, Error> {
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+
+        if extensible && self.0.read_bit()? {
+            // 17.3
+            let byte_len = self.read_length_determinant(None, None)?;
+            self.read_octets_fragmented(byte_len, true)
+        } else if upper_bound == 0 {
+            // 17.5
+            Ok(Vec::default())
+        } else if fixed_size && upper_bound <= MAX_UNALIGNED_FIXED_SIZE_OCTETS {
+            // 17.6
+            let mut buffer = vec![0u8; upper_bound as usize];
+            self.0.read_bits(&mut buffer[..])?;
+            Ok(buffer)
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 17.7
+            self.read_octets_fragmented(upper_bound, false)
+        } else {
+            // 17.8
+            let byte_len = self.read_length_determinant(lower_bound_size, upper_bound_size)?;
+            self.read_octets_fragmented(byte_len, upper_bound >= LENGTH_64K)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 23
+    #[inline]
+    fn read_choice_index(&mut self, std_variants: u64, extensible: bool) -> Result<u64, Error> {
+        self.read_enumeration_index(std_variants, extensible)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 14
+    #[inline]
+    fn read_enumeration_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+    ) -> Result<u64, Error> {
+        if extensible && self.0.read_bit()? {
+            Ok(self.read_normally_small_length()? + std_variants)
+        } else {
+            self.read_non_negative_binary_integer(None, Some(std_variants - 1))
+        }
+    }
+}
+
+impl Aligned<BitBuffer> {
+    /// Writes padding bits until the write position is at the next octet boundary
+    #[inline]
+    pub fn write_padding(&mut self) -> Result<(), Error> {
+        while !self.0.write_position.is_multiple_of(BYTE_LEN) {
+            self.0.write_bit(false)?;
+        }
+        Ok(())
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.5 - 11.9.3.8, see
+    /// [`PackedWrite::write_length_determinant`] for the meaning of the returned value
+    #[inline]
+    fn write_unconstrained_length_determinant(&mut self, value: u64) -> Result<Option<u64>, Error> {
+        self.write_padding()?;
+        if value <= LENGTH_127 {
+            // 11.9.3.6: less than or equal to 127
+            self.0.write_bits(&[value as u8])?;
+            Ok(None)
+        } else if value < LENGTH_16K {
+            // 11.9.3.7: greater than 127 and less than 16K
+            self.0.write_bits(&(0x8000 | value as u16).to_be_bytes())?;
+            Ok(None)
+        } else {
+            // 11.9.3.8: chunks of 16k multiples
+            let multiple = ((value / LENGTH_16K) as u8).min(MAX_FRAGMENTS);
+            self.0.write_bits(&[0xC0 | multiple])?;
+            Ok(Some(u64::from(multiple) * LENGTH_16K))
+        }
+    }
+
+    /// Writes the octet-aligned octets following the given length determinant result and all
+    /// further fragments if required
+    #[inline]
+    fn write_octets_fragmented(
+        &mut self,
+        src: &[u8],
+        fragment_size: Option<u64>,
+    ) -> Result<(), Error> {
+        let length = src.len() as u64;
+        if length > 0 {
+            self.write_padding()?;
+        }
+
+        self.0
+            .write_bits(&src[..fragment_size.unwrap_or(length) as usize])?;
+
+        if let Some(mut written_bytes) = fragment_size {
+            loop {
+                let remaining = length - written_bytes;
+                let fragment_size = self
+                    .write_unconstrained_length_determinant(remaining)?
+                    .unwrap_or(remaining);
+
+                self.0.write_bits(
+                    &src[written_bytes as usize..(written_bytes + fragment_size) as usize],
+                )?;
+
+                if fragment_size < MIN_FRAGMENT_SIZE {
+                    break;
+                }
+
+                written_bytes += fragment_size;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PackedWrite for Aligned<BitBuffer> {
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
+    #[inline]
+    fn write_boolean(&mut self, boolean: bool) -> Result<(), Error> {
+        self.0.write_bit(boolean)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.4
+    #[inline]
+    fn write_2s_compliment_binary_integer(
+        &mut self,
+        bit_len: u64,
+        value: i64,
+    ) -> Result<(), Error> {
+        self.0.write_2s_compliment_binary_integer(bit_len, value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.5
+    #[inline]
+    fn write_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        upper_bound: i64,
+        value: i64,
+    ) -> Result<(), Error> {
+        if upper_bound > lower_bound {
+            if value < lower_bound || value > upper_bound {
+                Err(ErrorKind::ValueNotInRange(value, lower_bound, upper_bound).into())
+            } else {
+                self.write_non_negative_binary_integer(
+                    None,
+                    Some(upper_bound.wrapping_sub(lower_bound) as u64),
+                    value.wrapping_sub(lower_bound) as u64,
+                )
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_length_determinant(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+        length: u64,
+    ) -> Result<Option<u64>, Error> {
+        match upper_bound {
+            Some(upper_bound) if upper_bound < LENGTH_64K => {
+                // 11.9.3.3 / 11.9.4.1: constrained whole number
+                let lower_bound = const_unwrap_or!(lower_bound, 0);
+                if length < lower_bound || length > upper_bound {
+                    Err(ErrorKind::ValueNotInRange(
+                        length as i64,
+                        lower_bound as i64,
+                        upper_bound as i64,
+                    )
+                    .into())
+                } else {
+                    self.write_non_negative_binary_integer(
+                        Some(lower_bound),
+                        Some(upper_bound),
+                        length,
+                    )?;
+                    Ok(None)
+                }
+            }
+            // 11.9.3.5 / 11.9.4.2: unconstrained, the lower bound is not used
+            _ => self.write_unconstrained_length_determinant(length),
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.9.3.4
+    #[inline]
+    fn write_normally_small_length(&mut self, value: u64) -> Result<(), Error> {
+        self.write_normally_small_non_negative_whole_number(value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.6
+    #[inline]
+    fn write_normally_small_non_negative_whole_number(&mut self, value: u64) -> Result<(), Error> {
+        let greater_or_equal_to_64 = value >= SMALL_NON_NEGATIVE_NUMBER;
+        self.0.write_bit(greater_or_equal_to_64)?;
+        if greater_or_equal_to_64 {
+            // 11.6.2
+            self.write_non_negative_binary_integer(None, None, value)
+        } else {
+            // 11.6.1
+            self.0.write_non_negative_binary_integer(
+                None,
+                Some(SMALL_NON_NEGATIVE_NUMBER - 1),
+                value,
+            )
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.3 and 11.5.7
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_non_negative_binary_integer(
+        &mut self,
+        lower_bound: Option<u64>,
+        upper_bound: Option<u64>,
+        value: u64,
+    ) -> Result<(), Error> {
+        let range = match (lower_bound, upper_bound) {
+            (None, None) => None,
+            (lb, ub) => Some((
+                const_unwrap_or!(lb, 0),
+                const_unwrap_or!(ub, i64::MAX as u64),
+            )),
+        };
+
+        if let Some((lower, upper)) = range {
+            let range = upper - lower;
+            let bytes = (value - lower).to_be_bytes();
+            if range <= BIT_FIELD_RANGE {
+                // 11.5.7.1: bit-field case
+                self.0
+                    .write_bits_with_offset(&bytes[..], range.leading_zeros() as usize)
+            } else if range <= ONE_OCTET_RANGE {
+                // 11.5.7.2: one-octet case
+                self.write_padding()?;
+                self.0.write_bits(&bytes[7..])
+            } else if range <= TWO_OCTETS_RANGE {
+                // 11.5.7.3: two-octet case
+                self.write_padding()?;
+                self.0.write_bits(&bytes[6..])
+            } else {
+                // 11.5.7.4: indefinite length case, the length is a constrained whole number
+                // between 1 and the number of octets required for the range
+                let max_octets = bytes.len() as u64 - u64::from(range.leading_zeros()) / 8;
+                let octets = (bytes.len() - (value - lower).leading_zeros() as usize / 8).max(1);
+                self.0.write_non_negative_binary_integer(
+                    None,
+                    Some(max_octets - 1),
+                    octets as u64 - 1,
+                )?;
+                self.write_padding()?;
+                self.0.write_bits(&bytes[bytes.len() - octets..])
+            }
+        } else {
+            // 11.7: length determinant followed by the minimum number of octets
+            let bytes = value.to_be_bytes();
+            let octets = (bytes.len() - value.leading_zeros() as usize / 8).max(1);
+            self.write_length_determinant(None, None, octets as u64)?;
+            self.0.write_bits(&bytes[bytes.len() - octets..])
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.7
+    #[inline]
+    fn write_semi_constrained_whole_number(
+        &mut self,
+        lower_bound: i64,
+        value: i64,
+    ) -> Result<(), Error> {
+        if value < lower_bound {
+            Err(ErrorKind::ValueNotInRange(value, lower_bound, i64::MAX).into())
+        } else {
+            self.write_non_negative_binary_integer(
+                None,
+                None,
+                value.wrapping_sub(lower_bound) as u64,
+            )
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.8
+    #[inline]
+    fn write_unconstrained_whole_number(&mut self, value: i64) -> Result<(), Error> {
+        let prefix_len = if value.is_negative() {
+            value.leading_ones().saturating_sub(1)
+        } else {
+            value.leading_zeros().saturating_sub(1)
+        } as u64
+            / 8;
+        let octet_len = core::mem::size_of::<i64>() as u64 - prefix_len;
+        self.write_length_determinant(None, None, octet_len)?;
+        self.write_2s_compliment_binary_integer(octet_len * BYTE_LEN as u64, value)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 16
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_bitstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+        src: &[u8],
+        offset: u64,
+        len: u64,
+    ) -> Result<(), Error> {
+        let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+        let out_of_range = len < lower_bound || len > upper_bound;
+
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        let fragment_size = if out_of_range {
+            if extensible {
+                // 16.6
+                self.write_length_determinant(None, None, len)?
+            } else {
+                return Err(ErrorKind::SizeNotInRange(len, lower_bound, upper_bound).into());
+            }
+        } else if upper_bound == 0 {
+            // 16.8
+            return Ok(());
+        } else if fixed_size && upper_bound <= MAX_UNALIGNED_FIXED_SIZE_BITS {
+            // 16.9
+            return self
+                .0
+                .write_bits_with_offset_len(src, offset as usize, len as usize);
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 16.10
+            None
+        } else {
+            // 16.11
+            self.write_length_determinant(lower_bound_size, upper_bound_size, len)?
+        };
+
+        if len > 0 {
+            self.write_padding()?;
+        }
+
+        self.0.write_bits_with_offset_len(
+            src,
+            offset as usize,
+            fragment_size.unwrap_or(len) as usize,
+        )?;
+
+        if let Some(mut written_bits) = fragment_size {
+            loop {
+                let remaining = len - written_bits;
+                let fragment_size = self
+                    .write_unconstrained_length_determinant(remaining)?
+                    .unwrap_or(remaining);
+
+                self.0.write_bits_with_offset_len(
+                    src,
+                    (offset + written_bits) as usize,
+                    fragment_size as usize,
+                )?;
+
+                if fragment_size < MIN_FRAGMENT_SIZE {
+                    break;
+                }
+
+                written_bits += fragment_size;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 17
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_octetstring(
+        &mut self,
+        lower_bound_size: Option<u64>,
+        upper_bound_size: Option<u64>,
+        extensible: bool,
+        src: &[u8],
+    ) -> Result<(), Error> {
+        let lower_bound = const_unwrap_or!(lower_bound_size, 0);
+        let upper_bound = const_unwrap_or!(upper_bound_size, i64::MAX as u64);
+        let fixed_size = const_is_some!(lower_bound_size) && lower_bound_size == upper_bound_size;
+        let length = src.len() as u64;
+        let out_of_range = length < lower_bound || length > upper_bound;
+
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        let fragment_size = if out_of_range {
+            if extensible {
+                // 17.3
+                self.write_length_determinant(None, None, length)?
+            } else {
+                return Err(ErrorKind::SizeNotInRange(length, lower_bound, upper_bound).into());
+            }
+        } else if upper_bound == 0 {
+            // 17.5
+            return Ok(());
+        } else if fixed_size && upper_bound <= MAX_UNALIGNED_FIXED_SIZE_OCTETS {
+            // 17.6
+            return self.0.write_bits(src);
+        } else if fixed_size && upper_bound < LENGTH_64K {
+            // 17.7
+            None
+        } else {
+            // 17.8
+            self.write_length_determinant(lower_bound_size, upper_bound_size, length)?
+        };
+
+        self.write_octets_fragmented(src, fragment_size)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 23
+    #[inline]
+    fn write_choice_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+        index: u64,
+    ) -> Result<(), Error> {
+        self.write_enumeration_index(std_variants, extensible, index)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 14
+    #[inline]
+    fn write_enumeration_index(
+        &mut self,
+        std_variants: u64,
+        extensible: bool,
+        index: u64,
+    ) -> Result<(), Error> {
+        let out_of_range = index >= std_variants;
+        if extensible {
+            self.0.write_bit(out_of_range)?;
+        }
+
+        if out_of_range {
+            if extensible {
+                self.write_normally_small_length(index - std_variants)
+            } else {
+                Err(ErrorKind::InvalidChoiceIndex(index, std_variants).into())
+            }
+        } else {
+            self.write_non_negative_binary_integer(None, Some(std_variants - 1), index)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::io::per::unaligned::buffer::Bits;
+    use crate::io::per::unaligned::BitRead;
+
+    fn written(f: impl FnOnce(&mut Aligned<BitBuffer>) -> Result<(), Error>) -> (Vec<u8>, usize) {
+        let mut buffer = Aligned::<BitBuffer>::default();
+        f(&mut buffer).unwrap();
+        let bit_len = buffer.bit_len();
+        (buffer.into_inner().into(), bit_len)
+    }
+
+    #[test]
+    fn aligned_constrained_whole_number_bit_field_is_not_padded() -> Result<(), Error> {
+        let (bytes, bit_len) = written(|b| {
+            b.write_bit(true)?;
+            b.write_constrained_whole_number(0, 7, 5)
+        });
+        assert_eq!((&[0b1101_0000][..], 4), (&bytes[..], bit_len));
+
+        let mut bits = Aligned::from(Bits::from((&bytes[..], bit_len)));
+        assert!(bits.read_bit()?);
+        assert_eq!(5, bits.read_constrained_whole_number(0, 7)?);
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_constrained_whole_number_one_and_two_octets() -> Result<(), Error> {
+        let (bytes, bit_len) = written(|b| {
+            b.write_bit(true)?;
+            b.write_constrained_whole_number(0, 255, 200)?;
+            b.write_bit(true)?;
+            b.write_constrained_whole_number(-10, 1000, 500)
+        });
+        assert_eq!(
+            (&[0x80, 200, 0x80, 0x01, 0xFE][..], 40),
+            (&bytes[..], bit_len)
+        );
+
+        let mut bits = Aligned::from(Bits::from((&bytes[..], bit_len)));
+        assert!(bits.read_bit()?);
+        assert_eq!(200, bits.read_constrained_whole_number(0, 255)?);
+        assert!(bits.read_bit()?);
+        assert_eq!(500, bits.read_constrained_whole_number(-10, 1000)?);
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_constrained_whole_number_indefinite_length() -> Result<(), Error> {
+        let (bytes, bit_len) = written(|b| b.write_constrained_whole_number(0, 4294967295, 256));
+        // 2 bits for the length (1..4 octets), padding and two octets for the value
+        assert_eq!((&[0b0100_0000, 0x01, 0x00][..], 24), (&bytes[..], bit_len));
+
+        let mut bits = Aligned::from(Bits::from((&bytes[..], bit_len)));
+        assert_eq!(256, bits.read_constrained_whole_number(0, 4294967295)?);
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_length_determinant_is_padded() -> Result<(), Error> {
+        let (bytes, bit_len) = written(|b| {
+            b.write_bit(true)?;
+            b.write_length_determinant(None, None, 128)?;
+            Ok(())
+        });
+        assert_eq!((&[0x80, 0x80, 0x80][..], 24), (&bytes[..], bit_len));
+
+        let mut bits = Aligned::from(Bits::from((&bytes[..], bit_len)));
+        assert!(bits.read_bit()?);
+        assert_eq!(128, bits.read_length_determinant(None, None)?);
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_octetstring_fixed_size() -> Result<(), Error> {
+        let (bytes, bit_len) = written(|b| {
+            b.write_bit(true)?;
+            b.write_octetstring(Some(2), Some(2), false, &[0xAB, 0xCD])?;
+            b.write_octetstring(Some(3), Some(3), false, &[0x01, 0x02, 0x03])
+        });
+        // 17.6 is not padded, 17.7 is padded
+        assert_eq!(
+            (&[0xD5, 0xE6, 0x80, 0x01, 0x02, 0x03][..], 48),
+            (&bytes[..], bit_len)
+        );
+
+        let mut bits = Aligned::from(Bits::from((&bytes[..], bit_len)));
+        assert!(bits.read_bit()?);
+        assert_eq!(
+            vec![0xAB, 0xCD],
+            bits.read_octetstring(Some(2), Some(2), false)?
+        );
+        assert_eq!(
+            vec![0x01, 0x02, 0x03],
+            bits.read_octetstring(Some(3), Some(3), false)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_octetstring_fragmented() -> Result<(), Error> {
+        let src = (0..LENGTH_16K + 3).map(|v| v as u8).collect::<Vec<_>>();
+        let (bytes, bit_len) = written(|b| b.write_octetstring(None, None, false, &src));
+        assert_eq!(1 + LENGTH_16K as usize + 1 + 3, bytes.len());
+        assert_eq!(0xC1, bytes[0]);
+        assert_eq!(0x03, bytes[1 + LENGTH_16K as usize]);
+
+        let mut bits = Aligned::from(Bits::from((&bytes[..], bit_len)));
+        assert_eq!(src, bits.read_octetstring(None, None, false)?);
+        Ok(())
+    }
+}
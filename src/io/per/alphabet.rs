@@ -0,0 +1,85 @@
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5, the effective permitted alphabet of a
+/// known-multiplier character string that is constrained through `FROM`. The ranges of characters
+/// must be sorted and disjoint.
+#[derive(Debug, Copy, Clone)]
+pub struct EffectiveAlphabet<'a> {
+    ranges: &'a [(char, char)],
+    bits_per_char: usize,
+    indexed: bool,
+}
+
+impl<'a> EffectiveAlphabet<'a> {
+    /// `None` for an empty slice, which means that the charset is not constrained any further.
+    /// The ALIGNED variant rounds the number of bits per character up to a power of two.
+    /// ```rust
+    /// use asn1rs::io::per::alphabet::EffectiveAlphabet;
+    /// let alphabet = EffectiveAlphabet::new(&[('0', '9'), ('A', 'Z')], false).unwrap();
+    /// assert_eq!(6, alphabet.bits_per_char());
+    /// assert_eq!(Some(10), alphabet.encode('A'));
+    /// assert_eq!(Some('Z'), alphabet.decode(35));
+    /// assert_eq!(8, EffectiveAlphabet::new(&[('0', '9'), ('A', 'Z')], true).unwrap().bits_per_char());
+    /// ```
+    pub fn new(ranges: &'a [(char, char)], aligned: bool) -> Option<Self> {
+        let len = ranges
+            .iter()
+            .map(|(min, max)| u64::from(*max as u32 - *min as u32) + 1)
+            .sum::<u64>();
+        let upper_bound = u64::from(ranges.last()?.1 as u32);
+
+        // 30.5.3, the smallest number of bits that can represent every index
+        let bits_per_char = (64 - (len - 1).leading_zeros()) as usize;
+        let bits_per_char = if aligned && bits_per_char > 1 {
+            bits_per_char.next_power_of_two()
+        } else {
+            bits_per_char
+        };
+
+        Some(Self {
+            ranges,
+            bits_per_char,
+            // 30.5.4, the characters are only encoded by their value if all values fit
+            indexed: upper_bound > (1_u64 << bits_per_char) - 1,
+        })
+    }
+
+    pub const fn bits_per_char(&self) -> usize {
+        self.bits_per_char
+    }
+
+    /// The value that represents the character, `None` if it is not in the alphabet
+    pub fn encode(&self, char: char) -> Option<u32> {
+        let mut index = 0;
+        for (min, max) in self.ranges {
+            if (*min..=*max).contains(&char) {
+                return Some(if self.indexed {
+                    index + (char as u32 - *min as u32)
+                } else {
+                    char as u32
+                });
+            }
+            index += *max as u32 - *min as u32 + 1;
+        }
+        None
+    }
+
+    /// The character represented by the value, `None` if it is not in the alphabet
+    pub fn decode(&self, value: u32) -> Option<char> {
+        if !self.indexed {
+            return core::char::from_u32(value).filter(|char| {
+                self.ranges
+                    .iter()
+                    .any(|(min, max)| (*min..=*max).contains(char))
+            });
+        }
+
+        let mut index = value;
+        for (min, max) in self.ranges {
+            let len = *max as u32 - *min as u32 + 1;
+            if index < len {
+                return core::char::from_u32(*min as u32 + index);
+            }
+            index -= len;
+        }
+        None
+    }
+}
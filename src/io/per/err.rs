@@ -1,4 +1,4 @@
-use crate::model::Charset;
+use crate::model::{Charset, TimeType};
 use backtrace::Backtrace;
 use std::string::FromUtf8Error;
 
@@ -60,6 +60,7 @@ pub(crate) struct Inner {
 pub enum ErrorKind {
     FromUtf8Error(FromUtf8Error),
     InvalidString(Charset, char, usize),
+    InvalidStringEncoding(Charset),
     UnsupportedOperation(String),
     InsufficientSpaceInDestinationBuffer(Backtrace),
     InsufficientDataInSourceBuffer(Backtrace),
@@ -69,14 +70,22 @@ pub enum ErrorKind {
         backtrace: Backtrace,
     },
     InvalidChoiceIndex(u64, u64),
+    InvalidOpenTypeKey(i64),
     ExtensionFieldsInconsistent(String),
     ValueNotInRange(i64, i64, i64),
     ValueExceedsMaxInt,
     ValueIsNegativeButExpectedUnsigned(i64),
     SizeNotInRange(u64, u64, u64),
     BitLenNotInRange(u64, u64, u64),
+    InvalidReal(u8),
+    InvalidObjectIdentifier,
+    InvalidTime(TimeType, String),
     OptFlagsExhausted,
     EndOfStream,
+    /// The available data ends within the value and the source would block, so reading it again
+    /// requires more data
+    NeedMoreData,
+    Io(std::io::ErrorKind, String),
 }
 
 impl Error {
@@ -89,6 +98,23 @@ impl Error {
         }
     }
 
+    /// Decodes the octets of a string, which are UTF-8 for all but the charsets with a
+    /// dedicated representation in [`Charset::decode_octets`]
+    pub fn decode_string(charset: Charset, octets: Vec<u8>) -> Result<String, Self> {
+        match charset {
+            Charset::Utf8
+            | Charset::Numeric
+            | Charset::Printable
+            | Charset::Ia5
+            | Charset::Visible => {
+                String::from_utf8(octets).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+            }
+            _ => charset
+                .decode_octets(octets)
+                .ok_or_else(|| ErrorKind::InvalidStringEncoding(charset).into()),
+        }
+    }
+
     #[cold]
     #[inline(never)]
     pub fn insufficient_space_in_destination_buffer() -> Self {
@@ -127,6 +153,11 @@ impl std::fmt::Display for ErrorKind {
                     charset, index, char
                 )
             }
+            Self::InvalidStringEncoding(charset) => write!(
+                f,
+                "The octets do not form a string with the charset {:?}",
+                charset
+            ),
             Self::UnsupportedOperation(o) => write!(f, "The operation is not supported: {}", o),
             Self::InsufficientSpaceInDestinationBuffer(backtrace) => write!(
                 f,
@@ -167,6 +198,13 @@ impl std::fmt::Display for ErrorKind {
                 "Unexpected choice-index {} with variant count {}",
                 index, variant_count
             ),
+            Self::InvalidOpenTypeKey(key) => {
+                write!(
+                    f,
+                    "There is no alternative of the open type for the key {}",
+                    key
+                )
+            }
             Self::ExtensionFieldsInconsistent(name) => {
                 write!(
                     f,
@@ -197,11 +235,31 @@ impl std::fmt::Display for ErrorKind {
                 "The length {} is not within the inclusive range of {} and {} for a bit field",
                 size, min, max
             ),
+            Self::InvalidReal(first) => write!(
+                f,
+                "The REAL with the first contents octet {:#04x} is invalid or unsupported",
+                first
+            ),
+            Self::InvalidObjectIdentifier => write!(
+                f,
+                "The OBJECT IDENTIFIER or RELATIVE-OID is invalid or cannot be encoded"
+            ),
+            Self::InvalidTime(r#type, text) => write!(
+                f,
+                "Expected a {} value but found {:?}",
+                r#type.asn_name(),
+                text
+            ),
             Self::OptFlagsExhausted => write!(f, "All optional flags have already been exhausted"),
             Self::EndOfStream => write!(
                 f,
                 "Can no longer read or write any bytes from the underlying dataset"
             ),
+            Self::NeedMoreData => write!(
+                f,
+                "The available data ends within the value, more data is needed to read it"
+            ),
+            Self::Io(_, message) => write!(f, "Failed to read from the source: {}", message),
         }
     }
 }
@@ -213,6 +271,9 @@ impl PartialEq for ErrorKind {
             Self::InvalidString(a, b, c) => {
                 matches!(other, Self::InvalidString(oa, ob, oc) if (a, b, c) == (oa, ob, oc))
             }
+            Self::InvalidStringEncoding(a) => {
+                matches!(other, Self::InvalidStringEncoding(oa) if a == oa)
+            }
             Self::UnsupportedOperation(a) => {
                 matches!(other, Self::UnsupportedOperation(oa) if a == oa)
             }
@@ -228,6 +289,9 @@ impl PartialEq for ErrorKind {
             Self::InvalidChoiceIndex(a, b) => {
                 matches!(other, Self::InvalidChoiceIndex(oa, ob) if (a, b) == (oa, ob))
             }
+            Self::InvalidOpenTypeKey(a) => {
+                matches!(other, Self::InvalidOpenTypeKey(oa) if a == oa)
+            }
             Self::ExtensionFieldsInconsistent(a) => {
                 matches!(other, Self::ExtensionFieldsInconsistent(oa) if a == oa)
             }
@@ -244,8 +308,15 @@ impl PartialEq for ErrorKind {
             Self::BitLenNotInRange(a, b, c) => {
                 matches!(other, Self::BitLenNotInRange(oa, ob, oc) if (a,b ,c) == (oa, ob,oc))
             }
+            Self::InvalidReal(a) => matches!(other, Self::InvalidReal(oa) if a == oa),
+            Self::InvalidObjectIdentifier => matches!(other, Self::InvalidObjectIdentifier),
+            Self::InvalidTime(t, v) => {
+                matches!(other, Self::InvalidTime(ot, ov) if t == ot && v == ov)
+            }
             Self::OptFlagsExhausted => matches!(other, Self::OptFlagsExhausted),
             Self::EndOfStream => matches!(other, Self::EndOfStream),
+            Self::NeedMoreData => matches!(other, Self::NeedMoreData),
+            Self::Io(a, b) => matches!(other, Self::Io(oa, ob) if a == oa && b == ob),
         }
     }
 }
@@ -3,6 +3,8 @@
 //! The idea is to provide all building blocks to composite the more complex types on top of the
 //! traits without caring about the representation being ALIGNED or UNALIGNED.
 
+pub mod aligned;
+pub mod alphabet;
 pub mod err;
 pub mod unaligned;
 
@@ -72,6 +74,32 @@ pub trait PackedRead {
         extensible: bool,
     ) -> Result<Vec<u8>, Error>;
 
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 15, the contents octets of the
+    /// CER/DER encoding of the real value, preceded by an unconstrained length determinant
+    fn read_real(&mut self) -> Result<f64, Error> {
+        let content = self.read_octetstring(None, None, false)?;
+        crate::io::ber::read_real(&content).map_err(|_| {
+            ErrorKind::InvalidReal(content.first().copied().unwrap_or_default()).into()
+        })
+    }
+
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 24, the contents octets of the
+    /// BER encoding of the object identifier, preceded by an unconstrained length determinant
+    fn read_object_identifier(&mut self) -> Result<Vec<u64>, Error> {
+        let content = self.read_octetstring(None, None, false)?;
+        crate::io::ber::read_object_identifier(&content)
+            .map_err(|_| ErrorKind::InvalidObjectIdentifier.into())
+    }
+
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 25, the contents octets of the
+    /// BER encoding of the relative object identifier, preceded by an unconstrained length
+    /// determinant
+    fn read_relative_oid(&mut self) -> Result<Vec<u64>, Error> {
+        let content = self.read_octetstring(None, None, false)?;
+        crate::io::ber::read_relative_oid(&content)
+            .map_err(|_| ErrorKind::InvalidObjectIdentifier.into())
+    }
+
     fn read_choice_index(&mut self, std_variants: u64, extensible: bool) -> Result<u64, Error>;
 
     fn read_enumeration_index(&mut self, std_variants: u64, extensible: bool)
@@ -155,6 +183,33 @@ pub trait PackedWrite {
         src: &[u8],
     ) -> Result<(), Error>;
 
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 15, the contents octets of the
+    /// CER/DER encoding of the real value, preceded by an unconstrained length determinant
+    fn write_real(&mut self, value: f64) -> Result<(), Error> {
+        let mut content = Vec::with_capacity(10);
+        crate::io::ber::write_real(&mut content, value);
+        self.write_octetstring(None, None, false, &content)
+    }
+
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 24, the contents octets of the
+    /// BER encoding of the object identifier, preceded by an unconstrained length determinant
+    fn write_object_identifier(&mut self, arcs: &[u64]) -> Result<(), Error> {
+        let mut content = Vec::with_capacity(arcs.len() * 2);
+        crate::io::ber::write_object_identifier(&mut content, arcs)
+            .map_err(|_| Error::from(ErrorKind::InvalidObjectIdentifier))?;
+        self.write_octetstring(None, None, false, &content)
+    }
+
+    /// According to ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 25, the contents octets of the
+    /// BER encoding of the relative object identifier, preceded by an unconstrained length
+    /// determinant
+    fn write_relative_oid(&mut self, arcs: &[u64]) -> Result<(), Error> {
+        let mut content = Vec::with_capacity(arcs.len() * 2);
+        crate::io::ber::write_relative_oid(&mut content, arcs)
+            .map_err(|_| Error::from(ErrorKind::InvalidObjectIdentifier))?;
+        self.write_octetstring(None, None, false, &content)
+    }
+
     fn write_choice_index(
         &mut self,
         std_variants: u64,
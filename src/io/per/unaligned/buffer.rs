@@ -230,6 +230,45 @@ impl BitWrite for BitBuffer {
     }
 }
 
+impl ScopedBitWrite for BitBuffer {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.write_position
+    }
+
+    #[inline]
+    fn set_pos(&mut self, position: usize) {
+        debug_assert!(position <= self.buffer.len() * BYTE_LEN);
+        self.write_position = position;
+    }
+
+    #[inline]
+    fn content(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    #[inline]
+    fn insert_octet(&mut self, position: usize) -> Result<(), Error> {
+        let end = self.write_position;
+        self.write_bits(&[0x00])?;
+        move_by_octet(&mut self.buffer, position, end);
+        Ok(())
+    }
+}
+
+/// Moves the bits in the given range by one octet towards the end and clears the bits that are
+/// left behind
+#[inline]
+fn move_by_octet(bytes: &mut [u8], from: usize, to: usize) {
+    for index in (from / BYTE_LEN..to.div_ceil(BYTE_LEN)).rev() {
+        let first = from.max(index * BYTE_LEN) - index * BYTE_LEN;
+        let last = to.min((index + 1) * BYTE_LEN) - index * BYTE_LEN;
+        let mask = (0xFF >> first) & (0xFF << (BYTE_LEN - last));
+        bytes[index + 1] = (bytes[index + 1] & !mask) | (bytes[index] & mask);
+        bytes[index] &= !mask;
+    }
+}
+
 pub struct Bits<'a> {
     slice: &'a [u8],
     pos: usize,
@@ -339,6 +378,109 @@ impl ScopedBitRead for Bits<'_> {
     }
 }
 
+/// Writes bits into a borrowed slice instead of a growing [`BitBuffer`]. A write that does not fit
+/// into the remaining bits of the slice fails with
+/// [`ErrorKind::InsufficientSpaceInDestinationBuffer`]. The slice is cleared when it is borrowed.
+pub struct BitsMut<'a> {
+    slice: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> From<&'a mut [u8]> for BitsMut<'a> {
+    fn from(slice: &'a mut [u8]) -> Self {
+        slice.fill(0);
+        Self { slice, pos: 0 }
+    }
+}
+
+impl BitsMut<'_> {
+    /// The number of bytes of the slice
+    pub fn capacity(&self) -> usize {
+        self.slice.len()
+    }
+
+    #[inline]
+    fn ensure_can_write_additional_bits(&self, bit_len: usize) -> Result<(), Error> {
+        if self.pos + bit_len > self.slice.len() * BYTE_LEN {
+            Err(Error::insufficient_space_in_destination_buffer())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl BitWrite for BitsMut<'_> {
+    #[inline]
+    fn write_bit(&mut self, bit: bool) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(1)?;
+        BitWrite::write_bit(&mut (&mut self.slice[..], &mut self.pos), bit)
+    }
+
+    #[inline]
+    fn write_bits(&mut self, src: &[u8]) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(src.len() * BYTE_LEN)?;
+        BitWrite::write_bits(&mut (&mut self.slice[..], &mut self.pos), src)
+    }
+
+    #[inline]
+    fn write_bits_with_offset(&mut self, src: &[u8], src_bit_offset: usize) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(src.len() * BYTE_LEN - src_bit_offset)?;
+        BitWrite::write_bits_with_offset(
+            &mut (&mut self.slice[..], &mut self.pos),
+            src,
+            src_bit_offset,
+        )
+    }
+
+    #[inline]
+    fn write_bits_with_len(&mut self, src: &[u8], bit_len: usize) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(bit_len)?;
+        BitWrite::write_bits_with_len(&mut (&mut self.slice[..], &mut self.pos), src, bit_len)
+    }
+
+    #[inline]
+    fn write_bits_with_offset_len(
+        &mut self,
+        src: &[u8],
+        src_bit_offset: usize,
+        src_bit_len: usize,
+    ) -> Result<(), Error> {
+        self.ensure_can_write_additional_bits(src_bit_len)?;
+        BitWrite::write_bits_with_offset_len(
+            &mut (&mut self.slice[..], &mut self.pos),
+            src,
+            src_bit_offset,
+            src_bit_len,
+        )
+    }
+}
+
+impl ScopedBitWrite for BitsMut<'_> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn set_pos(&mut self, position: usize) {
+        debug_assert!(position <= self.slice.len() * BYTE_LEN);
+        self.pos = position;
+    }
+
+    #[inline]
+    fn content(&self) -> &[u8] {
+        &self.slice[..self.pos.div_ceil(BYTE_LEN)]
+    }
+
+    #[inline]
+    fn insert_octet(&mut self, position: usize) -> Result<(), Error> {
+        let end = self.pos;
+        self.write_bits(&[0x00])?;
+        move_by_octet(self.slice, position, end);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::identity_op, clippy::inconsistent_digit_grouping)] // this makes various examples easier to understand
 pub mod tests {
@@ -0,0 +1,77 @@
+use super::BitWrite;
+use super::ScopedBitWrite;
+use super::BYTE_LEN;
+use crate::io::per::Error;
+
+/// Counts the written bits instead of storing them, which determines the length of an encoding
+/// without producing it. It has no content.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct BitCounter {
+    pos: usize,
+}
+
+impl BitCounter {
+    pub const fn bit_len(&self) -> usize {
+        self.pos
+    }
+}
+
+impl BitWrite for BitCounter {
+    #[inline]
+    fn write_bit(&mut self, _bit: bool) -> Result<(), Error> {
+        self.pos += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bits(&mut self, src: &[u8]) -> Result<(), Error> {
+        self.write_bits_with_offset_len(src, 0, src.len() * BYTE_LEN)
+    }
+
+    #[inline]
+    fn write_bits_with_offset(&mut self, src: &[u8], src_bit_offset: usize) -> Result<(), Error> {
+        self.write_bits_with_offset_len(src, src_bit_offset, src.len() * BYTE_LEN - src_bit_offset)
+    }
+
+    #[inline]
+    fn write_bits_with_len(&mut self, src: &[u8], bit_len: usize) -> Result<(), Error> {
+        self.write_bits_with_offset_len(src, 0, bit_len)
+    }
+
+    #[inline]
+    fn write_bits_with_offset_len(
+        &mut self,
+        src: &[u8],
+        src_bit_offset: usize,
+        src_bit_len: usize,
+    ) -> Result<(), Error> {
+        if src.len() * BYTE_LEN < src_bit_offset + src_bit_len {
+            return Err(Error::insufficient_data_in_source_buffer());
+        }
+        self.pos += src_bit_len;
+        Ok(())
+    }
+}
+
+impl ScopedBitWrite for BitCounter {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn set_pos(&mut self, position: usize) {
+        self.pos = position;
+    }
+
+    #[inline]
+    fn content(&self) -> &[u8] {
+        &[]
+    }
+
+    #[inline]
+    fn insert_octet(&mut self, _position: usize) -> Result<(), Error> {
+        self.pos += BYTE_LEN;
+        Ok(())
+    }
+}
@@ -2,20 +2,22 @@ use crate::io::per::{Error, ErrorKind};
 use crate::io::per::{PackedRead, PackedWrite};
 
 pub mod buffer;
+pub mod counter;
 pub mod slice;
+pub mod stream;
 
 pub const BYTE_LEN: usize = 8;
 
 const FRAGMENT_SIZE: u64 = 16 * 1024;
-const MAX_FRAGMENTS: u8 = 4  /* 11.9.3.8, NOTE */ ;
-const MIN_FRAGMENT_SIZE: u64 = FRAGMENT_SIZE;
+pub(crate) const MAX_FRAGMENTS: u8 = 4  /* 11.9.3.8, NOTE */ ;
+pub(crate) const MIN_FRAGMENT_SIZE: u64 = FRAGMENT_SIZE;
 const MAX_FRAGMENTS_SIZE: u64 = FRAGMENT_SIZE * MAX_FRAGMENTS as u64;
 
-const LENGTH_127: u64 = 127;
-const LENGTH_16K: u64 = 16 * 1024;
-const LENGTH_64K: u64 = 64 * 1024;
+pub(crate) const LENGTH_127: u64 = 127;
+pub(crate) const LENGTH_16K: u64 = 16 * 1024;
+pub(crate) const LENGTH_64K: u64 = 64 * 1024;
 
-const SMALL_NON_NEGATIVE_NUMBER: u64 = 64;
+pub(crate) const SMALL_NON_NEGATIVE_NUMBER: u64 = 64;
 
 pub trait BitRead {
     fn read_bit(&mut self) -> Result<bool, Error>;
@@ -399,6 +401,33 @@ pub trait BitWrite {
     ) -> Result<(), Error>;
 }
 
+pub trait ScopedBitWrite: BitWrite {
+    /// The position of the next bit to write, which is the number of written bits unless it is
+    /// moved back through [`ScopedBitWrite::set_pos`]
+    fn pos(&self) -> usize;
+
+    /// Moves the write-position to a bit that is already written
+    fn set_pos(&mut self, position: usize);
+
+    /// The written bits, padded with zero bits to the next octet
+    fn content(&self) -> &[u8];
+
+    /// Moves the written bits from the given position on by one octet towards the end, which
+    /// leaves a cleared octet at the given position
+    fn insert_octet(&mut self, position: usize) -> Result<(), Error>;
+
+    /// Changes the write-position to the given position for the closure call.
+    /// Restores the original write-position after the call.
+    #[inline]
+    fn with_write_position_at<T, F: Fn(&mut Self) -> T>(&mut self, pos: usize, f: F) -> T {
+        let original_pos = self.pos();
+        self.set_pos(pos);
+        let result = f(self);
+        self.set_pos(original_pos);
+        result
+    }
+}
+
 impl<T: BitWrite> PackedWrite for T {
     /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 12
     #[inline]
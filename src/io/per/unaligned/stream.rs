@@ -0,0 +1,297 @@
+use super::BitRead;
+use super::ScopedBitRead;
+use super::BYTE_LEN;
+use crate::io::per::{Error, ErrorKind};
+use std::io::Read;
+
+const CHUNK_SIZE: usize = 4 * 1024;
+
+/// Reads bits from a [`Read`] and pulls its bytes only when a read needs them, so that a message
+/// can be decoded while it is still being received. The bytes of the current message are kept
+/// until [`StreamBits::finish_message`], because the presence bits of `OPTIONAL` components are
+/// read out of order.
+///
+/// Bytes can also be pushed in chunks through [`StreamBits::push`]. A read beyond the available
+/// bytes fails with [`ErrorKind::NeedMoreData`] if the source would block, which is always the
+/// case for the source of [`StreamBits::chunked`]. The end of the source is reported as
+/// [`ErrorKind::EndOfStream`].
+#[derive(Debug)]
+pub struct StreamBits<R: Read> {
+    source: R,
+    buffer: Vec<u8>,
+    pos: usize,
+    len: usize,
+    /// The bits of the current message the read that failed with [`ErrorKind::NeedMoreData`]
+    /// needed, which a retry waits for
+    needed: usize,
+}
+
+/// The source of [`StreamBits::chunked`], which has no bytes on its own and never ends
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Pending;
+
+impl Read for Pending {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::ErrorKind::WouldBlock.into())
+    }
+}
+
+impl StreamBits<Pending> {
+    /// Reads only the bytes that are [`StreamBits::push`]ed
+    pub fn chunked() -> Self {
+        Self::new(Pending)
+    }
+}
+
+impl<R: Read> From<R> for StreamBits<R> {
+    fn from(source: R) -> Self {
+        Self::new(source)
+    }
+}
+
+impl<R: Read> StreamBits<R> {
+    pub fn new(source: R) -> Self {
+        Self {
+            source,
+            buffer: Vec::new(),
+            pos: 0,
+            len: usize::MAX,
+            needed: 0,
+        }
+    }
+
+    /// Appends the bytes to the ones already received from the source
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// The number of received bytes that are not yet consumed by a finished message
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Restarts the current message at its first bit, for example to read it again once more data
+    /// is available
+    pub fn rewind(&mut self) {
+        self.pos = 0;
+        self.len = usize::MAX;
+    }
+
+    /// Drops the bytes of the current message, which ends at the next octet boundary
+    /// (ITU-T X.691 | ISO/IEC 8825-2, 11.1), so that the next read starts the next message
+    pub fn finish_message(&mut self) {
+        let consumed = self.pos.div_ceil(BYTE_LEN).min(self.buffer.len());
+        self.buffer.drain(..consumed);
+        self.needed = 0;
+        self.rewind();
+    }
+
+    /// Ensures that the bits of the current message are received that the last read, which failed
+    /// with [`ErrorKind::NeedMoreData`], needed, so that a retry fails early instead of decoding
+    /// the message again
+    pub fn receive_needed(&mut self) -> Result<(), Error> {
+        let needed = self.needed.saturating_sub(self.pos);
+        self.fill(needed)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Ensures that the given number of bits following the current position are received
+    fn fill(&mut self, bits: usize) -> Result<(), Error> {
+        let end = self.pos + bits;
+        if end > self.len {
+            return Err(ErrorKind::EndOfStream.into());
+        }
+        while self.buffer.len() * BYTE_LEN < end {
+            let received = self.buffer.len();
+            let missing = (end - received * BYTE_LEN).div_ceil(BYTE_LEN);
+            self.buffer.resize(received + missing.max(CHUNK_SIZE), 0);
+            let result = self.source.read(&mut self.buffer[received..]);
+            self.buffer
+                .truncate(received + *result.as_ref().unwrap_or(&0));
+            match result {
+                Ok(0) => return Err(ErrorKind::EndOfStream.into()),
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    self.needed = end;
+                    return Err(ErrorKind::NeedMoreData.into());
+                }
+                Err(e) => return Err(ErrorKind::Io(e.kind(), e.to_string()).into()),
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn bits(&mut self) -> (&[u8], &mut usize) {
+        (&self.buffer[..], &mut self.pos)
+    }
+}
+
+impl<R: Read> BitRead for StreamBits<R> {
+    #[inline]
+    fn read_bit(&mut self) -> Result<bool, Error> {
+        self.fill(1)?;
+        BitRead::read_bit(&mut self.bits())
+    }
+
+    #[inline]
+    fn read_bits(&mut self, dst: &mut [u8]) -> Result<(), Error> {
+        self.fill(dst.len() * BYTE_LEN)?;
+        BitRead::read_bits(&mut self.bits(), dst)
+    }
+
+    #[inline]
+    fn read_bits_with_offset(
+        &mut self,
+        dst: &mut [u8],
+        dst_bit_offset: usize,
+    ) -> Result<(), Error> {
+        self.fill(dst.len() * BYTE_LEN - dst_bit_offset)?;
+        BitRead::read_bits_with_offset(&mut self.bits(), dst, dst_bit_offset)
+    }
+
+    #[inline]
+    fn read_bits_with_len(&mut self, dst: &mut [u8], dst_bit_len: usize) -> Result<(), Error> {
+        self.fill(dst_bit_len)?;
+        BitRead::read_bits_with_len(&mut self.bits(), dst, dst_bit_len)
+    }
+
+    #[inline]
+    fn read_bits_with_offset_len(
+        &mut self,
+        dst: &mut [u8],
+        dst_bit_offset: usize,
+        dst_bit_len: usize,
+    ) -> Result<(), Error> {
+        self.fill(dst_bit_len)?;
+        BitRead::read_bits_with_offset_len(&mut self.bits(), dst, dst_bit_offset, dst_bit_len)
+    }
+}
+
+/// The length of a message is not known in advance, so unless it is limited through
+/// [`ScopedBitRead::set_len`], it is `usize::MAX`.
+impl<R: Read> ScopedBitRead for StreamBits<R> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn set_pos(&mut self, position: usize) -> usize {
+        let pos = position.min(self.len);
+        self.pos = pos;
+        pos
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn set_len(&mut self, len: usize) -> usize {
+        self.len = len;
+        len
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len - self.pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::per::PackedRead;
+
+    /// Returns at most one byte per call
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((first, rest)), Some(dst)) => {
+                    *dst = *first;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn bytes_are_pulled_on_demand() {
+        let mut bits = StreamBits::new(Trickle(&[0x80, 0x12, 0x34]));
+        assert!(bits.read_bit().unwrap());
+        assert_eq!(1, bits.buffered_len());
+        assert_eq!(
+            0x0024,
+            bits.read_constrained_whole_number(0, i64::from(u16::MAX))
+                .unwrap()
+        );
+        assert_eq!(3, bits.buffered_len());
+        assert_eq!(
+            Err(ErrorKind::EndOfStream.into()),
+            bits.read_bits(&mut [0_u8; 1])
+        );
+    }
+
+    #[test]
+    fn chunked_input_asks_for_more_data() {
+        let mut bits = StreamBits::chunked();
+        bits.push(&[0xFF]);
+        let mut dst = [0_u8; 2];
+        assert_eq!(
+            Err(ErrorKind::NeedMoreData.into()),
+            bits.read_bits(&mut dst)
+        );
+        bits.push(&[0x00]);
+        bits.read_bits(&mut dst).unwrap();
+        assert_eq!([0xFF, 0x00], dst);
+    }
+
+    #[test]
+    fn retry_waits_for_the_needed_bits() {
+        let mut bits = StreamBits::chunked();
+        bits.push(&[0xFF]);
+        let mut dst = [0_u8; 3];
+        assert_eq!(
+            Err(ErrorKind::NeedMoreData.into()),
+            bits.read_bits(&mut dst)
+        );
+        bits.rewind();
+        bits.push(&[0x00]);
+        assert_eq!(Err(ErrorKind::NeedMoreData.into()), bits.receive_needed());
+        bits.push(&[0x11]);
+        bits.receive_needed().unwrap();
+        bits.read_bits(&mut dst).unwrap();
+        assert_eq!([0xFF, 0x00, 0x11], dst);
+    }
+
+    #[test]
+    fn finished_message_is_dropped_up_to_the_octet_boundary() {
+        let mut bits = StreamBits::chunked();
+        bits.push(&[0xC0, 0xAA]);
+        assert!(bits.read_bit().unwrap());
+        bits.finish_message();
+        assert_eq!(1, bits.buffered_len());
+        let mut dst = [0_u8; 1];
+        bits.read_bits(&mut dst).unwrap();
+        assert_eq!([0xAA], dst);
+    }
+
+    #[test]
+    fn scoped_length_is_respected() {
+        let mut bits = StreamBits::chunked();
+        bits.push(&[0xFF]);
+        bits.set_len(1);
+        assert!(bits.read_bit().unwrap());
+        assert_eq!(Err(ErrorKind::EndOfStream.into()), bits.read_bit());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::TimeType;
 use crate::syn::bitstring::BitVec;
 use backtrace::Backtrace;
 use byteorder::LittleEndian as E;
@@ -23,6 +24,8 @@ pub enum Error {
     InvalidVariant(Backtrace, u64),
     UnexpectedFormat(Backtrace, Format),
     UnexpectedTag(Backtrace, (u32, Format)),
+    InvalidObjectIdentifier(String),
+    InvalidTime(TimeType, String),
 }
 
 impl Error {
@@ -69,6 +72,19 @@ impl std::fmt::Display for Error {
             Error::UnexpectedTag(b, (tag, format)) => {
                 write!(f, "Tag({}/{:?}) is unexpected\n{:?}", tag, format, b)
             }
+            Error::InvalidObjectIdentifier(text) => {
+                write!(
+                    f,
+                    "Expected a dotted object identifier but found {:?}",
+                    text
+                )
+            }
+            Error::InvalidTime(r#type, text) => write!(
+                f,
+                "Expected a {} value but found {:?}",
+                r#type.asn_name(),
+                text
+            ),
         }
     }
 }
@@ -126,6 +142,8 @@ pub trait ProtoWrite {
 
     fn write_sfixed32(&mut self, value: i32) -> Result<(), Error>;
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error>;
+
     fn write_uint32(&mut self, value: u32) -> Result<(), Error> {
         self.write_varint(u64::from(value))
     }
@@ -168,6 +186,11 @@ pub trait ProtoWrite {
         self.write_sfixed32(value)
     }
 
+    fn write_tagged_double(&mut self, field: u32, value: f64) -> Result<(), Error> {
+        self.write_tag(field, Format::Fixed64)?;
+        self.write_double(value)
+    }
+
     fn write_tagged_uint32(&mut self, field: u32, value: u32) -> Result<(), Error> {
         self.write_tag(field, Format::VarInt)?;
         self.write_uint32(value)
@@ -224,6 +247,11 @@ impl<W: Write> ProtoWrite for W {
         Ok(())
     }
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error> {
+        self.write_f64::<E>(value)?;
+        Ok(())
+    }
+
     fn write_string(&mut self, value: &str) -> Result<(), Error> {
         self.write_bytes(value.as_bytes())?;
         Ok(())
@@ -258,6 +286,8 @@ pub trait ProtoRead {
 
     fn read_sfixed32(&mut self) -> Result<i32, Error>;
 
+    fn read_double(&mut self) -> Result<f64, Error>;
+
     fn read_uint32(&mut self) -> Result<u32, Error> {
         Ok(self.read_varint()? as u32)
     }
@@ -308,6 +338,10 @@ impl<R: Read> ProtoRead for R {
         Ok(self.read_i32::<E>()?)
     }
 
+    fn read_double(&mut self) -> Result<f64, Error> {
+        Ok(self.read_f64::<E>()?)
+    }
+
     fn read_string(&mut self) -> Result<String, Error> {
         let bytes = self.read_bytes()?;
         if let Ok(string) = String::from_utf8(bytes) {
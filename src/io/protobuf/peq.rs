@@ -1,4 +1,7 @@
 use crate::syn::BitVec;
+use crate::syn::ObjectIdentifier;
+use crate::syn::RelativeOid;
+use crate::syn::{Date, DateTime, Duration, GeneralizedTime, TimeOfDay, UtcTime};
 
 /// In protobuf default-ish-values - such as '0' for numbers - might be serialized as `null`/`None`
 /// if this is possible in the current context. [`ProtobufEq`] will consider these values as equal
@@ -66,6 +69,54 @@ impl ProtobufEq<BitVec> for BitVec {
     }
 }
 
+impl ProtobufEq<ObjectIdentifier> for ObjectIdentifier {
+    fn protobuf_eq(&self, other: &ObjectIdentifier) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<RelativeOid> for RelativeOid {
+    fn protobuf_eq(&self, other: &RelativeOid) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<GeneralizedTime> for GeneralizedTime {
+    fn protobuf_eq(&self, other: &GeneralizedTime) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<UtcTime> for UtcTime {
+    fn protobuf_eq(&self, other: &UtcTime) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<Date> for Date {
+    fn protobuf_eq(&self, other: &Date) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<TimeOfDay> for TimeOfDay {
+    fn protobuf_eq(&self, other: &TimeOfDay) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<DateTime> for DateTime {
+    fn protobuf_eq(&self, other: &DateTime) -> bool {
+        self.eq(other)
+    }
+}
+
+impl ProtobufEq<Duration> for Duration {
+    fn protobuf_eq(&self, other: &Duration) -> bool {
+        self.eq(other)
+    }
+}
+
 impl ProtobufEq<bool> for bool {
     fn protobuf_eq(&self, other: &Self) -> bool {
         self == other
@@ -125,3 +176,9 @@ impl ProtobufEq<String> for String {
         self == other
     }
 }
+
+impl ProtobufEq<f64> for f64 {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
@@ -1,2 +1,5 @@
 pub mod bit_vec_impl;
+pub mod object_identifier_impl;
+pub mod open_type_impl;
+pub mod time_impl;
 pub mod unit_impl;
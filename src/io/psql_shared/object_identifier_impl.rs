@@ -0,0 +1,80 @@
+use crate::syn::objectidentifier::ObjectIdentifier;
+use crate::syn::relativeoid::RelativeOid;
+use bytes::BytesMut;
+use std::error::Error;
+
+#[cfg(feature = "psql")]
+use postgres::types::{FromSql, IsNull, ToSql, Type};
+
+#[cfg(all(feature = "async-psql", not(feature = "psql")))]
+use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+impl<'a> FromSql<'a> for ObjectIdentifier {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let text = <&str as FromSql>::from_sql(ty, raw)?;
+        Ok(text.parse()?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for ObjectIdentifier {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        <String as ToSql>::to_sql(&self.to_string(), ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        <String as ToSql>::accepts(ty)
+    }
+
+    fn to_sql_checked(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        <String as ToSql>::to_sql_checked(&self.to_string(), ty, out)
+    }
+}
+
+impl<'a> FromSql<'a> for RelativeOid {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let text = <&str as FromSql>::from_sql(ty, raw)?;
+        Ok(text.parse()?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for RelativeOid {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        <String as ToSql>::to_sql(&self.to_string(), ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        <String as ToSql>::accepts(ty)
+    }
+
+    fn to_sql_checked(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        <String as ToSql>::to_sql_checked(&self.to_string(), ty, out)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::syn::opentype::OpenType;
+use bytes::BytesMut;
+use std::error::Error;
+
+#[cfg(feature = "psql")]
+use postgres::types::{FromSql, IsNull, ToSql, Type};
+
+#[cfg(all(feature = "async-psql", not(feature = "psql")))]
+use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+impl<'a> FromSql<'a> for OpenType {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(OpenType::from(<Vec<u8> as FromSql>::from_sql(ty, raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Vec<u8> as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for OpenType {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        <&[u8] as ToSql>::to_sql(&self.as_bytes(), ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool
+    where
+        Self: Sized,
+    {
+        <&[u8] as ToSql>::accepts(ty)
+    }
+
+    fn to_sql_checked(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        <&[u8] as ToSql>::to_sql_checked(&self.as_bytes(), ty, out)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::syn::time::{Date, DateTime, Duration, GeneralizedTime, TimeOfDay, UtcTime};
+use bytes::BytesMut;
+use std::error::Error;
+
+#[cfg(feature = "psql")]
+use postgres::types::{FromSql, IsNull, ToSql, Type};
+
+#[cfg(all(feature = "async-psql", not(feature = "psql")))]
+use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+macro_rules! time_sql_impl {
+    ($($name:ident),*) => {
+        $(
+            impl<'a> FromSql<'a> for $name {
+                fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                    let text = <&str as FromSql>::from_sql(ty, raw)?;
+                    Ok(text.parse()?)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <&str as FromSql>::accepts(ty)
+                }
+            }
+
+            impl ToSql for $name {
+                fn to_sql(
+                    &self,
+                    ty: &Type,
+                    out: &mut BytesMut,
+                ) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+                where
+                    Self: Sized,
+                {
+                    <&str as ToSql>::to_sql(&self.as_str(), ty, out)
+                }
+
+                fn accepts(ty: &Type) -> bool
+                where
+                    Self: Sized,
+                {
+                    <&str as ToSql>::accepts(ty)
+                }
+
+                fn to_sql_checked(
+                    &self,
+                    ty: &Type,
+                    out: &mut BytesMut,
+                ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                    <&str as ToSql>::to_sql_checked(&self.as_str(), ty, out)
+                }
+            }
+        )*
+    };
+}
+
+time_sql_impl!(
+    GeneralizedTime,
+    UtcTime,
+    Date,
+    TimeOfDay,
+    DateTime,
+    Duration
+);
@@ -0,0 +1,135 @@
+use asn1rs_model::model::{Charset, TimeType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(pub(crate) Box<ErrorKind>);
+
+impl Error {
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_valid(charset: Charset, str: &str) -> Result<(), Self> {
+        match charset.find_invalid(str) {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::InvalidString(charset, char, index).into()),
+        }
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[cold]
+    #[inline(never)]
+    fn from(kind: ErrorKind) -> Self {
+        Self(Box::new(kind))
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding XER failed"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    InvalidString(Charset, char, usize),
+    InsufficientDataInSourceBuffer,
+    InvalidXml(usize),
+    InvalidNumber(String),
+    InvalidReal(String),
+    InvalidHexString,
+    InvalidBitString,
+    InvalidChoiceIndex(u64, u64),
+    InvalidOpenTypeKey(i64),
+    UnknownIdentifier(String),
+    ValueNotInRange(i64, i64, i64),
+    ValueExceedsMaxInt,
+    InvalidObjectIdentifier(String),
+    InvalidTime(TimeType, String),
+    MissingElement(&'static str),
+    UnexpectedElements(&'static str),
+    UnexpectedContent(&'static str),
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidString(charset, char, index) => {
+                write!(
+                    f,
+                    "Invalid character for a string with the charset {:?} at index {}: {}",
+                    charset, index, char
+                )
+            }
+            Self::InsufficientDataInSourceBuffer => write!(
+                f,
+                "There is insufficient data in the source buffer for this operation"
+            ),
+            Self::InvalidXml(position) => {
+                write!(f, "The source is not valid XML at position {}", position)
+            }
+            Self::InvalidNumber(text) => write!(f, "Expected an integer but found {:?}", text),
+            Self::InvalidReal(text) => write!(f, "Expected a real number but found {:?}", text),
+            Self::InvalidHexString => {
+                write!(f, "Expected a string of an even amount of hex digits")
+            }
+            Self::InvalidBitString => write!(f, "Expected a string of the digits 0 and 1"),
+            Self::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Self::InvalidOpenTypeKey(key) => {
+                write!(
+                    f,
+                    "There is no alternative of the open type for the key {}",
+                    key
+                )
+            }
+            Self::UnknownIdentifier(identifier) => write!(
+                f,
+                "There is no value or alternative with the identifier {}",
+                identifier
+            ),
+            Self::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Self::ValueExceedsMaxInt => {
+                write!(f, "The value exceeds the maximum supported integer size",)
+            }
+            Self::InvalidObjectIdentifier(text) => {
+                write!(
+                    f,
+                    "Expected a dotted object identifier but found {:?}",
+                    text
+                )
+            }
+            Self::InvalidTime(r#type, text) => write!(
+                f,
+                "Expected a {} value but found {:?}",
+                r#type.asn_name(),
+                text
+            ),
+            Self::MissingElement(name) => write!(f, "The element {} is missing", name),
+            Self::UnexpectedElements(name) => write!(
+                f,
+                "{} contains elements that are not part of its definition",
+                name
+            ),
+            Self::UnexpectedContent(name) => {
+                write!(f, "The content of the element is invalid for {}", name)
+            }
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! This module contains the building blocks of the XML Encoding Rules (ITU-T X.693 |
+//! ISO/IEC 8825-4): a minimal XML [`Element`] tree with its parsing and the escaping of text.
+//! The generic [`crate::syn::io::XerWriter`] and [`crate::syn::io::XerReader`] are built on top
+//! of them.
+
+pub mod err;
+
+pub use err::Error;
+pub use err::ErrorKind;
+
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Element {
+    pub name: String,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    /// The child elements, ignoring all text between them
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    pub fn into_elements(self) -> impl Iterator<Item = Element> {
+        self.children.into_iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// The concatenation of all text that is directly contained in this element
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(text) => Some(text.as_str()),
+                Node::Element(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Escapes the characters that must not appear literally in the content of an element
+pub fn write_text(out: &mut String, text: &str) {
+    for char in text.chars() {
+        match char {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            char if char < ' ' && char != '\n' && char != '\t' => {
+                let _ = write!(out, "&#x{:X};", char as u32);
+            }
+            char => out.push(char),
+        }
+    }
+}
+
+/// Writes the element with all of its children, using an empty-element tag if there are none
+pub fn write_element(out: &mut String, element: &Element) {
+    if element.children.is_empty() {
+        let _ = write!(out, "<{}/>", element.name);
+    } else {
+        let _ = write!(out, "<{}>", element.name);
+        for child in &element.children {
+            match child {
+                Node::Element(element) => write_element(out, element),
+                Node::Text(text) => write_text(out, text),
+            }
+        }
+        let _ = write!(out, "</{}>", element.name);
+    }
+}
+
+/// Parses one root element after the other from the source, skipping XML declarations,
+/// processing instructions, comments and document type declarations in between
+pub struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> From<&'a str> for Parser<'a> {
+    fn from(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+}
+
+impl Parser<'_> {
+    /// The amount of bytes that have not been parsed yet, excluding trailing whitespace and
+    /// markup other than elements
+    pub fn bytes_remaining(&mut self) -> usize {
+        let _ = self.skip_misc();
+        self.source.len() - self.pos
+    }
+
+    pub fn parse_element(&mut self) -> Result<Element, Error> {
+        self.skip_misc()?;
+        if self.pos >= self.source.len() {
+            return Err(ErrorKind::InsufficientDataInSourceBuffer.into());
+        }
+        self.parse_element_at()
+    }
+
+    #[inline]
+    fn rest(&self) -> &str {
+        &self.source[self.pos..]
+    }
+
+    #[cold]
+    fn invalid(&self) -> Error {
+        ErrorKind::InvalidXml(self.pos).into()
+    }
+
+    /// Skips everything up to and including the given terminator
+    fn skip_past(&mut self, terminator: &str) -> Result<(), Error> {
+        match self.rest().find(terminator) {
+            Some(index) => {
+                self.pos += index + terminator.len();
+                Ok(())
+            }
+            None => Err(self.invalid()),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, Error> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>' || c == '=')
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(self.invalid());
+        }
+        let name = rest[..end].to_string();
+        self.pos += end;
+        Ok(name)
+    }
+
+    fn parse_element_at(&mut self) -> Result<Element, Error> {
+        if !self.rest().starts_with('<') {
+            return Err(self.invalid());
+        }
+        self.pos += 1;
+        let name = self.parse_name()?;
+        if self.skip_attributes()? {
+            return Ok(Element {
+                name,
+                children: Vec::new(),
+            });
+        }
+
+        let mut children = Vec::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.pos += 2;
+                if self.parse_name()? != name {
+                    return Err(self.invalid());
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.invalid());
+                }
+                self.pos += 1;
+                return Ok(Element { name, children });
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let end = self.rest().find("]]>").ok_or_else(|| self.invalid())?;
+                children.push(Node::Text(self.rest()[..end].to_string()));
+                self.pos += end + "]]>".len();
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                children.push(Node::Element(self.parse_element_at()?));
+            } else if rest.is_empty() {
+                return Err(self.invalid());
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                let text = self.parse_text(end)?;
+                children.push(Node::Text(text));
+            }
+        }
+    }
+
+    /// Skips the attributes of a start tag and returns whether it is an empty-element tag
+    fn skip_attributes(&mut self) -> Result<bool, Error> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(true);
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok(false);
+            }
+            self.parse_name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.invalid());
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ '"') | Some(quote @ '\'') => quote,
+                _ => return Err(self.invalid()),
+            };
+            self.pos += 1;
+            match self.rest().find(quote) {
+                Some(end) => self.pos += end + 1,
+                None => return Err(self.invalid()),
+            }
+        }
+    }
+
+    /// Resolves the entity and character references in the text of the given length
+    fn parse_text(&mut self, length: usize) -> Result<String, Error> {
+        let end = self.pos + length;
+        let mut text = String::with_capacity(length);
+        while self.pos < end {
+            let rest = &self.source[self.pos..end];
+            let reference = rest.find('&').unwrap_or(rest.len());
+            text.push_str(&rest[..reference]);
+            self.pos += reference;
+            if self.pos < end {
+                let rest = &self.source[self.pos..end];
+                let semicolon = rest.find(';').ok_or_else(|| self.invalid())?;
+                text.push(self.resolve_reference(&rest[1..semicolon])?);
+                self.pos += semicolon + 1;
+            }
+        }
+        Ok(text)
+    }
+
+    fn resolve_reference(&self, reference: &str) -> Result<char, Error> {
+        let code = match reference {
+            "lt" => return Ok('<'),
+            "gt" => return Ok('>'),
+            "amp" => return Ok('&'),
+            "quot" => return Ok('"'),
+            "apos" => return Ok('\''),
+            _ if reference.starts_with("#x") => u32::from_str_radix(&reference[2..], 16).ok(),
+            _ if reference.starts_with('#') => reference[1..].parse().ok(),
+            _ => None,
+        };
+        code.and_then(char::from_u32).ok_or_else(|| self.invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(name: &str, children: Vec<Node>) -> Node {
+        Node::Element(Element {
+            name: name.to_string(),
+            children,
+        })
+    }
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.to_string())
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let mut parser = Parser::from(
+            "<?xml version=\"1.0\"?>\n<!-- comment -->\n<A x='1'>\n <b>1 &lt; 2 &#x26; &#51;</b><c/><d><![CDATA[<e>]]></d></A >\n",
+        );
+        let a = parser.parse_element().unwrap();
+        assert_eq!(0, parser.bytes_remaining());
+        assert_eq!("A", a.name);
+        assert_eq!(
+            vec![
+                text("\n "),
+                element("b", vec![text("1 < 2 & 3")]),
+                element("c", vec![]),
+                element("d", vec![text("<e>")]),
+            ],
+            a.children
+        );
+        assert_eq!(
+            vec!["b", "c", "d"],
+            a.elements().map(|e| e.name.as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid_xml() {
+        for (source, position) in [
+            ("<a></b>", 6),
+            ("<a>", 3),
+            ("<a x=1/>", 5),
+            ("<a>&unknown;</a>", 3),
+            ("text", 0),
+        ] {
+            assert_eq!(
+                &ErrorKind::InvalidXml(position),
+                Parser::from(source).parse_element().unwrap_err().kind(),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_text() {
+        let mut out = String::new();
+        write_text(&mut out, "a<b>&c\u{1}");
+        assert_eq!("a&lt;b&gt;&amp;c&#x1;", out);
+    }
+}
@@ -21,6 +21,8 @@ pub mod syn;
 #[cfg(feature = "model")]
 pub mod converter;
 #[cfg(feature = "model")]
+pub mod dynamic;
+#[cfg(feature = "model")]
 pub use asn1rs_model::ast;
 #[cfg(feature = "model")]
 pub use asn1rs_model::gen;
@@ -41,6 +41,7 @@ pub fn main() {
         cli::CONVERSION_TARGET_RUST => converter.to_rust(&params.destination_dir, |rust| {
             rust.set_fields_pub(!params.rust_fields_not_public);
             rust.set_fields_have_getter_and_setter(params.rust_getter_and_setter);
+            rust.set_unknown_extensions_kept(params.rust_keep_unknown_extensions);
         }),
         cli::CONVERSION_TARGET_PROTO => converter.to_protobuf(&params.destination_dir),
         cli::CONVERSION_TARGET_SQL => converter.to_sql(&params.destination_dir),
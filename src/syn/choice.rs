@@ -1,10 +1,12 @@
-use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use crate::syn::{OpenType, ReadableType, Reader, WritableType, Writer};
 use core::marker::PhantomData;
 
 pub struct Choice<C: Constraint>(PhantomData<C>);
 
 pub trait Constraint: super::common::Constraint + Sized {
     const NAME: &'static str;
+    /// The ASN.1 identifiers of the variants, indexed by their choice-index
+    const VARIANT_NAMES: &'static [&'static str];
     const VARIANT_COUNT: u64;
     const STD_VARIANT_COUNT: u64;
     const EXTENSIBLE: bool = false;
@@ -14,6 +16,20 @@ pub trait Constraint: super::common::Constraint + Sized {
     fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
 
     fn read_content<R: Reader>(index: u64, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    /// The complete encoding of an extension alternative that is unknown to this type and was
+    /// kept by [`Constraint::from_unknown_extension`] when the value was read
+    #[inline]
+    fn unknown_extension(&self) -> Option<&OpenType> {
+        None
+    }
+
+    /// Keeps the complete encoding of the extension alternative with the given choice-index,
+    /// which was added by a newer version of this type, if this type is able to keep it
+    #[inline]
+    fn from_unknown_extension(_index: u64, _content: OpenType) -> Option<Self> {
+        None
+    }
 }
 
 impl<C: Constraint> WritableType for Choice<C> {
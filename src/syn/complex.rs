@@ -3,7 +3,11 @@ use core::marker::PhantomData;
 
 pub struct Complex<V, T: Constraint>(PhantomData<T>, PhantomData<V>);
 
-pub trait Constraint: super::common::Constraint {}
+pub trait Constraint: super::common::Constraint {
+    /// Whether the field has no tag of its own, in which case `TAG` is the tag of the referenced
+    /// type and must not be treated as an additional tag
+    const UNTAGGED: bool = false;
+}
 
 impl<V: Writable, C: Constraint> WritableType for Complex<V, C> {
     type Type = V;
@@ -13,7 +17,7 @@ impl<V: Writable, C: Constraint> WritableType for Complex<V, C> {
         writer: &mut W,
         value: &Self::Type,
     ) -> Result<(), <W as Writer>::Error> {
-        value.write(writer)
+        writer.write_complex::<C, _>(|w| value.write(w))
     }
 }
 
@@ -22,6 +26,6 @@ impl<V: Readable, C: Constraint> ReadableType for Complex<V, C> {
 
     #[inline]
     fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
-        V::read(reader)
+        reader.read_complex::<C, V, _>(V::read)
     }
 }
@@ -5,6 +5,10 @@ pub struct Enumerated<C: Constraint>(PhantomData<C>);
 
 pub trait Constraint: super::common::Constraint + Sized {
     const NAME: &'static str;
+    /// The ASN.1 identifiers of the variants, indexed by their choice-index
+    const VARIANT_NAMES: &'static [&'static str];
+    /// The values of the variants in the ASN.1 definition, indexed by their choice-index
+    const VARIANT_VALUES: &'static [i64];
     const VARIANT_COUNT: u64;
     const STD_VARIANT_COUNT: u64;
     const EXTENSIBLE: bool = false;
@@ -12,6 +16,17 @@ pub trait Constraint: super::common::Constraint + Sized {
     fn to_choice_index(&self) -> u64;
 
     fn from_choice_index(index: u64) -> Option<Self>;
+
+    /// The value of the variant in the ASN.1 definition, which some encoding rules encode instead
+    /// of the choice-index
+    fn to_value(&self) -> i64 {
+        Self::VARIANT_VALUES[self.to_choice_index() as usize]
+    }
+
+    fn from_value(value: i64) -> Option<Self> {
+        let index = Self::VARIANT_VALUES.iter().position(|v| *v == value)?;
+        Self::from_choice_index(index as u64)
+    }
 }
 
 impl<C: Constraint> WritableType for Enumerated<C> {
@@ -0,0 +1,29 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A value with an explicit tag, which is encoded around the (unchanged) encoding of the value
+/// itself by encodings that transmit tags
+pub struct Explicit<T, C: Constraint>(PhantomData<T>, PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {}
+
+impl<T: WritableType, C: Constraint> WritableType for Explicit<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_explicit::<C, _>(|w| T::write_value(w, value))
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for Explicit<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_explicit::<C, T::Type, _>(T::read_value)
+    }
+}
@@ -0,0 +1,1529 @@
+use crate::io::per::aligned::Aligned;
+use crate::io::per::aligned::MAX_UNALIGNED_FIXED_SIZE_BITS;
+use crate::io::per::alphabet::EffectiveAlphabet;
+use crate::io::per::err::Error;
+use crate::io::per::err::ErrorKind;
+use crate::io::per::unaligned::buffer::BitBuffer;
+use crate::io::per::unaligned::BitWrite;
+use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::PackedRead;
+use crate::io::per::PackedWrite;
+use crate::model::Charset;
+use crate::model::TimeType;
+use crate::syn::io::uper::Scope;
+#[cfg(feature = "descriptive-deserialize-errors")]
+use crate::syn::io::uper::ScopeDescription;
+use crate::syn::io::uper::{Bits, ScopedBitRead};
+use crate::syn::*;
+
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.3, in the ALIGNED variant the number of bits
+/// per character is rounded up to the next power of two
+const BITS_PER_CHAR_VISIBLE: usize = 8;
+const BITS_PER_CHAR_NUMERIC: usize = 4;
+const BITS_PER_CHAR_BMP: usize = 16;
+const BITS_PER_CHAR_UNIVERSAL: usize = 32;
+
+/// The characters of the known-multiplier strings are passed as whole big-endian octets
+#[inline]
+const fn octets_per_char(bits_per_char: usize) -> usize {
+    if bits_per_char > BYTE_LEN {
+        bits_per_char / BYTE_LEN
+    } else {
+        1
+    }
+}
+
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.7, a character string is octet-aligned unless
+/// its upper bound guarantees that it never exceeds 16 bits
+#[inline]
+fn is_known_multiplier_string_aligned(max: Option<u64>, bits_per_char: usize) -> bool {
+    max.is_none_or(|max| max.saturating_mul(bits_per_char as u64) > MAX_UNALIGNED_FIXED_SIZE_BITS)
+}
+
+#[derive(Default)]
+pub struct AperWriter {
+    bits: Aligned<BitBuffer>,
+    scope: Option<Scope>,
+}
+
+impl AperWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            bits: Aligned::from(BitBuffer::with_capacity(capacity_bytes)),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        self.bits.content()
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bits.bit_len()
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.bits.into_inner().into()
+    }
+
+    pub fn as_reader(&self) -> AperReader<Bits<'_>> {
+        AperReader::from(Bits::from((self.byte_content(), self.bit_len())))
+    }
+
+    #[inline]
+    pub fn scope_pushed<T, E, F: FnOnce(&mut Self) -> Result<T, E>>(
+        &mut self,
+        scope: Scope,
+        f: F,
+    ) -> Result<T, E> {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        if cfg!(debug_assertions) && result.is_ok() {
+            let scope = core::mem::replace(&mut self.scope, original);
+            // call to .unwrap() is save because this is supposed to be the original from above
+            debug_assert!(
+                scope.clone().unwrap().exhausted(),
+                "Not exhausted: {:?}",
+                scope.unwrap()
+            );
+        } else {
+            self.scope = original;
+        }
+        result
+    }
+
+    #[inline]
+    pub fn scope_stashed<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    #[inline]
+    pub fn write_bit_field_entry(&mut self, is_opt: bool, is_present: bool) -> Result<(), Error> {
+        if let Some(scope) = &mut self.scope {
+            // The presence bit-field of extension additions is never octet-aligned, only its
+            // length for more than 64 extension additions would be (11.6.2)
+            scope.write_into_field(&mut *self.bits, is_opt, is_present)
+        } else if is_opt {
+            self.bits.write_bit(is_present)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.2, open type fields are octet-aligned and
+    /// their content is encoded as a separate, complete encoding
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        if const_map_or!(self.scope, Scope::encode_as_open_type_field, false) {
+            let mut writer = AperWriter::with_capacity(512);
+            let result = f(&mut writer)?;
+            self.bits
+                .write_octetstring(None, None, false, writer.bits.content())?;
+            Ok(result)
+        } else {
+            f(self)
+        }
+    }
+
+    /// Like [`UperWriter`], writes a sequence whose extension additions are followed by the given
+    /// ones that are unknown to its type
+    #[inline]
+    fn write_sequence_extended_by<
+        C: sequence::Constraint,
+        F: Fn(&mut Self) -> Result<(), Error>,
+    >(
+        &mut self,
+        unknown: &[Option<OpenType>],
+        f: F,
+    ) -> Result<(), Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            let extension = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
+                let bit_pos = w.bits.write_position;
+                // if no extension field is present, none will call into overwriting this
+                w.bits.write_bit(false)?;
+                Some((extension_after, bit_pos))
+            } else {
+                None
+            };
+
+            // As in UPER, the values for all OPTIONAL flags are written as bit-field (without
+            // padding) before any field value is written. This remembers their position, so a
+            // later call of `write_opt` can write them to the buffer
+            let write_pos = w.bits.write_position;
+            let range = write_pos..write_pos + C::STD_OPTIONAL_FIELDS as usize;
+            for _ in 0..C::STD_OPTIONAL_FIELDS {
+                if let Err(e) = w.bits.write_bit(false) {
+                    w.bits.write_position = write_pos; // undo write_bits
+                    return Err(e);
+                }
+            }
+
+            if let Some((extension_after, bit_pos)) = extension {
+                w.scope_pushed(
+                    Scope::ExtensibleSequence {
+                        name: C::NAME,
+                        bit_pos,
+                        opt_bit_field: Some(range),
+                        calls_until_ext_bitfield: (extension_after + 1) as usize,
+                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize
+                            + unknown.len(),
+                        unknown_ext_fields_present: unknown.iter().any(Option::is_some),
+                    },
+                    |w| {
+                        f(w)?;
+                        w.write_unknown_ext_fields(unknown)
+                    },
+                )
+            } else {
+                w.scope_pushed(Scope::OptBitField(range), f)
+            }
+        })
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, 19.9, the extension additions unknown to the type are
+    /// written as the octet-aligned open types they were read as
+    #[inline]
+    fn write_unknown_ext_fields(&mut self, unknown: &[Option<OpenType>]) -> Result<(), Error> {
+        for addition in unknown {
+            self.write_bit_field_entry(true, addition.is_some())?;
+            if let Some(addition) = addition {
+                self.bits
+                    .write_octetstring(None, None, false, addition.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn write_extensible_bit_and_length_or_err(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        upper_limit: u64,
+        len: u64,
+    ) -> Result<bool, Error> {
+        let unwrapped_min = const_unwrap_or!(min, 0);
+        let unwrapped_max = const_unwrap_or!(max, upper_limit);
+        let out_of_range = len < unwrapped_min || len > unwrapped_max;
+
+        if extensible {
+            self.bits.write_bit(out_of_range)?;
+        }
+
+        if out_of_range {
+            if !extensible {
+                return Err(ErrorKind::SizeNotInRange(len, unwrapped_min, unwrapped_max).into());
+            } else {
+                self.bits.write_length_determinant(None, None, len)?;
+            }
+        } else {
+            self.bits.write_length_determinant(min, max, len)?;
+        }
+
+        Ok(out_of_range)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5, `chars` holds each character in as many
+    /// octets as needed for `bits_per_char`
+    #[inline]
+    pub fn write_known_multiplier_string(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        bits_per_char: usize,
+        chars: &[u8],
+    ) -> Result<(), Error> {
+        let octets_per_char = octets_per_char(bits_per_char);
+        let out_of_range = self.write_extensible_bit_and_length_or_err(
+            extensible,
+            min,
+            max,
+            u64::MAX,
+            (chars.len() / octets_per_char) as u64,
+        )?;
+
+        if !chars.is_empty()
+            && (out_of_range || is_known_multiplier_string_aligned(max, bits_per_char))
+        {
+            self.bits.write_padding()?;
+        }
+
+        for char in chars.chunks(octets_per_char) {
+            self.bits
+                .write_bits_with_offset(char, octets_per_char * BYTE_LEN - bits_per_char)?;
+        }
+
+        Ok(())
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5, `BMPString` and `UniversalString` in
+    /// their full width of 16 and 32 bits per character
+    #[inline]
+    fn write_wide_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        let bits_per_char = match charset {
+            Charset::Bmp => BITS_PER_CHAR_BMP,
+            _ => BITS_PER_CHAR_UNIVERSAL,
+        };
+        let octets = charset.encode_octets(value);
+        self.write_known_multiplier_string(extensible, min, max, bits_per_char, &octets)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.4, the characters of a string that is
+    /// constrained through `FROM` are replaced by their values in the permitted alphabet
+    #[inline]
+    fn write_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: EffectiveAlphabet,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        let octets_per_char = octets_per_char(alphabet.bits_per_char());
+        let mut chars = Vec::with_capacity(value.len() * octets_per_char);
+        for (index, char) in value.chars().enumerate() {
+            let value = alphabet
+                .encode(char)
+                .ok_or(ErrorKind::InvalidString(charset, char, index))?;
+            chars.extend_from_slice(&value.to_be_bytes()[4 - octets_per_char..]);
+        }
+        self.write_known_multiplier_string(extensible, min, max, alphabet.bits_per_char(), &chars)
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.6, the character strings that are not
+    /// known-multiplier are encoded like an unconstrained `OCTET STRING`
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_octet_string_of_chars(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        if !extensible {
+            let chars = value.chars().count() as u64;
+            let min = const_unwrap_or!(min, 0);
+            let max = const_unwrap_or!(max, u64::MAX);
+            if chars < min || chars > max {
+                return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+            }
+        }
+        self.bits
+            .write_octetstring(None, None, false, &charset.encode_octets(value))
+    }
+}
+
+impl Writer for AperWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_extended_by::<C, F>(&[], f)
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.scope_stashed(|w| {
+            w.write_extensible_bit_and_length_or_err(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                i64::MAX as u64,
+                slice.len() as u64,
+            )?;
+
+            w.scope_stashed(|w| {
+                for value in slice {
+                    T::write_value(w, value)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[<T as WritableType>::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_sequence_with_unknown_extensions<
+        C: sequence::Constraint,
+        F: Fn(&mut Self) -> Result<(), Self::Error>,
+    >(
+        &mut self,
+        unknown: &UnknownExtensions,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_extended_by::<C, F>(unknown.additions(), f)
+    }
+
+    #[inline]
+    fn write_set_with_unknown_extensions<
+        C: set::Constraint,
+        F: Fn(&mut Self) -> Result<(), Self::Error>,
+    >(
+        &mut self,
+        unknown: &UnknownExtensions,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_extended_by::<C, F>(unknown.additions(), f)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits.write_enumeration_index(
+                C::STD_VARIANT_COUNT,
+                C::EXTENSIBLE,
+                enumerated.to_choice_index(),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.scope_stashed(|w| {
+            let index = choice.to_choice_index();
+
+            // this fails if the index is out of range
+            w.bits
+                .write_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE, index)?;
+
+            if let Some(content) = choice.unknown_extension() {
+                w.bits
+                    .write_octetstring(None, None, false, content.as_bytes())
+            } else if index >= C::STD_VARIANT_COUNT {
+                let mut writer = AperWriter::with_capacity(512);
+                choice.write_content(&mut writer)?;
+                w.bits
+                    .write_octetstring(None, None, false, writer.byte_content())
+            } else {
+                choice.write_content(w)
+            }
+        })
+    }
+
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.scope_stashed(|w| {
+                // ITU-T X.691 | ISO/IEC 8825-2, 11.2, the complete encoding as octet string
+                let mut writer = AperWriter::with_capacity(512);
+                choice.write_content(&mut writer)?;
+                if writer.bit_len() == 0 {
+                    w.bits.write_octetstring(None, None, false, &[0x00])
+                } else {
+                    w.bits
+                        .write_octetstring(None, None, false, writer.byte_content())
+                }
+            })
+        })
+    }
+
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        _choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, value))
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(true, const_is_some!(value))?;
+        if let Some(value) = value {
+            self.with_buffer(|w| w.scope_stashed(|w| T::write_value(w, value)))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        let present = C::DEFAULT_VALUE.ne(value);
+        self.write_bit_field_entry(true, present)?;
+        if present {
+            self.scope_stashed(|w| T::write_value(w, value))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        let value = value.to_i64();
+
+        let out_of_range = C::EXTENSIBLE && {
+            let min = const_unwrap_or!(C::MIN, i64::MIN);
+            let max = const_unwrap_or!(C::MAX, i64::MAX);
+            value < min || value > max
+        };
+
+        self.with_buffer(|w| {
+            if C::EXTENSIBLE {
+                w.bits.write_bit(out_of_range)?;
+            }
+
+            match (C::MIN, C::MAX) {
+                (Some(min), Some(max)) if !out_of_range => {
+                    w.bits.write_constrained_whole_number(min, max, value)
+                }
+                (Some(min), None) if !out_of_range => {
+                    w.bits.write_semi_constrained_whole_number(min, value)
+                }
+                _ => w.bits.write_unconstrained_whole_number(value),
+            }
+        })
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_real(value))
+    }
+
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if !C::EXTENSIBLE {
+                let chars = value.chars().count() as u64;
+                let min = const_unwrap_or!(C::MIN, 0);
+                let max = const_unwrap_or!(C::MAX, u64::MAX);
+                if chars < min || chars > max {
+                    return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+                }
+            }
+
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            w.bits
+                .write_octetstring(None, None, false, value.as_bytes())
+        })
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            Error::ensure_string_valid(Charset::Ia5, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_VISIBLE,
+                value.as_bytes(),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            Error::ensure_string_valid(Charset::Numeric, value)?;
+            let chars = value
+                .chars()
+                .map(|c| match c as u8 - 32 {
+                    0 => 0,
+                    c => c - 15,
+                })
+                .collect::<Vec<u8>>();
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_NUMERIC,
+                &chars,
+            )
+        })
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            Error::ensure_string_valid(Charset::Printable, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_VISIBLE,
+                value.as_bytes(),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            Error::ensure_string_valid(Charset::Visible, value)?;
+            w.write_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_VISIBLE,
+                value.as_bytes(),
+            )
+        })
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            w.write_wide_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            w.write_wide_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_octet_string_of_chars(Charset::Teletex, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_octet_string_of_chars(Charset::Graphic, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_octet_string_of_chars(Charset::General, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_octetstring(C::MIN, C::MAX, C::EXTENSIBLE, value)
+        })
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.bits
+                .write_bitstring(C::MIN, C::MAX, C::EXTENSIBLE, value, 0, bit_len)
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_boolean(value))
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_object_identifier(value))
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_relative_oid(value))
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        _type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            // encoded as an unconstrained VisibleString
+            Error::ensure_string_valid(Charset::Visible, value)?;
+            w.write_known_multiplier_string(
+                false,
+                None,
+                None,
+                BITS_PER_CHAR_VISIBLE,
+                value.as_bytes(),
+            )
+        })
+    }
+}
+
+pub struct AperReader<B: ScopedBitRead> {
+    bits: Aligned<B>,
+    scope: Option<Scope>,
+    #[cfg(feature = "descriptive-deserialize-errors")]
+    scope_description: Vec<ScopeDescription>,
+}
+
+impl<'a, I: Into<Bits<'a>>> From<I> for AperReader<Bits<'a>> {
+    fn from(bits: I) -> Self {
+        Self {
+            bits: Aligned::from(bits.into()),
+            scope: None,
+            #[cfg(feature = "descriptive-deserialize-errors")]
+            scope_description: Vec::new(),
+        }
+    }
+}
+
+impl<B: ScopedBitRead> AperReader<B> {
+    #[inline]
+    pub fn bits_remaining(&self) -> usize {
+        self.bits.remaining()
+    }
+
+    #[inline]
+    pub fn scope_pushed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        scope: Scope,
+        f: F,
+    ) -> Result<T, Error> {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        if cfg!(debug_assertions) && result.is_ok() {
+            let scope = core::mem::replace(&mut self.scope, original);
+            // call to .unwrap() is save because this is supposed to be the original from above
+            debug_assert!(
+                scope.clone().unwrap().exhausted(),
+                "Not exhausted: {:?}",
+                scope.unwrap()
+            );
+        } else {
+            self.scope = original;
+        }
+        result
+    }
+
+    #[inline]
+    pub fn scope_stashed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    #[inline]
+    pub fn read_whole_sub_slice<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        length_bytes: usize,
+        f: F,
+    ) -> Result<T, Error> {
+        let end_position = self.bits.pos() + (length_bytes * BYTE_LEN);
+        if end_position > self.bits.len() {
+            return Err(ErrorKind::EndOfStream.into());
+        }
+        let original_len = self.bits.len();
+        self.bits.set_len(end_position);
+        let result = f(self);
+        // extend to original position
+        self.bits.set_len(original_len);
+        if result.is_ok() {
+            // on successful read, skip the slice
+            self.bits.set_pos(end_position);
+        }
+        result
+    }
+
+    #[inline]
+    pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
+        if let Some(scope) = &mut self.scope {
+            scope.read_from_field(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut self.scope_description,
+                &mut *self.bits,
+                is_opt,
+            )
+        } else if is_opt {
+            Some(self.bits.read_bit()).transpose()
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    pub fn with_buffer<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        if self
+            .scope
+            .as_ref()
+            .map(Scope::encode_as_open_type_field)
+            .unwrap_or(false)
+        {
+            let len = self.bits.read_length_determinant(None, None)?;
+            self.bits.skip_padding()?;
+            self.read_whole_sub_slice(len as usize, f)
+        } else {
+            f(self)
+        }
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5
+    #[inline]
+    pub fn read_known_multiplier_string(
+        &mut self,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        bits_per_char: usize,
+    ) -> Result<Vec<u8>, Error> {
+        let out_of_range = extensible && self.bits.read_bit()?;
+        let len = if out_of_range {
+            self.bits.read_length_determinant(None, None)?
+        } else {
+            self.bits.read_length_determinant(min, max)?
+        };
+
+        if len > 0 && (out_of_range || is_known_multiplier_string_aligned(max, bits_per_char)) {
+            self.bits.skip_padding()?;
+        }
+
+        let octets_per_char = octets_per_char(bits_per_char);
+        let mut buffer = vec![0u8; len as usize * octets_per_char];
+        buffer
+            .chunks_exact_mut(octets_per_char)
+            .try_for_each(|chunk| {
+                self.bits
+                    .read_bits_with_offset(chunk, octets_per_char * BYTE_LEN - bits_per_char)
+            })?;
+        Ok(buffer)
+    }
+
+    #[inline]
+    fn read_wide_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let bits_per_char = match charset {
+            Charset::Bmp => BITS_PER_CHAR_BMP,
+            _ => BITS_PER_CHAR_UNIVERSAL,
+        };
+        let buffer = self.read_known_multiplier_string(extensible, min, max, bits_per_char)?;
+        Error::decode_string(charset, buffer)
+    }
+
+    #[inline]
+    fn read_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: EffectiveAlphabet,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let bits_per_char = alphabet.bits_per_char();
+        let buffer = self.read_known_multiplier_string(extensible, min, max, bits_per_char)?;
+        buffer
+            .chunks_exact(octets_per_char(bits_per_char))
+            .map(|octets| {
+                let value = octets
+                    .iter()
+                    .fold(0_u32, |value, octet| value << BYTE_LEN | u32::from(*octet));
+                alphabet
+                    .decode(value)
+                    .ok_or_else(|| ErrorKind::InvalidStringEncoding(charset).into())
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn read_octet_string_of_chars(&mut self, charset: Charset) -> Result<String, Error> {
+        let octets = self.bits.read_octetstring(None, None, false)?;
+        let string = Error::decode_string(charset, octets)?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+}
+
+impl<B: ScopedBitRead> Reader for AperReader<B> {
+    type Error = Error;
+
+    #[inline]
+    fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+    {
+        #[allow(clippy::let_and_return)]
+        let value = T::read(self);
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        let value = value.map_err(|mut e| {
+            e.0.description = core::mem::take(&mut self.scope_description);
+            e
+        });
+        value
+    }
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let extension_after = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
+                let bit_pos = r.bits.pos();
+                if r.bits.read_bit()? {
+                    Some((extension_after, bit_pos))
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            // As in UPER, the values for all OPTIONAL flags are written before any field
+            // value is written. This remembers their position, so a later call of `read_opt`
+            // can retrieve them from the buffer
+            if r.bits.remaining() < C::STD_OPTIONAL_FIELDS as usize {
+                return Err(ErrorKind::EndOfStream.into());
+            }
+
+            let range = r.bits.pos()..r.bits.pos() + C::STD_OPTIONAL_FIELDS as usize;
+            r.bits.set_pos(range.end); // skip optional
+
+            if let Some((extension_after, bit_pos)) = extension_after {
+                r.scope_pushed(
+                    Scope::ExtensibleSequence {
+                        name: C::NAME,
+                        bit_pos,
+                        opt_bit_field: Some(range),
+                        calls_until_ext_bitfield: (extension_after + 1) as usize,
+                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        unknown_ext_fields_present: false,
+                    },
+                    |r| {
+                        let value = f(r)?;
+                        // skips the extension additions a type without the field
+                        // `unknown_extensions` does not keep
+                        r.read_unknown_extensions::<C>()?;
+                        Ok(value)
+                    },
+                )
+            } else {
+                r.scope_pushed(Scope::OptBitField(range), f)
+            }
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let len = if C::EXTENSIBLE && r.bits.read_bit()? {
+                r.bits.read_length_determinant(None, None)?
+            } else {
+                r.bits.read_length_determinant(C::MIN, C::MAX)?
+            };
+
+            if len > 0 {
+                r.scope_stashed(|r| {
+                    let mut vec = Vec::with_capacity(len as usize);
+                    for _ in 0..len {
+                        vec.push(T::read_value(r)?);
+                    }
+                    Ok(vec)
+                })
+            } else {
+                Ok(Vec::new())
+            }
+        })
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_unknown_extensions<C: sequence::Constraint>(
+        &mut self,
+    ) -> Result<UnknownExtensions, Self::Error> {
+        // without known extension additions, their presence flags were not read yet
+        if let Some(scope) = &mut self.scope {
+            scope.read_ext_bit_field(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut self.scope_description,
+                &mut *self.bits,
+            )?;
+        }
+
+        let mut additions = Vec::new();
+        while const_map_or!(self.scope, Scope::has_remaining_ext_bits, false) {
+            if self.read_bit_field_entry(true)? == Some(true) {
+                let content = self.bits.read_octetstring(None, None, false)?;
+                additions.push(Some(OpenType::from(content)));
+            } else {
+                additions.push(None);
+            }
+        }
+        Ok(UnknownExtensions::from(additions))
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.bits
+                .read_enumeration_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)
+        })
+        .and_then(|index| {
+            C::from_choice_index(index)
+                .ok_or_else(|| ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into())
+        })
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.scope_stashed(|r| {
+            let index = r
+                .bits
+                .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
+            if index >= C::VARIANT_COUNT {
+                let content = OpenType::from(r.bits.read_octetstring(None, None, false)?);
+                Ok((index, C::from_unknown_extension(index, content)))
+            } else if index >= C::STD_VARIANT_COUNT {
+                let length = r.bits.read_length_determinant(None, None)?;
+                r.bits.skip_padding()?;
+                r.read_whole_sub_slice(length as usize, |r| Ok((index, C::read_content(index, r)?)))
+            } else {
+                Ok((index, C::read_content(index, r)?))
+            }
+            .and_then(|(index, content)| {
+                content.ok_or_else(|| ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into())
+            })
+        })
+    }
+
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            r.scope_stashed(|r| {
+                let index = C::index_of_key(key).ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+                let length = r.bits.read_length_determinant(None, None)?;
+                r.bits.skip_padding()?;
+                r.read_whole_sub_slice(length as usize, |r| C::read_content(index, r))?
+                    .ok_or_else(|| ErrorKind::InvalidOpenTypeKey(key).into())
+            })
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_octetstring(None, None, false))
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        // unwrap: as opt-field this must and will return some value
+        if self.read_bit_field_entry(true)?.unwrap() {
+            self.with_buffer(|w| w.scope_stashed(T::read_value))
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        // unwrap: as opt-field this must and will return some value
+        if self.read_bit_field_entry(true)?.unwrap() {
+            self.scope_stashed(T::read_value)
+        } else {
+            Ok(C::DEFAULT_VALUE.to_owned())
+        }
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let out_of_range = C::EXTENSIBLE && r.bits.read_bit()?;
+
+            match (C::MIN, C::MAX) {
+                (Some(min), Some(max)) if !out_of_range => {
+                    r.bits.read_constrained_whole_number(min, max)
+                }
+                (Some(min), None) if !out_of_range => {
+                    r.bits.read_semi_constrained_whole_number(min)
+                }
+                _ => r.bits.read_unconstrained_whole_number(),
+            }
+            .map(T::from_i64)
+        })
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_real())
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
+            // For 'known-multiplier character string types' there is no min/max in the encoding
+            let octets = r.bits.read_octetstring(None, None, false)?;
+            String::from_utf8(octets).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            let buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_VISIBLE,
+            )?;
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            let mut buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_NUMERIC,
+            )?;
+            for char in buffer.iter_mut() {
+                match *char {
+                    0_u8 => *char = 32_u8,
+                    c => *char = 32_u8 + 15 + c,
+                }
+            }
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            let buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_VISIBLE,
+            )?;
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            let buffer = r.read_known_multiplier_string(
+                C::EXTENSIBLE,
+                C::MIN,
+                C::MAX,
+                BITS_PER_CHAR_VISIBLE,
+            )?;
+            String::from_utf8(buffer).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+        })
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            r.read_wide_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX)
+        })
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, true) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            r.read_wide_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX)
+        })
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.read_octet_string_of_chars(Charset::Teletex))
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.read_octet_string_of_chars(Charset::Graphic))
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.read_octet_string_of_chars(Charset::General))
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_octetstring(C::MIN, C::MAX, C::EXTENSIBLE))
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_bitstring(C::MIN, C::MAX, C::EXTENSIBLE))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_boolean())
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        Ok(Null)
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_object_identifier())
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| r.bits.read_relative_oid())
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let buffer =
+                r.read_known_multiplier_string(false, None, None, BITS_PER_CHAR_VISIBLE)?;
+            let string =
+                String::from_utf8(buffer).map_err(|e| Error::from(ErrorKind::FromUtf8Error(e)))?;
+            if time::is_valid(r#type, &string) {
+                Ok(string)
+            } else {
+                Err(ErrorKind::InvalidTime(r#type, string).into())
+            }
+        })
+    }
+}
+
+pub trait AperDecodable<'a, I: Into<Bits<'a>> + 'a> {
+    fn decode_from_aper(bits: I) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<'a, R: Readable, I: Into<Bits<'a>> + 'a> AperDecodable<'a, I> for R {
+    fn decode_from_aper(bits: I) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = AperReader::from(bits);
+        Self::read(&mut reader)
+    }
+}
@@ -0,0 +1,1179 @@
+use crate::io::ber::err::Error;
+use crate::io::ber::err::ErrorKind;
+use crate::io::ber::{
+    read_header, read_identifier, read_integer, read_object_identifier, read_real,
+    read_relative_oid, write_identifier, write_integer, write_length, write_object_identifier,
+    write_real, write_relative_oid, END_OF_CONTENTS, INDEFINITE_LENGTH,
+};
+use crate::syn::*;
+use asn1rs_model::model::Charset;
+use asn1rs_model::model::Tag;
+use asn1rs_model::model::TimeType;
+
+/// ITU-T X.690 | ISO/IEC 8825-1, 8.6.2.2, the initial octet of a BIT STRING counts the unused
+/// bits of the final octet
+const MAX_UNUSED_BITS: u8 = 7;
+
+/// Writes values in the Basic Encoding Rules (ITU-T X.690 | ISO/IEC 8825-1). The tag of each
+/// value is taken from its [`common::Constraint::TAG`], which is overwritten by the tag of a
+/// referencing field (implicit tagging). Because tags on a `CHOICE` are always explicit, a tagged
+/// `CHOICE` field is wrapped in a constructed encoding with the tag of the field.
+///
+/// A writer created by [`BerWriter::distinguished`] produces the canonical form of the
+/// Distinguished Encoding Rules (DER) instead.
+#[derive(Default)]
+pub struct BerWriter {
+    buffer: Vec<u8>,
+    pending_tag: Option<Tag>,
+    indefinite_length: bool,
+    segment_size: Option<usize>,
+    distinguished: bool,
+}
+
+impl BerWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a writer for the Distinguished Encoding Rules (ITU-T X.690 | ISO/IEC 8825-1, 10
+    /// and 11). In addition to the minimal definite lengths and the omitted `DEFAULT` values that
+    /// every [`BerWriter`] produces, the components of a `SET` are ordered by their tags and the
+    /// elements of a `SET OF` by their encodings.
+    pub fn distinguished() -> Self {
+        Self {
+            distinguished: true,
+            ..Default::default()
+        }
+    }
+
+    /// Use the indefinite length form (ITU-T X.690 | ISO/IEC 8825-1, 8.1.3.6) for all constructed
+    /// encodings instead of the definite length form
+    ///
+    /// # Panics
+    ///
+    /// If this writer is [`BerWriter::distinguished`], which only allows the definite length form
+    pub fn with_indefinite_length(mut self) -> Self {
+        assert!(
+            !self.distinguished,
+            "DER does not allow the indefinite length form"
+        );
+        self.indefinite_length = true;
+        self
+    }
+
+    /// Use the constructed encoding (ITU-T X.690 | ISO/IEC 8825-1, 8.6.3, 8.7.3 and 8.23.6) for
+    /// BIT STRINGs, OCTET STRINGs and character strings with more than `segment_size` octets,
+    /// splitting the content into segments of up to `segment_size` octets
+    ///
+    /// # Panics
+    ///
+    /// If `segment_size` is zero or if this writer is [`BerWriter::distinguished`], which only
+    /// allows the primitive encoding of strings
+    pub fn with_constructed_strings(mut self, segment_size: usize) -> Self {
+        assert!(segment_size > 0, "The segment size must not be zero");
+        assert!(
+            !self.distinguished,
+            "DER does not allow the constructed encoding of strings"
+        );
+        self.segment_size = Some(segment_size);
+        self
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer[..]
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> BerReader<'_> {
+        BerReader::from(self.byte_content())
+    }
+
+    #[inline]
+    fn take_tag(&mut self, tag: Tag) -> Tag {
+        self.pending_tag.take().unwrap_or(tag)
+    }
+
+    pub fn write_primitive(&mut self, tag: Tag, content: &[u8]) {
+        write_identifier(&mut self.buffer, tag, false);
+        write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(content);
+    }
+
+    pub fn write_constructed<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<(), Error> {
+        write_identifier(&mut self.buffer, tag, true);
+        if self.indefinite_length {
+            self.buffer.push(INDEFINITE_LENGTH);
+            f(self)?;
+            self.buffer.extend_from_slice(&END_OF_CONTENTS);
+        } else {
+            // the length is only known after the content has been written
+            let start = self.buffer.len();
+            f(self)?;
+            let content = self.buffer.split_off(start);
+            write_length(&mut self.buffer, content.len());
+            self.buffer.extend_from_slice(&content);
+        }
+        Ok(())
+    }
+
+    /// Reorders the encodings that were written since `start` by the given comparison
+    fn sort_encodings<F: Fn(&(Tag, &[u8]), &(Tag, &[u8])) -> core::cmp::Ordering>(
+        &mut self,
+        start: usize,
+        compare: F,
+    ) -> Result<(), Error> {
+        let content = self.buffer.split_off(start);
+        let mut encodings = Vec::new();
+        let mut remaining = &content[..];
+        while !remaining.is_empty() {
+            // only definite lengths are written in DER
+            let header = read_header(remaining)?;
+            let length = header.octets + header.length.ok_or(ErrorKind::InvalidLength)?;
+            let (encoding, rest) = remaining.split_at(length);
+            encodings.push((header.tag, encoding));
+            remaining = rest;
+        }
+        encodings.sort_by(compare);
+        for (_, encoding) in encodings {
+            self.buffer.extend_from_slice(encoding);
+        }
+        Ok(())
+    }
+
+    fn write_integer_value(&mut self, tag: Tag, value: i64) {
+        let mut content = Vec::with_capacity(core::mem::size_of::<i64>());
+        write_integer(&mut content, value);
+        self.write_primitive(tag, &content);
+    }
+
+    fn write_string(&mut self, tag: Tag, content: &[u8]) -> Result<(), Error> {
+        match self.segment_size {
+            Some(segment_size) if content.len() > segment_size => {
+                self.write_constructed(tag, |w| {
+                    for segment in content.chunks(segment_size) {
+                        w.write_primitive(Tag::DEFAULT_OCTET_STRING, segment);
+                    }
+                    Ok(())
+                })
+            }
+            _ => {
+                self.write_primitive(tag, content);
+                Ok(())
+            }
+        }
+    }
+
+    fn write_character_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        let tag = self.take_tag(tag);
+        self.write_string(tag, &charset.encode_octets(value))
+    }
+
+    fn write_bit_string_segment(&mut self, tag: Tag, unused_bits: u8, bytes: &[u8]) {
+        write_identifier(&mut self.buffer, tag, false);
+        write_length(&mut self.buffer, bytes.len() + 1);
+        self.buffer.push(unused_bits);
+        self.buffer.extend_from_slice(bytes);
+        // ITU-T X.690 | ISO/IEC 8825-1, 11.2.1, DER requires the unused bits to be zero
+        if let (Some(last), true) = (self.buffer.last_mut(), unused_bits > 0) {
+            *last &= 0xFF << unused_bits;
+        }
+    }
+}
+
+impl Writer for BerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // the outermost tag replaces all inner tags
+        if !C::UNTAGGED && self.pending_tag.is_none() {
+            self.pending_tag = Some(C::TAG);
+        }
+        f(self)
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // an outer implicit tag replaces the explicit tag, but not the tag of the inner value
+        let tag = self.take_tag(C::TAG);
+        self.write_constructed(tag, f)
+    }
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.9.2, the components of an extension addition group are
+        // encoded as if they were not grouped
+        if C::TRANSPARENT || C::EXTENSION_GROUP {
+            f(self)
+        } else {
+            let tag = self.take_tag(C::TAG);
+            self.write_constructed(tag, f)
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_constructed(tag, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if self.distinguished && !C::TRANSPARENT {
+            // ITU-T X.690 | ISO/IEC 8825-1, 10.3, the components of a SET are ordered by the tag
+            // that is actually encoded - which for an untagged CHOICE is the tag of the chosen
+            // alternative - and extension additions are not treated any different
+            let tag = self.take_tag(C::TAG);
+            self.write_constructed(tag, |w| {
+                let start = w.buffer.len();
+                f(w)?;
+                w.sort_encodings(start, |a, b| a.0.cmp(&b.0))
+            })
+        } else {
+            self.write_sequence::<C, F>(f)
+        }
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        if self.distinguished {
+            // ITU-T X.690 | ISO/IEC 8825-1, 11.6, the elements of a SET OF are ordered by their
+            // encodings compared as octet strings
+            let tag = self.take_tag(C::TAG);
+            self.write_constructed(tag, |w| {
+                let start = w.buffer.len();
+                for value in slice {
+                    T::write_value(w, value)?;
+                }
+                w.sort_encodings(start, |a, b| a.1.cmp(b.1))
+            })
+        } else {
+            self.write_sequence_of::<C, T>(slice)
+        }
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        // ITU-T X.690 | ISO/IEC 8825-1, 8.4, the value of the variant, not its index
+        self.write_integer_value(tag, enumerated.to_value());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        // an alternative unknown to the type was kept in another encoding
+        let index = choice.to_choice_index();
+        if index >= C::VARIANT_COUNT {
+            return Err(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into());
+        }
+        // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, tagging a CHOICE is always explicit
+        match self.pending_tag.take() {
+            Some(tag) => self.write_constructed(tag, |w| choice.write_content(w)),
+            None => choice.write_content(self),
+        }
+    }
+
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, tagging an open type is always explicit
+        match self.pending_tag.take() {
+            Some(tag) => self.write_constructed(tag, |w| choice.write_content(w)),
+            None => choice.write_content(self),
+        }
+    }
+
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        _choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        // the value is the complete encoding of the inner value, including its tag and length
+        match self.pending_tag.take().or(C::TAG) {
+            Some(tag) => self.write_constructed(tag, |w| {
+                w.buffer.extend_from_slice(value);
+                Ok(())
+            }),
+            None => {
+                self.buffer.extend_from_slice(value);
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        if let Some(value) = value {
+            T::write_value(self, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        if C::DEFAULT_VALUE.ne(value) {
+            T::write_value(self, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        if !C::EXTENSIBLE {
+            let min = C::MIN.unwrap_or(i64::MIN);
+            let max = C::MAX.unwrap_or(i64::MAX);
+            if value < min || value > max {
+                return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+            }
+        }
+        let tag = self.take_tag(C::TAG);
+        self.write_integer_value(tag, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let mut content = Vec::with_capacity(1 + core::mem::size_of::<f64>() + 2);
+        write_real(&mut content, value);
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Utf8, value)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Ia5, value)
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Numeric, value)
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Visible, value)
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Bmp, value)
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Universal, value)
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Teletex, value)
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Graphic, value)
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::General, value)
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Printable, value)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_string(tag, value)
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let bytes = &value[..(bit_len as usize).div_ceil(8)];
+        let unused_bits = (bytes.len() * 8 - bit_len as usize) as u8;
+        match self.segment_size {
+            Some(segment_size) if bytes.len() > segment_size => self.write_constructed(tag, |w| {
+                let segments = bytes.chunks(segment_size);
+                let count = segments.len();
+                for (index, segment) in segments.enumerate() {
+                    let unused = if index + 1 == count { unused_bits } else { 0 };
+                    w.write_bit_string_segment(Tag::DEFAULT_BIT_STRING, unused, segment);
+                }
+                Ok(())
+            }),
+            _ => {
+                self.write_bit_string_segment(tag, unused_bits, bytes);
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_primitive(tag, &[if value { 0xFF } else { 0x00 }]);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.write_primitive(tag, &[]);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let mut content = Vec::with_capacity(value.len() * 2);
+        write_object_identifier(&mut content, value)?;
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let mut content = Vec::with_capacity(value.len() * 2);
+        write_relative_oid(&mut content, value)?;
+        self.write_primitive(tag, &content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        _type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(C::TAG, Charset::Visible, value)
+    }
+}
+
+/// Position of the identifier octets and the contents octets of an encoding within the source
+#[derive(Debug, Copy, Clone)]
+struct Tlv {
+    tag: Tag,
+    constructed: bool,
+    content: usize,
+    /// The end of the contents octets, excluding the end-of-contents octets
+    end: usize,
+    /// The beginning of the next encoding
+    next: usize,
+}
+
+/// The content of a constructed encoding that is currently being read
+struct Frame {
+    end: usize,
+    /// The components of a SET, which can be in any order
+    components: Option<Vec<Component>>,
+}
+
+struct Component {
+    tag: Tag,
+    pos: usize,
+    consumed: bool,
+}
+
+/// Reads values in the Basic Encoding Rules (ITU-T X.690 | ISO/IEC 8825-1), accepting the
+/// definite and indefinite length forms as well as primitive and constructed strings. Whether an
+/// OPTIONAL or DEFAULT value is present and which alternative of a CHOICE is encoded is
+/// determined by the tag of the next encoding.
+pub struct BerReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    frames: Vec<Frame>,
+    pending_tag: Option<Tag>,
+    /// The amount of frames when the last [`ErrorKind::UnexpectedTag`] was created
+    mismatch_depth: Option<usize>,
+}
+
+impl<'a> From<&'a [u8]> for BerReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            frames: Vec::new(),
+            pending_tag: None,
+            mismatch_depth: None,
+        }
+    }
+}
+
+impl<'a> BerReader<'a> {
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    #[inline]
+    fn take_tag(&mut self, tag: Tag) -> Tag {
+        self.pending_tag.take().unwrap_or(tag)
+    }
+
+    #[inline]
+    fn limit(&self) -> usize {
+        self.frames
+            .last()
+            .map(|frame| frame.end)
+            .unwrap_or(self.bytes.len())
+    }
+
+    fn tlv_at(&self, pos: usize, limit: usize) -> Result<Tlv, Error> {
+        let header = read_header(&self.bytes[pos..limit])?;
+        let content = pos + header.octets;
+        if let Some(length) = header.length {
+            let end = content
+                .checked_add(length)
+                .filter(|end| *end <= limit)
+                .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+            Ok(Tlv {
+                tag: header.tag,
+                constructed: header.constructed,
+                content,
+                end,
+                next: end,
+            })
+        } else {
+            let mut cursor = content;
+            while !self.bytes[cursor..limit].starts_with(&END_OF_CONTENTS) {
+                cursor = self.tlv_at(cursor, limit)?.next;
+            }
+            Ok(Tlv {
+                tag: header.tag,
+                constructed: header.constructed,
+                content,
+                end: cursor,
+                next: cursor + END_OF_CONTENTS.len(),
+            })
+        }
+    }
+
+    #[cold]
+    fn mismatch(&mut self, expected: Tag, found: Option<Tag>) -> Error {
+        self.mismatch_depth = Some(self.frames.len());
+        ErrorKind::UnexpectedTag { expected, found }.into()
+    }
+
+    fn peek_tag(&self) -> Option<Tag> {
+        let limit = self.limit();
+        if self.pos < limit && !self.in_set() {
+            read_identifier(&self.bytes[self.pos..limit])
+                .ok()
+                .map(|(tag, _, _)| tag)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn in_set(&self) -> bool {
+        self.frames
+            .last()
+            .is_some_and(|frame| frame.components.is_some())
+    }
+
+    /// Locates the next encoding, which must have the given tag. Within a SET, this is the
+    /// first component with the tag that has not been read yet.
+    fn next_tlv(&mut self, expected: Tag) -> Result<Tlv, Error> {
+        let limit = self.limit();
+        if let Some(components) = self
+            .frames
+            .last_mut()
+            .and_then(|frame| frame.components.as_mut())
+        {
+            return match components
+                .iter_mut()
+                .find(|component| !component.consumed && component.tag == expected)
+            {
+                Some(component) => {
+                    component.consumed = true;
+                    let pos = component.pos;
+                    self.tlv_at(pos, limit)
+                }
+                None => Err(self.mismatch(expected, None)),
+            };
+        }
+        if self.pos >= limit {
+            return Err(self.mismatch(expected, None));
+        }
+        let tlv = self.tlv_at(self.pos, limit)?;
+        if tlv.tag != expected {
+            return Err(self.mismatch(expected, Some(tlv.tag)));
+        }
+        Ok(tlv)
+    }
+
+    /// Tries to read a value, but restores the previous state and returns `None` if the next
+    /// encoding does not have the expected tag
+    fn try_read<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<Option<T>, Error> {
+        let pos = self.pos;
+        let depth = self.frames.len();
+        let pending_tag = self.pending_tag;
+        match f(self) {
+            Ok(value) => Ok(Some(value)),
+            Err(e)
+                if matches!(e.kind(), ErrorKind::UnexpectedTag { .. })
+                    && self.mismatch_depth == Some(depth) =>
+            {
+                self.pos = pos;
+                self.pending_tag = pending_tag;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read_primitive(&mut self, tag: Tag) -> Result<&'a [u8], Error> {
+        let tlv = self.next_tlv(tag)?;
+        if tlv.constructed {
+            return Err(ErrorKind::UnexpectedConstructed(tag).into());
+        }
+        self.pos = tlv.next;
+        Ok(&self.bytes[tlv.content..tlv.end])
+    }
+
+    fn read_constructed<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        tag: Tag,
+        set: bool,
+        extensible: bool,
+        name: &'static str,
+        f: F,
+    ) -> Result<T, Error> {
+        let tlv = self.next_tlv(tag)?;
+        if !tlv.constructed {
+            return Err(ErrorKind::UnexpectedPrimitive(tag).into());
+        }
+        let components = if set {
+            Some(self.components(&tlv)?)
+        } else {
+            None
+        };
+        let depth = self.frames.len();
+        self.frames.push(Frame {
+            end: tlv.end,
+            components,
+        });
+        self.pos = tlv.content;
+
+        let result = f(self);
+        let frame = self.frames.drain(depth..).next();
+        let value = result?;
+
+        let complete = match frame.and_then(|frame| frame.components) {
+            Some(components) => components.iter().all(|component| component.consumed),
+            None => self.pos >= tlv.end,
+        };
+        // unknown extension additions are skipped
+        if !complete && !extensible {
+            return Err(ErrorKind::UnexpectedComponents(name).into());
+        }
+        self.pos = tlv.next;
+        Ok(value)
+    }
+
+    fn components(&self, tlv: &Tlv) -> Result<Vec<Component>, Error> {
+        let mut components = Vec::new();
+        let mut cursor = tlv.content;
+        while cursor < tlv.end {
+            let component = self.tlv_at(cursor, tlv.end)?;
+            components.push(Component {
+                tag: component.tag,
+                pos: cursor,
+                consumed: false,
+            });
+            cursor = component.next;
+        }
+        Ok(components)
+    }
+
+    /// ITU-T X.690 | ISO/IEC 8825-1, 8.6.4, 8.7.3 and 8.23.6, the content of a constructed string
+    /// is the concatenation of its (possibly constructed) segments
+    fn segments(
+        &self,
+        tlv: &Tlv,
+        segment_tag: Tag,
+        segments: &mut Vec<&'a [u8]>,
+    ) -> Result<(), Error> {
+        if !tlv.constructed {
+            segments.push(&self.bytes[tlv.content..tlv.end]);
+            return Ok(());
+        }
+        let mut cursor = tlv.content;
+        while cursor < tlv.end {
+            let segment = self.tlv_at(cursor, tlv.end)?;
+            if segment.tag != segment_tag {
+                return Err(ErrorKind::UnexpectedSegment(segment.tag).into());
+            }
+            self.segments(&segment, segment_tag, segments)?;
+            cursor = segment.next;
+        }
+        Ok(())
+    }
+
+    fn read_string(&mut self, tag: Tag) -> Result<Vec<u8>, Error> {
+        let tlv = self.next_tlv(tag)?;
+        self.pos = tlv.next;
+        let mut segments = Vec::new();
+        self.segments(&tlv, Tag::DEFAULT_OCTET_STRING, &mut segments)?;
+        Ok(segments.concat())
+    }
+
+    fn read_character_string(&mut self, tag: Tag, charset: Charset) -> Result<String, Error> {
+        let tag = self.take_tag(tag);
+        let string = Error::decode_string(charset, self.read_string(tag)?)?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    /// Reads the next encoding with its identifier and length octets, whatever its tag is
+    fn read_encoding(&mut self) -> Result<Vec<u8>, Error> {
+        let limit = self.limit();
+        if self.pos >= limit {
+            return Err(ErrorKind::InsufficientDataInSourceBuffer.into());
+        }
+        let tlv = self.tlv_at(self.pos, limit)?;
+        let encoding = self.bytes[self.pos..tlv.next].to_vec();
+        self.pos = tlv.next;
+        Ok(encoding)
+    }
+
+    fn read_choice_alternative<C: choice::Constraint>(&mut self) -> Result<C, Error> {
+        for index in 0..C::VARIANT_COUNT {
+            if let Some(Some(value)) = self.try_read(|r| C::read_content(index, r))? {
+                return Ok(value);
+            }
+        }
+        let found = self.peek_tag();
+        Err(self.mismatch(C::TAG, found))
+    }
+}
+
+impl Reader for BerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_complex<
+        C: complex::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        // the outermost tag replaces all inner tags
+        if !C::UNTAGGED && self.pending_tag.is_none() {
+            self.pending_tag = Some(C::TAG);
+        }
+        f(self)
+    }
+
+    #[inline]
+    fn read_explicit<
+        C: explicit::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_constructed(tag, false, false, "EXPLICIT", f)
+    }
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT || C::EXTENSION_GROUP {
+            f(self)
+        } else {
+            let tag = self.take_tag(C::TAG);
+            self.read_constructed(tag, false, C::EXTENDED_AFTER_FIELD.is_some(), C::NAME, f)
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_constructed(tag, false, false, "SEQUENCE OF", |r| {
+            let mut values = Vec::new();
+            while r.pos < r.limit() {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            f(self)
+        } else {
+            let tag = self.take_tag(C::TAG);
+            self.read_constructed(tag, true, C::EXTENDED_AFTER_FIELD.is_some(), C::NAME, f)
+        }
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let value = read_integer(tag, self.read_primitive(tag)?)?;
+        C::from_value(value).ok_or_else(|| ErrorKind::InvalidEnumeratedValue(value).into())
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, tagging a CHOICE is always explicit
+        match self.pending_tag.take() {
+            Some(tag) => {
+                self.read_constructed(tag, false, false, C::NAME, Self::read_choice_alternative)
+            }
+            None => self.read_choice_alternative(),
+        }
+    }
+
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        let index = C::index_of_key(key).ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+        let read = |r: &mut Self| {
+            C::read_content(index, r)?.ok_or_else(|| ErrorKind::InvalidOpenTypeKey(key).into())
+        };
+        // ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, tagging an open type is always explicit
+        match self.pending_tag.take() {
+            Some(tag) => self.read_constructed(tag, false, false, C::NAME, read),
+            None => read(self),
+        }
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        match self.pending_tag.take().or(C::TAG) {
+            Some(tag) => self.read_constructed(tag, false, false, "OpenType", Self::read_encoding),
+            None => self.read_encoding(),
+        }
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        self.try_read(T::read_value)
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        Ok(self
+            .try_read(T::read_value)?
+            .unwrap_or_else(|| C::DEFAULT_VALUE.to_owned()))
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let value = read_integer(tag, self.read_primitive(tag)?)?;
+        if !C::EXTENSIBLE {
+            let min = C::MIN.unwrap_or(i64::MIN);
+            let max = C::MAX.unwrap_or(i64::MAX);
+            if value < min || value > max {
+                return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+            }
+        }
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        read_real(self.read_primitive(tag)?)
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Utf8)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Ia5)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Numeric)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Visible)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Bmp)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Universal)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Teletex)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Graphic)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::General)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_character_string(C::TAG, Charset::Printable)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        self.read_string(tag)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        let tlv = self.next_tlv(tag)?;
+        self.pos = tlv.next;
+        let mut segments = Vec::new();
+        self.segments(&tlv, Tag::DEFAULT_BIT_STRING, &mut segments)?;
+
+        let mut bytes = Vec::new();
+        let mut unused_bits = 0;
+        for (index, segment) in segments.iter().enumerate() {
+            let (unused, content) = segment
+                .split_first()
+                .ok_or(ErrorKind::InvalidContentLength(tag, 0))?;
+            // only the final segment may have unused bits and an empty one has none
+            if *unused > MAX_UNUSED_BITS
+                || (*unused > 0 && (index + 1 < segments.len() || content.is_empty()))
+            {
+                return Err(ErrorKind::InvalidUnusedBits(*unused).into());
+            }
+            unused_bits = *unused;
+            bytes.extend_from_slice(content);
+        }
+        if let Some(last) = bytes.last_mut() {
+            *last &= 0xFF << unused_bits;
+        }
+        let bit_len = (bytes.len() * 8) as u64 - u64::from(unused_bits);
+        Ok((bytes, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        match self.read_primitive(tag)? {
+            [value] => Ok(*value != 0x00),
+            content => Err(ErrorKind::InvalidContentLength(tag, content.len()).into()),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        match self.read_primitive(tag)? {
+            [] => Ok(Null),
+            content => Err(ErrorKind::InvalidContentLength(tag, content.len()).into()),
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        read_object_identifier(self.read_primitive(tag)?)
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let tag = self.take_tag(C::TAG);
+        read_relative_oid(self.read_primitive(tag)?)
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        let string = self.read_character_string(C::TAG, Charset::Visible)?;
+        if time::is_valid(r#type, &string) {
+            Ok(string)
+        } else {
+            Err(ErrorKind::InvalidTime(r#type, string).into())
+        }
+    }
+}
+
+pub trait BerDecodable<'a> {
+    fn decode_from_ber(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<'a, R: Readable> BerDecodable<'a> for R {
+    fn decode_from_ber(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = BerReader::from(bytes);
+        Self::read(&mut reader)
+    }
+}
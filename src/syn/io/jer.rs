@@ -0,0 +1,871 @@
+use crate::io::jer::err::Error;
+use crate::io::jer::err::ErrorKind;
+use crate::io::jer::{read_hex, write_hex, write_value, Parser, Value};
+use crate::syn::*;
+use asn1rs_model::model::Charset;
+use asn1rs_model::model::TimeType;
+use std::convert::TryFrom;
+
+/// ITU-T X.697 | ISO/IEC 8825-8, 22.3, the members of the JSON object of a BIT STRING without a
+/// fixed size
+const BIT_STRING_VALUE: &str = "value";
+const BIT_STRING_LENGTH: &str = "length";
+const BIT_STRING_MEMBERS: &[&str] = &[BIT_STRING_VALUE, BIT_STRING_LENGTH];
+
+/// ITU-T X.697 | ISO/IEC 8825-8, 20, the JSON strings of the special REAL values
+const REAL_PLUS_INFINITY: &str = "INF";
+const REAL_MINUS_INFINITY: &str = "-INF";
+const REAL_NOT_A_NUMBER: &str = "NaN";
+const REAL_MINUS_ZERO: &str = "-0";
+
+/// The JSON value of a constructed type that is currently being written
+enum WriteFrame {
+    /// The members of a SEQUENCE or SET and the index of the next field
+    Sequence {
+        names: &'static [&'static str],
+        index: usize,
+        members: Vec<(String, Value)>,
+    },
+    /// The elements of a SEQUENCE OF or SET OF or the value of the chosen alternative of a CHOICE
+    Values(Vec<Value>),
+}
+
+/// Writes values in the JSON Encoding Rules (ITU-T X.697 | ISO/IEC 8825-8). The members of a
+/// `SEQUENCE` or `SET` and the alternatives of a `CHOICE` are named by their ASN.1 identifiers
+/// (see [`sequence::Constraint::FIELD_NAMES`] and [`choice::Constraint::VARIANT_NAMES`]),
+/// absent `OPTIONAL` fields and `DEFAULT` values are omitted.
+///
+/// Each value that is written by [`Writer::write`] is appended to the output in a line of its
+/// own.
+#[derive(Default)]
+pub struct JerWriter {
+    output: String,
+    frames: Vec<WriteFrame>,
+}
+
+impl JerWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            output: String::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.output
+    }
+
+    pub fn into_string(self) -> String {
+        self.output
+    }
+
+    pub fn as_reader(&self) -> JerReader<'_> {
+        JerReader::from(self.as_str())
+    }
+
+    fn put(&mut self, value: Value) {
+        match self.frames.last_mut() {
+            Some(WriteFrame::Sequence {
+                names,
+                index,
+                members,
+            }) => {
+                members.push((names[*index].to_string(), value));
+                *index += 1;
+            }
+            Some(WriteFrame::Values(values)) => values.push(value),
+            None => {
+                if !self.output.is_empty() {
+                    self.output.push('\n');
+                }
+                write_value(&mut self.output, &value);
+            }
+        }
+    }
+
+    /// Omits the next field of a SEQUENCE or SET
+    fn skip(&mut self) {
+        if let Some(WriteFrame::Sequence { index, .. }) = self.frames.last_mut() {
+            *index += 1;
+        }
+    }
+
+    fn write_frame<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        frame: WriteFrame,
+        f: F,
+    ) -> Result<WriteFrame, Error> {
+        let depth = self.frames.len();
+        self.frames.push(frame);
+        let result = f(self);
+        let frame = self.frames.drain(depth..).next();
+        result.map(|_| frame.expect("the frame was pushed before"))
+    }
+
+    fn write_character_string(&mut self, charset: Charset, value: &str) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        self.put(Value::String(value.to_string()));
+        Ok(())
+    }
+}
+
+impl Writer for JerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        let frame = WriteFrame::Sequence {
+            names: C::FIELD_NAMES,
+            index: 0,
+            members: Vec::with_capacity(C::FIELD_NAMES.len()),
+        };
+        if let WriteFrame::Sequence { members, .. } = self.write_frame(frame, f)? {
+            self.put(Value::Object(members));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let frame = WriteFrame::Values(Vec::with_capacity(slice.len()));
+        let frame = self.write_frame(frame, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })?;
+        if let WriteFrame::Values(values) = frame {
+            self.put(Value::Array(values));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT))?;
+        self.put(Value::String(name.to_string()));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let index = choice.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT))?;
+        let frame = WriteFrame::Values(Vec::with_capacity(1));
+        if let WriteFrame::Values(values) = self.write_frame(frame, |w| choice.write_content(w))? {
+            let members = values
+                .into_iter()
+                .map(|value| (name.to_string(), value))
+                .collect();
+            self.put(Value::Object(members));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, an open type is encoded as the value of its actual type
+        choice.write_content(self)
+    }
+
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        _choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        // the value is the JSON text of the actual type, which is embedded as it is
+        let text = core::str::from_utf8(value)
+            .map_err(|e| Error::from(ErrorKind::InvalidJson(e.valid_up_to())))?;
+        let mut parser = Parser::from(text);
+        let json = parser.parse_value()?;
+        if parser.bytes_remaining() > 0 {
+            return Err(
+                ErrorKind::InvalidJson(text.trim_end().len() - parser.bytes_remaining()).into(),
+            );
+        }
+        self.put(json);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        if let Some(value) = value {
+            T::write_value(self, value)
+        } else {
+            self.skip();
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        if C::DEFAULT_VALUE.ne(value) {
+            T::write_value(self, value)
+        } else {
+            self.skip();
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        if !C::EXTENSIBLE {
+            let min = C::MIN.unwrap_or(i64::MIN);
+            let max = C::MAX.unwrap_or(i64::MAX);
+            if value < min || value > max {
+                return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+            }
+        }
+        self.put(Value::Number(value.to_string()));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, 20, the special values are written as JSON strings
+        self.put(if value.is_nan() {
+            Value::String(REAL_NOT_A_NUMBER.to_string())
+        } else if value == f64::INFINITY {
+            Value::String(REAL_PLUS_INFINITY.to_string())
+        } else if value == f64::NEG_INFINITY {
+            Value::String(REAL_MINUS_INFINITY.to_string())
+        } else if value == 0.0 && value.is_sign_negative() {
+            Value::String(REAL_MINUS_ZERO.to_string())
+        } else {
+            Value::Number(format!("{:?}", value))
+        });
+        Ok(())
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Utf8, value)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Ia5, value)
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Numeric, value)
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Visible, value)
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Bmp, value)
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Universal, value)
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Teletex, value)
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Graphic, value)
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::General, value)
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Printable, value)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.put(Value::String(write_hex(value)));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let mut bytes = value[..(bit_len as usize).div_ceil(8)].to_vec();
+        // ITU-T X.697 | ISO/IEC 8825-8, 22.2, the trailing bits of the final hex digits are zero
+        let unused_bits = bytes.len() * 8 - bit_len as usize;
+        if let (Some(last), true) = (bytes.last_mut(), unused_bits > 0) {
+            *last &= 0xFF << unused_bits;
+        }
+        let hex = Value::String(write_hex(&bytes));
+        if is_fixed_size::<C>() {
+            self.put(hex);
+        } else {
+            self.put(Value::Object(vec![
+                (BIT_STRING_VALUE.to_string(), hex),
+                (
+                    BIT_STRING_LENGTH.to_string(),
+                    Value::Number(bit_len.to_string()),
+                ),
+            ]));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.put(Value::Bool(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        self.put(Value::Null);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, the dotted form as JSON string
+        self.put(Value::String(
+            ObjectIdentifier::from(value.to_vec()).to_string(),
+        ));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.put(Value::String(RelativeOid::from(value.to_vec()).to_string()));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        _type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(Charset::Visible, value)
+    }
+}
+
+/// ITU-T X.697 | ISO/IEC 8825-8, 22.2, a BIT STRING with a fixed size is written as plain hex
+/// string without its length
+#[inline]
+fn is_fixed_size<C: bitstring::Constraint>() -> bool {
+    !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX
+}
+
+/// The members of a SEQUENCE or SET that is currently being read
+struct ReadFrame {
+    name: &'static str,
+    names: &'static [&'static str],
+    index: usize,
+    members: Vec<(String, Value)>,
+}
+
+/// Reads values in the JSON Encoding Rules (ITU-T X.697 | ISO/IEC 8825-8). The members of an
+/// object are looked up by the ASN.1 identifiers of the fields regardless of their order and
+/// unknown members are ignored if the type is extensible.
+pub struct JerReader<'a> {
+    parser: Parser<'a>,
+    frames: Vec<ReadFrame>,
+    /// The value to read next, if it was already taken from its parent
+    pending: Option<Value>,
+}
+
+impl<'a> From<&'a str> for JerReader<'a> {
+    fn from(source: &'a str) -> Self {
+        Self {
+            parser: Parser::from(source),
+            frames: Vec::new(),
+            pending: None,
+        }
+    }
+}
+
+impl JerReader<'_> {
+    pub fn bytes_remaining(&mut self) -> usize {
+        self.parser.bytes_remaining()
+    }
+
+    /// Takes the next value, which is `None` for an absent member of an object
+    fn next_opt(&mut self) -> Result<Option<Value>, Error> {
+        if let Some(value) = self.pending.take() {
+            return Ok(Some(value));
+        }
+        match self.frames.last_mut() {
+            Some(frame) => {
+                let name = frame.names[frame.index];
+                frame.index += 1;
+                Ok(frame
+                    .members
+                    .iter()
+                    .position(|(member, _)| member == name)
+                    .map(|index| frame.members.remove(index).1))
+            }
+            None => self.parser.parse_value().map(Some),
+        }
+    }
+
+    fn next(&mut self) -> Result<Value, Error> {
+        match self.next_opt()? {
+            Some(value) => Ok(value),
+            None => {
+                let frame = self.frames.last().expect("only a member can be absent");
+                Err(ErrorKind::MissingMember(frame.names[frame.index - 1]).into())
+            }
+        }
+    }
+
+    fn next_string(&mut self) -> Result<String, Error> {
+        match self.next()? {
+            Value::String(string) => Ok(string),
+            value => Err(unexpected("string", &value)),
+        }
+    }
+
+    fn next_number(&mut self) -> Result<i64, Error> {
+        match self.next()? {
+            Value::Number(number) => number.parse().map_err(|_| {
+                if number.bytes().all(|b| b == b'-' || b.is_ascii_digit()) {
+                    ErrorKind::ValueExceedsMaxInt.into()
+                } else {
+                    unexpected("integer", &Value::Number(number))
+                }
+            }),
+            value => Err(unexpected("number", &value)),
+        }
+    }
+
+    fn read_character_string(&mut self, charset: Charset) -> Result<String, Error> {
+        let string = self.next_string()?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    /// Reads the next value with `f` after it was taken from its parent
+    fn read_pending<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        value: Value,
+        f: F,
+    ) -> Result<T, Error> {
+        self.pending = Some(value);
+        let result = f(self);
+        self.pending = None;
+        result
+    }
+
+    fn read_object<S, F: Fn(&mut Self) -> Result<S, Error>>(
+        &mut self,
+        name: &'static str,
+        names: &'static [&'static str],
+        extensible: bool,
+        f: F,
+    ) -> Result<S, Error> {
+        let members = match self.next()? {
+            Value::Object(members) => members,
+            value => return Err(unexpected("object", &value)),
+        };
+        let depth = self.frames.len();
+        self.frames.push(ReadFrame {
+            name,
+            names,
+            index: 0,
+            members,
+        });
+        let result = f(self);
+        let frame = self.frames.drain(depth..).next();
+        let value = result?;
+        match frame {
+            Some(frame) if !frame.members.is_empty() && !extensible => {
+                Err(ErrorKind::UnexpectedMembers(frame.name).into())
+            }
+            _ => Ok(value),
+        }
+    }
+}
+
+#[cold]
+fn unexpected(expected: &'static str, found: &Value) -> Error {
+    ErrorKind::UnexpectedType {
+        expected,
+        found: found.type_name(),
+    }
+    .into()
+}
+
+impl Reader for JerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            f(self)
+        } else {
+            self.read_object(
+                C::NAME,
+                C::FIELD_NAMES,
+                C::EXTENDED_AFTER_FIELD.is_some(),
+                f,
+            )
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        match self.next()? {
+            Value::Array(values) => values
+                .into_iter()
+                .map(|value| self.read_pending(value, T::read_value))
+                .collect(),
+            value => Err(unexpected("array", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let identifier = self.next_string()?;
+        C::VARIANT_NAMES
+            .iter()
+            .position(|name| *name == identifier)
+            .and_then(|index| C::from_choice_index(index as u64))
+            .ok_or_else(|| ErrorKind::UnknownIdentifier(identifier).into())
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        // ITU-T X.697 | ISO/IEC 8825-8, 26, an object with the chosen alternative as only member
+        let (identifier, value) = match self.next()? {
+            Value::Object(members) if members.len() == 1 => {
+                members.into_iter().next().expect("there is one member")
+            }
+            Value::Object(_) => return Err(ErrorKind::UnexpectedMembers(C::NAME).into()),
+            value => return Err(unexpected("object", &value)),
+        };
+        let index = C::VARIANT_NAMES
+            .iter()
+            .position(|name| *name == identifier)
+            .ok_or_else(|| ErrorKind::UnknownIdentifier(identifier.clone()))?;
+        self.read_pending(value, |r| C::read_content(index as u64, r))?
+            .ok_or_else(|| ErrorKind::UnknownIdentifier(identifier).into())
+    }
+
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        let index = C::index_of_key(key).ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+        C::read_content(index, self)?.ok_or_else(|| ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let mut text = String::new();
+        write_value(&mut text, &self.next()?);
+        Ok(text.into_bytes())
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        match self.next_opt()? {
+            Some(value) => self.read_pending(value, T::read_value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        match self.next_opt()? {
+            Some(value) => self.read_pending(value, T::read_value),
+            None => Ok(C::DEFAULT_VALUE.to_owned()),
+        }
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let value = self.next_number()?;
+        if !C::EXTENSIBLE {
+            let min = C::MIN.unwrap_or(i64::MIN);
+            let max = C::MAX.unwrap_or(i64::MAX);
+            if value < min || value > max {
+                return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+            }
+        }
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        match self.next()? {
+            Value::Number(number) => number
+                .parse()
+                .map_err(|_| unexpected("real", &Value::Number(number))),
+            Value::String(string) => match string.as_str() {
+                REAL_NOT_A_NUMBER => Ok(f64::NAN),
+                REAL_PLUS_INFINITY => Ok(f64::INFINITY),
+                REAL_MINUS_INFINITY => Ok(f64::NEG_INFINITY),
+                REAL_MINUS_ZERO => Ok(-0.0),
+                _ => Err(unexpected("real", &Value::String(string))),
+            },
+            value => Err(unexpected("real", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Utf8)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Ia5)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Numeric)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Visible)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Bmp)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Universal)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Teletex)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Graphic)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::General)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Printable)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        read_hex(&self.next_string()?)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (hex, bit_len) = if is_fixed_size::<C>() {
+            let bit_len = C::MIN.unwrap_or_default();
+            (self.next_string()?, bit_len)
+        } else {
+            let (hex, length) = self.read_object("BIT STRING", BIT_STRING_MEMBERS, false, |r| {
+                Ok((r.next_string()?, r.next_number()?))
+            })?;
+            let bit_len = u64::try_from(length)
+                .map_err(|_| ErrorKind::ValueNotInRange(length, 0, i64::MAX))?;
+            (hex, bit_len)
+        };
+        let mut bytes = read_hex(&hex)?;
+        if (bytes.len() as u64) != bit_len.div_ceil(8) {
+            return Err(ErrorKind::InvalidBitStringLength(bit_len).into());
+        }
+        let unused_bits = bytes.len() * 8 - bit_len as usize;
+        if let (Some(last), true) = (bytes.last_mut(), unused_bits > 0) {
+            *last &= 0xFF << unused_bits;
+        }
+        Ok((bytes, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match self.next()? {
+            Value::Bool(value) => Ok(value),
+            value => Err(unexpected("boolean", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        match self.next()? {
+            Value::Null => Ok(Null),
+            value => Err(unexpected("null", &value)),
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let string = self.next_string()?;
+        string
+            .parse::<ObjectIdentifier>()
+            .map(Vec::from)
+            .map_err(|_| ErrorKind::InvalidObjectIdentifier(string).into())
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let string = self.next_string()?;
+        string
+            .parse::<RelativeOid>()
+            .map(Vec::from)
+            .map_err(|_| ErrorKind::InvalidObjectIdentifier(string).into())
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        let string = self.next_string()?;
+        if time::is_valid(r#type, &string) {
+            Ok(string)
+        } else {
+            Err(ErrorKind::InvalidTime(r#type, string).into())
+        }
+    }
+}
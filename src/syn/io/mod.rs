@@ -1,9 +1,21 @@
+mod aper;
+mod ber;
+mod jer;
+mod oer;
 mod println;
 mod proto_read;
 mod proto_write;
 mod uper;
+mod uper_trace;
+mod xer;
 
+pub use aper::*;
+pub use ber::*;
+pub use jer::*;
+pub use oer::*;
 pub use println::*;
 pub use proto_read::*;
 pub use proto_write::*;
 pub use uper::*;
+pub use uper_trace::*;
+pub use xer::*;
@@ -0,0 +1,1323 @@
+use crate::io::ber;
+use crate::io::oer::err::Error;
+use crate::io::oer::err::ErrorKind;
+use crate::io::oer::{
+    read_length, read_signed, read_tag, read_unsigned, signed_octets, unsigned_octets,
+    write_length, write_signed, write_tag, write_unsigned, IntegerEncoding,
+};
+use crate::syn::*;
+use asn1rs_model::model::Charset;
+use asn1rs_model::model::Tag;
+use asn1rs_model::model::TimeType;
+use std::convert::TryFrom;
+
+/// ITU-T X.696 | ISO/IEC 8825-7, 11.3, ENUMERATED values up to 127 are encoded in a single octet
+const ENUMERATED_SHORT_FORM_MAX: i64 = 127;
+const BOOLEAN_TRUE: u8 = 0xFF;
+const BOOLEAN_FALSE: u8 = 0x00;
+
+/// The fields of a SEQUENCE or SET that is currently being written
+struct WriteFrame {
+    /// The position of the preamble with the extension bit and the presence bitmap
+    preamble: usize,
+    extensible: bool,
+    extended_after_field: Option<u64>,
+    field: u64,
+    in_field: bool,
+    optional: usize,
+    /// Whether the current extension addition is absent
+    absent: bool,
+    additions: Vec<Option<Vec<u8>>>,
+}
+
+impl WriteFrame {
+    #[inline]
+    fn is_addition(&self) -> bool {
+        self.extended_after_field
+            .is_some_and(|after| self.field > after)
+    }
+}
+
+/// Writes values in the Octet Encoding Rules (ITU-T X.696 | ISO/IEC 8825-7). Because `DEFAULT`
+/// values are omitted, `SET` components are written in their canonical order, the extension
+/// bitmap always covers all known extension additions and all lengths are minimal, the encoding
+/// is also the canonical one (COER) required for signatures.
+///
+/// The alternatives of a `CHOICE` are identified by the outermost tag of their type. `ENUMERATED`
+/// values are encoded by the number of their variant in the ASN.1 definition.
+#[derive(Default)]
+pub struct OerWriter {
+    buffer: Vec<u8>,
+    frames: Vec<WriteFrame>,
+    /// Whether the next tag is the one of a CHOICE alternative
+    capture_tag: bool,
+    captured_tag: Option<Tag>,
+}
+
+impl OerWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer[..]
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn as_reader(&self) -> OerReader<'_> {
+        OerReader::from(self.byte_content())
+    }
+
+    /// Notes the tag of the value that is about to be written
+    #[inline]
+    fn tag(&mut self, tag: Tag) {
+        if self.capture_tag {
+            self.capture_tag = false;
+            self.captured_tag = Some(tag);
+        }
+    }
+
+    /// Writes the content of `f` into a buffer of its own and returns it
+    fn write_detached<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<Vec<u8>, Error> {
+        let outer = core::mem::take(&mut self.buffer);
+        let result = f(self);
+        let content = core::mem::replace(&mut self.buffer, outer);
+        result.map(|_| content)
+    }
+
+    /// Writes a value and - if it is the next field of the current SEQUENCE - advances to the
+    /// next field. Extension additions are written into buffers of their own, so that they can
+    /// be appended as open types after the extension bitmap.
+    fn field<F: FnOnce(&mut Self) -> Result<(), Error>>(&mut self, f: F) -> Result<(), Error> {
+        let addition = match self.frames.last_mut() {
+            Some(frame) if !frame.in_field => {
+                frame.in_field = true;
+                Some(frame.is_addition())
+            }
+            _ => return f(self),
+        };
+        let depth = self.frames.len();
+        if addition == Some(true) {
+            let content = self.write_detached(f)?;
+            let frame = &mut self.frames[depth - 1];
+            let absent = core::mem::take(&mut frame.absent);
+            frame.additions.push(Some(content).filter(|_| !absent));
+        } else {
+            f(self)?;
+        }
+        let frame = &mut self.frames[depth - 1];
+        frame.in_field = false;
+        frame.field += 1;
+        Ok(())
+    }
+
+    /// Marks the presence of the current OPTIONAL or DEFAULT field
+    fn presence(&mut self, present: bool) {
+        if let Some(frame) = self.frames.last_mut() {
+            if frame.is_addition() {
+                frame.absent = !present;
+            } else {
+                if present {
+                    let bit = frame.optional + usize::from(frame.extensible);
+                    self.buffer[frame.preamble + bit / 8] |= 0x80 >> (bit % 8);
+                }
+                frame.optional += 1;
+            }
+        }
+    }
+
+    fn write_bitmap(&mut self, bits: impl ExactSizeIterator<Item = bool>) {
+        let bit_len = bits.len();
+        let octets = bit_len.div_ceil(8);
+        write_length(&mut self.buffer, octets + 1);
+        self.buffer.push((octets * 8 - bit_len) as u8);
+        let start = self.buffer.len();
+        self.buffer.resize(start + octets, 0x00);
+        for (bit, set) in bits.enumerate() {
+            if set {
+                self.buffer[start + bit / 8] |= 0x80 >> (bit % 8);
+            }
+        }
+    }
+
+    fn write_size<F: FnOnce(&mut Self)>(&mut self, fixed: bool, length: usize, f: F) {
+        if !fixed {
+            write_length(&mut self.buffer, length);
+        }
+        f(self)
+    }
+
+    fn write_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        fixed_size: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        self.field(|w| {
+            w.tag(tag);
+            Error::ensure_string_valid(charset, value)?;
+            // ITU-T X.696 | ISO/IEC 8825-7, 27.2, only known-multiplier character strings have
+            // a fixed size in octets
+            let fixed = fixed_size.filter(|_| charset.is_known_multiplier());
+            if let Some(size) = fixed {
+                ensure_size(value.chars().count() as u64, size)?;
+            }
+            let octets = charset.encode_octets(value);
+            w.write_size(fixed.is_some(), octets.len(), |w| {
+                w.buffer.extend_from_slice(&octets)
+            });
+            Ok(())
+        })
+    }
+}
+
+/// ITU-T X.696 | ISO/IEC 8825-7, 8.2, the size constraint is only OER-visible if it is not
+/// extensible
+#[inline]
+fn fixed_size(min: Option<u64>, max: Option<u64>, extensible: bool) -> Option<u64> {
+    min.filter(|min| !extensible && Some(*min) == max)
+}
+
+#[inline]
+fn ensure_size(size: u64, fixed: u64) -> Result<(), Error> {
+    if size == fixed {
+        Ok(())
+    } else {
+        Err(ErrorKind::SizeNotInRange(size, fixed, fixed).into())
+    }
+}
+
+impl Writer for OerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            if !C::UNTAGGED {
+                w.tag(C::TAG);
+            }
+            f(w)
+        })
+    }
+
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            f(w)
+        })
+    }
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        self.field(|w| {
+            w.tag(C::TAG);
+            let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+            let bits = C::STD_OPTIONAL_FIELDS as usize + usize::from(extensible);
+            let preamble = w.buffer.len();
+            w.buffer.resize(preamble + bits.div_ceil(8), 0x00);
+
+            let depth = w.frames.len();
+            w.frames.push(WriteFrame {
+                preamble,
+                extensible,
+                extended_after_field: C::EXTENDED_AFTER_FIELD,
+                field: 0,
+                in_field: false,
+                optional: 0,
+                absent: false,
+                additions: Vec::new(),
+            });
+            let result = f(w);
+            let frame = w.frames.drain(depth..).next();
+            result?;
+
+            let additions = frame.map(|frame| frame.additions).unwrap_or_default();
+            if additions.iter().any(Option::is_some) {
+                // ITU-T X.696 | ISO/IEC 8825-7, 16.4, the extension bit, the bitmap of the
+                // present extension additions and each of them as open type
+                w.buffer[preamble] |= 0x80;
+                w.write_bitmap(additions.iter().map(Option::is_some));
+                for addition in additions.iter().flatten() {
+                    write_length(&mut w.buffer, addition.len());
+                    w.buffer.extend_from_slice(addition);
+                }
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            // ITU-T X.696 | ISO/IEC 8825-7, 19.2, the quantity is preceded by its amount of octets
+            let quantity = slice.len() as u64;
+            let octets = unsigned_octets(quantity);
+            w.buffer.push(octets as u8);
+            write_unsigned(&mut w.buffer, quantity, octets);
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            let value = enumerated.to_value();
+            if (0..=ENUMERATED_SHORT_FORM_MAX).contains(&value) {
+                w.buffer.push(value as u8);
+            } else {
+                let octets = signed_octets(value);
+                w.buffer.push(0x80 | octets as u8);
+                write_signed(&mut w.buffer, value, octets);
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.field(|w| {
+            let index = choice.to_choice_index();
+            if index >= C::VARIANT_COUNT {
+                return Err(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT).into());
+            }
+            w.capture_tag = true;
+            let content = w.write_detached(|w| choice.write_content(w));
+            w.capture_tag = false;
+            let content = content?;
+            let tag = w.captured_tag.take().unwrap_or(C::TAG);
+
+            // ITU-T X.696 | ISO/IEC 8825-7, 20, extension additions are written as open type
+            write_tag(&mut w.buffer, tag);
+            if index >= C::STD_VARIANT_COUNT {
+                write_length(&mut w.buffer, content.len());
+            }
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            // ITU-T X.696 | ISO/IEC 8825-7, the length followed by the complete encoding
+            let content = w.write_detached(|w| choice.write_content(w))?;
+            write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        _choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            if let Some(tag) = C::TAG {
+                w.tag(tag);
+            }
+            write_length(&mut w.buffer, value.len());
+            w.buffer.extend_from_slice(value);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.presence(value.is_some());
+            if let Some(value) = value {
+                T::write_value(w, value)
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            let present = C::DEFAULT_VALUE.ne(value);
+            w.presence(present);
+            if present {
+                T::write_value(w, value)
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            let value = value.to_i64();
+            if !C::EXTENSIBLE {
+                let min = C::MIN.unwrap_or(i64::MIN);
+                let max = C::MAX.unwrap_or(i64::MAX);
+                if value < min || value > max {
+                    return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+                }
+            }
+            match IntegerEncoding::from_constraint(C::MIN, C::MAX, C::EXTENSIBLE) {
+                IntegerEncoding::FixedUnsigned(octets) => {
+                    write_unsigned(&mut w.buffer, value as u64, octets)
+                }
+                IntegerEncoding::FixedSigned(octets) => write_signed(&mut w.buffer, value, octets),
+                IntegerEncoding::VariableUnsigned => {
+                    let octets = unsigned_octets(value as u64);
+                    write_length(&mut w.buffer, octets);
+                    write_unsigned(&mut w.buffer, value as u64, octets);
+                }
+                IntegerEncoding::VariableSigned => {
+                    let octets = signed_octets(value);
+                    write_length(&mut w.buffer, octets);
+                    write_signed(&mut w.buffer, value, octets);
+                }
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            // ITU-T X.696 | ISO/IEC 8825-7, 12.4, the CER/DER contents octets with a length
+            let mut content = Vec::with_capacity(1 + core::mem::size_of::<f64>() + 2);
+            ber::write_real(&mut content, value);
+            write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Utf8, size, value)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Ia5, size, value)
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Numeric, size, value)
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Visible, size, value)
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Bmp, size, value)
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Universal, size, value)
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Teletex, size, value)
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Graphic, size, value)
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::General, size, value)
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.write_string(C::TAG, Charset::Printable, size, value)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            let fixed = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+            if let Some(size) = fixed {
+                ensure_size(value.len() as u64, size)?;
+            }
+            w.write_size(fixed.is_some(), value.len(), |w| {
+                w.buffer.extend_from_slice(value)
+            });
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            let fixed = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+            if let Some(size) = fixed {
+                ensure_size(bit_len, size)?;
+            }
+            let bytes = &value[..(bit_len as usize).div_ceil(8)];
+            let unused_bits = (bytes.len() * 8 - bit_len as usize) as u8;
+            // ITU-T X.696 | ISO/IEC 8825-7, 16.2 and 16.3, only a BIT STRING without a fixed
+            // size is preceded by a length determinant and the amount of unused bits
+            if fixed.is_none() {
+                write_length(&mut w.buffer, bytes.len() + 1);
+                w.buffer.push(unused_bits);
+            }
+            w.buffer.extend_from_slice(bytes);
+            if let (Some(last), true) = (w.buffer.last_mut(), unused_bits > 0) {
+                *last &= 0xFF << unused_bits;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            w.buffer
+                .push(if value { BOOLEAN_TRUE } else { BOOLEAN_FALSE });
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            // ITU-T X.696 | ISO/IEC 8825-7, the BER contents octets with a length
+            let mut content = Vec::with_capacity(value.len() * 2);
+            ber::write_object_identifier(&mut content, value)
+                .map_err(|_| Error::from(ErrorKind::InvalidObjectIdentifier))?;
+            write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.field(|w| {
+            w.tag(C::TAG);
+            // ITU-T X.696 | ISO/IEC 8825-7, the BER contents octets with a length
+            let mut content = Vec::with_capacity(value.len() * 2);
+            ber::write_relative_oid(&mut content, value)
+                .map_err(|_| Error::from(ErrorKind::InvalidObjectIdentifier))?;
+            write_length(&mut w.buffer, content.len());
+            w.buffer.extend_from_slice(&content);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        _type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_string(C::TAG, Charset::Visible, None, value)
+    }
+}
+
+/// The fields of a SEQUENCE or SET that is currently being read
+struct ReadFrame {
+    name: &'static str,
+    preamble: Vec<u8>,
+    extensible: bool,
+    extended_after_field: Option<u64>,
+    field: u64,
+    in_field: bool,
+    optional: usize,
+    /// The bitmap of the present extension additions, once it was read
+    additions: Option<Vec<bool>>,
+    addition: usize,
+}
+
+impl ReadFrame {
+    #[inline]
+    fn is_addition(&self) -> bool {
+        self.extended_after_field
+            .is_some_and(|after| self.field > after)
+    }
+
+    #[inline]
+    fn bit(&self, bit: usize) -> bool {
+        self.preamble[bit / 8] & (0x80 >> (bit % 8)) != 0
+    }
+
+    #[inline]
+    fn is_extended(&self) -> bool {
+        self.extensible && self.bit(0)
+    }
+}
+
+/// Reads values in the Octet Encoding Rules (ITU-T X.696 | ISO/IEC 8825-7), skipping unknown
+/// extension additions of a `SEQUENCE` or `SET`. See [`OerWriter`] for how `CHOICE` and
+/// `ENUMERATED` values are identified.
+pub struct OerReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// The ends of the open types that are currently being read
+    limits: Vec<usize>,
+    frames: Vec<ReadFrame>,
+    /// The tag of the CHOICE alternative that is about to be read
+    expected_tag: Option<Tag>,
+    /// Whether the current field is an absent extension addition
+    absent: bool,
+}
+
+impl<'a> From<&'a [u8]> for OerReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            limits: Vec::new(),
+            frames: Vec::new(),
+            expected_tag: None,
+            absent: false,
+        }
+    }
+}
+
+impl<'a> OerReader<'a> {
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    #[inline]
+    fn limit(&self) -> usize {
+        self.limits.last().copied().unwrap_or(self.bytes.len())
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .pos
+            .checked_add(length)
+            .filter(|end| *end <= self.limit())
+            .ok_or(ErrorKind::InsufficientDataInSourceBuffer)?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn take_length(&mut self) -> Result<usize, Error> {
+        let (length, octets) = read_length(&self.bytes[self.pos..self.limit()])?;
+        self.pos += octets;
+        Ok(length)
+    }
+
+    fn take_byte(&mut self) -> Result<u8, Error> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    /// Checks the tag of the value that is about to be read
+    #[inline]
+    fn tag(&mut self, tag: Tag) -> Result<(), Error> {
+        if self.absent {
+            let name = self
+                .frames
+                .last()
+                .map(|frame| frame.name)
+                .unwrap_or_default();
+            return Err(ErrorKind::MissingExtensionAddition(name).into());
+        }
+        match self.expected_tag.take() {
+            Some(expected) if expected != tag => Err(ErrorKind::UnexpectedTag {
+                expected: tag,
+                found: expected,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads the content of an open type with `f`, ignoring whatever `f` does not consume
+    fn read_open_type_with<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, Error> {
+        let length = self.take_length()?;
+        let end = self.pos + length;
+        if end > self.limit() {
+            return Err(ErrorKind::InsufficientDataInSourceBuffer.into());
+        }
+        let depth = self.limits.len();
+        self.limits.push(end);
+        let result = f(self);
+        self.limits.truncate(depth);
+        self.pos = end;
+        result
+    }
+
+    fn read_bitmap(&mut self) -> Result<Vec<bool>, Error> {
+        let length = self.take_length()?;
+        let unused_bits = self.take_byte()?;
+        let bytes = self.take(length.saturating_sub(1))?;
+        if length == 0 || unused_bits > 7 || (bytes.is_empty() && unused_bits > 0) {
+            return Err(ErrorKind::InvalidUnusedBits(unused_bits).into());
+        }
+        let bit_len = bytes.len() * 8 - usize::from(unused_bits);
+        Ok((0..bit_len)
+            .map(|bit| bytes[bit / 8] & (0x80 >> (bit % 8)) != 0)
+            .collect())
+    }
+
+    /// The bitmap of the present extension additions of the current SEQUENCE
+    fn additions(&mut self) -> Result<&mut ReadFrame, Error> {
+        let index = self.frames.len() - 1;
+        if self.frames[index].additions.is_none() {
+            let additions = if self.frames[index].is_extended() {
+                self.read_bitmap()?
+            } else {
+                Vec::new()
+            };
+            self.frames[index].additions = Some(additions);
+        }
+        Ok(&mut self.frames[index])
+    }
+
+    /// Reads a value and - if it is the next field of the current SEQUENCE - advances to the
+    /// next field. Present extension additions are read from their open type.
+    fn field<T, F: FnOnce(&mut Self) -> Result<T, Error>>(&mut self, f: F) -> Result<T, Error> {
+        let addition = match self.frames.last_mut() {
+            Some(frame) if !frame.in_field => {
+                frame.in_field = true;
+                frame.is_addition()
+            }
+            _ => return f(self),
+        };
+        let depth = self.frames.len();
+        let result = if addition {
+            let frame = self.additions()?;
+            let present = frame
+                .additions
+                .as_ref()
+                .and_then(|additions| additions.get(frame.addition).copied())
+                .unwrap_or(false);
+            frame.addition += 1;
+            if present {
+                self.read_open_type_with(f)
+            } else {
+                self.absent = true;
+                let result = f(self);
+                self.absent = false;
+                result
+            }
+        } else {
+            f(self)
+        };
+        if let Some(frame) = self.frames.get_mut(depth - 1) {
+            frame.in_field = false;
+            frame.field += 1;
+        }
+        result
+    }
+
+    /// Whether the current OPTIONAL or DEFAULT field is present
+    fn presence(&mut self) -> bool {
+        if self.absent {
+            self.absent = false;
+            return false;
+        }
+        match self.frames.last_mut() {
+            Some(frame) if !frame.is_addition() => {
+                let present = frame.bit(frame.optional + usize::from(frame.extensible));
+                frame.optional += 1;
+                present
+            }
+            _ => true,
+        }
+    }
+
+    fn read_size(&mut self, fixed: Option<u64>) -> Result<usize, Error> {
+        match fixed {
+            Some(size) => Ok(size as usize),
+            None => self.take_length(),
+        }
+    }
+
+    fn read_string(
+        &mut self,
+        tag: Tag,
+        charset: Charset,
+        fixed_size: Option<u64>,
+    ) -> Result<String, Error> {
+        self.field(|r| {
+            r.tag(tag)?;
+            let fixed = fixed_size
+                .filter(|_| charset.is_known_multiplier())
+                .map(|size| size * charset.octets_per_char().unwrap_or(1) as u64);
+            let length = r.read_size(fixed)?;
+            let string = Error::decode_string(charset, r.take(length)?.to_vec())?;
+            Error::ensure_string_valid(charset, &string)?;
+            Ok(string)
+        })
+    }
+
+    fn read_choice_alternative<C: choice::Constraint>(&mut self, tag: Tag) -> Result<C, Error> {
+        for index in 0..C::VARIANT_COUNT {
+            let pos = self.pos;
+            self.expected_tag = Some(tag);
+            let result = if index >= C::STD_VARIANT_COUNT {
+                self.read_open_type_with(|r| C::read_content(index, r))
+            } else {
+                C::read_content(index, self)
+            };
+            match result {
+                Ok(Some(value)) => return Ok(value),
+                Ok(None) => {}
+                Err(e) if matches!(e.kind(), ErrorKind::UnexpectedTag { found, .. } if *found == tag) =>
+                {
+                    self.pos = pos;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        self.expected_tag = None;
+        Err(ErrorKind::UnknownAlternative(C::NAME, tag).into())
+    }
+}
+
+impl Reader for OerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_complex<
+        C: complex::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.field(|r| {
+            if !C::UNTAGGED {
+                r.tag(C::TAG)?;
+            }
+            f(r)
+        })
+    }
+
+    #[inline]
+    fn read_explicit<
+        C: explicit::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            f(r)
+        })
+    }
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+            let bits = C::STD_OPTIONAL_FIELDS as usize + usize::from(extensible);
+            let preamble = r.take(bits.div_ceil(8))?.to_vec();
+
+            let depth = r.frames.len();
+            r.frames.push(ReadFrame {
+                name: C::NAME,
+                preamble,
+                extensible,
+                extended_after_field: C::EXTENDED_AFTER_FIELD,
+                field: 0,
+                in_field: false,
+                optional: 0,
+                additions: None,
+                addition: 0,
+            });
+            let result = f(r).and_then(|value| {
+                // unknown extension additions are skipped
+                let frame = r.additions()?;
+                let skipped = frame
+                    .additions
+                    .as_ref()
+                    .map(|additions| {
+                        additions
+                            .iter()
+                            .skip(frame.addition)
+                            .filter(|p| **p)
+                            .count()
+                    })
+                    .unwrap_or_default();
+                for _ in 0..skipped {
+                    r.read_open_type_with(|_| Ok(()))?;
+                }
+                Ok(value)
+            });
+            r.frames.truncate(depth);
+            result
+        })
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let octets = usize::from(r.take_byte()?);
+            let quantity = read_unsigned(r.take(octets)?)?;
+            // every element occupies at least one octet, except for the empty ones
+            let mut values = Vec::with_capacity((quantity as usize).min(r.bytes_remaining()));
+            for _ in 0..quantity {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let first = r.take_byte()?;
+            let value = if first & 0x80 == 0 {
+                i64::from(first)
+            } else {
+                let octets = usize::from(first & 0x7F);
+                read_signed(r.take(octets)?)?
+            };
+            C::from_value(value).ok_or_else(|| ErrorKind::InvalidEnumeratedValue(value).into())
+        })
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.field(|r| {
+            let (tag, octets) = read_tag(&r.bytes[r.pos..r.limit()])?;
+            r.pos += octets;
+            r.read_choice_alternative(tag)
+        })
+    }
+
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        self.field(|r| {
+            let index = C::index_of_key(key).ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+            r.read_open_type_with(|r| C::read_content(index, r))?
+                .ok_or_else(|| ErrorKind::InvalidOpenTypeKey(key).into())
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.field(|r| {
+            if let Some(tag) = C::TAG {
+                r.tag(tag)?;
+            }
+            let length = r.take_length()?;
+            Ok(r.take(length)?.to_vec())
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        self.field(|r| {
+            if r.presence() {
+                T::read_value(r).map(Some)
+            } else {
+                Ok(None)
+            }
+        })
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        self.field(|r| {
+            if r.presence() {
+                T::read_value(r)
+            } else {
+                Ok(C::DEFAULT_VALUE.to_owned())
+            }
+        })
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let value = match IntegerEncoding::from_constraint(C::MIN, C::MAX, C::EXTENSIBLE) {
+                IntegerEncoding::FixedUnsigned(octets) => unsigned_to_i64(r.take(octets)?)?,
+                IntegerEncoding::FixedSigned(octets) => read_signed(r.take(octets)?)?,
+                IntegerEncoding::VariableUnsigned => {
+                    let octets = r.take_length()?;
+                    unsigned_to_i64(r.take(octets)?)?
+                }
+                IntegerEncoding::VariableSigned => {
+                    let octets = r.take_length()?;
+                    read_signed(r.take(octets)?)?
+                }
+            };
+            if !C::EXTENSIBLE {
+                let min = C::MIN.unwrap_or(i64::MIN);
+                let max = C::MAX.unwrap_or(i64::MAX);
+                if value < min || value > max {
+                    return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+                }
+            }
+            Ok(T::from_i64(value))
+        })
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let length = r.take_length()?;
+            let content = r.take(length)?;
+            ber::read_real(content).map_err(|_| {
+                ErrorKind::InvalidReal(content.first().copied().unwrap_or_default()).into()
+            })
+        })
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Utf8, size)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Ia5, size)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Numeric, size)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Visible, size)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Bmp, size)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Universal, size)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Teletex, size)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Graphic, size)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::General, size)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let size = fixed_size(C::MIN, C::MAX, C::EXTENSIBLE);
+        self.read_string(C::TAG, Charset::Printable, size)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let length = r.read_size(fixed_size(C::MIN, C::MAX, C::EXTENSIBLE))?;
+            Ok(r.take(length)?.to_vec())
+        })
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let (mut bytes, bit_len) = match fixed_size(C::MIN, C::MAX, C::EXTENSIBLE) {
+                Some(bit_len) => (r.take(bit_len.div_ceil(8) as usize)?.to_vec(), bit_len),
+                None => {
+                    let length = r.take_length()?;
+                    if length == 0 {
+                        return Err(ErrorKind::InvalidContentLength(length).into());
+                    }
+                    let unused_bits = r.take_byte()?;
+                    if unused_bits > 7 || (length == 1 && unused_bits > 0) {
+                        return Err(ErrorKind::InvalidUnusedBits(unused_bits).into());
+                    }
+                    let bytes = r.take(length - 1)?.to_vec();
+                    let bit_len = (bytes.len() * 8) as u64 - u64::from(unused_bits);
+                    (bytes, bit_len)
+                }
+            };
+            let unused_bits = bytes.len() * 8 - bit_len as usize;
+            if let (Some(last), true) = (bytes.last_mut(), unused_bits > 0) {
+                *last &= 0xFF << unused_bits;
+            }
+            Ok((bytes, bit_len))
+        })
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            Ok(r.take_byte()? != BOOLEAN_FALSE)
+        })
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            Ok(Null)
+        })
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let length = r.take_length()?;
+            ber::read_object_identifier(r.take(length)?)
+                .map_err(|_| ErrorKind::InvalidObjectIdentifier.into())
+        })
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        self.field(|r| {
+            r.tag(C::TAG)?;
+            let length = r.take_length()?;
+            ber::read_relative_oid(r.take(length)?)
+                .map_err(|_| ErrorKind::InvalidObjectIdentifier.into())
+        })
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        let string = self.read_string(C::TAG, Charset::Visible, None)?;
+        if time::is_valid(r#type, &string) {
+            Ok(string)
+        } else {
+            Err(ErrorKind::InvalidTime(r#type, string).into())
+        }
+    }
+}
+
+#[inline]
+fn unsigned_to_i64(bytes: &[u8]) -> Result<i64, Error> {
+    let value = read_unsigned(bytes)?;
+    i64::try_from(value).map_err(|_| ErrorKind::ValueExceedsMaxInt.into())
+}
+
+pub trait OerDecodable<'a> {
+    fn decode_from_oer(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+impl<'a, R: Readable> OerDecodable<'a> for R {
+    fn decode_from_oer(bytes: &'a [u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut reader = OerReader::from(bytes);
+        Self::read(&mut reader)
+    }
+}
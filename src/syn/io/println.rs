@@ -1,4 +1,5 @@
 use crate::syn::*;
+use asn1rs_model::model::TimeType;
 
 #[derive(Default)]
 pub struct PrintlnWriter(usize);
@@ -121,6 +122,44 @@ impl Writer for PrintlnWriter {
         })
     }
 
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("Write open type {}", C::NAME));
+        self.with_increased_indentation(|w| {
+            w.indented_println(format!(
+                "choice_index {}/{}",
+                choice.to_choice_index(),
+                C::KEYS.len()
+            ));
+            choice.write_content(w)
+        })
+    }
+
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Write open type {} with invalid key {}, expected {}",
+            C::NAME,
+            key,
+            choice.key()
+        ));
+        Ok(())
+    }
+
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING OpenType, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:02x?}", value)));
+        Ok(())
+    }
+
     fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
         self.indented_println("Writing OPTIONAL");
         self.with_increased_indentation(|w| {
@@ -169,6 +208,12 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING Real, tag={:?}", C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -259,6 +304,101 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing BmpString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing UniversalString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing TeletexString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing GraphicString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!(
+            "Writing GeneralString({}..{}), tag={:?}",
+            C::MIN
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            C::MAX
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            C::TAG
+        ));
+        self.with_increased_indentation(|w| w.indented_println(format!("{:?}", value)));
+        Ok(())
+    }
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -307,4 +447,34 @@ impl Writer for PrintlnWriter {
         self.indented_println(format!("WRITING NULL, tag={:?}", C::TAG));
         Ok(())
     }
+
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING ObjectIdentifier, tag={:?}", C::TAG));
+        let value = ObjectIdentifier::from(value.to_vec());
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING RelativeOid, tag={:?}", C::TAG));
+        let value = RelativeOid::from(value.to_vec());
+        self.with_increased_indentation(|w| w.indented_println(value.to_string()));
+        Ok(())
+    }
+
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        r#type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING {}, tag={:?}", r#type.asn_name(), C::TAG));
+        self.with_increased_indentation(|w| w.indented_println(value));
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use crate::io::protobuf::ProtoRead as _;
 use crate::io::protobuf::{Error, Format};
 use crate::syn::*;
+use asn1rs_model::model::TimeType;
 use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::ops::Range;
@@ -201,6 +202,49 @@ impl<'a> ProtobufReader<'a> {
         self.increment_tag_counter();
         Ok(vec)
     }
+
+    /// Reads the alternative of a CHOICE or an open type from the field with the tag `index + 1`
+    #[inline]
+    fn read_alternative<T, F: FnOnce(u64, &mut Self) -> Result<Option<T>, Error>>(
+        &mut self,
+        name: &'static str,
+        read_content: F,
+    ) -> Result<T, Error> {
+        match self.next_tag_range::<true>() {
+            None => Err(Error::MissingRequiredField(name)),
+            Some(range) => {
+                let (format, range, tag) = {
+                    let reader = &mut &self.source[range.clone()];
+                    let len_before = reader.len();
+                    let (tag, format) = reader.read_tag()?;
+                    if format == Format::LengthDelimited {
+                        let _len = reader.read_varint()?;
+                    }
+                    let len_after = reader.len();
+                    let read = len_before - len_after;
+                    (format, range.start + read..range.end, tag)
+                };
+
+                let mut state = State::Enclosed {
+                    tag_counter: 1,
+                    tags: {
+                        let mut v = VecDeque::with_capacity(1);
+                        v.push_back((1u32, format, range));
+                        v
+                    },
+                };
+                core::mem::swap(&mut self.state, &mut state);
+                let result = read_content(u64::from(tag.saturating_sub(1)), self);
+                self.state = state;
+
+                match result {
+                    Err(e) => Err(e),
+                    Ok(None) => Err(Error::unexpected_tag((tag, Format::LengthDelimited))),
+                    Ok(Some(v)) => Ok(v),
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Reader for ProtobufReader<'a> {
@@ -254,40 +298,28 @@ impl<'a> Reader for ProtobufReader<'a> {
 
     #[inline]
     fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
-        match self.next_tag_range::<true>() {
-            None => Err(Error::MissingRequiredField(C::NAME)),
-            Some(range) => {
-                let (format, range, tag) = {
-                    let reader = &mut &self.source[range.clone()];
-                    let len_before = reader.len();
-                    let (tag, format) = reader.read_tag()?;
-                    if format == Format::LengthDelimited {
-                        let _len = reader.read_varint()?;
-                    }
-                    let len_after = reader.len();
-                    let read = len_before - len_after;
-                    (format, range.start + read..range.end, tag)
-                };
-
-                let mut state = State::Enclosed {
-                    tag_counter: 1,
-                    tags: {
-                        let mut v = VecDeque::with_capacity(1);
-                        v.push_back((1u32, format, range));
-                        v
-                    },
-                };
-                core::mem::swap(&mut self.state, &mut state);
-                let result = C::read_content(u64::from(tag.saturating_sub(1)), self);
-                self.state = state;
+        self.read_alternative(C::NAME, C::read_content)
+    }
 
-                match result {
-                    Err(e) => Err(e),
-                    Ok(None) => Err(Error::unexpected_tag((tag, Format::LengthDelimited))),
-                    Ok(Some(v)) => Ok(v),
-                }
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        // the alternative is known by its field tag, but has to match the related field
+        let expected = C::index_of_key(key);
+        self.read_alternative(C::NAME, |index, r| {
+            if Some(index) == expected {
+                C::read_content(index, r)
+            } else {
+                Ok(None)
             }
-        }
+        })
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.read_octet_string::<octetstring::NoConstraint>()
     }
 
     #[inline]
@@ -339,6 +371,18 @@ impl<'a> Reader for ProtobufReader<'a> {
         }
     }
 
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::Fixed64);
+
+        // protobuf does not serialize null or 0-ish values
+        if reader.is_empty() {
+            return Ok(0.0);
+        }
+
+        reader.read_double()
+    }
+
     #[inline]
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
         let mut reader = self.next_range_format_reader(Format::LengthDelimited);
@@ -371,6 +415,38 @@ impl<'a> Reader for ProtobufReader<'a> {
         reader.read_string()
     }
 
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+        reader.read_string()
+    }
+
     #[inline]
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
         let mut reader = self.next_range_format_reader(Format::LengthDelimited); // TODO Format::VarInt ??
@@ -401,4 +477,55 @@ impl<'a> Reader for ProtobufReader<'a> {
     fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
         Ok(Null)
     }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+
+        // protobuf does not serialize empty values
+        if reader.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let string = reader.read_string()?;
+        string
+            .parse::<ObjectIdentifier>()
+            .map(Vec::from)
+            .map_err(|_| Error::InvalidObjectIdentifier(string))
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+
+        // protobuf does not serialize empty values
+        if reader.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let string = reader.read_string()?;
+        string
+            .parse::<RelativeOid>()
+            .map(Vec::from)
+            .map_err(|_| Error::InvalidObjectIdentifier(string))
+    }
+
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        let mut reader = self.next_range_format_reader(Format::LengthDelimited);
+
+        // protobuf does not serialize empty values, which no time type has
+        let string = if reader.is_empty() {
+            String::new()
+        } else {
+            reader.read_string()?
+        };
+
+        if time::is_valid(r#type, &string) {
+            Ok(string)
+        } else {
+            Err(Error::InvalidTime(r#type, string))
+        }
+    }
 }
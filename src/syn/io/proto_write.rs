@@ -2,6 +2,7 @@ use crate::io::protobuf::ProtoWrite as _;
 use crate::io::protobuf::{Error, Format};
 use crate::prelude::ProtobufReader;
 use crate::syn::*;
+use asn1rs_model::model::TimeType;
 use std::io::Write;
 
 #[derive(Debug, Default, Copy, Clone)]
@@ -172,6 +173,47 @@ impl<'a> ProtobufWriter<'a> {
         //self.state.format = Some(Format::LengthDelimited);
         Ok(())
     }
+
+    /// Writes the alternative of a CHOICE or an open type as field with the tag `index + 1`
+    #[inline]
+    fn write_alternative<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        index: u64,
+        write_content: F,
+    ) -> Result<(), Error> {
+        let root = core::mem::take(&mut self.is_root);
+
+        let result = if !root {
+            let mut state = core::mem::take(&mut self.state);
+            let mut buffer = core::mem::take(&mut self.buffer);
+
+            // writing to the new buffer
+            self.state.tag_counter = index as u32;
+            let result = write_content(self);
+
+            // restore the original self attributes
+            core::mem::swap(&mut buffer, &mut self.buffer);
+            core::mem::swap(&mut state, &mut self.state);
+
+            if result.is_ok() {
+                let buffer = buffer.into_inner_vec().unwrap(); // fine because take creates a vec
+                let format = Format::LengthDelimited;
+                let tag = self.state.tag_counter + 1;
+                self.buffer.write_tag(tag, format)?;
+                self.buffer.write_bytes(&buffer[..])?;
+                self.state.tag_counter = tag;
+                self.state.format = Some(format);
+            }
+
+            result
+        } else {
+            self.state.tag_counter = index as u32;
+            write_content(self)
+        };
+
+        self.state.format = Some(Format::LengthDelimited);
+        result
+    }
 }
 
 impl Writer for ProtobufWriter<'_> {
@@ -229,38 +271,53 @@ impl Writer for ProtobufWriter<'_> {
 
     #[inline]
     fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
-        let root = core::mem::take(&mut self.is_root);
-
-        let result = if !root {
-            let mut state = core::mem::take(&mut self.state);
-            let mut buffer = core::mem::take(&mut self.buffer);
-
-            // writing to the new buffer
-            self.state.tag_counter = choice.to_choice_index() as u32;
-            let result = choice.write_content(self);
+        // an alternative unknown to the type was kept in another encoding
+        let index = choice.to_choice_index();
+        if index >= C::VARIANT_COUNT {
+            return Err(Error::invalid_variant(index));
+        }
+        self.write_alternative(index, |w| choice.write_content(w))
+    }
 
-            // restore the original self attributes
-            core::mem::swap(&mut buffer, &mut self.buffer);
-            core::mem::swap(&mut state, &mut self.state);
+    #[inline]
+    fn write_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if choice.key() != key {
+            return self.reject_open_type_key(choice, key);
+        }
+        // there is no field number for an object unknown to the type
+        if choice.unknown().is_some() {
+            return Err(Error::invalid_variant(choice.to_choice_index()));
+        }
+        self.write_open_type_alternative(choice)
+    }
 
-            if result.is_ok() {
-                let buffer = buffer.into_inner_vec().unwrap(); // fine because take creates a vec
-                let format = Format::LengthDelimited;
-                let tag = self.state.tag_counter + 1;
-                self.buffer.write_tag(tag, format)?;
-                self.buffer.write_bytes(&buffer[..])?;
-                self.state.tag_counter = tag;
-                self.state.format = Some(format);
-            }
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_alternative(choice.to_choice_index(), |w| choice.write_content(w))
+    }
 
-            result
-        } else {
-            self.state.tag_counter = choice.to_choice_index() as u32;
-            choice.write_content(self)
-        };
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+        _key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(Error::invalid_variant(choice.to_choice_index()))
+    }
 
-        self.state.format = Some(Format::LengthDelimited);
-        result
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_octet_string::<octetstring::NoConstraint>(value)
     }
 
     #[inline]
@@ -318,6 +375,15 @@ impl Writer for ProtobufWriter<'_> {
         Ok(())
     }
 
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_double(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::Fixed64);
+        Ok(())
+    }
+
     #[inline]
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
@@ -378,6 +444,66 @@ impl Writer for ProtobufWriter<'_> {
         Ok(())
     }
 
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
     #[inline]
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
@@ -419,4 +545,42 @@ impl Writer for ProtobufWriter<'_> {
     fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        let value = ObjectIdentifier::from(value.to_vec()).to_string();
+        self.buffer.write_tagged_string(tag, &value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        let value = RelativeOid::from(value.to_vec()).to_string();
+        self.buffer.write_tagged_string(tag, &value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
+
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        _type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.state.tag_counter + 1;
+        self.buffer.write_tagged_string(tag, value)?;
+        self.state.tag_counter = tag;
+        self.state.format = Some(Format::LengthDelimited);
+        Ok(())
+    }
 }
@@ -1,17 +1,26 @@
+use crate::io::per::alphabet::EffectiveAlphabet;
 use crate::io::per::err::Error;
 use crate::io::per::err::ErrorKind;
 use crate::io::per::unaligned::buffer::BitBuffer;
-use crate::io::per::unaligned::BitWrite;
 use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::unaligned::LENGTH_127;
+use crate::io::per::unaligned::LENGTH_16K;
 use crate::io::per::PackedRead;
 use crate::io::per::PackedWrite;
 use crate::model::Charset;
+use crate::model::TimeType;
+use crate::syn::io::uper_trace::{elements, hex, variant_name, Children, Trace};
 use crate::syn::*;
 use std::fmt::Debug;
+use std::io::Read;
 use std::ops::Range;
 
 pub use crate::io::per::unaligned::buffer::Bits;
+pub use crate::io::per::unaligned::buffer::BitsMut;
+pub use crate::io::per::unaligned::counter::BitCounter;
+pub use crate::io::per::unaligned::stream::{Pending, StreamBits};
 pub use crate::io::per::unaligned::ScopedBitRead;
+pub use crate::io::per::unaligned::ScopedBitWrite;
 
 #[derive(Debug, Clone)]
 pub enum Scope {
@@ -35,12 +44,17 @@ pub enum Scope {
     /// To find the beginning of part2 - and thus to be able to insert the secondary-header - one
     /// needs to keep track of the current field number. Also, the position of where to write
     /// the presence flags to must be updated as well.
+    ///
+    /// The extension additions that are unknown to the type but were kept when reading a value
+    /// follow the known ones. If any of them is present, the extension bit has to be set even if
+    /// all known extension additions are absent.
     ExtensibleSequence {
         name: &'static str,
         bit_pos: usize,
         opt_bit_field: Option<Range<usize>>,
         calls_until_ext_bitfield: usize,
         number_of_ext_fields: usize,
+        unknown_ext_fields_present: bool,
     },
     /// Indicates that the extensible sequence has no extension body
     ExtensibleSequenceEmpty(&'static str),
@@ -58,6 +72,7 @@ impl Scope {
                 opt_bit_field,
                 calls_until_ext_bitfield: _,
                 number_of_ext_fields: _,
+                unknown_ext_fields_present: _,
             } => match opt_bit_field {
                 Some(range) => range.start == range.end,
                 None => true,
@@ -66,6 +81,13 @@ impl Scope {
         }
     }
 
+    /// Whether presence flags of extension additions are left after all fields of the type were
+    /// read or written, which then belong to extension additions that are unknown to the type
+    #[inline]
+    pub const fn has_remaining_ext_bits(&self) -> bool {
+        matches!(self, Scope::AllBitField(range) if range.start < range.end)
+    }
+
     #[inline]
     pub const fn encode_as_open_type_field(&self) -> bool {
         matches!(
@@ -77,7 +99,7 @@ impl Scope {
     #[inline]
     pub fn write_into_field(
         &mut self,
-        buffer: &mut BitBuffer,
+        buffer: &mut impl ScopedBitWrite,
         is_opt: bool,
         is_present: bool,
     ) -> Result<(), Error> {
@@ -104,25 +126,30 @@ impl Scope {
                 opt_bit_field,
                 calls_until_ext_bitfield,
                 number_of_ext_fields,
+                unknown_ext_fields_present,
             } => {
                 if *calls_until_ext_bitfield == 0 {
-                    buffer.with_write_position_at(*ext_bit_pos, |b| b.write_bit(is_present))?;
-                    if is_present {
+                    let extended = is_present || *unknown_ext_fields_present;
+                    buffer.with_write_position_at(*ext_bit_pos, |b| b.write_bit(extended))?;
+                    if extended {
                         // when we reach this point, there is never zero numbers of ext-fields
                         buffer.write_normally_small_non_negative_whole_number(
                             *number_of_ext_fields as u64 - 1,
                         )?;
-                        let pos = buffer.write_position;
+                        let pos = buffer.pos();
                         for _ in 0..*number_of_ext_fields {
                             if let Err(e) = buffer.write_bit(true) {
-                                buffer.write_position = pos;
+                                buffer.set_pos(pos);
                                 return Err(e);
                             }
                         }
+                        if !is_present {
+                            buffer.with_write_position_at(pos, |b| b.write_bit(false))?;
+                        }
 
                         // pos + 1 because the bit for the current call is already set
                         // by the initializer loop above
-                        let range = pos + 1..buffer.write_position;
+                        let range = pos + 1..buffer.pos();
                         *self = Scope::AllBitField(range);
                     } else {
                         *self = Scope::ExtensibleSequenceEmpty(name);
@@ -190,34 +217,16 @@ impl Scope {
                 }
             }
             Scope::ExtensibleSequence {
-                name,
-                bit_pos: ext_bit_pos,
                 opt_bit_field,
                 calls_until_ext_bitfield,
-                number_of_ext_fields,
+                ..
             } => {
                 if *calls_until_ext_bitfield == 0 {
-                    if bits.with_read_position_at(*ext_bit_pos, |b| b.read_bit())? {
-                        let read_number_of_ext_fields =
-                            bits.read_normally_small_length()? as usize + 1;
-                        if read_number_of_ext_fields > *number_of_ext_fields {
-                            #[cfg(feature = "descriptive-deserialize-errors")]
-                            descriptions.push(ScopeDescription::warning(
-                                format!("read_number_of_ext_fields({read_number_of_ext_fields}) > *number_of_ext_fields({number_of_ext_fields})")
-                            ));
-                            //     return Err(Error::UnsupportedOperation(format!(
-                            //         "Expected no more than {} extended field{} but got {}",
-                            //         number_of_ext_fields,
-                            //         if *number_of_ext_fields != 1 { "s" } else { "" },
-                            //         read_number_of_ext_fields
-                            //     )));
-                        }
-                        let range = bits.pos()..bits.pos() + *number_of_ext_fields;
-                        bits.set_pos(range.start + read_number_of_ext_fields); // skip bit-field
-                        *self = Scope::AllBitField(range);
-                    } else {
-                        *self = Scope::ExtensibleSequenceEmpty(name);
-                    }
+                    self.read_ext_bit_field(
+                        #[cfg(feature = "descriptive-deserialize-errors")]
+                        descriptions,
+                        bits,
+                    )?;
                     self.read_from_field(
                         #[cfg(feature = "descriptive-deserialize-errors")]
                         descriptions,
@@ -241,39 +250,108 @@ impl Scope {
             Scope::ExtensibleSequenceEmpty(_) => Ok(Some(false)),
         }
     }
+
+    /// Reads the extension bit and the presence flags of the extension additions, once all
+    /// fields of the root component list of an extensible sequence were read
+    #[inline]
+    pub fn read_ext_bit_field(
+        &mut self,
+        #[cfg(feature = "descriptive-deserialize-errors")] descriptions: &mut Vec<ScopeDescription>,
+        bits: &mut impl ScopedBitRead,
+    ) -> Result<(), Error> {
+        if let Scope::ExtensibleSequence {
+            name,
+            bit_pos: ext_bit_pos,
+            opt_bit_field: _,
+            calls_until_ext_bitfield: 0,
+            number_of_ext_fields,
+            unknown_ext_fields_present: _,
+        } = self
+        {
+            if bits.with_read_position_at(*ext_bit_pos, |b| b.read_bit())? {
+                let read_number_of_ext_fields = bits.read_normally_small_length()? as usize + 1;
+                if read_number_of_ext_fields > *number_of_ext_fields {
+                    #[cfg(feature = "descriptive-deserialize-errors")]
+                    descriptions.push(ScopeDescription::warning(
+                        format!("read_number_of_ext_fields({read_number_of_ext_fields}) > *number_of_ext_fields({number_of_ext_fields}), keeping the unknown ones")
+                    ));
+                }
+                // the flags beyond the known extension additions are left for
+                // `read_unknown_extensions`, missing ones mean absent additions
+                let range = bits.pos()..bits.pos() + read_number_of_ext_fields;
+                bits.set_pos(range.end); // skip bit-field
+                *self = Scope::AllBitField(range);
+            } else {
+                *self = Scope::ExtensibleSequenceEmpty(name);
+            }
+        }
+        Ok(())
+    }
 }
 
-#[derive(Default)]
-pub struct UperWriter {
-    bits: BitBuffer,
+/// Writes into a growing [`BitBuffer`] by default. To encode without allocating, it can write
+/// into a borrowed slice instead, see [`BitsMut`]:
+///
+/// ```rust
+/// use asn1rs::prelude::*;
+///
+/// let mut dma_buffer = [0_u8; 64];
+/// let mut writer = UperWriter::from(&mut dma_buffer[..]);
+/// writer.write_utf8string::<asn1rs::syn::utf8string::NoConstraint>("hi").unwrap();
+/// assert_eq!(&[0x02, 0x68, 0x69], writer.byte_content());
+///
+/// let mut too_small = [0_u8; 2];
+/// let mut writer = UperWriter::from(&mut too_small[..]);
+/// assert!(writer.write_utf8string::<asn1rs::syn::utf8string::NoConstraint>("hi").is_err());
+/// ```
+pub struct UperWriter<B: ScopedBitWrite = BitBuffer> {
+    bits: B,
     scope: Option<Scope>,
 }
 
+impl Default for UperWriter {
+    fn default() -> Self {
+        Self::from(BitBuffer::default())
+    }
+}
+
+impl<B: ScopedBitWrite> From<B> for UperWriter<B> {
+    fn from(bits: B) -> Self {
+        Self { bits, scope: None }
+    }
+}
+
+impl<'a> From<&'a mut [u8]> for UperWriter<BitsMut<'a>> {
+    fn from(slice: &'a mut [u8]) -> Self {
+        Self::from(BitsMut::from(slice))
+    }
+}
+
 impl UperWriter {
     pub fn with_capacity(capacity_bytes: usize) -> Self {
-        Self {
-            bits: BitBuffer::with_capacity(capacity_bytes),
-            ..Default::default()
-        }
+        Self::from(BitBuffer::with_capacity(capacity_bytes))
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        debug_assert_eq!(self.bit_len().div_ceil(BYTE_LEN), self.bits.buffer.len());
+        self.bits.into()
     }
+}
 
+impl<B: ScopedBitWrite> UperWriter<B> {
     pub fn byte_content(&self) -> &[u8] {
         self.bits.content()
     }
 
-    pub const fn bit_len(&self) -> usize {
-        self.bits.bit_len()
+    pub fn bit_len(&self) -> usize {
+        self.bits.pos()
     }
 
-    pub fn into_bytes_vec(self) -> Vec<u8> {
-        debug_assert_eq!(
-            (self.bit_len() + BYTE_LEN - 1) / BYTE_LEN,
-            self.bits.buffer.len()
-        );
-        self.bits.into()
+    pub fn into_inner(self) -> B {
+        self.bits
     }
 
-    pub fn as_reader(&self) -> UperReader<Bits> {
+    pub fn as_reader(&self) -> UperReader<Bits<'_>> {
         UperReader::from(Bits::from((self.byte_content(), self.bit_len())))
     }
 
@@ -325,16 +403,65 @@ impl UperWriter {
         f: F,
     ) -> Result<T, Error> {
         if const_map_or!(self.scope, Scope::encode_as_open_type_field, false) {
-            let mut writer = UperWriter::with_capacity(512);
-            let result = f(&mut writer)?;
-            self.bits
-                .write_octetstring(None, None, false, writer.bits.content())?;
-            Ok(result)
+            self.write_open_type_with(false, f)
         } else {
             f(self)
         }
     }
 
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 11.2, writes the complete encoding of the
+    /// closure as an unconstrained octet string without a separate buffer. One octet is reserved
+    /// for the length determinant. If it needs two octets (11.9.3.7) or the encoding has to be
+    /// fragmented (11.9.3.8), the encoding is moved to make room for them.
+    #[inline]
+    fn write_open_type_with<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        empty_as_zero_octet: bool,
+        f: F,
+    ) -> Result<T, Error> {
+        let start = self.bits.pos();
+        self.bits.write_bits(&[0x00])?;
+        let content_start = self.bits.pos();
+        let result = self.scope_stashed(f)?;
+
+        if empty_as_zero_octet && self.bits.pos() == content_start {
+            self.bits.write_bits(&[0x00])?;
+        }
+        while !(self.bits.pos() - content_start).is_multiple_of(BYTE_LEN) {
+            self.bits.write_bit(false)?;
+        }
+
+        let len = ((self.bits.pos() - content_start) / BYTE_LEN) as u64;
+        let mut header = start;
+        let mut reserved = BYTE_LEN;
+        let mut written = 0;
+
+        loop {
+            let remaining = len - written;
+            let header_len = if remaining > LENGTH_127 && remaining < LENGTH_16K {
+                2 * BYTE_LEN
+            } else {
+                BYTE_LEN
+            };
+            while reserved < header_len {
+                self.bits.insert_octet(header + reserved)?;
+                reserved += BYTE_LEN;
+            }
+
+            let fragment = self.bits.with_write_position_at(header, |bits| {
+                bits.write_length_determinant(None, None, remaining)
+            })?;
+
+            if let Some(fragment) = fragment {
+                written += fragment;
+                header += header_len + fragment as usize * BYTE_LEN;
+                reserved = 0;
+            } else {
+                return Ok(result);
+            }
+        }
+    }
+
     #[inline]
     pub fn write_extensible_bit_and_length_or_err(
         &mut self,
@@ -364,20 +491,22 @@ impl UperWriter {
 
         Ok(out_of_range)
     }
-}
-
-impl Writer for UperWriter {
-    type Error = Error;
 
+    /// Writes a sequence whose extension additions are followed by the given ones that are
+    /// unknown to its type, see [`Reader::read_unknown_extensions`]
     #[inline]
-    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+    fn write_sequence_extended_by<
+        C: sequence::Constraint,
+        F: Fn(&mut Self) -> Result<(), Error>,
+    >(
         &mut self,
+        unknown: &[Option<OpenType>],
         f: F,
-    ) -> Result<(), Self::Error> {
+    ) -> Result<(), Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
             let extension = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
-                let bit_pos = w.bits.write_position;
+                let bit_pos = w.bits.pos();
                 // if no extension field is present, none will call into overwriting this
                 w.bits.write_bit(false)?;
                 Some((extension_after, bit_pos))
@@ -388,13 +517,13 @@ impl Writer for UperWriter {
             // In UPER the values for all OPTIONAL flags are written before any field
             // value is written. This remembers their position, so a later call of `write_opt`
             // can write them to the buffer
-            let write_pos = w.bits.write_position;
+            let write_pos = w.bits.pos();
             let range = write_pos..write_pos + C::STD_OPTIONAL_FIELDS as usize;
             for _ in 0..C::STD_OPTIONAL_FIELDS {
                 // insert in reverse order so that a simple pop() in `write_opt` retrieves
                 // the relevant position
                 if let Err(e) = w.bits.write_bit(false) {
-                    w.bits.write_position = write_pos; // undo write_bits
+                    w.bits.set_pos(write_pos); // undo write_bits
                     return Err(e);
                 }
             }
@@ -406,9 +535,14 @@ impl Writer for UperWriter {
                         bit_pos,
                         opt_bit_field: Some(range),
                         calls_until_ext_bitfield: (extension_after + 1) as usize,
-                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize
+                            + unknown.len(),
+                        unknown_ext_fields_present: unknown.iter().any(Option::is_some),
+                    },
+                    |w| {
+                        f(w)?;
+                        w.write_unknown_ext_fields(unknown)
                     },
-                    f,
                 )
             } else {
                 w.scope_pushed(Scope::OptBitField(range), f)
@@ -416,6 +550,111 @@ impl Writer for UperWriter {
         })
     }
 
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, 19.9, the extension additions unknown to the type are
+    /// written as the open types they were read as
+    #[inline]
+    fn write_unknown_ext_fields(&mut self, unknown: &[Option<OpenType>]) -> Result<(), Error> {
+        for addition in unknown {
+            self.write_bit_field_entry(true, addition.is_some())?;
+            if let Some(addition) = addition {
+                self.bits
+                    .write_octetstring(None, None, false, addition.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5, `BMPString` and `UniversalString` in
+    /// their full width of 16 and 32 bits per character
+    #[inline]
+    fn write_wide_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        self.write_extensible_bit_and_length_or_err(
+            extensible,
+            min,
+            max,
+            u64::MAX,
+            value.chars().count() as u64,
+        )?;
+        self.bits.write_bits(&charset.encode_octets(value))
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.4, the characters of a string that is
+    /// constrained through `FROM` only take as many bits as needed for the permitted alphabet
+    #[inline]
+    fn write_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: EffectiveAlphabet,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        self.write_extensible_bit_and_length_or_err(
+            extensible,
+            min,
+            max,
+            u64::MAX,
+            value.chars().count() as u64,
+        )?;
+
+        let offset = core::mem::size_of::<u32>() * BYTE_LEN - alphabet.bits_per_char();
+        for (index, char) in value.chars().enumerate() {
+            let value = alphabet
+                .encode(char)
+                .ok_or(ErrorKind::InvalidString(charset, char, index))?;
+            self.bits
+                .write_bits_with_offset(&value.to_be_bytes(), offset)?;
+        }
+
+        Ok(())
+    }
+
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.6, the character strings that are not
+    /// known-multiplier are encoded like an unconstrained `OCTET STRING`
+    #[inline]
+    #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
+    fn write_octet_string_of_chars(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        if !extensible {
+            let chars = value.chars().count() as u64;
+            let min = const_unwrap_or!(min, 0);
+            let max = const_unwrap_or!(max, u64::MAX);
+            if chars < min || chars > max {
+                return Err(ErrorKind::SizeNotInRange(chars, min, max).into());
+            }
+        }
+        self.bits
+            .write_octetstring(None, None, false, &charset.encode_octets(value))
+    }
+}
+
+impl<B: ScopedBitWrite> Writer for UperWriter<B> {
+    type Error = Error;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_extended_by::<C, F>(&[], f)
+    }
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
@@ -457,6 +696,30 @@ impl Writer for UperWriter {
         self.write_sequence_of::<C, T>(slice)
     }
 
+    #[inline]
+    fn write_sequence_with_unknown_extensions<
+        C: sequence::Constraint,
+        F: Fn(&mut Self) -> Result<(), Self::Error>,
+    >(
+        &mut self,
+        unknown: &UnknownExtensions,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_extended_by::<C, F>(unknown.additions(), f)
+    }
+
+    #[inline]
+    fn write_set_with_unknown_extensions<
+        C: set::Constraint,
+        F: Fn(&mut Self) -> Result<(), Self::Error>,
+    >(
+        &mut self,
+        unknown: &UnknownExtensions,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_extended_by::<C, F>(unknown.additions(), f)
+    }
+
     #[inline]
     fn write_enumerated<C: enumerated::Constraint>(
         &mut self,
@@ -482,18 +745,48 @@ impl Writer for UperWriter {
             w.bits
                 .write_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE, index)?;
 
-            if index >= C::STD_VARIANT_COUNT {
-                // TODO performance
-                let mut writer = UperWriter::with_capacity(512);
-                choice.write_content(&mut writer)?;
+            if let Some(content) = choice.unknown_extension() {
                 w.bits
-                    .write_octetstring(None, None, false, writer.byte_content())
+                    .write_octetstring(None, None, false, content.as_bytes())
+            } else if index >= C::STD_VARIANT_COUNT {
+                w.write_open_type_with(false, |w| choice.write_content(w))
             } else {
                 choice.write_content(w)
             }
         })
     }
 
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            // ITU-T X.691 | ISO/IEC 8825-2, 11.2, the complete encoding as octet string, in
+            // which an empty encoding is replaced by a single zero octet
+            w.write_open_type_with(true, |w| choice.write_content(w))
+        })
+    }
+
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        _choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_octetstring(None, None, false, value))
+    }
+
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_opt<T: WritableType>(
@@ -560,6 +853,12 @@ impl Writer for UperWriter {
         }
     }
 
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_real(value))
+    }
+
     #[inline]
     #[allow(clippy::redundant_pattern_matching)] // allow for const_*!
     fn write_utf8string<C: utf8string::Constraint>(
@@ -591,6 +890,16 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
             Error::ensure_string_valid(Charset::Ia5, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -617,6 +926,16 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
             Error::ensure_string_valid(Charset::Numeric, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -646,6 +965,16 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
             Error::ensure_string_valid(Charset::Printable, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -671,6 +1000,16 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
             Error::ensure_string_valid(Charset::Visible, value)?;
 
             w.write_extensible_bit_and_length_or_err(
@@ -689,6 +1028,81 @@ impl Writer for UperWriter {
         })
     }
 
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            w.write_wide_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return w.write_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                    value,
+                );
+            }
+            w.write_wide_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_octet_string_of_chars(Charset::Teletex, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_octet_string_of_chars(Charset::Graphic, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            w.write_octet_string_of_chars(Charset::General, C::EXTENSIBLE, C::MIN, C::MAX, value)
+        })
+    }
+
     #[inline]
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
@@ -724,36 +1138,227 @@ impl Writer for UperWriter {
     fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
         Ok(())
     }
-}
-
-pub struct UperReader<B: ScopedBitRead> {
-    bits: B,
-    scope: Option<Scope>,
-    #[cfg(feature = "descriptive-deserialize-errors")]
-    scope_description: Vec<ScopeDescription>,
-}
 
-/*
-impl<B: ScopedBitRead> From<B> for UperReader<B> {
-    fn from(bits: B) -> Self {
-        UperReader { bits, scope: None }
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_object_identifier(value))
     }
-}*/
 
-impl<'a, I: Into<Bits<'a>>> From<I> for UperReader<Bits<'a>> {
-    fn from(bits: I) -> Self {
-        Self {
-            bits: bits.into(),
-            scope: None,
-            #[cfg(feature = "descriptive-deserialize-errors")]
-            scope_description: Vec::new(),
-        }
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| w.bits.write_relative_oid(value))
     }
-}
 
-impl<B: ScopedBitRead> UperReader<B> {
     #[inline]
-    fn read_length_determinant(
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        _type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_bit_field_entry(false, true)?;
+        self.with_buffer(|w| {
+            // encoded as an unconstrained VisibleString
+            Error::ensure_string_valid(Charset::Visible, value)?;
+            w.write_extensible_bit_and_length_or_err(
+                false,
+                None,
+                None,
+                u64::MAX,
+                value.chars().count() as u64,
+            )?;
+
+            for char in value.chars() {
+                w.bits.write_bits_with_offset(&[char as u8], 1)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// The number of bits of the UPER encoding of the value, determined by a [`UperWriter`] that
+/// only counts the bits through a [`BitCounter`]. It fails with the error that encoding the value
+/// would fail with.
+pub fn uper_encoded_bits(value: &impl Writable) -> Result<usize, Error> {
+    let mut writer = UperWriter::from(BitCounter::default());
+    writer.write(value)?;
+    Ok(writer.bit_len())
+}
+
+pub struct UperReader<B: ScopedBitRead> {
+    bits: B,
+    scope: Option<Scope>,
+    #[cfg(feature = "descriptive-deserialize-errors")]
+    scope_description: Vec<ScopeDescription>,
+    trace: Option<Trace>,
+}
+
+/*
+impl<B: ScopedBitRead> From<B> for UperReader<B> {
+    fn from(bits: B) -> Self {
+        UperReader { bits, scope: None }
+    }
+}*/
+
+impl<'a, I: Into<Bits<'a>>> From<I> for UperReader<Bits<'a>> {
+    fn from(bits: I) -> Self {
+        Self {
+            bits: bits.into(),
+            scope: None,
+            #[cfg(feature = "descriptive-deserialize-errors")]
+            scope_description: Vec::new(),
+            trace: None,
+        }
+    }
+}
+
+impl<R: Read> From<StreamBits<R>> for UperReader<StreamBits<R>> {
+    fn from(bits: StreamBits<R>) -> Self {
+        Self {
+            bits,
+            scope: None,
+            #[cfg(feature = "descriptive-deserialize-errors")]
+            scope_description: Vec::new(),
+            trace: None,
+        }
+    }
+}
+
+impl<R: Read> UperReader<StreamBits<R>> {
+    /// Reads the messages of the source as they arrive, see [`UperReader::read_message`]
+    pub fn from_read(source: R) -> Self {
+        Self::from(StreamBits::new(source))
+    }
+
+    /// Appends received bytes, see [`StreamBits::push`]
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.bits.push(bytes);
+    }
+
+    /// Reads the next of several messages that follow each other, each padded to a multiple of
+    /// eight bits. If the data ends within the message and the source would block, the reading
+    /// fails with [`ErrorKind::NeedMoreData`], the bytes of the message are kept and the next call
+    /// starts at its beginning again once more bytes are pushed or readable from the source. On
+    /// any other error, the bytes up to the failing read are dropped, so that the next call does
+    /// not fail on the same bytes again. The remainder of a malformed message cannot be told apart
+    /// from the next message though, unless it failed on its last read.
+    ///
+    /// Each retry decodes the message from its beginning. A retry therefore fails early until the
+    /// bytes that the failed read needed are received, but a large message that is pushed in many
+    /// small chunks is still decoded about once per chunk.
+    pub fn read_message<T: Readable>(&mut self) -> Result<T, Error> {
+        self.bits.receive_needed()?;
+        let traced = self.trace.as_ref().map(|trace| trace.entries().len());
+        let result = self.read::<T>();
+        match &result {
+            Ok(_) => self.bits.finish_message(),
+            Err(e) if e.kind() == &ErrorKind::NeedMoreData => {
+                self.bits.rewind();
+                // the message is traced again once more bytes are available
+                if let (Some(trace), Some(len)) = (&mut self.trace, traced) {
+                    trace.truncate(len);
+                }
+            }
+            Err(_) => self.bits.finish_message(),
+        }
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.clear();
+        result
+    }
+
+    pub fn into_inner(self) -> R {
+        self.bits.into_inner()
+    }
+}
+
+impl<B: ScopedBitRead> UperReader<B> {
+    /// Records every value decoded from now on in a [`Trace`], an annotated dissection with the
+    /// position, the raw bits and the decoded value of each component, which shows where and
+    /// why an encoding from another implementation is not understood.
+    ///
+    /// ```rust
+    /// use asn1rs::prelude::*;
+    ///
+    /// let mut writer = UperWriter::default();
+    /// writer.write_utf8string::<asn1rs::syn::utf8string::NoConstraint>("hi").unwrap();
+    ///
+    /// let mut reader = writer.as_reader().with_trace();
+    /// reader.read_utf8string::<asn1rs::syn::utf8string::NoConstraint>().unwrap();
+    ///
+    /// let entry = &reader.trace().unwrap().entries()[0];
+    /// assert_eq!((0, 24), (entry.offset, entry.len));
+    /// assert_eq!(Some("\"hi\""), entry.value.as_deref());
+    /// ```
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(Trace::default());
+        self
+    }
+
+    #[inline]
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    /// Returns the entries recorded so far and continues with an empty [`Trace`], if tracing is
+    /// enabled
+    pub fn take_trace(&mut self) -> Option<Trace> {
+        self.trace.as_mut().map(core::mem::take)
+    }
+
+    #[inline]
+    fn trace_begin(
+        &mut self,
+        kind: &'static str,
+        type_name: Option<&'static str>,
+    ) -> Option<usize> {
+        let offset = self.bits.pos();
+        self.trace
+            .as_mut()
+            .map(|trace| trace.begin(kind, type_name, offset))
+    }
+
+    #[inline]
+    fn trace_children(&mut self, children: Children) {
+        if let Some(trace) = &mut self.trace {
+            trace.set_children(children);
+        }
+    }
+
+    fn trace_end<T, F: FnOnce(&T) -> Option<String>>(
+        &mut self,
+        token: Option<usize>,
+        result: &Result<T, Error>,
+        value: F,
+    ) {
+        if let (Some(token), Some(trace)) = (token, &mut self.trace) {
+            if let Some(offset) = trace.offset(token) {
+                let pos = self.bits.pos();
+                let len = pos.saturating_sub(offset);
+                let mut bits = vec![0_u8; len.div_ceil(BYTE_LEN)];
+                self.bits.set_pos(offset);
+                if self.bits.read_bits_with_len(&mut bits, len).is_err() {
+                    bits.clear();
+                }
+                self.bits.set_pos(pos);
+                let value = match result {
+                    Ok(result) => Ok(value(result)),
+                    Err(e) => Err(e.kind().clone()),
+                };
+                trace.end(token, len, bits, value);
+            }
+        }
+    }
+
+    #[inline]
+    fn read_length_determinant(
         &mut self,
         lower_bound: Option<u64>,
         upper_bound: Option<u64>,
@@ -894,41 +1499,98 @@ impl<B: ScopedBitRead> UperReader<B> {
             f(self)
         }
     }
-}
 
-impl<B: ScopedBitRead> Reader for UperReader<B> {
-    type Error = Error;
+    #[inline]
+    fn read_wide_string(
+        &mut self,
+        charset: Charset,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let len = if extensible && self.bits.read_bit()? {
+            self.read_length_determinant(None, None)?
+        } else {
+            self.read_length_determinant(min, max)?
+        };
+
+        let octets_per_char = charset.octets_per_char().unwrap_or(1);
+        let mut buffer = vec![0u8; len as usize * octets_per_char];
+        self.bits.read_bits(&mut buffer)?;
+        Error::decode_string(charset, buffer)
+    }
 
     #[inline]
-    fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
-    where
-        Self: Sized,
-    {
-        #[allow(clippy::let_and_return)]
-        let value = T::read(self);
-        #[cfg(feature = "descriptive-deserialize-errors")]
-        let value = value.map_err(|mut e| {
-            e.0.description = core::mem::take(&mut self.scope_description);
-            e
-        });
-        value
+    fn read_permitted_alphabet_string(
+        &mut self,
+        charset: Charset,
+        alphabet: EffectiveAlphabet,
+        extensible: bool,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Result<String, Error> {
+        let len = if extensible && self.bits.read_bit()? {
+            self.read_length_determinant(None, None)?
+        } else {
+            self.read_length_determinant(min, max)?
+        };
+
+        let offset = core::mem::size_of::<u32>() * BYTE_LEN - alphabet.bits_per_char();
+        (0..len)
+            .map(|_| {
+                let mut value = [0_u8; 4];
+                self.bits.read_bits_with_offset(&mut value, offset)?;
+                alphabet
+                    .decode(u32::from_be_bytes(value))
+                    .ok_or_else(|| ErrorKind::InvalidStringEncoding(charset).into())
+            })
+            .collect()
     }
 
     #[inline]
-    fn read_sequence<
-        C: sequence::Constraint,
-        S: Sized,
-        F: Fn(&mut Self) -> Result<S, Self::Error>,
-    >(
+    fn read_octet_string_of_chars(&mut self, charset: Charset) -> Result<String, Error> {
+        let octets = self.bits.read_octetstring(None, None, false)?;
+        let string = Error::decode_string(charset, octets)?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    #[inline]
+    fn read_extension_additions(&mut self) -> Result<Vec<Option<OpenType>>, Error> {
+        // without known extension additions, their presence flags were not read yet
+        if let Some(scope) = &mut self.scope {
+            scope.read_ext_bit_field(
+                #[cfg(feature = "descriptive-deserialize-errors")]
+                &mut self.scope_description,
+                &mut self.bits,
+            )?;
+        }
+
+        let mut additions = Vec::new();
+        while const_map_or!(self.scope, Scope::has_remaining_ext_bits, false) {
+            if self.read_bit_field_entry(true)? == Some(true) {
+                let content = self.bits.read_octetstring(None, None, false)?;
+                additions.push(Some(OpenType::from(content)));
+            } else {
+                additions.push(None);
+            }
+        }
+        Ok(additions)
+    }
+
+    #[inline]
+    fn read_components<C: sequence::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Error>>(
         &mut self,
+        kind: &'static str,
         f: F,
-    ) -> Result<S, Self::Error> {
+    ) -> Result<S, Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description
             .push(ScopeDescription::sequence::<C>());
 
         let _ = self.read_bit_field_entry(false);
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin(kind, Some(C::NAME));
+        self.trace_children(Children::Components(C::FIELD_NAMES, 0));
         let result = self.with_buffer(|r| {
             let extension_after = if let Some(extension_after) = C::EXTENDED_AFTER_FIELD {
                 let bit_pos = r.bits.pos();
@@ -959,8 +1621,15 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                         opt_bit_field: Some(range),
                         calls_until_ext_bitfield: (extension_after + 1) as usize,
                         number_of_ext_fields: (C::FIELD_COUNT - (extension_after + 1)) as usize,
+                        unknown_ext_fields_present: false,
+                    },
+                    |r| {
+                        let value = f(r)?;
+                        // skips the extension additions a type without the field
+                        // `unknown_extensions` does not keep
+                        r.read_unknown_extensions::<C>()?;
+                        Ok(value)
                     },
-                    f,
                 )
             } else {
                 r.scope_pushed(Scope::OptBitField(range), f)
@@ -970,20 +1639,23 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description.push(ScopeDescription::End(C::NAME));
 
+        self.trace_end(trace, &result, |_| None);
         result
     }
 
     #[inline]
-    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+    fn read_elements<C: sequenceof::Constraint, T: ReadableType>(
         &mut self,
-    ) -> Result<Vec<T::Type>, Self::Error> {
+        kind: &'static str,
+    ) -> Result<Vec<T::Type>, Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description
             .push(ScopeDescription::sequence_of::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
-        self.with_buffer(|r| {
+        let trace = self.trace_begin(kind, None);
+        self.trace_children(Children::Elements(0));
+        let result = self.with_buffer(|r| {
             let len = if C::EXTENSIBLE {
                 let extensible = r.bits.read_bit()?;
                 if extensible {
@@ -1006,7 +1678,51 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             } else {
                 Ok(Vec::new())
             }
-        })
+        });
+
+        self.trace_end(trace, &result, |v| Some(elements(v.len())));
+        result
+    }
+}
+
+impl<B: ScopedBitRead> Reader for UperReader<B> {
+    type Error = Error;
+
+    #[inline]
+    fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+    {
+        #[allow(clippy::let_and_return)]
+        let value = T::read(self);
+        if let Some(trace) = &mut self.trace {
+            trace.close();
+        }
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        let value = value.map_err(|mut e| {
+            e.0.description = core::mem::take(&mut self.scope_description);
+            e
+        });
+        value
+    }
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_components::<C, S, F>("SEQUENCE", f)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_elements::<C, T>("SEQUENCE OF")
     }
 
     #[inline]
@@ -1014,14 +1730,33 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         &mut self,
         f: F,
     ) -> Result<S, Self::Error> {
-        self.read_sequence::<C, S, F>(f)
+        self.read_components::<C, S, F>("SET", f)
     }
 
     #[inline]
     fn read_set_of<C: setof::Constraint, T: ReadableType>(
         &mut self,
     ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
-        self.read_sequence_of::<C, T>()
+        self.read_elements::<C, T>("SET OF")
+    }
+
+    #[inline]
+    fn read_unknown_extensions<C: sequence::Constraint>(
+        &mut self,
+    ) -> Result<UnknownExtensions, Self::Error> {
+        let trace = self.trace_begin("unknown extension additions", None);
+        let result = self.read_extension_additions();
+
+        match (&result, trace, &mut self.trace) {
+            (Ok(additions), Some(token), Some(trace)) if additions.is_empty() => {
+                trace.discard(token)
+            }
+            _ => self.trace_end(trace, &result, |additions| {
+                let present = additions.iter().filter(|a| a.is_some()).count();
+                Some(format!("{present} of {} present", additions.len()))
+            }),
+        }
+        result.map(UnknownExtensions::from)
     }
 
     #[inline]
@@ -1031,7 +1766,7 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::enumerated::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("ENUMERATED", Some(C::NAME));
         let result = self.with_buffer(|r| r.read_enumeration_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE))
             .and_then(|index| {
                 #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1055,6 +1790,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description.push(ScopeDescription::End(C::NAME));
 
+        self.trace_end(trace, &result, |v| {
+            Some(variant_name(C::VARIANT_NAMES, v.to_choice_index()))
+        });
+
         result
     }
 
@@ -1064,12 +1803,18 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description.push(ScopeDescription::choice::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("CHOICE", Some(C::NAME));
         let result = self.scope_stashed(|r| {
             let index = r
                 .bits
                 .read_choice_index(C::STD_VARIANT_COUNT, C::EXTENSIBLE)?;
-            if index >= C::STD_VARIANT_COUNT {
+            if let Some(name) = C::VARIANT_NAMES.get(index as usize) {
+                r.trace_children(Children::Alternative(name));
+            }
+            if index >= C::VARIANT_COUNT {
+                let content = OpenType::from(r.bits.read_octetstring(None, None, false)?);
+                Ok((index, C::from_unknown_extension(index, content)))
+            } else if index >= C::STD_VARIANT_COUNT {
                 let length = r.read_length_determinant(None, None)?;
                 r.read_whole_sub_slice(length as usize, |r| Ok((index, C::read_content(index, r)?)))
             } else {
@@ -1083,6 +1828,69 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         #[cfg(feature = "descriptive-deserialize-errors")]
         self.scope_description.push(ScopeDescription::End(C::NAME));
 
+        self.trace_end(trace, &result, |v| {
+            Some(variant_name(C::VARIANT_NAMES, v.to_choice_index()))
+        });
+        result
+    }
+
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::open_type_choice::<C>(key));
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("open type", Some(C::NAME));
+        let result = self.with_buffer(|r| {
+            r.scope_stashed(|r| {
+                let index = C::index_of_key(key).ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+                if let Some(name) = C::VARIANT_NAMES.get(index as usize) {
+                    r.trace_children(Children::Alternative(name));
+                }
+                let length = r.read_length_determinant(None, None)?;
+                r.read_whole_sub_slice(length as usize, |r| C::read_content(index, r))?
+                    .ok_or_else(|| ErrorKind::InvalidOpenTypeKey(key).into())
+            })
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::End(C::NAME));
+
+        self.trace_end(trace, &result, |v| {
+            Some(variant_name(C::VARIANT_NAMES, v.to_choice_index()))
+        });
+        result
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::open_type::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("open type", None);
+        let result = self.with_buffer(|r| r.bits.read_octetstring(None, None, false));
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(|s| {
+                    s.iter()
+                        .map(|v| format!("{v:02x}"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .map_err(|e| e.clone()),
+        ));
+
+        self.trace_end(trace, &result, |v| Some(hex(v)));
+
         result
     }
 
@@ -1094,12 +1902,20 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description.push(ScopeDescription::optional());
 
         // unwrap: as opt-field this must and will return some value
-        if self.read_bit_field_entry(true)?.unwrap() {
+        let present = self.read_bit_field_entry(true)?.unwrap();
+        let trace = self.trace_begin("OPTIONAL", None);
+        let result = if present {
+            self.trace_children(Children::Content);
             self.with_buffer(|w| w.scope_stashed(T::read_value))
                 .map(Some)
         } else {
             Ok(None)
-        }
+        };
+
+        self.trace_end(trace, &result, |v| {
+            v.is_none().then(|| "absent".to_string())
+        });
+        result
     }
 
     #[inline]
@@ -1111,11 +1927,19 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::default_type());
 
         // unwrap: as opt-field this must and will return some value
-        if self.read_bit_field_entry(true)?.unwrap() {
+        let present = self.read_bit_field_entry(true)?.unwrap();
+        let trace = self.trace_begin("DEFAULT", None);
+        let result = if present {
+            self.trace_children(Children::Content);
             self.scope_stashed(T::read_value)
         } else {
             Ok(C::DEFAULT_VALUE.to_owned())
-        }
+        };
+
+        self.trace_end(trace, &result, |_| {
+            (!present).then(|| "absent, DEFAULT".to_string())
+        });
+        result
     }
 
     #[inline]
@@ -1128,7 +1952,8 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::number::<T, C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        self.with_buffer(|r| {
+        let trace = self.trace_begin("INTEGER", None);
+        let result = self.with_buffer(|r| {
             let unconstrained = if C::EXTENSIBLE {
                 r.bits.read_bit()?
             } else {
@@ -1153,7 +1978,32 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             ));
 
             result.map(T::from_i64)
-        })
+        });
+
+        self.trace_end(trace, &result, |v| Some(v.to_i64().to_string()));
+        result
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::real::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("REAL", None);
+        let result = self.with_buffer(|r| r.bits.read_real());
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(|v| v.to_string())
+                .map_err(|e| e.clone()),
+        ));
+
+        self.trace_end(trace, &result, |v| Some(v.to_string()));
+
+        result
     }
 
     #[inline]
@@ -1163,7 +2013,7 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::utf8string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("UTF8String", None);
         let result = self.with_buffer(|r| {
             // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
             // For 'known-multiplier character string types' there is no min/max in the encoding
@@ -1175,6 +2025,8 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description
             .push(ScopeDescription::Result(result.clone()));
 
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
         result
     }
 
@@ -1185,8 +2037,17 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::ia5string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("IA5String", None);
         let result = self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Ia5,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
             let len = if C::EXTENSIBLE && r.bits.read_bit()? {
                 r.read_length_determinant(None, None)?
             } else {
@@ -1205,6 +2066,8 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description
             .push(ScopeDescription::Result(result.clone()));
 
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
         result
     }
 
@@ -1215,8 +2078,17 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::numeric_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("NumericString", None);
         let result = self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Numeric,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
             let len = if C::EXTENSIBLE && r.bits.read_bit()? {
                 r.read_length_determinant(None, None)?
             } else {
@@ -1239,6 +2111,8 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description
             .push(ScopeDescription::Result(result.clone()));
 
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
         result
     }
 
@@ -1251,8 +2125,17 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::printable_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("PrintableString", None);
         let result = self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Printable,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
             let len = if C::EXTENSIBLE && r.bits.read_bit()? {
                 r.read_length_determinant(None, None)?
             } else {
@@ -1271,6 +2154,8 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description
             .push(ScopeDescription::Result(result.clone()));
 
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
         result
     }
 
@@ -1281,8 +2166,17 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::visible_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("VisibleString", None);
         let result = self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Visible,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
             let len = if C::EXTENSIBLE && r.bits.read_bit()? {
                 r.read_length_determinant(None, None)?
             } else {
@@ -1301,6 +2195,127 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         self.scope_description
             .push(ScopeDescription::Result(result.clone()));
 
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
+        result
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::bmp_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("BMPString", None);
+        let result = self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Bmp,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            r.read_wide_string(Charset::Bmp, C::EXTENSIBLE, C::MIN, C::MAX)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
+        result
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::universal_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("UniversalString", None);
+        let result = self.with_buffer(|r| {
+            if let Some(alphabet) = EffectiveAlphabet::new(C::PERMITTED_ALPHABET, false) {
+                return r.read_permitted_alphabet_string(
+                    Charset::Universal,
+                    alphabet,
+                    C::EXTENSIBLE,
+                    C::MIN,
+                    C::MAX,
+                );
+            }
+            r.read_wide_string(Charset::Universal, C::EXTENSIBLE, C::MIN, C::MAX)
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
+        result
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::teletex_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("TeletexString", None);
+        let result = self.with_buffer(|r| r.read_octet_string_of_chars(Charset::Teletex));
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
+        result
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::graphic_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("GraphicString", None);
+        let result = self.with_buffer(|r| r.read_octet_string_of_chars(Charset::Graphic));
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
+        result
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::general_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("GeneralString", None);
+        let result = self.with_buffer(|r| r.read_octet_string_of_chars(Charset::General));
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
         result
     }
 
@@ -1311,7 +2326,7 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::octet_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("OCTET STRING", None);
         let result = self.with_buffer(|r| r.bits.read_octetstring(C::MIN, C::MAX, C::EXTENSIBLE));
 
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1327,6 +2342,8 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 .map_err(|e| e.clone()),
         ));
 
+        self.trace_end(trace, &result, |v| Some(hex(v)));
+
         result
     }
 
@@ -1337,7 +2354,7 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::bit_string::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("BIT STRING", None);
         let result = self.with_buffer(|r| r.bits.read_bitstring(C::MIN, C::MAX, C::EXTENSIBLE));
 
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1356,6 +2373,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 .map_err(|e| e.clone()),
         ));
 
+        self.trace_end(trace, &result, |(bits, len)| {
+            Some(format!("{len} bits {}", hex(bits)))
+        });
+
         result
     }
 
@@ -1366,7 +2387,7 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             .push(ScopeDescription::boolean::<C>());
 
         let _ = self.read_bit_field_entry(false)?;
-        #[allow(clippy::let_and_return)]
+        let trace = self.trace_begin("BOOLEAN", None);
         let result = self.with_buffer(|r| r.bits.read_boolean());
 
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1377,12 +2398,102 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 .map_err(|e| e.clone()),
         ));
 
+        self.trace_end(trace, &result, |v| Some(v.to_string()));
+
         result
     }
 
     #[inline]
     fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
-        Ok(Null)
+        let trace = self.trace_begin("NULL", None);
+        let result = Ok(Null);
+        self.trace_end(trace, &result, |_| None);
+        result
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::object_identifier::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("OBJECT IDENTIFIER", None);
+        let result = self.with_buffer(|r| r.bits.read_object_identifier());
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(|v| ObjectIdentifier::from(v.clone()).to_string())
+                .map_err(|e| e.clone()),
+        ));
+
+        self.trace_end(trace, &result, |v| {
+            Some(ObjectIdentifier::from(v.clone()).to_string())
+        });
+
+        result
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::relative_oid::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin("RELATIVE-OID", None);
+        let result = self.with_buffer(|r| r.bits.read_relative_oid());
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(|v| RelativeOid::from(v.clone()).to_string())
+                .map_err(|e| e.clone()),
+        ));
+
+        self.trace_end(trace, &result, |v| {
+            Some(RelativeOid::from(v.clone()).to_string())
+        });
+
+        result
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::time::<C>(r#type));
+
+        let _ = self.read_bit_field_entry(false)?;
+        let trace = self.trace_begin(r#type.asn_name(), None);
+        let result = self.with_buffer(|r| {
+            let len = r.read_length_determinant(None, None)?;
+            let mut buffer = vec![0u8; len as usize];
+            buffer
+                .chunks_exact_mut(1)
+                .try_for_each(|chunk| r.bits.read_bits_with_offset(chunk, 1))?;
+
+            let string =
+                String::from_utf8(buffer).map_err(|e| Error::from(ErrorKind::FromUtf8Error(e)))?;
+            if time::is_valid(r#type, &string) {
+                Ok(string)
+            } else {
+                Err(ErrorKind::InvalidTime(r#type, string).into())
+            }
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::Result(result.clone()));
+
+        self.trace_end(trace, &result, |v| Some(format!("{v:?}")));
+
+        result
     }
 }
 
@@ -1436,6 +2547,13 @@ pub enum ScopeDescription {
         std_variant_count: u64,
         extensible: bool,
     },
+    OpenTypeChoice {
+        name: &'static str,
+        key: i64,
+    },
+    OpenType {
+        tag: Option<asn1rs_model::model::Tag>,
+    },
     Optional,
     Default,
     Number {
@@ -1444,6 +2562,19 @@ pub enum ScopeDescription {
         max: Option<i64>,
         extensible: bool,
     },
+    Real {
+        tag: asn1rs_model::model::Tag,
+    },
+    ObjectIdentifier {
+        tag: asn1rs_model::model::Tag,
+    },
+    RelativeOid {
+        tag: asn1rs_model::model::Tag,
+    },
+    Time {
+        tag: asn1rs_model::model::Tag,
+        r#type: asn1rs_model::model::TimeType,
+    },
     Utf8String {
         tag: asn1rs_model::model::Tag,
         min: Option<u64>,
@@ -1474,6 +2605,36 @@ pub enum ScopeDescription {
         max: Option<u64>,
         extensible: bool,
     },
+    BmpString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    UniversalString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    TeletexString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    GraphicString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
+    GeneralString {
+        tag: asn1rs_model::model::Tag,
+        min: Option<u64>,
+        max: Option<u64>,
+        extensible: bool,
+    },
     OctetString {
         tag: asn1rs_model::model::Tag,
         min: Option<u64>,
@@ -1565,6 +2726,16 @@ mod scope_description_impl {
             }
         }
 
+        #[inline]
+        pub fn open_type_choice<C: opentypechoice::Constraint>(key: i64) -> Self {
+            Self::OpenTypeChoice { name: C::NAME, key }
+        }
+
+        #[inline]
+        pub fn open_type<C: opentype::Constraint>() -> Self {
+            Self::OpenType { tag: C::TAG }
+        }
+
         #[inline]
         pub fn optional() -> Self {
             ScopeDescription::Optional
@@ -1585,6 +2756,29 @@ mod scope_description_impl {
             }
         }
 
+        #[inline]
+        pub fn real<C: real::Constraint>() -> Self {
+            Self::Real { tag: C::TAG }
+        }
+
+        #[inline]
+        pub fn object_identifier<C: objectidentifier::Constraint>() -> Self {
+            Self::ObjectIdentifier { tag: C::TAG }
+        }
+
+        #[inline]
+        pub fn relative_oid<C: relativeoid::Constraint>() -> Self {
+            Self::RelativeOid { tag: C::TAG }
+        }
+
+        #[inline]
+        pub fn time<C: time::Constraint>(r#type: asn1rs_model::model::TimeType) -> Self {
+            Self::Time {
+                tag: C::TAG,
+                r#type,
+            }
+        }
+
         #[inline]
         pub fn utf8string<C: utf8string::Constraint>() -> Self {
             Self::Utf8String {
@@ -1635,6 +2829,56 @@ mod scope_description_impl {
             }
         }
 
+        #[inline]
+        pub fn bmp_string<C: bmpstring::Constraint>() -> Self {
+            Self::BmpString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn universal_string<C: universalstring::Constraint>() -> Self {
+            Self::UniversalString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn teletex_string<C: teletexstring::Constraint>() -> Self {
+            Self::TeletexString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn graphic_string<C: graphicstring::Constraint>() -> Self {
+            Self::GraphicString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
+        #[inline]
+        pub fn general_string<C: generalstring::Constraint>() -> Self {
+            Self::GeneralString {
+                tag: C::TAG,
+                min: C::MIN,
+                max: C::MAX,
+                extensible: C::EXTENSIBLE,
+            }
+        }
+
         #[inline]
         pub fn octet_string<C: octetstring::Constraint>() -> Self {
             Self::OctetString {
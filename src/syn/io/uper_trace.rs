@@ -0,0 +1,339 @@
+use crate::io::per::ErrorKind;
+use std::fmt::{Display, Formatter};
+
+/// The number of raw bits shown per entry when printing a [`Trace`]
+const DISPLAY_BITS_MAX: usize = 64;
+
+/// A single decoded value, recorded by a [`UperReader`](crate::syn::io::UperReader) with an
+/// enabled trace
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+    /// The nesting level, `0` for the value passed to [`Reader::read`](crate::syn::Reader::read)
+    pub depth: usize,
+    /// The ASN.1 identifier of the component or alternative, `[n]` for elements of a
+    /// `SEQUENCE OF` and the name of the type (or its kind) for top-level values
+    pub name: String,
+    /// The names of all entries from the top-level value down to this one, like `a.b[2].c`
+    pub path: String,
+    /// The ASN.1 kind of the value, like `INTEGER` or `SEQUENCE`
+    pub kind: &'static str,
+    /// The name of the type, if it is a defined type
+    pub type_name: Option<&'static str>,
+    /// The position of the first bit, relative to the beginning of the source
+    pub offset: usize,
+    /// The number of bits consumed, including any preamble, length determinant or open type
+    /// wrapping. If the decoding failed, the number of bits consumed up to that point.
+    pub len: usize,
+    /// The consumed bits, left aligned and padded with zeros to the next octet
+    pub bits: Vec<u8>,
+    /// The decoded value in a human readable form, `None` for constructed values that are
+    /// described by their child entries and for values that failed to decode
+    pub value: Option<String>,
+    /// The reason the decoding failed, only set on the innermost entry that failed
+    pub error: Option<ErrorKind>,
+}
+
+impl TraceEntry {
+    fn write_bits(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "[")?;
+        for i in 0..self.len.min(DISPLAY_BITS_MAX).min(self.bits.len() * 8) {
+            if i > 0 && i % 8 == 0 {
+                write!(f, " ")?;
+            }
+            let bit = self.bits[i / 8] & (0x80 >> (i % 8)) != 0;
+            write!(f, "{}", u8::from(bit))?;
+        }
+        if self.len > DISPLAY_BITS_MAX {
+            write!(f, " ... +{} bits", self.len - DISPLAY_BITS_MAX)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The number of octets shown in the value of an `OCTET STRING` or `BIT STRING`
+const DISPLAY_OCTETS_MAX: usize = 32;
+
+/// Formats the octets as hex, shortened to [`DISPLAY_OCTETS_MAX`]
+pub(crate) fn hex(octets: &[u8]) -> String {
+    let mut hex = octets
+        .iter()
+        .take(DISPLAY_OCTETS_MAX)
+        .map(|v| format!("{v:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if octets.len() > DISPLAY_OCTETS_MAX {
+        hex.push_str(&format!(" ... ({} octets)", octets.len()));
+    }
+    hex
+}
+
+/// Formats the alternative or variant like `name (index)`
+pub(crate) fn variant_name(names: &[&str], index: u64) -> String {
+    match names.get(index as usize) {
+        Some(name) => format!("{name} ({index})"),
+        None => format!("unknown ({index})"),
+    }
+}
+
+pub(crate) fn elements(len: usize) -> String {
+    if len == 1 {
+        "1 element".to_string()
+    } else {
+        format!("{len} elements")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Children {
+    None,
+    /// Components of a `SEQUENCE` or `SET`, named in the order they are read
+    Components(&'static [&'static str], usize),
+    /// Elements of a `SEQUENCE OF` or `SET OF`, named by their index
+    Elements(usize),
+    /// The selected alternative of a `CHOICE`
+    Alternative(&'static str),
+    /// The next value is the content of this entry, like the value of an `OPTIONAL` component
+    Content,
+}
+
+#[derive(Debug, Clone)]
+struct Frame {
+    entry: usize,
+    children: Children,
+}
+
+/// The annotated dissection of everything a [`UperReader`](crate::syn::io::UperReader) decoded
+/// since the trace was enabled, see [`UperReader::with_trace`](crate::syn::io::UperReader::with_trace).
+///
+/// Printing it (`{}`) shows one line per entry with the bit offset, the bit length, the
+/// indented name, kind and value as well as the raw bits of primitive values:
+///
+/// ```text
+///      0    +79  Report: SEQUENCE
+///      2     +8    station-id: INTEGER = 42  [00101010]
+///     10    +24    name: UTF8String = "A1"  [00000010 01000001 00110001]
+///     34     +2    level: ENUMERATED ReportLevel = high (2)  [10]
+///     36    +40    payload: CHOICE Payload = note (2)
+///     52    +24      note: UTF8String = "hi"  [00000010 01101000 01101001]
+///     76     +3    samples: SEQUENCE OF = 0 elements  [000]
+///     79     +0    flag: OPTIONAL = absent
+/// ```
+///
+/// Bits between the entries of the components, like the presence bits of `OPTIONAL` components
+/// or the preamble of extension additions, belong to the enclosing entry only.
+#[derive(Debug, Default, Clone)]
+pub struct Trace {
+    entries: Vec<TraceEntry>,
+    open: Vec<Frame>,
+    failed: bool,
+}
+
+impl Trace {
+    /// All entries in the order decoding started, so that each entry is followed by its children
+    #[inline]
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// Finds the first entry with the given path
+    pub fn entry(&self, path: &str) -> Option<&TraceEntry> {
+        self.entries.iter().find(|e| e.path == path)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.open.clear();
+        self.failed = false;
+    }
+
+    /// Starts a new entry as child of the innermost open entry and returns the token to pass to
+    /// [`Trace::end`]
+    pub(crate) fn begin(
+        &mut self,
+        kind: &'static str,
+        type_name: Option<&'static str>,
+        offset: usize,
+    ) -> usize {
+        let token = self.open.len();
+        let parent = match self.open.last_mut() {
+            None => {
+                self.failed = false;
+                None
+            }
+            Some(frame) => {
+                let name = match &mut frame.children {
+                    Children::Content => {
+                        // the value of an OPTIONAL or DEFAULT component, which already has its
+                        // entry
+                        let entry = &mut self.entries[frame.entry];
+                        entry.kind = kind;
+                        entry.type_name = type_name;
+                        let entry = frame.entry;
+                        frame.children = Children::None;
+                        self.open.push(Frame {
+                            entry,
+                            children: Children::None,
+                        });
+                        return token;
+                    }
+                    Children::Components(names, next) => {
+                        let name = names.get(*next).copied().unwrap_or("...").to_string();
+                        *next += 1;
+                        name
+                    }
+                    Children::Elements(next) => {
+                        let name = format!("[{next}]");
+                        *next += 1;
+                        name
+                    }
+                    Children::Alternative(name) => name.to_string(),
+                    Children::None => type_name.unwrap_or(kind).to_string(),
+                };
+                Some((&self.entries[frame.entry], name))
+            }
+        };
+
+        let (depth, name, path) = match parent {
+            None => {
+                let name = type_name.unwrap_or(kind).to_string();
+                (0, name.clone(), name)
+            }
+            Some((parent, name)) => {
+                let path = if name.starts_with('[') {
+                    format!("{}{name}", parent.path)
+                } else {
+                    format!("{}.{name}", parent.path)
+                };
+                (parent.depth + 1, name, path)
+            }
+        };
+
+        self.open.push(Frame {
+            entry: self.entries.len(),
+            children: Children::None,
+        });
+        self.entries.push(TraceEntry {
+            depth,
+            name,
+            path,
+            kind,
+            type_name,
+            offset,
+            len: 0,
+            bits: Vec::new(),
+            value: None,
+            error: None,
+        });
+        token
+    }
+
+    /// Defines how the following child entries of the innermost open entry are named
+    pub(crate) fn set_children(&mut self, children: Children) {
+        if let Some(frame) = self.open.last_mut() {
+            frame.children = children;
+        }
+    }
+
+    /// The offset of the entry opened with the given token
+    pub(crate) fn offset(&self, token: usize) -> Option<usize> {
+        self.open
+            .get(token)
+            .map(|frame| self.entries[frame.entry].offset)
+    }
+
+    /// Completes the entry opened with the given token, and any entry opened after it that was
+    /// never completed. A `value` of `None` keeps the value of a merged content entry.
+    pub(crate) fn end(
+        &mut self,
+        token: usize,
+        len: usize,
+        bits: Vec<u8>,
+        value: Result<Option<String>, ErrorKind>,
+    ) {
+        if token >= self.open.len() {
+            return;
+        }
+        let frame = self.open.swap_remove(token);
+        self.open.truncate(token);
+
+        let entry = &mut self.entries[frame.entry];
+        entry.len = len;
+        entry.bits = bits;
+        match value {
+            Ok(Some(value)) => entry.value = Some(value),
+            Ok(None) => {}
+            Err(error) => {
+                if !self.failed {
+                    self.failed = true;
+                    entry.error = Some(error);
+                }
+            }
+        }
+    }
+
+    /// Removes the entry opened with the given token, which must not have any children
+    pub(crate) fn discard(&mut self, token: usize) {
+        if let Some(frame) = self.open.get(token) {
+            if frame.entry + 1 == self.entries.len() {
+                self.entries.truncate(frame.entry);
+            }
+            self.open.truncate(token);
+        }
+    }
+
+    /// Closes all open entries, for example after the decoding was aborted
+    pub(crate) fn close(&mut self) {
+        self.open.clear();
+    }
+
+    /// Drops all entries after the given number of entries
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.entries.truncate(len);
+        self.open.clear();
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, entry) in self.entries.iter().enumerate() {
+            write!(
+                f,
+                "{:>6} {:>6}  {:indent$}{}: {}",
+                entry.offset,
+                format!("+{}", entry.len),
+                "",
+                entry.name,
+                entry.kind,
+                indent = entry.depth * 2,
+            )?;
+            if let Some(type_name) = entry.type_name.filter(|t| *t != entry.name) {
+                write!(f, " {type_name}")?;
+            }
+            if let Some(value) = &entry.value {
+                write!(f, " = {value}")?;
+            }
+            if let Some(error) = &entry.error {
+                // only the message, without the backtrace some kinds append
+                let error = error.to_string();
+                let message = error.lines().next().unwrap_or_default();
+                write!(f, " !! {}", message.trim_end_matches(':'))?;
+            }
+            let is_leaf = self
+                .entries
+                .get(i + 1)
+                .map(|next| next.depth <= entry.depth)
+                .unwrap_or(true);
+            if is_leaf && entry.len > 0 {
+                write!(f, "  ")?;
+                entry.write_bits(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,961 @@
+use crate::io::xer::err::Error;
+use crate::io::xer::err::ErrorKind;
+use crate::io::xer::{write_element, write_text, Element, Node, Parser};
+use crate::syn::*;
+use asn1rs_model::model::Charset;
+use asn1rs_model::model::TimeType;
+use std::fmt::Write;
+
+/// ITU-T X.693 | ISO/IEC 8825-4, 11.3, the identifiers of the special REAL values
+const REAL_PLUS_INFINITY: &str = "PLUS-INFINITY";
+const REAL_MINUS_INFINITY: &str = "MINUS-INFINITY";
+const REAL_NOT_A_NUMBER: &str = "NOT-A-NUMBER";
+
+/// The element of a constructed type that is currently being written
+enum WriteFrame {
+    /// The fields of a SEQUENCE or SET and the index of the next field
+    Sequence {
+        names: &'static [&'static str],
+        index: usize,
+    },
+    /// The identifier of the chosen alternative of a CHOICE
+    Choice(&'static str),
+    /// The elements of a SEQUENCE OF or SET OF
+    List,
+}
+
+/// Writes values in the BASIC-XER variant of the XML Encoding Rules (ITU-T X.693 |
+/// ISO/IEC 8825-4). Each value is wrapped in an element named after the ASN.1 identifier of its
+/// field or alternative (see [`sequence::Constraint::FIELD_NAMES`] and
+/// [`choice::Constraint::VARIANT_NAMES`]). The elements of a `SEQUENCE OF` are named after their
+/// type, except for `BOOLEAN` and `ENUMERATED` values, which are listed as empty elements.
+///
+/// Each value that is written by [`Writer::write`] is appended to the output in a line of its
+/// own.
+#[derive(Default)]
+pub struct XerWriter {
+    output: String,
+    frames: Vec<WriteFrame>,
+    /// Whether the next value is referenced by its type name
+    reference: bool,
+    type_name: Option<&'static str>,
+}
+
+impl XerWriter {
+    pub fn with_capacity(capacity_bytes: usize) -> Self {
+        Self {
+            output: String::with_capacity(capacity_bytes),
+            ..Default::default()
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.output
+    }
+
+    pub fn into_string(self) -> String {
+        self.output
+    }
+
+    pub fn as_reader(&self) -> XerReader<'_> {
+        XerReader::from(self.as_str())
+    }
+
+    /// Remembers the name of a referenced or root type for the element of its value
+    #[inline]
+    fn name_type(&mut self, name: &'static str) {
+        if self.type_name.is_none() && (self.reference || self.frames.is_empty()) {
+            self.type_name = Some(name);
+        }
+        self.reference = false;
+    }
+
+    /// Determines the name of the element for the next value. Within a `SEQUENCE OF`, values of
+    /// the given `builtin` type are named after their referenced type, if any, and the values
+    /// that are listed as empty elements are not wrapped at all.
+    fn element_name(&mut self, builtin: &'static str, listed: bool) -> Option<&'static str> {
+        let type_name = self.type_name.take();
+        self.reference = false;
+        match self.frames.last_mut() {
+            Some(WriteFrame::Sequence { names, index }) => {
+                let name = names[*index];
+                *index += 1;
+                Some(name)
+            }
+            Some(WriteFrame::Choice(name)) => Some(*name),
+            Some(WriteFrame::List) if listed => None,
+            Some(WriteFrame::List) | None => Some(type_name.unwrap_or(builtin)),
+        }
+    }
+
+    /// Writes the start tag and returns the position of its end, if any
+    fn start(&mut self, name: Option<&'static str>) -> Option<(&'static str, usize)> {
+        let name = name?;
+        if self.frames.is_empty() && !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        let _ = write!(self.output, "<{}>", name);
+        Some((name, self.output.len()))
+    }
+
+    /// Writes the end tag or turns the start tag into an empty-element tag if there is no content
+    fn end(&mut self, start: Option<(&'static str, usize)>) {
+        if let Some((name, position)) = start {
+            if self.output.len() == position {
+                self.output.pop();
+                self.output.push_str("/>");
+            } else {
+                let _ = write!(self.output, "</{}>", name);
+            }
+        }
+    }
+
+    fn write_element<F: FnOnce(&mut Self) -> Result<(), Error>>(
+        &mut self,
+        builtin: &'static str,
+        listed: bool,
+        frame: Option<WriteFrame>,
+        f: F,
+    ) -> Result<(), Error> {
+        let name = self.element_name(builtin, listed);
+        let start = self.start(name);
+        let depth = self.frames.len();
+        self.frames.extend(frame);
+        let result = f(self);
+        self.frames.truncate(depth);
+        result?;
+        self.end(start);
+        Ok(())
+    }
+
+    fn write_text_element(&mut self, builtin: &'static str, text: &str) {
+        let name = self.element_name(builtin, false);
+        let start = self.start(name);
+        write_text(&mut self.output, text);
+        self.end(start);
+    }
+
+    fn write_character_string(
+        &mut self,
+        builtin: &'static str,
+        charset: Charset,
+        value: &str,
+    ) -> Result<(), Error> {
+        Error::ensure_string_valid(charset, value)?;
+        self.write_text_element(builtin, value);
+        Ok(())
+    }
+
+    /// Omits the next field of a SEQUENCE or SET
+    fn skip(&mut self) {
+        self.type_name = None;
+        self.reference = false;
+        if let Some(WriteFrame::Sequence { index, .. }) = self.frames.last_mut() {
+            *index += 1;
+        }
+    }
+}
+
+impl Writer for XerWriter {
+    type Error = Error;
+
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.reference = true;
+        f(self)
+    }
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.name_type(C::NAME);
+        if C::TRANSPARENT {
+            // the wrapped value is named after this type instead
+            f(self)
+        } else {
+            let frame = WriteFrame::Sequence {
+                names: C::FIELD_NAMES,
+                index: 0,
+            };
+            self.write_element("SEQUENCE", false, Some(frame), f)
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_element("SEQUENCE_OF", false, Some(WriteFrame::List), |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    #[inline]
+    fn write_set_of<C: setof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.name_type(C::NAME);
+        let index = enumerated.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT))?;
+        self.write_element("ENUMERATED", true, None, |w| {
+            let _ = write!(w.output, "<{}/>", name);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.name_type(C::NAME);
+        let index = choice.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::VARIANT_COUNT))?;
+        self.write_element("CHOICE", false, Some(WriteFrame::Choice(name)), |w| {
+            choice.write_content(w)
+        })
+    }
+
+    #[inline]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.693 | ISO/IEC 8825-4, the value is wrapped in an element named after its type
+        let index = choice.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index as usize)
+            .ok_or(ErrorKind::InvalidChoiceIndex(index, C::KEYS.len() as u64))?;
+        self.write_element("OPEN-TYPE", false, Some(WriteFrame::Choice(name)), |w| {
+            choice.write_content(w)
+        })
+    }
+
+    #[inline]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        _choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        Err(ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        // the value is the element of the actual type, which is embedded as it is
+        let text = core::str::from_utf8(value)
+            .map_err(|e| Error::from(ErrorKind::InvalidXml(e.valid_up_to())))?;
+        let mut parser = Parser::from(text);
+        let element = parser.parse_element()?;
+        if parser.bytes_remaining() > 0 {
+            return Err(ErrorKind::InvalidXml(text.len() - parser.bytes_remaining()).into());
+        }
+        self.write_element("OPEN-TYPE", false, None, |w| {
+            write_element(&mut w.output, &element);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error> {
+        if let Some(value) = value {
+            T::write_value(self, value)
+        } else {
+            self.skip();
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        if C::DEFAULT_VALUE.ne(value) {
+            T::write_value(self, value)
+        } else {
+            self.skip();
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), Self::Error> {
+        let value = value.to_i64();
+        if !C::EXTENSIBLE {
+            let min = C::MIN.unwrap_or(i64::MIN);
+            let max = C::MAX.unwrap_or(i64::MAX);
+            if value < min || value > max {
+                return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+            }
+        }
+        self.write_text_element("INTEGER", &value.to_string());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // ITU-T X.693 | ISO/IEC 8825-4, 11.3, the special values are written as empty elements
+        let special = if value.is_nan() {
+            Some(REAL_NOT_A_NUMBER)
+        } else if value == f64::INFINITY {
+            Some(REAL_PLUS_INFINITY)
+        } else if value == f64::NEG_INFINITY {
+            Some(REAL_MINUS_INFINITY)
+        } else {
+            None
+        };
+        match special {
+            Some(identifier) => self.write_element("REAL", true, None, |w| {
+                let _ = write!(w.output, "<{}/>", identifier);
+                Ok(())
+            }),
+            None if value == 0.0 && value.is_sign_negative() => {
+                self.write_text_element("REAL", "-0");
+                Ok(())
+            }
+            None => {
+                self.write_text_element("REAL", &format!("{:?}", value));
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("UTF8String", Charset::Utf8, value)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("IA5String", Charset::Ia5, value)
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("NumericString", Charset::Numeric, value)
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("VisibleString", Charset::Visible, value)
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("BMPString", Charset::Bmp, value)
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("UniversalString", Charset::Universal, value)
+    }
+
+    #[inline]
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("TeletexString", Charset::Teletex, value)
+    }
+
+    #[inline]
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("GraphicString", Charset::Graphic, value)
+    }
+
+    #[inline]
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("GeneralString", Charset::General, value)
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string("PrintableString", Charset::Printable, value)
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let mut hex = String::with_capacity(value.len() * 2);
+        for byte in value {
+            let _ = write!(hex, "{:02X}", byte);
+        }
+        self.write_text_element("OCTET_STRING", &hex);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let bits = (0..bit_len as usize)
+            .map(|bit| {
+                if value[bit / 8] & (0x80 >> (bit % 8)) != 0 {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect::<String>();
+        self.write_text_element("BIT_STRING", &bits);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.write_element("BOOLEAN", true, None, |w| {
+            w.output
+                .push_str(if value { "<true/>" } else { "<false/>" });
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_null<C: null::Constraint>(&mut self, _value: &Null) -> Result<(), Self::Error> {
+        self.write_element("NULL", false, None, |_| Ok(()))
+    }
+
+    #[inline]
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        // ITU-T X.693 | ISO/IEC 8825-4, the components in the XMLNumberForm, separated by dots
+        let text = ObjectIdentifier::from(value.to_vec()).to_string();
+        self.write_text_element("OBJECT_IDENTIFIER", &text);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error> {
+        let text = RelativeOid::from(value.to_vec()).to_string();
+        self.write_text_element("RELATIVE_OID", &text);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        r#type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_character_string(time_element_name(r#type), Charset::Visible, value)
+    }
+}
+
+/// ITU-T X.693 | ISO/IEC 8825-4, the element names of the useful time types
+fn time_element_name(r#type: TimeType) -> &'static str {
+    match r#type {
+        TimeType::GeneralizedTime => "GeneralizedTime",
+        TimeType::UtcTime => "UTCTime",
+        TimeType::Date => "DATE",
+        TimeType::TimeOfDay => "TIME_OF_DAY",
+        TimeType::DateTime => "DATE_TIME",
+        TimeType::Duration => "DURATION",
+    }
+}
+
+/// The child elements of a SEQUENCE or SET that is currently being read
+struct ReadFrame {
+    name: &'static str,
+    names: &'static [&'static str],
+    index: usize,
+    elements: Vec<Element>,
+}
+
+/// Reads values in the BASIC-XER variant of the XML Encoding Rules (ITU-T X.693 |
+/// ISO/IEC 8825-4). The elements of the fields of a `SEQUENCE` are looked up by the ASN.1
+/// identifiers regardless of their order and unknown elements are ignored if the type is
+/// extensible. The names of root elements and elements of a `SEQUENCE OF` are not checked.
+pub struct XerReader<'a> {
+    parser: Parser<'a>,
+    frames: Vec<ReadFrame>,
+    /// The element of the value to read next, if it was already taken from its parent
+    pending: Option<Element>,
+}
+
+impl<'a> From<&'a str> for XerReader<'a> {
+    fn from(source: &'a str) -> Self {
+        Self {
+            parser: Parser::from(source),
+            frames: Vec::new(),
+            pending: None,
+        }
+    }
+}
+
+impl XerReader<'_> {
+    pub fn bytes_remaining(&mut self) -> usize {
+        self.parser.bytes_remaining()
+    }
+
+    /// Takes the element of the next value, which is `None` for an absent field
+    fn next_opt(&mut self) -> Result<Option<Element>, Error> {
+        if let Some(element) = self.pending.take() {
+            return Ok(Some(element));
+        }
+        match self.frames.last_mut() {
+            Some(frame) => {
+                let name = frame.names[frame.index];
+                frame.index += 1;
+                Ok(frame
+                    .elements
+                    .iter()
+                    .position(|element| element.name == name)
+                    .map(|index| frame.elements.remove(index)))
+            }
+            None => self.parser.parse_element().map(Some),
+        }
+    }
+
+    fn next(&mut self) -> Result<Element, Error> {
+        match self.next_opt()? {
+            Some(element) => Ok(element),
+            None => {
+                let frame = self.frames.last().expect("only a field can be absent");
+                Err(ErrorKind::MissingElement(frame.names[frame.index - 1]).into())
+            }
+        }
+    }
+
+    /// The identifier of an empty element, which is either the only child of the next element
+    /// or - within a `SEQUENCE OF` - the next element itself
+    fn next_identifier(&mut self) -> Result<String, Error> {
+        let element = self.next()?;
+        if element.children.is_empty() {
+            return Ok(element.name);
+        }
+        let mut elements = element.into_elements();
+        match (elements.next(), elements.next()) {
+            (Some(identifier), None) if identifier.children.is_empty() => Ok(identifier.name),
+            _ => Err(ErrorKind::UnexpectedContent("an identifier").into()),
+        }
+    }
+
+    fn next_text(&mut self) -> Result<String, Error> {
+        let element = self.next()?;
+        if element.elements().next().is_some() {
+            return Err(ErrorKind::UnexpectedContent("a text").into());
+        }
+        Ok(element.text())
+    }
+
+    fn read_character_string(&mut self, charset: Charset) -> Result<String, Error> {
+        let string = self.next_text()?;
+        Error::ensure_string_valid(charset, &string)?;
+        Ok(string)
+    }
+
+    /// Reads the next value with `f` after its element was taken from its parent
+    fn read_pending<T, F: FnOnce(&mut Self) -> Result<T, Error>>(
+        &mut self,
+        element: Element,
+        f: F,
+    ) -> Result<T, Error> {
+        self.pending = Some(element);
+        let result = f(self);
+        self.pending = None;
+        result
+    }
+}
+
+impl Reader for XerReader<'_> {
+    type Error = Error;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        if C::TRANSPARENT {
+            return f(self);
+        }
+        let elements = self.next()?.into_elements().collect();
+        let depth = self.frames.len();
+        self.frames.push(ReadFrame {
+            name: C::NAME,
+            names: C::FIELD_NAMES,
+            index: 0,
+            elements,
+        });
+        let result = f(self);
+        let frame = self.frames.drain(depth..).next();
+        let value = result?;
+        match frame {
+            Some(frame) if !frame.elements.is_empty() && C::EXTENDED_AFTER_FIELD.is_none() => {
+                Err(ErrorKind::UnexpectedElements(frame.name).into())
+            }
+            _ => Ok(value),
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.next()?
+            .into_elements()
+            .map(|element| self.read_pending(element, T::read_value))
+            .collect()
+    }
+
+    #[inline]
+    fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    #[inline]
+    fn read_set_of<C: setof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let identifier = self.next_identifier()?;
+        C::VARIANT_NAMES
+            .iter()
+            .position(|name| *name == identifier)
+            .and_then(|index| C::from_choice_index(index as u64))
+            .ok_or_else(|| ErrorKind::UnknownIdentifier(identifier).into())
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let mut elements = self.next()?.into_elements();
+        let alternative = match (elements.next(), elements.next()) {
+            (Some(alternative), None) => alternative,
+            _ => return Err(ErrorKind::UnexpectedContent(C::NAME).into()),
+        };
+        let index = C::VARIANT_NAMES
+            .iter()
+            .position(|name| *name == alternative.name)
+            .ok_or_else(|| ErrorKind::UnknownIdentifier(alternative.name.clone()))?;
+        let identifier = alternative.name.clone();
+        self.read_pending(alternative, |r| C::read_content(index as u64, r))?
+            .ok_or_else(|| ErrorKind::UnknownIdentifier(identifier).into())
+    }
+
+    #[inline]
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error> {
+        let index = C::index_of_key(key).ok_or(ErrorKind::InvalidOpenTypeKey(key))?;
+        let mut elements = self.next()?.into_elements();
+        let value = match (elements.next(), elements.next()) {
+            (Some(value), None) => value,
+            _ => return Err(ErrorKind::UnexpectedContent(C::NAME).into()),
+        };
+        if value.name != C::VARIANT_NAMES[index as usize] {
+            return Err(ErrorKind::UnknownIdentifier(value.name).into());
+        }
+        self.read_pending(value, |r| C::read_content(index, r))?
+            .ok_or_else(|| ErrorKind::InvalidOpenTypeKey(key).into())
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let mut text = String::new();
+        for child in self.next()?.children {
+            match child {
+                Node::Element(element) => write_element(&mut text, &element),
+                Node::Text(content) => write_text(&mut text, &content),
+            }
+        }
+        Ok(text.into_bytes())
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error> {
+        match self.next_opt()? {
+            Some(element) => self.read_pending(element, T::read_value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        match self.next_opt()? {
+            Some(element) => self.read_pending(element, T::read_value),
+            None => Ok(C::DEFAULT_VALUE.to_owned()),
+        }
+    }
+
+    #[inline]
+    fn read_number<T: numbers::Number, C: numbers::Constraint<T>>(
+        &mut self,
+    ) -> Result<T, Self::Error> {
+        let text = self.next_text()?;
+        let number = text.trim();
+        let value = number.parse::<i64>().map_err(|_| {
+            if !number.is_empty()
+                && number
+                    .trim_start_matches('-')
+                    .bytes()
+                    .all(|b| b.is_ascii_digit())
+            {
+                Error::from(ErrorKind::ValueExceedsMaxInt)
+            } else {
+                Error::from(ErrorKind::InvalidNumber(text.clone()))
+            }
+        })?;
+        if !C::EXTENSIBLE {
+            let min = C::MIN.unwrap_or(i64::MIN);
+            let max = C::MAX.unwrap_or(i64::MAX);
+            if value < min || value > max {
+                return Err(ErrorKind::ValueNotInRange(value, min, max).into());
+            }
+        }
+        Ok(T::from_i64(value))
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let element = self.next()?;
+        if element.elements().next().is_some() {
+            let mut elements = element.into_elements();
+            return match (elements.next(), elements.next()) {
+                (Some(identifier), None) if identifier.children.is_empty() => {
+                    match identifier.name.as_str() {
+                        REAL_NOT_A_NUMBER => Ok(f64::NAN),
+                        REAL_PLUS_INFINITY => Ok(f64::INFINITY),
+                        REAL_MINUS_INFINITY => Ok(f64::NEG_INFINITY),
+                        _ => Err(ErrorKind::UnknownIdentifier(identifier.name).into()),
+                    }
+                }
+                _ => Err(ErrorKind::UnexpectedContent("a real number").into()),
+            };
+        }
+        let text = element.text();
+        let number = text.trim();
+        if number.is_empty()
+            || !number
+                .bytes()
+                .all(|b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E'))
+        {
+            return Err(ErrorKind::InvalidReal(text).into());
+        }
+        number
+            .parse::<f64>()
+            .map_err(|_| ErrorKind::InvalidReal(text.clone()).into())
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Utf8)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Ia5)
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Numeric)
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Visible)
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Bmp)
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Universal)
+    }
+
+    #[inline]
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Teletex)
+    }
+
+    #[inline]
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Graphic)
+    }
+
+    #[inline]
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::General)
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_character_string(Charset::Printable)
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        // ITU-T X.680 | ISO/IEC 8824-1, 12.12, white-space within an xmlhstring is insignificant
+        let digits = self
+            .next_text()?
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_digit(16).ok_or(ErrorKind::InvalidHexString))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !digits.len().is_multiple_of(2) {
+            return Err(ErrorKind::InvalidHexString.into());
+        }
+        Ok(digits
+            .chunks(2)
+            .map(|pair| ((pair[0] << 4) | pair[1]) as u8)
+            .collect())
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        // ITU-T X.680 | ISO/IEC 8824-1, 12.11, white-space within an xmlbstring is insignificant
+        let mut bytes = Vec::new();
+        let mut bit_len = 0_u64;
+        for char in self.next_text()?.chars().filter(|c| !c.is_whitespace()) {
+            if bit_len.is_multiple_of(8) {
+                bytes.push(0);
+            }
+            match (char, bytes.last_mut()) {
+                ('1', Some(byte)) => *byte |= 0x80 >> (bit_len % 8),
+                ('0', _) => {}
+                _ => return Err(ErrorKind::InvalidBitString.into()),
+            }
+            bit_len += 1;
+        }
+        Ok((bytes, bit_len))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match self.next_identifier()?.as_str() {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            identifier => Err(ErrorKind::UnknownIdentifier(identifier.to_string()).into()),
+        }
+    }
+
+    #[inline]
+    fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error> {
+        let element = self.next()?;
+        if element.elements().next().is_none() && element.text().trim().is_empty() {
+            Ok(Null)
+        } else {
+            Err(ErrorKind::UnexpectedContent("NULL").into())
+        }
+    }
+
+    #[inline]
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let text = self.next_text()?;
+        text.trim()
+            .parse::<ObjectIdentifier>()
+            .map(Vec::from)
+            .map_err(|_| ErrorKind::InvalidObjectIdentifier(text).into())
+    }
+
+    #[inline]
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error> {
+        let text = self.next_text()?;
+        text.trim()
+            .parse::<RelativeOid>()
+            .map(Vec::from)
+            .map_err(|_| ErrorKind::InvalidObjectIdentifier(text).into())
+    }
+
+    #[inline]
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error> {
+        let string = self.next_text()?;
+        if time::is_valid(r#type, &string) {
+            Ok(string)
+        } else {
+            Err(ErrorKind::InvalidTime(r#type, string).into())
+        }
+    }
+}
@@ -1,52 +1,99 @@
 pub mod bitstring;
+pub mod bmpstring;
 pub mod boolean;
 pub mod choice;
 pub mod common;
 pub mod complex;
 pub mod default;
 pub mod enumerated;
+pub mod explicit;
+pub mod generalstring;
+pub mod graphicstring;
 pub mod ia5string;
 pub mod io;
 pub mod null;
 pub mod numbers;
 pub mod numericstring;
+pub mod objectidentifier;
 pub mod octetstring;
+pub mod opentype;
+pub mod opentypechoice;
 pub mod optional;
 pub mod printablestring;
+pub mod real;
+pub mod relativeoid;
 pub mod sequence;
 pub mod sequenceof;
 pub mod set;
 pub mod setof;
+pub mod teletexstring;
+pub mod time;
+pub mod universalstring;
+pub mod unknownextensions;
 pub mod utf8string;
 pub mod visiblestring;
 
 pub use crate::syn::null::Null;
 pub use bitstring::BitString;
 pub use bitstring::BitVec;
+pub use bmpstring::BmpString;
 pub use boolean::Boolean;
 pub use choice::Choice;
 pub use complex::Complex;
 pub use default::DefaultValue;
 pub use enumerated::Enumerated;
+pub use explicit::Explicit;
+pub use generalstring::GeneralString;
+pub use graphicstring::GraphicString;
 pub use ia5string::Ia5String;
 pub use null::NullT;
 pub use numbers::Integer;
 pub use numericstring::NumericString;
+pub use objectidentifier::ObjectIdentifier;
+pub use objectidentifier::ObjectIdentifierT;
 pub use octetstring::OctetString;
+pub use opentype::OpenType;
+pub use opentype::OpenTypeT;
+pub use opentypechoice::OpenTypeChoice;
 pub use printablestring::PrintableString;
+pub use real::Real;
+pub use relativeoid::RelativeOid;
+pub use relativeoid::RelativeOidT;
 pub use sequence::Sequence;
 pub use sequenceof::SequenceOf;
 pub use set::Set;
 pub use setof::SetOf;
+pub use teletexstring::TeletexString;
+pub use time::Date;
+pub use time::DateTime;
+pub use time::Duration;
+pub use time::GeneralizedTime;
+pub use time::TimeOfDay;
+pub use time::TimeT;
+pub use time::UtcTime;
+pub use universalstring::UniversalString;
+pub use unknownextensions::UnknownExtensions;
 pub use utf8string::Utf8String;
 pub use visiblestring::VisibleString;
 
+use asn1rs_model::model::TimeType;
+
 pub mod prelude {
     pub use super::bitstring::BitVec;
+    pub use super::Date;
+    pub use super::DateTime;
+    pub use super::Duration;
+    pub use super::GeneralizedTime;
     pub use super::Null;
+    pub use super::ObjectIdentifier;
+    pub use super::OpenType;
     pub use super::Readable;
     pub use super::ReadableType;
     pub use super::Reader;
+    pub use super::RelativeOid;
+    pub use super::TimeOfDay;
+    pub use super::UnknownExtensions;
+    pub use super::UtcTime;
     pub use super::Writable;
     pub use super::WritableType;
     pub use super::Writer;
@@ -63,6 +110,34 @@ pub trait Reader {
         T::read(self)
     }
 
+    /// Reads the value of a field that refers to another type definition. Encodings that
+    /// transmit tags can override the tag of the referenced type with `C::TAG`.
+    #[inline]
+    fn read_complex<
+        C: complex::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        f(self)
+    }
+
+    /// Reads a value with an explicit tag. Encodings that transmit tags read the tag `C::TAG`
+    /// around the encoding of the value.
+    #[inline]
+    fn read_explicit<
+        C: explicit::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        f(self)
+    }
+
     fn read_sequence<
         C: sequence::Constraint,
         S: Sized,
@@ -85,10 +160,30 @@ pub trait Reader {
         &mut self,
     ) -> Result<Vec<T::Type>, Self::Error>;
 
+    /// Reads the extension additions of the extensible SEQUENCE or SET that is being read which
+    /// follow the ones known to its type, after all of its fields were read. Encodings that are
+    /// not able to keep them as they were read return none.
+    #[inline]
+    fn read_unknown_extensions<C: sequence::Constraint>(
+        &mut self,
+    ) -> Result<UnknownExtensions, Self::Error> {
+        Ok(UnknownExtensions::default())
+    }
+
     fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error>;
 
     fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error>;
 
+    /// Reads the alternative of the open type that is selected by the given value of the
+    /// related field
+    fn read_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        key: i64,
+    ) -> Result<C, Self::Error>;
+
+    /// Reads the complete encoding of the value of an open type without decoding it
+    fn read_open_type<C: opentype::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error>;
+
     fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error>;
 
     fn read_default<C: default::Constraint<Owned = T::Type>, T: ReadableType>(
@@ -99,6 +194,8 @@ pub trait Reader {
         &mut self,
     ) -> Result<T, Self::Error>;
 
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error>;
+
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error>;
 
     fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error>;
@@ -107,6 +204,18 @@ pub trait Reader {
 
     fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error>;
 
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
+    fn read_teletex_string<C: teletexstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_graphic_string<C: graphicstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_general_string<C: generalstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
     fn read_printable_string<C: printablestring::Constraint>(
         &mut self,
     ) -> Result<String, Self::Error>;
@@ -118,6 +227,16 @@ pub trait Reader {
     fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error>;
 
     fn read_null<C: null::Constraint>(&mut self) -> Result<Null, Self::Error>;
+
+    fn read_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+    ) -> Result<Vec<u64>, Self::Error>;
+
+    fn read_relative_oid<C: relativeoid::Constraint>(&mut self) -> Result<Vec<u64>, Self::Error>;
+
+    /// Reads the string representation of the given time type, which is checked to be
+    /// in the format required by the time type
+    fn read_time<C: time::Constraint>(&mut self, r#type: TimeType) -> Result<String, Self::Error>;
 }
 
 pub trait Readable: Sized {
@@ -150,6 +269,26 @@ pub trait Writer {
         value.write(self)
     }
 
+    /// Writes the value of a field that refers to another type definition. Encodings that
+    /// transmit tags can override the tag of the referenced type with `C::TAG`.
+    #[inline]
+    fn write_complex<C: complex::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        f(self)
+    }
+
+    /// Writes a value with an explicit tag. Encodings that transmit tags write the tag `C::TAG`
+    /// around the encoding of the value.
+    #[inline]
+    fn write_explicit<C: explicit::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        f(self)
+    }
+
     fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
         &mut self,
         f: F,
@@ -170,6 +309,34 @@ pub trait Writer {
         slice: &[T::Type],
     ) -> Result<(), Self::Error>;
 
+    /// Writes an extensible SEQUENCE like [`Writer::write_sequence`], followed by the extension
+    /// additions unknown to its type that were kept by [`Reader::read_unknown_extensions`].
+    /// Encodings that are not able to write them as they were read ignore them.
+    #[inline]
+    fn write_sequence_with_unknown_extensions<
+        C: sequence::Constraint,
+        F: Fn(&mut Self) -> Result<(), Self::Error>,
+    >(
+        &mut self,
+        _unknown: &UnknownExtensions,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    /// Like [`Writer::write_sequence_with_unknown_extensions`], but for an extensible SET
+    #[inline]
+    fn write_set_with_unknown_extensions<
+        C: set::Constraint,
+        F: Fn(&mut Self) -> Result<(), Self::Error>,
+    >(
+        &mut self,
+        _unknown: &UnknownExtensions,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_set::<C, F>(f)
+    }
+
     fn write_enumerated<C: enumerated::Constraint>(
         &mut self,
         enumerated: &C,
@@ -177,6 +344,45 @@ pub trait Writer {
 
     fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error>;
 
+    /// Writes the value of an open type, which has to be the one selected by the given value of
+    /// the related field
+    #[inline]
+    fn write_open_type_choice<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error> {
+        if choice.key() != key {
+            return self.reject_open_type_key(choice, key);
+        }
+        match choice.unknown() {
+            // the complete encoding of an object unknown to the type, as it was read
+            Some((_, content)) => {
+                self.write_open_type::<opentype::NoConstraint>(content.as_bytes())
+            }
+            None => self.write_open_type_alternative(choice),
+        }
+    }
+
+    /// Writes the value of an open type for an object known to the type,
+    /// see [`Writer::write_open_type_choice`]
+    fn write_open_type_alternative<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+    ) -> Result<(), Self::Error>;
+
+    /// Called for the value of an open type that is not the one selected by the given value of
+    /// the related field, see [`Writer::write_open_type_choice`]
+    fn reject_open_type_key<C: opentypechoice::Constraint>(
+        &mut self,
+        choice: &C,
+        key: i64,
+    ) -> Result<(), Self::Error>;
+
+    /// Writes the given complete encoding of the value of an open type unchanged
+    fn write_open_type<C: opentype::Constraint>(&mut self, value: &[u8])
+        -> Result<(), Self::Error>;
+
     fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error>;
 
     fn write_default<C: default::Constraint<Owned = T::Type>, T: WritableType>(
@@ -189,6 +395,8 @@ pub trait Writer {
         value: T,
     ) -> Result<(), Self::Error>;
 
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error>;
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -207,6 +415,31 @@ pub trait Writer {
         value: &str,
     ) -> Result<(), Self::Error>;
 
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_teletex_string<C: teletexstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_graphic_string<C: graphicstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_general_string<C: generalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
     fn write_printable_string<C: printablestring::Constraint>(
         &mut self,
         value: &str,
@@ -226,6 +459,22 @@ pub trait Writer {
     fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error>;
 
     fn write_null<C: null::Constraint>(&mut self, value: &Null) -> Result<(), Self::Error>;
+
+    fn write_object_identifier<C: objectidentifier::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error>;
+
+    fn write_relative_oid<C: relativeoid::Constraint>(
+        &mut self,
+        value: &[u64],
+    ) -> Result<(), Self::Error>;
+
+    fn write_time<C: time::Constraint>(
+        &mut self,
+        r#type: TimeType,
+        value: &str,
+    ) -> Result<(), Self::Error>;
 }
 
 pub trait Writable {
@@ -268,6 +517,7 @@ mod tests {
         impl sequence::Constraint for Whatever {
             const NAME: &'static str = "Whatever";
             const STD_OPTIONAL_FIELDS: u64 = 2;
+            const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
             const FIELD_COUNT: u64 = 3;
             const EXTENDED_AFTER_FIELD: Option<u64> = None;
 
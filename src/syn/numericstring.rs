@@ -8,6 +8,7 @@ pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
     const EXTENSIBLE: bool = false;
+    const PERMITTED_ALPHABET: &'static [(char, char)] = &[];
 }
 
 #[derive(Default)]
@@ -0,0 +1,145 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct ObjectIdentifierT<C: Constraint = NoConstraint>(PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_OBJECT_IDENTIFIER;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for ObjectIdentifierT<C> {
+    type Type = ObjectIdentifier;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_object_identifier::<C>(value.arcs())
+    }
+}
+
+impl<C: Constraint> ReadableType for ObjectIdentifierT<C> {
+    type Type = ObjectIdentifier;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        Ok(ObjectIdentifier::from(
+            reader.read_object_identifier::<C>()?,
+        ))
+    }
+}
+
+/// The arcs of an `OBJECT IDENTIFIER` value, displayed and parsed in the dotted form
+/// `1.2.840.113549`
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ObjectIdentifier(Cow<'static, [u64]>);
+
+impl ObjectIdentifier {
+    /// Used by the generated constants of value references
+    pub const fn from_static(arcs: &'static [u64]) -> Self {
+        Self(Cow::Borrowed(arcs))
+    }
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Whether the first two arcs are within the ranges assigned by ITU-T X.660 | ISO/IEC
+    /// 9834-1, which is required to encode the value
+    pub fn is_valid(&self) -> bool {
+        match self.arcs() {
+            [0..=1, second, ..] => *second < 40,
+            [2, _, ..] => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<Vec<u64>> for ObjectIdentifier {
+    fn from(arcs: Vec<u64>) -> Self {
+        Self(Cow::Owned(arcs))
+    }
+}
+
+impl From<ObjectIdentifier> for Vec<u64> {
+    fn from(value: ObjectIdentifier) -> Self {
+        value.0.into_owned()
+    }
+}
+
+impl FromStr for ObjectIdentifier {
+    type Err = ParseObjectIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Some(Self::from(parse_arcs(s)?))
+            .filter(ObjectIdentifier::is_valid)
+            .ok_or_else(|| ParseObjectIdentifierError(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_arcs(self.arcs(), f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseObjectIdentifierError(String);
+
+impl std::fmt::Display for ParseObjectIdentifierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid dotted object identifier: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseObjectIdentifierError {}
+
+pub(crate) fn parse_arcs(s: &str) -> Result<Vec<u64>, ParseObjectIdentifierError> {
+    s.split('.')
+        .map(|arc| {
+            Some(arc)
+                .filter(|arc| !arc.is_empty() && arc.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|arc| arc.parse::<u64>().ok())
+                .ok_or_else(|| ParseObjectIdentifierError(s.to_string()))
+        })
+        .collect()
+}
+
+pub(crate) fn fmt_arcs(arcs: &[u64], f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (index, arc) in arcs.iter().enumerate() {
+        if index > 0 {
+            write!(f, ".")?;
+        }
+        write!(f, "{}", arc)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn dotted_form_roundtrip() {
+        let oid = "1.2.840.113549".parse::<ObjectIdentifier>().unwrap();
+        assert_eq!(&[1, 2, 840, 113549][..], oid.arcs());
+        assert_eq!("1.2.840.113549", oid.to_string());
+        assert_eq!(ObjectIdentifier::from_static(&[1, 2, 840, 113549]), oid);
+    }
+
+    #[test]
+    fn dotted_form_invalid() {
+        for s in ["", "1", "1..2", "1.2.", "+1.2", "0.40", "3.1", "1.a"] {
+            assert_eq!(
+                Err(ParseObjectIdentifierError(s.to_string())),
+                s.parse::<ObjectIdentifier>(),
+            );
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::io::per::err::Error;
+use crate::io::{ber, oer};
+use crate::syn::io::{AperReader, AperWriter, BerReader, BerWriter, OerReader, OerWriter};
+use crate::syn::io::{UperReader, UperWriter};
+use crate::syn::{Readable, ReadableType, Reader, Writable, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use std::marker::PhantomData;
+
+/// An open type (ITU-T X.681 | ISO/IEC 8824-2, 14.2) without a table constraint, like
+/// `TYPE-IDENTIFIER.&Type` or the historic `ANY` and `ANY DEFINED BY`
+pub struct OpenTypeT<C: Constraint = NoConstraint>(PhantomData<C>);
+
+pub trait Constraint {
+    /// ITU-T X.680 | ISO/IEC 8824-1, 31.2.7, the tag of an open type is always explicit and there
+    /// is no tag at all if the referencing component is untagged
+    const TAG: Option<Tag> = None;
+}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for OpenTypeT<C> {
+    type Type = OpenType;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_open_type::<C>(value.as_bytes())
+    }
+}
+
+impl<C: Constraint> ReadableType for OpenTypeT<C> {
+    type Type = OpenType;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        Ok(OpenType::from(reader.read_open_type::<C>()?))
+    }
+}
+
+/// The complete encoding of the value of an open type, whose actual type is not known while
+/// decoding the outer value. The bytes are kept as they were read, so that the value can be
+/// forwarded unchanged or decoded later on by [`OpenType::decode_as`].
+///
+/// The encoding is the one of the reader or writer of the outer value. For the packed encoding
+/// rules, this is the encoding of the inner value as octet string (ITU-T X.691 | ISO/IEC 8825-2,
+/// 11.2), which is what [`OpenType::encode_from`] and [`OpenType::decode_as`] produce and expect
+/// for the unaligned variant and [`OpenType::encode_from_aper`] and [`OpenType::decode_as_aper`]
+/// for the aligned one. The basic and octet encoding rules have their own pair of functions.
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OpenType(Vec<u8>);
+
+impl OpenType {
+    pub const fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Encodes the given value in the unaligned packed encoding rules. An empty encoding is
+    /// replaced by a single zero octet (ITU-T X.691 | ISO/IEC 8825-2, 11.2.2).
+    pub fn encode_from<T: Writable>(value: &T) -> Result<Self, Error> {
+        let mut writer = UperWriter::default();
+        writer.write(value)?;
+        if writer.bit_len() == 0 {
+            Ok(Self(vec![0x00]))
+        } else {
+            Ok(Self(writer.into_bytes_vec()))
+        }
+    }
+
+    /// Decodes the bytes as a value of the given type in the unaligned packed encoding rules
+    pub fn decode_as<T: Readable>(&self) -> Result<T, Error> {
+        UperReader::from((&self.0[..], self.0.len() * 8)).read::<T>()
+    }
+
+    /// Encodes the given value in the aligned packed encoding rules. An empty encoding is
+    /// replaced by a single zero octet (ITU-T X.691 | ISO/IEC 8825-2, 11.2.2).
+    pub fn encode_from_aper<T: Writable>(value: &T) -> Result<Self, Error> {
+        let mut writer = AperWriter::default();
+        writer.write(value)?;
+        if writer.bit_len() == 0 {
+            Ok(Self(vec![0x00]))
+        } else {
+            Ok(Self(writer.into_bytes_vec()))
+        }
+    }
+
+    /// Decodes the bytes as a value of the given type in the aligned packed encoding rules
+    pub fn decode_as_aper<T: Readable>(&self) -> Result<T, Error> {
+        AperReader::from((&self.0[..], self.0.len() * 8)).read::<T>()
+    }
+
+    /// Encodes the given value in the basic encoding rules, including its tag and length
+    pub fn encode_from_ber<T: Writable>(value: &T) -> Result<Self, ber::Error> {
+        let mut writer = BerWriter::default();
+        writer.write(value)?;
+        Ok(Self(writer.into_bytes_vec()))
+    }
+
+    /// Decodes the bytes as a value of the given type in the basic encoding rules
+    pub fn decode_as_ber<T: Readable>(&self) -> Result<T, ber::Error> {
+        BerReader::from(&self.0[..]).read::<T>()
+    }
+
+    /// Encodes the given value in the octet encoding rules
+    pub fn encode_from_oer<T: Writable>(value: &T) -> Result<Self, oer::Error> {
+        let mut writer = OerWriter::default();
+        writer.write(value)?;
+        Ok(Self(writer.into_bytes_vec()))
+    }
+
+    /// Decodes the bytes as a value of the given type in the octet encoding rules
+    pub fn decode_as_oer<T: Readable>(&self) -> Result<T, oer::Error> {
+        OerReader::from(&self.0[..]).read::<T>()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for OpenType {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<OpenType> for Vec<u8> {
+    fn from(value: OpenType) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syn::boolean::Boolean;
+    use crate::syn::null::NullT;
+    use crate::syn::Null;
+
+    #[derive(Debug, PartialEq)]
+    struct Flag(bool);
+
+    impl Writable for Flag {
+        fn write<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error> {
+            Boolean::<crate::syn::boolean::NoConstraint>::write_value(writer, &self.0)
+        }
+    }
+
+    impl Readable for Flag {
+        fn read<R: Reader>(reader: &mut R) -> Result<Self, R::Error> {
+            Boolean::<crate::syn::boolean::NoConstraint>::read_value(reader).map(Flag)
+        }
+    }
+
+    #[test]
+    fn test_encode_and_decode() {
+        let open = OpenType::encode_from(&Flag(true)).unwrap();
+        assert_eq!(&[0x80], open.as_bytes());
+        assert_eq!(Flag(true), open.decode_as::<Flag>().unwrap());
+    }
+
+    #[test]
+    fn test_empty_encoding_is_one_octet() {
+        struct Empty;
+        impl Writable for Empty {
+            fn write<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error> {
+                NullT::<crate::syn::null::NoConstraint>::write_value(writer, &Null)
+            }
+        }
+        assert_eq!(&[0x00], OpenType::encode_from(&Empty).unwrap().as_bytes());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::syn::numbers::Number;
+use crate::syn::opentype::{NoConstraint, OpenType};
+use crate::syn::{complex, Reader, Writer};
+use core::marker::PhantomData;
+
+/// An open type constrained by a table constraint (ITU-T X.682 | ISO/IEC 8824-3, 10), whose
+/// actual type is selected by the value of the related field (`{@id}`)
+pub struct OpenTypeChoice<V: Constraint, C: complex::Constraint>(PhantomData<V>, PhantomData<C>);
+
+pub trait Constraint: Sized {
+    const NAME: &'static str;
+    /// The names of the alternatives, indexed by their choice-index
+    const VARIANT_NAMES: &'static [&'static str];
+    /// The values of the related field, each selecting the alternative of the same index
+    const KEYS: &'static [i64];
+    /// Whether the object set is extensible, so that values of objects that are not one of the
+    /// [`Constraint::KEYS`] are kept by [`Constraint::from_unknown`] instead of being rejected
+    const EXTENSIBLE: bool = false;
+
+    fn to_choice_index(&self) -> u64;
+
+    fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
+
+    fn read_content<R: Reader>(index: u64, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    #[inline]
+    fn index_of_key(key: i64) -> Option<u64> {
+        Self::KEYS
+            .iter()
+            .position(|k| *k == key)
+            .map(|index| index as u64)
+    }
+
+    #[inline]
+    fn key(&self) -> i64 {
+        match self.unknown() {
+            Some((key, _)) => key,
+            None => Self::KEYS[self.to_choice_index() as usize],
+        }
+    }
+
+    /// The value of the related field and the complete encoding of a value of an object unknown
+    /// to this type, see [`Constraint::EXTENSIBLE`]
+    #[inline]
+    fn unknown(&self) -> Option<(i64, &OpenType)> {
+        None
+    }
+
+    /// Keeps the complete encoding of a value of an object unknown to this type, which is only
+    /// possible if [`Constraint::EXTENSIBLE`]
+    #[inline]
+    fn from_unknown(_key: i64, _content: OpenType) -> Option<Self> {
+        None
+    }
+}
+
+impl<V: Constraint, C: complex::Constraint> OpenTypeChoice<V, C> {
+    /// Unlike other types, an open type cannot implement [`crate::syn::WritableType`], because
+    /// the value has to be the one selected by the value of the related field. Writing a value
+    /// with another [`Constraint::key`] fails.
+    #[inline]
+    pub fn write_value<W: Writer, K: Number>(
+        writer: &mut W,
+        value: &V,
+        key: K,
+    ) -> Result<(), W::Error> {
+        let key = key.to_i64();
+        writer.write_complex::<C, _>(|w| w.write_open_type_choice(value, key))
+    }
+
+    /// Unlike other types, an open type cannot implement [`crate::syn::ReadableType`], because
+    /// the alternative to read is determined by the previously read value of the related field
+    #[inline]
+    pub fn read_value<R: Reader, K: Number>(reader: &mut R, key: K) -> Result<V, R::Error> {
+        let key = key.to_i64();
+        reader.read_complex::<C, V, _>(|r| {
+            if V::EXTENSIBLE && V::index_of_key(key).is_none() {
+                let content = OpenType::from(r.read_open_type::<NoConstraint>()?);
+                if let Some(value) = V::from_unknown(key, content) {
+                    return Ok(value);
+                }
+            }
+            r.read_open_type_choice::<V>(key)
+        })
+    }
+}
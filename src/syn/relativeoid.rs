@@ -0,0 +1,77 @@
+use crate::syn::objectidentifier::{fmt_arcs, parse_arcs, ParseObjectIdentifierError};
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::Tag;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct RelativeOidT<C: Constraint = NoConstraint>(PhantomData<C>);
+
+pub trait Constraint: super::common::Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl super::common::Constraint for NoConstraint {
+    const TAG: Tag = Tag::DEFAULT_RELATIVE_OID;
+}
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for RelativeOidT<C> {
+    type Type = RelativeOid;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_relative_oid::<C>(value.arcs())
+    }
+}
+
+impl<C: Constraint> ReadableType for RelativeOidT<C> {
+    type Type = RelativeOid;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        Ok(RelativeOid::from(reader.read_relative_oid::<C>()?))
+    }
+}
+
+/// The arcs of a `RELATIVE-OID` value, relative to an `OBJECT IDENTIFIER` that is known from
+/// the context. Like [`super::ObjectIdentifier`], it is displayed and parsed in the dotted form.
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RelativeOid(Cow<'static, [u64]>);
+
+impl RelativeOid {
+    /// Used by the generated constants of value references
+    pub const fn from_static(arcs: &'static [u64]) -> Self {
+        Self(Cow::Borrowed(arcs))
+    }
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl From<Vec<u64>> for RelativeOid {
+    fn from(arcs: Vec<u64>) -> Self {
+        Self(Cow::Owned(arcs))
+    }
+}
+
+impl From<RelativeOid> for Vec<u64> {
+    fn from(value: RelativeOid) -> Self {
+        value.0.into_owned()
+    }
+}
+
+impl FromStr for RelativeOid {
+    type Err = ParseObjectIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_arcs(s).map(Self::from)
+    }
+}
+
+impl std::fmt::Display for RelativeOid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_arcs(self.arcs(), f)
+    }
+}
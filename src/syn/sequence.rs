@@ -1,4 +1,4 @@
-use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use crate::syn::{ReadableType, Reader, UnknownExtensions, WritableType, Writer};
 use core::marker::PhantomData;
 
 pub struct Sequence<T: Constraint>(PhantomData<T>);
@@ -6,14 +6,30 @@ pub struct Sequence<T: Constraint>(PhantomData<T>);
 pub trait Constraint: super::common::Constraint {
     const NAME: &'static str;
     const STD_OPTIONAL_FIELDS: u64;
+    /// The ASN.1 identifiers of the fields, in the order they are written
+    const FIELD_NAMES: &'static [&'static str];
     const FIELD_COUNT: u64;
     const EXTENDED_AFTER_FIELD: Option<u64>;
+    /// Whether this only wraps a single field to give another type a name of its own (see
+    /// `Rust::TupleStruct`), so that encodings which frame a SEQUENCE must not do so here
+    const TRANSPARENT: bool = false;
+    /// Whether this holds the components of an extension addition group. PER and OER encode
+    /// those like a SEQUENCE in a single extension addition, BER writes them as components of
+    /// the surrounding SEQUENCE and JER and XER as a member of their own.
+    const EXTENSION_GROUP: bool = false;
 
     fn read_seq<R: Reader>(reader: &mut R) -> Result<Self, R::Error>
     where
         Self: Sized;
 
     fn write_seq<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
+
+    /// The extension additions of a newer version of this type that were kept when the value
+    /// was read, if this type is able to keep them
+    #[inline]
+    fn unknown_extensions(&self) -> Option<&UnknownExtensions> {
+        None
+    }
 }
 
 impl<C: Constraint> WritableType for Sequence<C> {
@@ -24,7 +40,15 @@ impl<C: Constraint> WritableType for Sequence<C> {
         writer: &mut W,
         value: &Self::Type,
     ) -> Result<(), <W as Writer>::Error> {
-        writer.write_sequence::<C, _>(|w| value.write_seq::<W>(w))
+        match value
+            .unknown_extensions()
+            .filter(|unknown| !unknown.is_empty())
+        {
+            Some(unknown) => writer.write_sequence_with_unknown_extensions::<C, _>(unknown, |w| {
+                value.write_seq::<W>(w)
+            }),
+            None => writer.write_sequence::<C, _>(|w| value.write_seq::<W>(w)),
+        }
     }
 }
 
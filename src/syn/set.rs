@@ -13,7 +13,14 @@ impl<C: Constraint> WritableType for Set<C> {
         writer: &mut W,
         value: &Self::Type,
     ) -> Result<(), <W as Writer>::Error> {
-        writer.write_set::<C, _>(|w| value.write_seq::<W>(w))
+        match value
+            .unknown_extensions()
+            .filter(|unknown| !unknown.is_empty())
+        {
+            Some(unknown) => writer
+                .write_set_with_unknown_extensions::<C, _>(unknown, |w| value.write_seq::<W>(w)),
+            None => writer.write_set::<C, _>(|w| value.write_seq::<W>(w)),
+        }
     }
 }
 
@@ -0,0 +1,994 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use asn1rs_model::model::{Tag, TimeType};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt::Write as _;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const NANOS_PER_MINUTE: u128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u128 = 60 * NANOS_PER_MINUTE;
+
+pub struct TimeT<V: TimeValue, C: Constraint = NoConstraint<V>>(PhantomData<(V, C)>);
+
+pub trait Constraint: super::common::Constraint {}
+
+pub struct NoConstraint<V>(PhantomData<V>);
+
+impl<V> Default for NoConstraint<V> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<V: TimeValue> super::common::Constraint for NoConstraint<V> {
+    const TAG: Tag = V::TYPE.default_tag();
+}
+
+impl<V: TimeValue> Constraint for NoConstraint<V> {}
+
+impl<V: TimeValue, C: Constraint> WritableType for TimeT<V, C> {
+    type Type = V;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_time::<C>(V::TYPE, value.as_str())
+    }
+}
+
+impl<V: TimeValue, C: Constraint> ReadableType for TimeT<V, C> {
+    type Type = V;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        Ok(V::from_checked(reader.read_time::<C>(V::TYPE)?))
+    }
+}
+
+/// The string representation shared by all useful time types
+pub trait TimeValue: Sized {
+    const TYPE: TimeType;
+
+    fn as_str(&self) -> &str;
+
+    /// Wraps a string that already passed [`is_valid`] for [`TimeValue::TYPE`]
+    fn from_checked(value: String) -> Self;
+}
+
+/// Whether the given string is in the format required by the given time type
+pub fn is_valid(r#type: TimeType, value: &str) -> bool {
+    match r#type {
+        TimeType::GeneralizedTime => parse_generalized_time(value).is_some(),
+        TimeType::UtcTime => parse_utc_time(value).is_some(),
+        TimeType::Date => parse_date(value).is_some(),
+        TimeType::TimeOfDay => parse_time_of_day(value).is_some(),
+        TimeType::DateTime => parse_date_time(value).is_some(),
+        TimeType::Duration => parse_duration(value).is_some(),
+    }
+}
+
+macro_rules! time_value {
+    ($(#[$meta:meta])* $name:ident, $type:expr, $default:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(Cow<'static, str>);
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::from_static($default)
+            }
+        }
+
+        impl $name {
+            /// Used by the generated constants of value references, the format is not checked
+            pub const fn from_static(value: &'static str) -> Self {
+                Self(Cow::Borrowed(value))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl TimeValue for $name {
+            const TYPE: TimeType = $type;
+
+            fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            fn from_checked(value: String) -> Self {
+                Self(Cow::Owned(value))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseTimeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if is_valid($type, s) {
+                    Ok(Self(Cow::Owned(s.to_string())))
+                } else {
+                    Err(ParseTimeError::new($type, s))
+                }
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ParseTimeError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                if is_valid($type, &value) {
+                    Ok(Self(Cow::Owned(value)))
+                } else {
+                    Err(ParseTimeError($type, value))
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0.into_owned()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+time_value!(
+    /// `GeneralizedTime` as `YYYYMMDDhh[mm[ss]][.f][Z|±hh[mm]]`, ITU-T X.680 | ISO/IEC 8824-1, 46
+    GeneralizedTime,
+    TimeType::GeneralizedTime,
+    "19700101000000Z"
+);
+time_value!(
+    /// `UTCTime` as `YYMMDDhhmm[ss](Z|±hhmm)`, ITU-T X.680 | ISO/IEC 8824-1, 47
+    UtcTime,
+    TimeType::UtcTime,
+    "700101000000Z"
+);
+time_value!(
+    /// `DATE` as `YYYY-MM-DD`
+    Date,
+    TimeType::Date,
+    "1970-01-01"
+);
+time_value!(
+    /// `TIME-OF-DAY` as `hh:mm:ss`
+    TimeOfDay,
+    TimeType::TimeOfDay,
+    "00:00:00"
+);
+time_value!(
+    /// `DATE-TIME` as `YYYY-MM-DDThh:mm:ss`
+    DateTime,
+    TimeType::DateTime,
+    "1970-01-01T00:00:00"
+);
+time_value!(
+    /// `DURATION` in the ISO 8601 form `PnYnMnDTnHnMnS` or `PnW`
+    Duration,
+    TimeType::Duration,
+    "PT0S"
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimeError(TimeType, String);
+
+impl ParseTimeError {
+    fn new(r#type: TimeType, value: impl ToString) -> Self {
+        Self(r#type, value.to_string())
+    }
+
+    pub fn time_type(&self) -> TimeType {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ParseTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid {} value: {:?}", self.0.asn_name(), self.1)
+    }
+}
+
+impl std::error::Error for ParseTimeError {}
+
+#[derive(Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct CalendarDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl CalendarDate {
+    pub fn is_valid(&self) -> bool {
+        self.year <= 9999 && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct ClockTime {
+    pub hour: u8,
+    pub minute: u8,
+    /// Up to 60 to allow for leap seconds
+    pub second: u8,
+    pub nanosecond: u32,
+}
+
+impl ClockTime {
+    pub fn is_valid(&self) -> bool {
+        self.hour < 24
+            && self.minute < 60
+            && self.second <= 60
+            && u128::from(self.nanosecond) < NANOS_PER_SECOND
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct CalendarDateTime {
+    pub date: CalendarDate,
+    pub time: ClockTime,
+    /// The offset to UTC in minutes, `None` for local time
+    pub utc_offset: Option<i16>,
+}
+
+/// Fails if the value was not checked, see [`GeneralizedTime::from_static`]
+impl TryFrom<&GeneralizedTime> for CalendarDateTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: &GeneralizedTime) -> Result<Self, Self::Error> {
+        parse_generalized_time(value.as_str())
+            .ok_or_else(|| ParseTimeError::new(TimeType::GeneralizedTime, value))
+    }
+}
+
+impl TryFrom<CalendarDateTime> for GeneralizedTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: CalendarDateTime) -> Result<Self, Self::Error> {
+        let CalendarDateTime {
+            date,
+            time,
+            utc_offset,
+        } = value;
+        let mut string = format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            date.year, date.month, date.day, time.hour, time.minute, time.second
+        );
+        if time.nanosecond > 0 {
+            let fraction = format!("{:09}", time.nanosecond);
+            string.push('.');
+            string.push_str(fraction.trim_end_matches('0'));
+        }
+        fmt_utc_offset(&mut string, utc_offset);
+        GeneralizedTime::try_from(string)
+    }
+}
+
+/// Fails if the value was not checked, see [`UtcTime::from_static`]
+impl TryFrom<&UtcTime> for CalendarDateTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: &UtcTime) -> Result<Self, Self::Error> {
+        parse_utc_time(value.as_str()).ok_or_else(|| ParseTimeError::new(TimeType::UtcTime, value))
+    }
+}
+
+/// Only years from 1950 to 2049 can be represented
+impl TryFrom<CalendarDateTime> for UtcTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: CalendarDateTime) -> Result<Self, Self::Error> {
+        let CalendarDateTime {
+            date,
+            time,
+            utc_offset,
+        } = value;
+        if !(1950..=2049).contains(&date.year) || time.nanosecond > 0 || utc_offset.is_none() {
+            return Err(ParseTimeError::new(
+                TimeType::UtcTime,
+                format!("{:?}", value),
+            ));
+        }
+        let mut string = format!(
+            "{:02}{:02}{:02}{:02}{:02}{:02}",
+            date.year % 100,
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second
+        );
+        fmt_utc_offset(&mut string, utc_offset);
+        UtcTime::try_from(string)
+    }
+}
+
+/// Fails if the value was not checked, see [`Date::from_static`]
+impl TryFrom<&Date> for CalendarDate {
+    type Error = ParseTimeError;
+
+    fn try_from(value: &Date) -> Result<Self, Self::Error> {
+        parse_date(value.as_str()).ok_or_else(|| ParseTimeError::new(TimeType::Date, value))
+    }
+}
+
+impl TryFrom<CalendarDate> for Date {
+    type Error = ParseTimeError;
+
+    fn try_from(value: CalendarDate) -> Result<Self, Self::Error> {
+        Date::try_from(format!(
+            "{:04}-{:02}-{:02}",
+            value.year, value.month, value.day
+        ))
+    }
+}
+
+/// Fails if the value was not checked, see [`TimeOfDay::from_static`]
+impl TryFrom<&TimeOfDay> for ClockTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: &TimeOfDay) -> Result<Self, Self::Error> {
+        parse_time_of_day(value.as_str())
+            .ok_or_else(|| ParseTimeError::new(TimeType::TimeOfDay, value))
+    }
+}
+
+/// Fractions of a second cannot be represented
+impl TryFrom<ClockTime> for TimeOfDay {
+    type Error = ParseTimeError;
+
+    fn try_from(value: ClockTime) -> Result<Self, Self::Error> {
+        if value.nanosecond > 0 {
+            return Err(ParseTimeError::new(
+                TimeType::TimeOfDay,
+                format!("{:?}", value),
+            ));
+        }
+        TimeOfDay::try_from(format!(
+            "{:02}:{:02}:{:02}",
+            value.hour, value.minute, value.second
+        ))
+    }
+}
+
+/// Fails if the value was not checked, see [`DateTime::from_static`]
+impl TryFrom<&DateTime> for CalendarDateTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: &DateTime) -> Result<Self, Self::Error> {
+        parse_date_time(value.as_str())
+            .ok_or_else(|| ParseTimeError::new(TimeType::DateTime, value))
+    }
+}
+
+/// Only local times without fractions of a second can be represented
+impl TryFrom<CalendarDateTime> for DateTime {
+    type Error = ParseTimeError;
+
+    fn try_from(value: CalendarDateTime) -> Result<Self, Self::Error> {
+        let CalendarDateTime {
+            date,
+            time,
+            utc_offset,
+        } = value;
+        if time.nanosecond > 0 || utc_offset.is_some() {
+            return Err(ParseTimeError::new(
+                TimeType::DateTime,
+                format!("{:?}", value),
+            ));
+        }
+        DateTime::try_from(format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            date.year, date.month, date.day, time.hour, time.minute, time.second
+        ))
+    }
+}
+
+/// Durations with years or months have no fixed length and cannot be converted
+impl TryFrom<&Duration> for std::time::Duration {
+    type Error = ParseTimeError;
+
+    fn try_from(value: &Duration) -> Result<Self, Self::Error> {
+        parse_duration(value.as_str())
+            .and_then(DurationComponents::into_std)
+            .ok_or_else(|| ParseTimeError::new(TimeType::Duration, value))
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(value: std::time::Duration) -> Self {
+        let mut string = format!("PT{}", value.as_secs());
+        if value.subsec_nanos() > 0 {
+            let fraction = format!("{:09}", value.subsec_nanos());
+            string.push('.');
+            string.push_str(fraction.trim_end_matches('0'));
+        }
+        string.push('S');
+        Duration(Cow::Owned(string))
+    }
+}
+
+fn fmt_utc_offset(string: &mut String, utc_offset: Option<i16>) {
+    match utc_offset {
+        None => {}
+        Some(0) => string.push('Z'),
+        Some(offset) => {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.unsigned_abs();
+            let _ = write!(string, "{}{:02}{:02}", sign, offset / 60, offset % 60);
+        }
+    }
+}
+
+const fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Splits the given amount of fixed-width digits off the front of `s`
+fn take_digits(s: &mut &str, width: usize) -> Option<u32> {
+    let digits = s.get(..width)?;
+    if digits.bytes().all(|b| b.is_ascii_digit()) {
+        *s = &s[width..];
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Splits a non-empty sequence of digits off the front of `s`
+fn take_digit_sequence<'a>(s: &mut &'a str) -> Option<&'a str> {
+    let len = s.bytes().take_while(u8::is_ascii_digit).count();
+    if len > 0 {
+        let digits = &s[..len];
+        *s = &s[len..];
+        Some(digits)
+    } else {
+        None
+    }
+}
+
+fn take_char(s: &mut &str, c: char) -> Option<()> {
+    *s = s.strip_prefix(c)?;
+    Some(())
+}
+
+/// Splits a decimal fraction introduced by `.` or `,` off the front of `s`
+fn take_fraction<'a>(s: &mut &'a str) -> Option<&'a str> {
+    let mut rest = s.strip_prefix(&['.', ','][..])?;
+    let digits = take_digit_sequence(&mut rest)?;
+    *s = rest;
+    Some(digits)
+}
+
+/// The nanoseconds of the given decimal fraction of a unit, digits beyond nanosecond
+/// precision are truncated
+fn fraction_to_nanos(fraction: &str, unit_nanos: u128) -> u128 {
+    let digits = &fraction[..fraction.len().min(18)];
+    let numerator = digits.parse::<u128>().unwrap_or_default();
+    numerator * unit_nanos / 10_u128.pow(digits.len() as u32)
+}
+
+fn date_of(year: u32, month: u32, day: u32) -> Option<CalendarDate> {
+    Some(CalendarDate {
+        year: u16::try_from(year).ok()?,
+        month: u8::try_from(month).ok()?,
+        day: u8::try_from(day).ok()?,
+    })
+    .filter(CalendarDate::is_valid)
+}
+
+fn time_of(hour: u32, minute: u32, second: u32, nanosecond: u32) -> Option<ClockTime> {
+    Some(ClockTime {
+        hour: u8::try_from(hour).ok()?,
+        minute: u8::try_from(minute).ok()?,
+        second: u8::try_from(second).ok()?,
+        nanosecond,
+    })
+    .filter(ClockTime::is_valid)
+}
+
+/// `Z` or `±hh[mm]`, the minutes are mandatory if `require_minutes` is set
+fn take_utc_offset(s: &mut &str, require_minutes: bool) -> Option<i16> {
+    if take_char(s, 'Z').is_some() {
+        return Some(0);
+    }
+    let negative = match s.chars().next()? {
+        '+' => false,
+        '-' => true,
+        _ => return None,
+    };
+    *s = &s[1..];
+    let hours = take_digits(s, 2)?;
+    let minutes = if require_minutes || !s.is_empty() {
+        take_digits(s, 2)?
+    } else {
+        0
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    let offset = (hours * 60 + minutes) as i16;
+    Some(if negative { -offset } else { offset })
+}
+
+fn parse_generalized_time(mut s: &str) -> Option<CalendarDateTime> {
+    let date = date_of(
+        take_digits(&mut s, 4)?,
+        take_digits(&mut s, 2)?,
+        take_digits(&mut s, 2)?,
+    )?;
+
+    let hour = take_digits(&mut s, 2)?;
+    let mut minute = 0;
+    let mut second = 0;
+    let mut unit = NANOS_PER_HOUR;
+    if let Some(value) = take_digits(&mut s, 2) {
+        minute = value;
+        unit = NANOS_PER_MINUTE;
+        if let Some(value) = take_digits(&mut s, 2) {
+            second = value;
+            unit = NANOS_PER_SECOND;
+        }
+    }
+    // the fraction applies to the last given component and is always less than one of it
+    let fraction = take_fraction(&mut s)
+        .map(|fraction| fraction_to_nanos(fraction, unit))
+        .unwrap_or_default();
+    minute += (fraction / NANOS_PER_MINUTE) as u32;
+    second += (fraction % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u32;
+    let time = time_of(hour, minute, second, (fraction % NANOS_PER_SECOND) as u32)?;
+
+    let utc_offset = if s.is_empty() {
+        None
+    } else {
+        Some(take_utc_offset(&mut s, false)?)
+    };
+
+    Some(CalendarDateTime {
+        date,
+        time,
+        utc_offset,
+    })
+    .filter(|_| s.is_empty())
+}
+
+fn parse_utc_time(mut s: &str) -> Option<CalendarDateTime> {
+    let year = take_digits(&mut s, 2)?;
+    let year = if year < 50 { 2000 + year } else { 1900 + year };
+    let date = date_of(year, take_digits(&mut s, 2)?, take_digits(&mut s, 2)?)?;
+    let hour = take_digits(&mut s, 2)?;
+    let minute = take_digits(&mut s, 2)?;
+    let second = take_digits(&mut s, 2).unwrap_or_default();
+    let time = time_of(hour, minute, second, 0)?;
+    let utc_offset = Some(take_utc_offset(&mut s, true)?);
+
+    Some(CalendarDateTime {
+        date,
+        time,
+        utc_offset,
+    })
+    .filter(|_| s.is_empty())
+}
+
+fn take_date(s: &mut &str) -> Option<CalendarDate> {
+    let year = take_digits(s, 4)?;
+    take_char(s, '-')?;
+    let month = take_digits(s, 2)?;
+    take_char(s, '-')?;
+    date_of(year, month, take_digits(s, 2)?)
+}
+
+fn take_time_of_day(s: &mut &str) -> Option<ClockTime> {
+    let hour = take_digits(s, 2)?;
+    take_char(s, ':')?;
+    let minute = take_digits(s, 2)?;
+    take_char(s, ':')?;
+    time_of(hour, minute, take_digits(s, 2)?, 0)
+}
+
+fn parse_date(mut s: &str) -> Option<CalendarDate> {
+    take_date(&mut s).filter(|_| s.is_empty())
+}
+
+fn parse_time_of_day(mut s: &str) -> Option<ClockTime> {
+    take_time_of_day(&mut s).filter(|_| s.is_empty())
+}
+
+fn parse_date_time(mut s: &str) -> Option<CalendarDateTime> {
+    let date = take_date(&mut s)?;
+    take_char(&mut s, 'T')?;
+    let time = take_time_of_day(&mut s)?;
+    Some(CalendarDateTime {
+        date,
+        time,
+        utc_offset: None,
+    })
+    .filter(|_| s.is_empty())
+}
+
+#[derive(Default)]
+struct DurationComponents<'a> {
+    years: u64,
+    months: u64,
+    weeks: u64,
+    days: u64,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    /// The decimal fraction of the last component and the designator of that component
+    fraction: Option<(&'a str, char)>,
+}
+
+impl DurationComponents<'_> {
+    fn into_std(self) -> Option<std::time::Duration> {
+        let fraction_unit = match self.fraction {
+            None => 0,
+            Some((_, 'W')) => 7 * 24 * NANOS_PER_HOUR,
+            Some((_, 'D')) => 24 * NANOS_PER_HOUR,
+            Some((_, 'H')) => NANOS_PER_HOUR,
+            Some((_, 'm')) => NANOS_PER_MINUTE,
+            Some((_, 'S')) => NANOS_PER_SECOND,
+            Some(_) => return None,
+        };
+        if self.years > 0 || self.months > 0 {
+            return None;
+        }
+        let fraction = self
+            .fraction
+            .map(|(digits, _)| fraction_to_nanos(digits, fraction_unit))
+            .unwrap_or_default();
+        let seconds = [
+            (self.weeks, 7 * 24 * 60 * 60),
+            (self.days, 24 * 60 * 60),
+            (self.hours, 60 * 60),
+            (self.minutes, 60),
+            (self.seconds, 1),
+        ]
+        .iter()
+        .try_fold(0_u64, |sum, (value, factor)| {
+            sum.checked_add(value.checked_mul(*factor)?)
+        })?
+        .checked_add(u64::try_from(fraction / NANOS_PER_SECOND).ok()?)?;
+        Some(std::time::Duration::new(
+            seconds,
+            (fraction % NANOS_PER_SECOND) as u32,
+        ))
+    }
+}
+
+fn parse_duration(mut s: &str) -> Option<DurationComponents<'_>> {
+    take_char(&mut s, 'P')?;
+    let mut components = DurationComponents::default();
+    let mut in_time = false;
+    let mut count = 0;
+    let mut time_count = 0;
+    let mut weeks = false;
+    // the designators in their required order, the minute of the time part is `m`
+    let mut designators: &[char] = &['Y', 'M', 'W', 'D'];
+
+    while !s.is_empty() {
+        if !in_time && take_char(&mut s, 'T').is_some() {
+            in_time = true;
+            designators = &['H', 'm', 'S'];
+            continue;
+        }
+        if components.fraction.is_some() {
+            // only the last component may have a fraction
+            return None;
+        }
+        let value = take_digit_sequence(&mut s)?.parse::<u64>().ok()?;
+        let fraction = take_fraction(&mut s);
+        let designator = match s.chars().next()? {
+            'M' if in_time => 'm',
+            c => c,
+        };
+        s = &s[1..];
+        let position = designators.iter().position(|d| *d == designator)?;
+        designators = &designators[position + 1..];
+        *match designator {
+            'Y' => &mut components.years,
+            'M' => &mut components.months,
+            'W' => &mut components.weeks,
+            'D' => &mut components.days,
+            'H' => &mut components.hours,
+            'm' => &mut components.minutes,
+            _ => &mut components.seconds,
+        } = value;
+        components.fraction = fraction.map(|digits| (digits, designator));
+        count += 1;
+        if in_time {
+            time_count += 1;
+        }
+        weeks |= designator == 'W';
+    }
+
+    // at least one component, after `T` as well, and weeks are not combined with others
+    Some(components).filter(|_| count > 0 && (!in_time || time_count > 0) && (!weeks || count == 1))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn generalized_time_formats() {
+        for valid in [
+            "2023010112",
+            "202301011230",
+            "20230101123059",
+            "20230101123059.5",
+            "20230101123059,123Z",
+            "20230101123059Z",
+            "20230101123059+0100",
+            "20230101123059-05",
+            "20240229000000Z",
+            "20161231235960Z",
+        ] {
+            assert!(valid.parse::<GeneralizedTime>().is_ok(), "{}", valid);
+        }
+        for invalid in [
+            "",
+            "2023",
+            "202301011",
+            "20230229000000Z",
+            "20231301000000Z",
+            "20230101240000Z",
+            "20230101123059.Z",
+            "20230101123059Y",
+            "20230101123059+2400",
+            "20230101123059+01000",
+            "2023-01-01T12:30:59Z",
+        ] {
+            assert_eq!(
+                Err(ParseTimeError::new(TimeType::GeneralizedTime, invalid)),
+                invalid.parse::<GeneralizedTime>(),
+            );
+        }
+    }
+
+    #[test]
+    fn generalized_time_fractions_of_hours_and_minutes() {
+        let time =
+            CalendarDateTime::try_from(&GeneralizedTime::from_static("2023010112.5")).unwrap();
+        assert_eq!(
+            (12, 30, 0),
+            (time.time.hour, time.time.minute, time.time.second)
+        );
+
+        let time =
+            CalendarDateTime::try_from(&GeneralizedTime::from_static("202301011230.25Z")).unwrap();
+        assert_eq!(
+            (30, 15, 0),
+            (time.time.minute, time.time.second, time.time.nanosecond)
+        );
+        assert_eq!(Some(0), time.utc_offset);
+    }
+
+    #[test]
+    fn generalized_time_calendar_roundtrip() {
+        let calendar = CalendarDateTime {
+            date: CalendarDate {
+                year: 2023,
+                month: 7,
+                day: 14,
+            },
+            time: ClockTime {
+                hour: 9,
+                minute: 5,
+                second: 3,
+                nanosecond: 250_000_000,
+            },
+            utc_offset: Some(-90),
+        };
+        let time = GeneralizedTime::try_from(calendar).unwrap();
+        assert_eq!("20230714090503.25-0130", time.as_str());
+        assert_eq!(calendar, CalendarDateTime::try_from(&time).unwrap());
+
+        let utc = CalendarDateTime {
+            utc_offset: Some(0),
+            ..calendar
+        };
+        assert_eq!(
+            "20230714090503.25Z",
+            GeneralizedTime::try_from(utc).unwrap().as_str()
+        );
+
+        let invalid = CalendarDateTime {
+            date: CalendarDate {
+                month: 2,
+                day: 30,
+                ..calendar.date
+            },
+            ..calendar
+        };
+        assert!(GeneralizedTime::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn utc_time_century_window() {
+        let time = CalendarDateTime::try_from(&UtcTime::from_static("4912312359Z")).unwrap();
+        assert_eq!(2049, time.date.year);
+        assert_eq!(0, time.time.second);
+
+        let time = CalendarDateTime::try_from(&UtcTime::from_static("500101000000+0200")).unwrap();
+        assert_eq!(1950, time.date.year);
+        assert_eq!(Some(120), time.utc_offset);
+        assert_eq!(
+            "500101000000+0200",
+            UtcTime::try_from(time).unwrap().as_str()
+        );
+
+        let too_late = CalendarDateTime {
+            date: CalendarDate {
+                year: 2050,
+                ..time.date
+            },
+            ..time
+        };
+        assert!(UtcTime::try_from(too_late).is_err());
+
+        for invalid in [
+            "4912312359",
+            "49123123Z",
+            "4912312359+01",
+            "491231235960.5Z",
+        ] {
+            assert!(invalid.parse::<UtcTime>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn date_time_of_day_and_date_time() {
+        assert!("2023-07-14".parse::<Date>().is_ok());
+        assert!("20230714".parse::<Date>().is_err());
+        assert!("2023-7-14".parse::<Date>().is_err());
+        assert!("12:30:59".parse::<TimeOfDay>().is_ok());
+        assert!("24:00:00".parse::<TimeOfDay>().is_err());
+        assert!("12:30".parse::<TimeOfDay>().is_err());
+        assert!("2023-07-14T12:30:59".parse::<DateTime>().is_ok());
+        assert!("2023-07-14T12:30:59Z".parse::<DateTime>().is_err());
+        assert!("2023-07-14 12:30:59".parse::<DateTime>().is_err());
+
+        let date = CalendarDate {
+            year: 2024,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!("2024-02-29", Date::try_from(date).unwrap().as_str());
+        assert_eq!(
+            date,
+            CalendarDate::try_from(&Date::from_static("2024-02-29")).unwrap()
+        );
+
+        let time = ClockTime {
+            hour: 23,
+            minute: 59,
+            second: 1,
+            nanosecond: 0,
+        };
+        assert_eq!("23:59:01", TimeOfDay::try_from(time).unwrap().as_str());
+        assert!(TimeOfDay::try_from(ClockTime {
+            nanosecond: 1,
+            ..time
+        })
+        .is_err());
+
+        let date_time = CalendarDateTime {
+            date,
+            time,
+            utc_offset: None,
+        };
+        let value = DateTime::try_from(date_time).unwrap();
+        assert_eq!("2024-02-29T23:59:01", value.as_str());
+        assert_eq!(date_time, CalendarDateTime::try_from(&value).unwrap());
+    }
+
+    #[test]
+    fn duration_formats() {
+        for valid in [
+            "P1Y",
+            "P1Y2M3D",
+            "P1M",
+            "PT1M",
+            "P1MT1M",
+            "P2W",
+            "P1DT12H",
+            "PT0S",
+            "PT1.5S",
+            "PT0,5H",
+            "P1Y2M3DT4H5M6.789S",
+        ] {
+            assert!(valid.parse::<Duration>().is_ok(), "{}", valid);
+        }
+        for invalid in [
+            "", "P", "PT", "P1", "1Y", "P1DT", "P1D1Y", "P1W1D", "P1.5Y2M", "PT1H1H", "P-1D",
+            "PT1S1M", "P1Y.5M",
+        ] {
+            assert!(invalid.parse::<Duration>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn duration_std_roundtrip() {
+        let value = Duration::from(std::time::Duration::new(90, 500_000_000));
+        assert_eq!("PT90.5S", value.as_str());
+        assert_eq!(
+            Ok(std::time::Duration::new(90, 500_000_000)),
+            std::time::Duration::try_from(&value)
+        );
+        assert_eq!(
+            "PT0S",
+            Duration::from(std::time::Duration::default()).as_str()
+        );
+
+        assert_eq!(
+            Ok(std::time::Duration::from_secs(86_400 + 2 * 3600 + 30 * 60)),
+            std::time::Duration::try_from(&Duration::from_static("P1DT2.5H"))
+        );
+        assert_eq!(
+            Ok(std::time::Duration::from_secs(14 * 86_400)),
+            std::time::Duration::try_from(&Duration::from_static("P2W"))
+        );
+        assert!(std::time::Duration::try_from(&Duration::from_static("P1M")).is_err());
+        assert!(std::time::Duration::try_from(&Duration::from_static("P0Y1D")).is_ok());
+    }
+
+    #[test]
+    fn unchecked_value_is_not_converted() {
+        assert_eq!(
+            Err(ParseTimeError::new(TimeType::GeneralizedTime, "2023")),
+            CalendarDateTime::try_from(&GeneralizedTime::from_static("2023"))
+        );
+        assert!(CalendarDateTime::try_from(&UtcTime::from_static("")).is_err());
+        assert!(CalendarDate::try_from(&Date::from_static("2023-02-29")).is_err());
+        assert!(ClockTime::try_from(&TimeOfDay::from_static("24:00:00")).is_err());
+        assert!(CalendarDateTime::try_from(&DateTime::from_static("2023-01-01")).is_err());
+    }
+
+    #[test]
+    fn default_values_are_valid() {
+        assert!(is_valid(
+            TimeType::GeneralizedTime,
+            GeneralizedTime::default().as_str()
+        ));
+        assert!(is_valid(TimeType::UtcTime, UtcTime::default().as_str()));
+        assert!(is_valid(TimeType::Date, Date::default().as_str()));
+        assert!(is_valid(TimeType::TimeOfDay, TimeOfDay::default().as_str()));
+        assert!(is_valid(TimeType::DateTime, DateTime::default().as_str()));
+        assert!(is_valid(TimeType::Duration, Duration::default().as_str()));
+    }
+
+    #[test]
+    fn deserialize_checks_the_format() {
+        use serde::de::value::Error;
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let deserialize = |value: &str| {
+            Date::deserialize(value.to_string().into_deserializer()) as Result<_, Error>
+        };
+        assert_eq!(
+            Ok(Date::from_static("2024-02-29")),
+            deserialize("2024-02-29")
+        );
+        assert!(deserialize("2023-02-29").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::syn::OpenType;
+
+/// The extension additions of an extensible SEQUENCE or SET that a newer version of its type
+/// added and that were read into a version that does not know them. Each addition is kept as the
+/// open type it is encoded in or as `None` if it was absent, so that writing the value with the
+/// same encoding rules reproduces them unchanged.
+///
+/// Only the packed encoding rules keep and write these additions, the other encoding rules ignore
+/// them.
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnknownExtensions(Vec<Option<OpenType>>);
+
+impl UnknownExtensions {
+    pub const fn new(additions: Vec<Option<OpenType>>) -> Self {
+        Self(additions)
+    }
+
+    /// The unknown extension additions in the order they were read, following the ones known to
+    /// the type
+    pub fn additions(&self) -> &[Option<OpenType>] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn into_additions(self) -> Vec<Option<OpenType>> {
+        self.0
+    }
+}
+
+impl From<Vec<Option<OpenType>>> for UnknownExtensions {
+    fn from(additions: Vec<Option<OpenType>>) -> Self {
+        Self(additions)
+    }
+}
@@ -0,0 +1,105 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"AperBasic DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Simple ::= SEQUENCE {
+        small INTEGER (0..7),
+        octet INTEGER (0..255),
+        word INTEGER (0..65535),
+        flag BOOLEAN,
+        text IA5String (SIZE(1..16)),
+        data OCTET STRING,
+        opt INTEGER OPTIONAL
+    }
+
+    Decision ::= CHOICE {
+        abc BOOLEAN,
+        def INTEGER (0..1000),
+        ...,
+        ghi UTF8String
+    }
+
+    Numbers ::= SEQUENCE SIZE(1..4) OF INTEGER (0..65535)
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    END"
+);
+
+#[test]
+fn test_simple_sequence() {
+    serialize_and_deserialize_aper(
+        8 * 13,
+        &[
+            // opt-bit, small, padding
+            0b1101_0000,
+            // octet
+            0xC8,
+            // word
+            0x12,
+            0x34,
+            // flag, length of text, padding
+            0b1000_1000,
+            // text
+            b'A',
+            b'B',
+            // length and content of data
+            0x02,
+            0xDE,
+            0xAD,
+            // length and content of opt
+            0x02,
+            0x00,
+            0xFF,
+        ],
+        &Simple {
+            small: 5,
+            octet: 200,
+            word: 0x1234,
+            flag: true,
+            text: "AB".to_string(),
+            data: vec![0xDE, 0xAD],
+            opt: Some(255),
+        },
+    );
+}
+
+#[test]
+fn test_choice_in_root() {
+    serialize_and_deserialize_aper(8 * 3, &[0x40, 0x01, 0xF4], &Decision::Def(500));
+}
+
+#[test]
+fn test_choice_extended() {
+    serialize_and_deserialize_aper(
+        8 * 5,
+        &[0x80, 0x03, 0x02, b'h', b'i'],
+        &Decision::Ghi("hi".to_string()),
+    );
+}
+
+#[test]
+fn test_sequence_of() {
+    serialize_and_deserialize_aper(8 * 5, &[0x40, 0x00, 0x01, 0x00, 0x02], &Numbers(vec![1, 2]));
+}
+
+#[test]
+fn test_extensible_sequence() {
+    serialize_and_deserialize_aper(
+        8 * 5,
+        &[0x80, 0x01, 0x01, 0x01, 0x80],
+        &Extensible {
+            abc: 1,
+            def: Some(true),
+        },
+    );
+    serialize_and_deserialize_aper(8 * 2, &[0x00, 0x01], &Extensible { abc: 1, def: None });
+}
@@ -0,0 +1,54 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicComponentsOf DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Header ::= SEQUENCE {
+        id INTEGER (0..255),
+        flag BOOLEAN
+    }
+
+    Message ::= SEQUENCE {
+        COMPONENTS OF Header,
+        payload OCTET STRING (SIZE(2))
+    }
+
+    Decision ::= CHOICE {
+        number INTEGER (0..255),
+        text UTF8String
+    }
+
+    Picked ::= SEQUENCE {
+        number number < Decision
+    }
+
+    END"
+);
+
+#[test]
+fn test_components_of_is_flattened() {
+    let message = Message {
+        id: 5,
+        flag: true,
+        payload: vec![0xAB, 0xCD],
+    };
+    serialize_and_deserialize_uper(25, &[0x05, 0xD5, 0xE6, 0x80], &message);
+    // automatic tagging applies to the expanded components
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0A, // SEQUENCE
+            0x80, 0x01, 0x05, // [0] id
+            0x81, 0x01, 0xFF, // [1] flag
+            0x82, 0x02, 0xAB, 0xCD, // [2] payload
+        ],
+        &message,
+    );
+}
+
+#[test]
+fn test_selection_type_is_the_type_of_the_alternative() {
+    serialize_and_deserialize_uper(8, &[0x07], &Picked { number: 7 });
+}
@@ -0,0 +1,45 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicExports DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    EXPORTS Visible, visible-value;
+
+    visible-value INTEGER ::= 5
+    hidden-value INTEGER ::= 6
+
+    Visible ::= SEQUENCE {
+        hidden Hidden,
+        inline SEQUENCE {
+            flag BOOLEAN
+        }
+    }
+
+    Hidden ::= SEQUENCE {
+        number INTEGER (0..255)
+    }
+
+    Unreferenced ::= SEQUENCE {
+        flag BOOLEAN
+    }
+
+    END"
+);
+
+#[test]
+fn test_not_exported_types_are_still_usable_in_crate() {
+    serialize_and_deserialize_uper(
+        9,
+        &[0x05, 0x80],
+        &Visible {
+            hidden: Hidden { number: 5 },
+            inline: VisibleInline { flag: true },
+        },
+    );
+    serialize_and_deserialize_uper(1, &[0x80], &Unreferenced { flag: true });
+    assert_eq!(5, VISIBLE_VALUE);
+    assert_eq!(6, HIDDEN_VALUE);
+}
@@ -0,0 +1,96 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicExtensionGroups DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Versioned ::= SEQUENCE {
+        id INTEGER (0..255),
+        ...,
+        [[ 2:
+            level INTEGER (0..7),
+            flag BOOLEAN
+        ]],
+        trailer BOOLEAN
+    }
+
+    Decision ::= CHOICE {
+        number INTEGER (0..255),
+        ...,
+        [[
+            flag BOOLEAN,
+            text UTF8String
+        ]]
+    }
+
+    END"
+);
+
+#[test]
+fn test_group_is_a_single_extension_addition() {
+    let versioned = Versioned {
+        id: 5,
+        extension_group_2: Some(VersionedExtensionGroup2 {
+            level: 3,
+            flag: true,
+        }),
+        trailer: None,
+    };
+    // the extension bit, the id, two extension additions of which only the group is present and
+    // the group in an open type of one octet
+    serialize_and_deserialize_uper(34, &[0x82, 0x81, 0x80, 0x5C, 0x00], &versioned);
+}
+
+#[test]
+fn test_absent_group() {
+    serialize_and_deserialize_uper(
+        9,
+        &[0x02, 0x80],
+        &Versioned {
+            id: 5,
+            extension_group_2: None,
+            trailer: None,
+        },
+    );
+}
+
+#[test]
+fn test_group_is_flattened_in_der() {
+    // automatic tagging numbers the components of the group as if they were not grouped
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0C, // SEQUENCE
+            0x80, 0x01, 0x05, // [0] id
+            0x81, 0x01, 0x03, // [1] level
+            0x82, 0x01, 0xFF, // [2] flag
+            0x83, 0x01, 0x00, // [3] trailer
+        ],
+        &Versioned {
+            id: 5,
+            extension_group_2: Some(VersionedExtensionGroup2 {
+                level: 3,
+                flag: true,
+            }),
+            trailer: Some(false),
+        },
+    );
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x06, // SEQUENCE
+            0x80, 0x01, 0x05, // [0] id
+            0x83, 0x01, 0xFF, // [3] trailer
+        ],
+        &Versioned {
+            id: 5,
+            extension_group_2: None,
+            trailer: Some(true),
+        },
+    );
+}
+
+#[test]
+fn test_choice_group_alternatives_are_extension_alternatives() {
+    serialize_and_deserialize_uper(24, &[0x80, 0x01, 0x80], &Decision::Flag(true));
+}
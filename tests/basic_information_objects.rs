@@ -0,0 +1,186 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicInformationObjects DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Criticality ::= ENUMERATED { reject, ignore, notify }
+
+    ProtocolIE-ID ::= INTEGER (0..65535)
+
+    PROTOCOL-IES ::= CLASS {
+        &id             ProtocolIE-ID UNIQUE,
+        &criticality    Criticality,
+        &Value
+    }
+    WITH SYNTAX {
+        ID              &id
+        CRITICALITY     &criticality
+        TYPE            &Value
+    }
+
+    id-Cause ProtocolIE-ID ::= 1
+    id-UE-ID ProtocolIE-ID ::= 8
+
+    Cause ::= ENUMERATED { unspecified, overload, ... }
+
+    UE-ID ::= INTEGER (0..4095)
+
+    ExampleIEs PROTOCOL-IES ::= {
+        { ID id-Cause   CRITICALITY ignore  TYPE Cause } |
+        { ID id-UE-ID   CRITICALITY reject  TYPE UE-ID } |
+        { ID 9          CRITICALITY notify  TYPE NULL },
+        ...
+    }
+
+    ProtocolIE-Field ::= SEQUENCE {
+        id              PROTOCOL-IES.&id            ({ExampleIEs}),
+        criticality     PROTOCOL-IES.&criticality   ({ExampleIEs}{@id}),
+        value           PROTOCOL-IES.&Value         ({ExampleIEs}{@id})
+    }
+
+    ProtocolIE-Container ::= SEQUENCE (SIZE (0..16)) OF ProtocolIE-Field
+
+    StrictIEs PROTOCOL-IES ::= {
+        { ID id-Cause   CRITICALITY ignore  TYPE Cause }
+    }
+
+    Strict-Field ::= SEQUENCE {
+        id              PROTOCOL-IES.&id            ({StrictIEs}),
+        value           PROTOCOL-IES.&Value         ({StrictIEs}{@id})
+    }
+
+    END"#
+);
+
+fn cause_field() -> ProtocolIeField {
+    ProtocolIeField {
+        id: *ID_CAUSE,
+        criticality: Criticality::Ignore,
+        value: ProtocolIeFieldValue::Cause(Cause::Overload),
+    }
+}
+
+#[test]
+fn test_value_is_selected_by_id() {
+    use asn1rs::syn::opentypechoice::Constraint;
+    assert_eq!(&[1, 8, 9], ProtocolIeFieldValue::KEYS);
+    assert_eq!(
+        i64::from(*ID_UE_ID),
+        ProtocolIeFieldValue::UeId(UeId(42)).key()
+    );
+}
+
+#[test]
+fn test_uper() {
+    serialize_and_deserialize_uper(34, &[0x00, 0x01, 0x40, 0x50, 0x00], &cause_field());
+    serialize_and_deserialize_uper(
+        42,
+        &[0x00, 0x08, 0x00, 0x80, 0xA8, 0x00],
+        &ProtocolIeField {
+            id: *ID_UE_ID,
+            criticality: Criticality::Reject,
+            value: ProtocolIeFieldValue::UeId(UeId(42)),
+        },
+    );
+    // the empty encoding of NULL is replaced by a single octet
+    serialize_and_deserialize_uper(
+        34,
+        &[0x00, 0x09, 0x80, 0x40, 0x00],
+        &ProtocolIeField {
+            id: 9,
+            criticality: Criticality::Notify,
+            value: ProtocolIeFieldValue::Value9(Null),
+        },
+    );
+}
+
+#[test]
+fn test_aper() {
+    serialize_and_deserialize_aper(40, &[0x00, 0x01, 0x40, 0x01, 0x40], &cause_field());
+}
+
+#[test]
+fn test_container() {
+    let container = ProtocolIeContainer(vec![
+        cause_field(),
+        ProtocolIeField {
+            id: *ID_UE_ID,
+            criticality: Criticality::Reject,
+            value: ProtocolIeFieldValue::UeId(UeId(4095)),
+        },
+    ]);
+    let (bits, data) = serialize_uper(&container);
+    assert_eq!(container, deserialize_uper(&data, bits));
+    let (bits, data) = serialize_aper(&container);
+    assert_eq!(container, deserialize_aper(&data, bits));
+}
+
+#[test]
+fn test_value_of_another_id_is_rejected() {
+    let field = ProtocolIeField {
+        id: *ID_UE_ID,
+        ..cause_field()
+    };
+    assert!(UperWriter::default().write(&field).is_err());
+    assert!(AperWriter::default().write(&field).is_err());
+    assert!(BerWriter::default().write(&field).is_err());
+}
+
+#[test]
+fn test_unknown_id_is_kept() {
+    let field = ProtocolIeField {
+        id: 99,
+        criticality: Criticality::Ignore,
+        value: ProtocolIeFieldValue::Unknown(99, OpenType::from(vec![0x40])),
+    };
+    // the same encoding as the Cause, but for an id unknown to the object set
+    serialize_and_deserialize_uper(34, &[0x00, 0x63, 0x40, 0x50, 0x00], &field);
+    serialize_and_deserialize_aper(40, &[0x00, 0x63, 0x40, 0x01, 0x40], &field);
+
+    let mismatch = ProtocolIeField {
+        id: 98,
+        ..field.clone()
+    };
+    assert!(UperWriter::default().write(&mismatch).is_err());
+}
+
+#[test]
+fn test_unknown_id_is_rejected() {
+    let field = StrictField {
+        id: 99,
+        value: StrictFieldValue::Cause(Cause::Overload),
+    };
+    assert!(UperWriter::default().write(&field).is_err());
+
+    let (bits, mut data) = serialize_uper(&StrictField {
+        id: *ID_CAUSE,
+        value: StrictFieldValue::Cause(Cause::Overload),
+    });
+    data[1] = 99;
+    assert!(UperReader::from((&data[..], bits))
+        .read::<StrictField>()
+        .is_err());
+}
+
+#[test]
+fn test_other_encoding_rules() {
+    let field = cause_field();
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0B, 0x80, 0x01, 0x01, 0x81, 0x01, 0x01, 0xA2, 0x03, 0x0A, 0x01, 0x01,
+        ],
+        &field,
+    );
+    serialize_and_deserialize_oer(&[0x00, 0x01, 0x01, 0x01, 0x01], &field);
+    serialize_and_deserialize_jer(
+        r#"{"id":1,"criticality":"ignore","value":"overload"}"#,
+        &field,
+    );
+    serialize_and_deserialize_xer(
+        "<ProtocolIeField><id>1</id><criticality><ignore/></criticality><value><Cause><overload/></Cause></value></ProtocolIeField>",
+        &field,
+    );
+}
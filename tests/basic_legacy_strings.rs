@@ -0,0 +1,123 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicLegacyStrings DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Bmp ::= BMPString
+
+    Universal ::= UniversalString
+
+    Teletex ::= TeletexString
+
+    Graphic ::= GraphicString
+
+    General ::= GeneralString
+
+    Subscriber ::= SEQUENCE {
+        name BMPString (SIZE(1..8)),
+        alias UniversalString OPTIONAL,
+        operator TeletexString,
+        note GraphicString,
+        remark GeneralString
+    }
+
+    END"#
+);
+
+fn subscriber() -> Subscriber {
+    Subscriber {
+        name: "Zoë €".to_string(),
+        alias: Some("🦀".to_string()),
+        operator: "Télé".to_string(),
+        note: "note".to_string(),
+        remark: "rem".to_string(),
+    }
+}
+
+#[test]
+fn test_uper_bmp_string_uses_16_bit_chars() {
+    serialize_and_deserialize_uper(40, &[0x02, 0x00, 0x41, 0x20, 0xAC], &Bmp("A€".to_string()));
+}
+
+#[test]
+fn test_uper_universal_string_uses_32_bit_chars() {
+    serialize_and_deserialize_uper(
+        40,
+        &[0x01, 0x00, 0x01, 0xF9, 0x80],
+        &Universal("🦀".to_string()),
+    );
+}
+
+#[test]
+fn test_uper_teletex_string_is_octet_string() {
+    serialize_and_deserialize_uper(24, &[0x02, 0x54, 0xE9], &Teletex("Té".to_string()));
+    serialize_and_deserialize_uper(16, &[0x01, 0x67], &Graphic("g".to_string()));
+    serialize_and_deserialize_uper(16, &[0x01, 0x67], &General("g".to_string()));
+}
+
+#[test]
+fn test_uper_sequence() {
+    let value = subscriber();
+    let (bits, data) = serialize_uper(&value);
+    assert_eq!(value, deserialize_uper(&data, bits));
+}
+
+#[test]
+fn test_aper_sequence() {
+    serialize_and_deserialize_aper(40, &[0x02, 0x00, 0x41, 0x20, 0xAC], &Bmp("A€".to_string()));
+    let value = subscriber();
+    let (bits, data) = serialize_aper(&value);
+    assert_eq!(value, deserialize_aper(&data, bits));
+}
+
+#[test]
+fn test_uper_rejects_chars_outside_of_charset() {
+    assert!(UperWriter::default().write(&Bmp("🦀".to_string())).is_err());
+    assert!(UperWriter::default()
+        .write(&Teletex("€".to_string()))
+        .is_err());
+}
+
+#[test]
+fn test_ber_and_oer() {
+    serialize_and_deserialize_ber(
+        &[0x1E, 0x04, 0x00, 0x41, 0x20, 0xAC],
+        &Bmp("A€".to_string()),
+    );
+    serialize_and_deserialize_ber(
+        &[0x1C, 0x04, 0x00, 0x01, 0xF9, 0x80],
+        &Universal("🦀".to_string()),
+    );
+    serialize_and_deserialize_ber(&[0x14, 0x02, 0x54, 0xE9], &Teletex("Té".to_string()));
+    serialize_and_deserialize_oer(&[0x04, 0x00, 0x41, 0x20, 0xAC], &Bmp("A€".to_string()));
+    serialize_and_deserialize_oer(&[0x02, 0x54, 0xE9], &Teletex("Té".to_string()));
+
+    let value = subscriber();
+    assert_eq!(value, deserialize_ber(&serialize_ber(&value)));
+    assert_eq!(value, deserialize_oer(&serialize_oer(&value)));
+}
+
+#[test]
+fn test_jer_and_xer() {
+    let value = subscriber();
+    serialize_and_deserialize_jer(
+        r#"{"name":"Zoë €","alias":"🦀","operator":"Télé","note":"note","remark":"rem"}"#,
+        &value,
+    );
+    serialize_and_deserialize_xer(
+        "<Subscriber><name>Zoë €</name><alias>🦀</alias><operator>Télé</operator><note>note</note><remark>rem</remark></Subscriber>",
+        &value,
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf_string() {
+    serialize_and_deserialize_protobuf(
+        &[0x0A, 0x04, b'A', 0xE2, 0x82, 0xAC],
+        &Bmp("A€".to_string()),
+    );
+}
@@ -0,0 +1,103 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicObjectIdentifier DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Registration ::= SEQUENCE {
+        id OBJECT IDENTIFIER,
+        suffix RELATIVE-OID
+    }
+
+    Oid ::= OBJECT IDENTIFIER
+
+    id-rsadsi OBJECT IDENTIFIER ::= { iso(1) member-body(2) us(840) 113549 }
+    id-pkcs-1 OBJECT IDENTIFIER ::= { id-rsadsi pkcs(1) 1 }
+    id-joint OBJECT IDENTIFIER ::= { joint-iso-itu-t 999 3 }
+    id-suffix RELATIVE-OID ::= { 8571 3 2 }
+
+    END"
+);
+
+#[test]
+fn test_value_references_are_constants() {
+    assert_eq!(&[1, 2, 840, 113549][..], ID_RSADSI.arcs());
+    assert_eq!("1.2.840.113549.1.1", ID_PKCS_1.to_string());
+    assert_eq!("2.999.3", ID_JOINT.to_string());
+    assert_eq!(Ok(ID_SUFFIX), "8571.3.2".parse::<RelativeOid>());
+}
+
+#[test]
+fn test_uper() {
+    serialize_and_deserialize_uper(
+        8 * 11,
+        &[
+            0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x03, 0xC2, 0x7B, 0x03,
+        ],
+        &Registration {
+            id: ID_RSADSI,
+            suffix: RelativeOid::from(vec![8571, 3]),
+        },
+    );
+    serialize_and_deserialize_uper(8 * 4, &[0x03, 0x88, 0x37, 0x03], &Oid(ID_JOINT));
+}
+
+#[test]
+fn test_uper_invalid_arcs_are_rejected() {
+    let mut writer = UperWriter::default();
+    assert!(writer
+        .write(&Oid(ObjectIdentifier::from(vec![3, 1])))
+        .is_err());
+    assert!(UperReader::from((&[0x01, 0x80][..], 8 * 2))
+        .read::<Oid>()
+        .is_err());
+}
+
+#[test]
+fn test_other_encoding_rules() {
+    let registration = Registration {
+        id: ID_RSADSI,
+        suffix: ID_SUFFIX,
+    };
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0E, 0x80, 0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x81, 0x04, 0xC2, 0x7B,
+            0x03, 0x02,
+        ],
+        &registration,
+    );
+    serialize_and_deserialize_ber(&[0x06, 0x03, 0x88, 0x37, 0x03], &Oid(ID_JOINT));
+    serialize_and_deserialize_oer(
+        &[
+            0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x04, 0xC2, 0x7B, 0x03, 0x02,
+        ],
+        &registration,
+    );
+    serialize_and_deserialize_jer(
+        r#"{"id":"1.2.840.113549","suffix":"8571.3.2"}"#,
+        &registration,
+    );
+    serialize_and_deserialize_xer(
+        "<Registration><id>1.2.840.113549</id><suffix>8571.3.2</suffix></Registration>",
+        &registration,
+    );
+    serialize_and_deserialize_xer("<Oid>2.999.3</Oid>", &Oid(ID_JOINT));
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf_dotted_string() {
+    let mut expected = vec![0x0A, 14];
+    expected.extend_from_slice(b"1.2.840.113549");
+    expected.extend_from_slice(&[0x12, 8]);
+    expected.extend_from_slice(b"8571.3.2");
+    serialize_and_deserialize_protobuf(
+        &expected,
+        &Registration {
+            id: ID_RSADSI,
+            suffix: ID_SUFFIX,
+        },
+    );
+}
@@ -0,0 +1,134 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicOpenType DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Inner ::= SEQUENCE { a INTEGER (0..255), b BOOLEAN }
+
+    Flagged ::= SEQUENCE { b BOOLEAN, a INTEGER (0..255) }
+
+    Container ::= SEQUENCE {
+        id          INTEGER (0..255),
+        content     ANY DEFINED BY id
+    }
+
+    Typed ::= SEQUENCE {
+        type-id     TYPE-IDENTIFIER.&id,
+        value       TYPE-IDENTIFIER.&Type
+    }
+
+    Holder ::= ANY
+
+    END"#
+);
+
+const INNER: Inner = Inner { a: 5, b: true };
+
+fn container() -> Container {
+    Container {
+        id: 1,
+        content: OpenType::encode_from(&INNER).unwrap(),
+    }
+}
+
+#[test]
+fn test_encode_from_and_decode_as() {
+    let container = container();
+    assert_eq!(&[0x05, 0x80], container.content.as_bytes());
+    serialize_and_deserialize_uper(32, &[0x01, 0x02, 0x05, 0x80], &container);
+
+    let decoded = deserialize_uper::<Container>(&[0x01, 0x02, 0x05, 0x80], 32);
+    assert_eq!(INNER, decoded.content.decode_as::<Inner>().unwrap());
+}
+
+#[test]
+fn test_encode_from_and_decode_as_aper() {
+    let flagged = Flagged { b: true, a: 5 };
+    assert_eq!(
+        &[0x82, 0x80],
+        OpenType::encode_from(&flagged).unwrap().as_bytes()
+    );
+
+    // the integer is octet-aligned within the encoding of the value
+    let container = Container {
+        id: 1,
+        content: OpenType::encode_from_aper(&flagged).unwrap(),
+    };
+    assert_eq!(&[0x80, 0x05], container.content.as_bytes());
+    serialize_and_deserialize_aper(32, &[0x01, 0x02, 0x80, 0x05], &container);
+
+    let decoded = deserialize_aper::<Container>(&[0x01, 0x02, 0x80, 0x05], 32);
+    assert_eq!(
+        flagged,
+        decoded.content.decode_as_aper::<Flagged>().unwrap()
+    );
+}
+
+#[test]
+fn test_unknown_content_is_forwarded_unchanged() {
+    // the content is not a valid Inner, but is not decoded on the way through
+    let data = [0x07, 0x03, 0xDE, 0xAD, 0xBE];
+    let forwarded = deserialize_uper::<Container>(&data, 40);
+    assert_eq!(&[0xDE, 0xAD, 0xBE], forwarded.content.as_bytes());
+    assert_eq!((40, data.to_vec()), serialize_uper(&forwarded));
+}
+
+#[test]
+fn test_type_identifier() {
+    let typed = Typed {
+        type_id: "1.2.3".parse().unwrap(),
+        value: OpenType::encode_from(&INNER).unwrap(),
+    };
+    serialize_and_deserialize_uper(48, &[0x02, 0x2A, 0x03, 0x02, 0x05, 0x80], &typed);
+    serialize_and_deserialize_uper(24, &[0x02, 0x05, 0x80], &Holder(typed.value));
+}
+
+#[test]
+fn test_other_encoding_rules() {
+    let ber = Container {
+        id: 1,
+        content: OpenType::encode_from_ber(&INNER).unwrap(),
+    };
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0D, 0x80, 0x01, 0x01, 0xA1, 0x08, 0x30, 0x06, 0x80, 0x01, 0x05, 0x81, 0x01,
+            0xFF,
+        ],
+        &ber,
+    );
+    assert_eq!(INNER, ber.content.decode_as_ber::<Inner>().unwrap());
+
+    let oer = Container {
+        id: 1,
+        content: OpenType::encode_from_oer(&INNER).unwrap(),
+    };
+    serialize_and_deserialize_oer(&[0x01, 0x02, 0x05, 0xFF], &oer);
+    assert_eq!(INNER, oer.content.decode_as_oer::<Inner>().unwrap());
+
+    let jer = Container {
+        id: 1,
+        content: OpenType::from(serialize_jer(&INNER).into_bytes()),
+    };
+    serialize_and_deserialize_jer(r#"{"id":1,"content":{"a":5,"b":true}}"#, &jer);
+
+    let xer = Container {
+        id: 1,
+        content: OpenType::from(serialize_xer(&INNER).into_bytes()),
+    };
+    serialize_and_deserialize_xer(
+        "<Container><id>1</id><content><Inner><a>5</a><b><true/></b></Inner></content></Container>",
+        &xer,
+    );
+}
+
+#[test]
+fn test_invalid_json_is_rejected() {
+    let container = Container {
+        id: 1,
+        content: OpenType::from(b"{\"a\":".to_vec()),
+    };
+    assert!(JerWriter::default().write(&container).is_err());
+}
@@ -0,0 +1,88 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicParameterized DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Container {Type} ::= SEQUENCE { value Type }
+
+    Bounded {INTEGER : upper, Element} ::= SEQUENCE (SIZE (1..upper)) OF Element
+
+    Pair {First, Second} ::= SEQUENCE { first First, second Second OPTIONAL }
+
+    IntContainer ::= Container {INTEGER (0..255)}
+
+    Names ::= Bounded {3, UTF8String}
+
+    Holder ::= SEQUENCE {
+        pair    Pair {BOOLEAN, IntContainer},
+        names   Bounded {2, IA5String}
+    }
+
+    IES ::= CLASS { &id INTEGER (0..255) UNIQUE, &Value } WITH SYNTAX { ID &id TYPE &Value }
+
+    Field {IES : Set} ::= SEQUENCE { id IES.&id ({Set}), value IES.&Value ({Set}{@id}) }
+
+    Fields {IES : Set} ::= SEQUENCE (SIZE (0..4)) OF Field {{Set}}
+
+    RequestIEs IES ::= { { ID 1 TYPE BOOLEAN } | { ID 2 TYPE IntContainer } }
+
+    Request ::= SEQUENCE { protocolIEs Fields { {RequestIEs} } }
+
+    END"#
+);
+
+#[test]
+fn test_instances_are_monomorphized() {
+    assert_eq!(255, IntContainer::value_max());
+    serialize_and_deserialize_uper(8, &[0x05], &IntContainer { value: 5 });
+    serialize_and_deserialize_uper(
+        26,
+        &[0x40, 0x58, 0x40, 0x00],
+        &Names(vec!["a".to_string(), String::default()]),
+    );
+}
+
+#[test]
+fn test_size_parameter_is_checked() {
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&Names(vec![String::default(); 4])).is_err());
+}
+
+#[test]
+fn test_nested_instances() {
+    let holder = Holder {
+        pair: HolderPair {
+            first: true,
+            second: Some(IntContainer { value: 5 }),
+        },
+        names: vec!["ab".to_string()],
+    };
+    serialize_and_deserialize_uper(33, &[0xC1, 0x40, 0x58, 0x71, 0x00], &holder);
+    serialize_and_deserialize_jer(
+        r#"{"pair":{"first":true,"second":{"value":5}},"names":["ab"]}"#,
+        &holder,
+    );
+}
+
+#[test]
+fn test_object_set_parameter() {
+    let request = Request {
+        protocol_i_es: vec![
+            RequestProtocolIEs {
+                id: 2,
+                value: RequestProtocolIEsValue::IntContainer(IntContainer { value: 7 }),
+            },
+            RequestProtocolIEs {
+                id: 1,
+                value: RequestProtocolIEsValue::Value1(true),
+            },
+        ],
+    };
+    let (bits, data) = serialize_uper(&request);
+    assert_eq!(request, deserialize_uper(&data, bits));
+    let (bits, data) = serialize_aper(&request);
+    assert_eq!(request, deserialize_aper(&data, bits));
+}
@@ -0,0 +1,77 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicPermittedAlphabet DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Code ::= IA5String (SIZE(3)) (FROM ("A".."Z" | "0".."9"))
+
+    Dna ::= IA5String (FROM ("ACGT"))
+
+    Zeros ::= NumericString (SIZE(1..4) ^ FROM ("0"))
+
+    Octal ::= NumericString (SIZE(1)) (FROM ("0".."4"))
+
+    Greek ::= BMPString (FROM ("α".."ω"))
+
+    Station ::= SEQUENCE {
+        code Code,
+        name PrintableString (SIZE(1..16)) (FROM ("A".."Z" | " ")),
+        dna Dna OPTIONAL
+    }
+
+    END"#
+);
+
+#[test]
+fn test_uper_indexed_characters() {
+    // 36 characters take 6 bits, 'A' is at index 10 after the digits
+    serialize_and_deserialize_uper(18, &[0x28, 0x18, 0xC0], &Code("A1Z".to_string()));
+    serialize_and_deserialize_uper(22, &[0x07, 0x8F, 0x10], &Dna("GATTACA".to_string()));
+}
+
+#[test]
+fn test_uper_single_character_takes_no_bits() {
+    serialize_and_deserialize_uper(2, &[0x80], &Zeros("000".to_string()));
+}
+
+#[test]
+fn test_uper_bmp_string() {
+    serialize_and_deserialize_uper(18, &[0x02, 0xC0, 0x00], &Greek("ωα".to_string()));
+}
+
+#[test]
+fn test_aper_rounds_up_to_power_of_two() {
+    // 8 bits are enough to encode the characters by their value
+    serialize_and_deserialize_aper(24, &[0x41, 0x31, 0x5A], &Code("A1Z".to_string()));
+    serialize_and_deserialize_aper(22, &[0x07, 0x8F, 0x10], &Dna("GATTACA".to_string()));
+}
+
+#[test]
+fn test_sequence() {
+    let station = Station {
+        code: Code("XY9".to_string()),
+        name: "NORTH POLE".to_string(),
+        dna: Some(Dna("CAT".to_string())),
+    };
+    let (bits, data) = serialize_uper(&station);
+    assert_eq!(station, deserialize_uper(&data, bits));
+    let (bits, data) = serialize_aper(&station);
+    assert_eq!(station, deserialize_aper(&data, bits));
+    assert_eq!(station, deserialize_ber(&serialize_ber(&station)));
+}
+
+#[test]
+fn test_characters_outside_of_alphabet_are_rejected() {
+    assert!(UperWriter::default()
+        .write(&Dna("GATX".to_string()))
+        .is_err());
+    assert!(AperWriter::default()
+        .write(&Code("a1z".to_string()))
+        .is_err());
+    assert!(UperReader::from((&[0xE0_u8][..], 3))
+        .read::<Octal>()
+        .is_err());
+}
@@ -0,0 +1,95 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BasicReal DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Measurement ::= SEQUENCE {
+        value REAL,
+        valid BOOLEAN
+    }
+
+    Binary64 ::= REAL (WITH COMPONENTS {
+        mantissa (-9007199254740991..9007199254740991),
+        base (2),
+        exponent (-1022..1023)
+    })
+
+    END"
+);
+
+#[test]
+fn test_uper_binary_encoding() {
+    serialize_and_deserialize_uper(
+        8 * 4 + 1,
+        &[0x03, 0x80, 0x00, 0x01, 0x80],
+        &Measurement {
+            value: 1.0,
+            valid: true,
+        },
+    );
+    serialize_and_deserialize_uper(8 * 4, &[0x03, 0xC0, 0xFF, 0x05], &Binary64(-2.5));
+    serialize_and_deserialize_uper(
+        8 * 5,
+        &[0x04, 0x81, 0x00, 0xC8, 0x01],
+        &Binary64(2.0_f64.powi(200)),
+    );
+}
+
+#[test]
+fn test_uper_special_values() {
+    serialize_and_deserialize_uper(8, &[0x00], &Binary64(0.0));
+    serialize_and_deserialize_uper(8 * 2, &[0x01, 0x43], &Binary64(-0.0));
+    serialize_and_deserialize_uper(8 * 2, &[0x01, 0x40], &Binary64(f64::INFINITY));
+    serialize_and_deserialize_uper(8 * 2, &[0x01, 0x41], &Binary64(f64::NEG_INFINITY));
+
+    let (bits, data) = serialize_uper(&Binary64(f64::NAN));
+    assert_eq!((8 * 2, &[0x01, 0x42][..]), (bits, &data[..]));
+    assert!(deserialize_uper::<Binary64>(&data, bits).0.is_nan());
+}
+
+#[test]
+fn test_uper_decimal_encoding_is_read() {
+    // ITU-T X.690 | ISO/IEC 8825-1, 8.5.8, NR3 form of "-12.5E1"
+    assert_eq!(
+        Binary64(-125.0),
+        deserialize_uper(
+            &[0x08, 0x03, b'-', b'1', b'2', b'.', b'5', b'E', b'1'],
+            8 * 9
+        )
+    );
+}
+
+#[test]
+fn test_other_encoding_rules() {
+    let measurement = Measurement {
+        value: 1.0,
+        valid: true,
+    };
+    serialize_and_deserialize_ber(
+        &[0x30, 0x08, 0x80, 0x03, 0x80, 0x00, 0x01, 0x81, 0x01, 0xFF],
+        &measurement,
+    );
+    serialize_and_deserialize_oer(&[0x03, 0x80, 0x00, 0x01, 0xFF], &measurement);
+    serialize_and_deserialize_jer(r#"{"value":1.0,"valid":true}"#, &measurement);
+    serialize_and_deserialize_jer(r#""-INF""#, &Binary64(f64::NEG_INFINITY));
+    serialize_and_deserialize_xer(
+        "<Binary64><PLUS-INFINITY/></Binary64>",
+        &Binary64(f64::INFINITY),
+    );
+    serialize_and_deserialize_xer("<Binary64>-2.5</Binary64>", &Binary64(-2.5));
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf_double() {
+    serialize_and_deserialize_protobuf(
+        &[0x09, 0, 0, 0, 0, 0, 0, 0xF0, 0x3F, 0x10, 0x01],
+        &Measurement {
+            value: 1.0,
+            valid: true,
+        },
+    );
+}
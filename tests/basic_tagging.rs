@@ -0,0 +1,260 @@
+mod test_utils;
+
+use test_utils::*;
+
+mod explicit {
+    use super::*;
+
+    asn_to_rust!(
+        r"BasicTaggingExplicit DEFINITIONS EXPLICIT TAGS ::=
+        BEGIN
+
+        Inner ::= SEQUENCE {
+            number [0] INTEGER,
+            flag [1] IMPLICIT BOOLEAN
+        }
+
+        Wrapped ::= [APPLICATION 3] INTEGER
+
+        Alias ::= [APPLICATION 4] Inner
+
+        Referenced ::= SEQUENCE {
+            wrapped [2] Wrapped,
+            inner [3] Inner OPTIONAL
+        }
+
+        Untagged ::= SEQUENCE {
+            number INTEGER,
+            flag BOOLEAN
+        }
+
+        Decision ::= CHOICE {
+            number [0] INTEGER,
+            flag [1] BOOLEAN
+        }
+
+        END"
+    );
+}
+
+mod implicit {
+    use super::*;
+
+    asn_to_rust!(
+        r"BasicTaggingImplicit DEFINITIONS IMPLICIT TAGS ::=
+        BEGIN
+
+        Inner ::= SEQUENCE {
+            number [0] INTEGER,
+            flag [1] EXPLICIT BOOLEAN
+        }
+
+        Wrapped ::= [APPLICATION 3] INTEGER
+
+        Decision ::= CHOICE {
+            number [0] INTEGER,
+            flag [1] BOOLEAN
+        }
+
+        Holder ::= SEQUENCE {
+            decision [5] Decision
+        }
+
+        END"
+    );
+}
+
+mod automatic {
+    use super::*;
+
+    asn_to_rust!(
+        r"BasicTaggingAutomatic DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        Inner ::= SEQUENCE {
+            number INTEGER,
+            flag [5] EXPLICIT BOOLEAN
+        }
+
+        Decision ::= CHOICE {
+            number INTEGER,
+            flag BOOLEAN
+        }
+
+        Unordered ::= SET {
+            flag [APPLICATION 1] BOOLEAN,
+            decision Decision
+        }
+
+        END"
+    );
+}
+
+#[test]
+fn test_explicit_module_wraps_tagged_fields() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x08, // SEQUENCE
+            0xA0, 0x03, 0x02, 0x01, 0x05, // [0] { INTEGER }
+            0x81, 0x01, 0xFF, // [1] IMPLICIT BOOLEAN
+        ],
+        &explicit::Inner {
+            number: 5,
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_explicit_module_wraps_tagged_definition() {
+    serialize_and_deserialize_der(&[0x63, 0x03, 0x02, 0x01, 0x07], &explicit::Wrapped(7));
+}
+
+#[test]
+fn test_explicit_module_wraps_tagged_alias() {
+    serialize_and_deserialize_ber(
+        &[
+            0x64, 0x0A, 0x30, 0x08, // [APPLICATION 4] { SEQUENCE }
+            0xA0, 0x03, 0x02, 0x01, 0x05, 0x81, 0x01, 0xFF,
+        ],
+        &explicit::Alias(explicit::Inner {
+            number: 5,
+            flag: true,
+        }),
+    );
+}
+
+#[test]
+fn test_explicit_module_wraps_referenced_types() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x07, // SEQUENCE
+            0xA2, 0x05, 0x63, 0x03, 0x02, 0x01, 0x07, // [2] { [APPLICATION 3] { INTEGER } }
+        ],
+        &explicit::Referenced {
+            wrapped: explicit::Wrapped(7),
+            inner: None,
+        },
+    );
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x13, // SEQUENCE
+            0xA2, 0x05, 0x63, 0x03, 0x02, 0x01, 0x07, // [2] { [APPLICATION 3] { INTEGER } }
+            0xA3, 0x0A, 0x30, 0x08, // [3] { SEQUENCE }
+            0xA0, 0x03, 0x02, 0x01, 0x05, 0x81, 0x01, 0x00,
+        ],
+        &explicit::Referenced {
+            wrapped: explicit::Wrapped(7),
+            inner: Some(explicit::Inner {
+                number: 5,
+                flag: false,
+            }),
+        },
+    );
+}
+
+#[test]
+fn test_explicit_module_does_not_tag_automatically() {
+    serialize_and_deserialize_ber(
+        &[0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF],
+        &explicit::Untagged {
+            number: 5,
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_explicit_module_choice_alternatives() {
+    serialize_and_deserialize_ber(
+        &[0xA0, 0x03, 0x02, 0x01, 0x05],
+        &explicit::Decision::Number(5),
+    );
+    serialize_and_deserialize_ber(
+        &[0xA1, 0x03, 0x01, 0x01, 0xFF],
+        &explicit::Decision::Flag(true),
+    );
+}
+
+#[test]
+fn test_explicit_tags_are_visible_in_oer_choice_only() {
+    serialize_and_deserialize_oer(&[0x80, 0x01, 0x05], &explicit::Decision::Number(5));
+    serialize_and_deserialize_oer(&[0x01, 0x07], &explicit::Wrapped(7));
+}
+
+#[test]
+fn test_explicit_tags_are_invisible_in_uper() {
+    serialize_and_deserialize_uper(
+        17,
+        &[0x01, 0x05, 0x80],
+        &explicit::Inner {
+            number: 5,
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_implicit_module_replaces_tags() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x08, // SEQUENCE
+            0x80, 0x01, 0x05, // [0] IMPLICIT INTEGER
+            0xA1, 0x03, 0x01, 0x01, 0xFF, // [1] EXPLICIT { BOOLEAN }
+        ],
+        &implicit::Inner {
+            number: 5,
+            flag: true,
+        },
+    );
+    serialize_and_deserialize_der(&[0x43, 0x01, 0x07], &implicit::Wrapped(7));
+}
+
+#[test]
+fn test_implicit_module_tags_choice_explicitly() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x05, // SEQUENCE
+            0xA5, 0x03, 0x80, 0x01, 0x05, // [5] { [0] IMPLICIT INTEGER }
+        ],
+        &implicit::Holder {
+            decision: implicit::Decision::Number(5),
+        },
+    );
+}
+
+#[test]
+fn test_automatic_module_with_explicit_field() {
+    // automatic tagging is not applied, because one of the components is tagged already
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x08, // SEQUENCE
+            0x02, 0x01, 0x05, // INTEGER
+            0xA5, 0x03, 0x01, 0x01, 0xFF, // [5] { BOOLEAN }
+        ],
+        &automatic::Inner {
+            number: 5,
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_automatic_module_choice_alternatives() {
+    serialize_and_deserialize_ber(&[0x80, 0x01, 0x05], &automatic::Decision::Number(5));
+    serialize_and_deserialize_ber(&[0x81, 0x01, 0xFF], &automatic::Decision::Flag(true));
+}
+
+#[test]
+fn test_automatic_module_set_sorted_by_tag_of_choice_alternatives() {
+    // the untagged CHOICE has the tag [0] of its first automatically tagged alternative, which
+    // is sorted after [APPLICATION 1]
+    serialize_and_deserialize_uper(
+        18,
+        &[0x80, 0x41, 0x40],
+        &automatic::Unordered {
+            flag: true,
+            decision: automatic::Decision::Number(5),
+        },
+    );
+}
@@ -0,0 +1,157 @@
+mod test_utils;
+
+use asn1rs::syn::time::{CalendarDate, CalendarDateTime, ClockTime};
+use std::convert::TryFrom;
+use test_utils::*;
+
+asn_to_rust!(
+    r#"BasicTime DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    LogEntry ::= SEQUENCE {
+        timestamp GeneralizedTime,
+        expires UTCTime OPTIONAL,
+        day DATE,
+        at TIME-OF-DAY,
+        local DATE-TIME,
+        retention DURATION DEFAULT "P30D"
+    }
+
+    Stamp ::= GeneralizedTime
+
+    epoch GeneralizedTime ::= "19700101000000Z"
+
+    END"#
+);
+
+fn log_entry() -> LogEntry {
+    LogEntry {
+        timestamp: GeneralizedTime::from_static("20230714090503.25Z"),
+        expires: Some(UtcTime::from_static("491231235959Z")),
+        day: Date::from_static("2023-07-14"),
+        at: TimeOfDay::from_static("09:05:03"),
+        local: DateTime::from_static("2023-07-14T11:05:03"),
+        retention: Duration::from_static("P1DT12H"),
+    }
+}
+
+#[test]
+fn test_value_references_are_constants() {
+    assert_eq!("19700101000000Z", EPOCH.as_str());
+    assert_eq!(Ok(EPOCH), "19700101000000Z".parse::<GeneralizedTime>());
+    assert_eq!(
+        CalendarDateTime {
+            date: CalendarDate {
+                year: 1970,
+                month: 1,
+                day: 1,
+            },
+            time: ClockTime::default(),
+            utc_offset: Some(0),
+        },
+        CalendarDateTime::try_from(&EPOCH).unwrap()
+    );
+}
+
+#[test]
+fn test_uper_visible_string() {
+    serialize_and_deserialize_uper(
+        113,
+        &[
+            0x0F, 0x64, 0xC1, 0x93, 0x36, 0x0D, 0xD8, 0xB4, 0x60, 0xE5, 0x83, 0x56, 0x0C, 0xED,
+            0x00,
+        ],
+        &Stamp(GeneralizedTime::from_static("20230714090503Z")),
+    );
+
+    let entry = log_entry();
+    let (bits, data) = serialize_uper(&entry);
+    assert_eq!(entry, deserialize_uper(&data, bits));
+}
+
+#[test]
+fn test_uper_invalid_format_is_rejected() {
+    let mut writer = UperWriter::default();
+    writer
+        .write(&Stamp(GeneralizedTime::from_static("2023-07-14")))
+        .unwrap();
+    let bits = writer.bit_len();
+    let bytes = writer.into_bytes_vec();
+    assert!(UperReader::from((&bytes[..], bits))
+        .read::<Stamp>()
+        .is_err());
+}
+
+#[test]
+fn test_default_duration() {
+    let entry = LogEntry {
+        retention: Duration::from_static("P30D"),
+        expires: None,
+        ..log_entry()
+    };
+    let (bits, data) = serialize_uper(&entry);
+    assert_eq!(entry, deserialize_uper(&data, bits));
+    assert_eq!(
+        Ok(std::time::Duration::from_secs(30 * 24 * 60 * 60)),
+        std::time::Duration::try_from(&entry.retention)
+    );
+}
+
+#[test]
+fn test_other_encoding_rules() {
+    let stamp = Stamp(GeneralizedTime::from_static("20230714090503Z"));
+    let mut ber = vec![0x18, 15];
+    ber.extend_from_slice(b"20230714090503Z");
+    serialize_and_deserialize_ber(&ber, &stamp);
+    let mut oer = vec![15];
+    oer.extend_from_slice(b"20230714090503Z");
+    serialize_and_deserialize_oer(&oer, &stamp);
+
+    let entry = log_entry();
+    serialize_and_deserialize_jer(
+        r#"{"timestamp":"20230714090503.25Z","expires":"491231235959Z","day":"2023-07-14","at":"09:05:03","local":"2023-07-14T11:05:03","retention":"P1DT12H"}"#,
+        &entry,
+    );
+    serialize_and_deserialize_xer(
+        "<LogEntry><timestamp>20230714090503.25Z</timestamp><expires>491231235959Z</expires><day>2023-07-14</day><at>09:05:03</at><local>2023-07-14T11:05:03</local><retention>P1DT12H</retention></LogEntry>",
+        &entry,
+    );
+    serialize_and_deserialize_xer("<Stamp>20230714090503Z</Stamp>", &stamp);
+}
+
+#[test]
+fn test_calendar_conversion() {
+    let entry = log_entry();
+    let timestamp = CalendarDateTime::try_from(&entry.timestamp).unwrap();
+    assert_eq!(250_000_000, timestamp.time.nanosecond);
+    assert_eq!(
+        Ok(entry.timestamp.clone()),
+        GeneralizedTime::try_from(timestamp)
+    );
+
+    let expires = CalendarDateTime::try_from(entry.expires.as_ref().unwrap()).unwrap();
+    assert_eq!(2049, expires.date.year);
+
+    let local = CalendarDateTime::try_from(&entry.local).unwrap();
+    assert_eq!(None, local.utc_offset);
+    assert_eq!(CalendarDate::try_from(&entry.day).unwrap(), local.date);
+    assert_eq!(Ok(entry.local), DateTime::try_from(local));
+    assert_eq!(
+        Ok(entry.at),
+        TimeOfDay::try_from(ClockTime {
+            hour: 9,
+            ..local.time
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_protobuf_string() {
+    let mut expected = vec![0x0A, 15];
+    expected.extend_from_slice(b"20230714090503Z");
+    serialize_and_deserialize_protobuf(
+        &expected,
+        &Stamp(GeneralizedTime::from_static("20230714090503Z")),
+    );
+}
@@ -0,0 +1,258 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"BerBasic DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Simple ::= SEQUENCE {
+        small INTEGER (0..7),
+        flag BOOLEAN,
+        text IA5String,
+        data OCTET STRING,
+        opt INTEGER OPTIONAL
+    }
+
+    Decision ::= CHOICE {
+        abc BOOLEAN,
+        def INTEGER (0..1000),
+        ...,
+        ghi UTF8String
+    }
+
+    Wrapper ::= SEQUENCE {
+        decision Decision,
+        numbers SEQUENCE OF INTEGER
+    }
+
+    Holder ::= SEQUENCE {
+        decision Decision OPTIONAL,
+        flag BOOLEAN
+    }
+
+    Unordered ::= SET {
+        first INTEGER,
+        second BOOLEAN
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Tagged ::= [APPLICATION 3] INTEGER
+
+    Reference ::= SEQUENCE {
+        value Tagged
+    }
+
+    Flags ::= BIT STRING
+
+    WithDefault ::= SEQUENCE {
+        value INTEGER DEFAULT 5
+    }
+
+    Level ::= ENUMERATED { low(5), high(10), huge(200) }
+
+    END"
+);
+
+#[test]
+fn test_simple_sequence() {
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x12, // SEQUENCE
+            0x80, 0x01, 0x05, // small
+            0x81, 0x01, 0xFF, // flag
+            0x82, 0x02, b'A', b'B', // text
+            0x83, 0x02, 0xDE, 0xAD, // data
+            0x84, 0x02, 0x00, 0xFF, // opt
+        ],
+        &Simple {
+            small: 5,
+            flag: true,
+            text: "AB".to_string(),
+            data: vec![0xDE, 0xAD],
+            opt: Some(255),
+        },
+    );
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0C, 0x80, 0x01, 0x05, 0x81, 0x01, 0x00, 0x82, 0x00, 0x83, 0x02, 0xDE, 0xAD,
+        ],
+        &Simple {
+            small: 5,
+            flag: false,
+            text: String::default(),
+            data: vec![0xDE, 0xAD],
+            opt: None,
+        },
+    );
+}
+
+#[test]
+fn test_choice_is_tagged_explicitly() {
+    serialize_and_deserialize_ber(&[0x81, 0x02, 0x01, 0xF4], &Decision::Def(500));
+    serialize_and_deserialize_ber(&[0x82, 0x02, b'h', b'i'], &Decision::Ghi("hi".to_string()));
+    serialize_and_deserialize_ber(
+        &[
+            0x30, 0x0E, // SEQUENCE
+            0xA0, 0x04, 0x81, 0x02, 0x01, 0xF4, // decision
+            0xA1, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, // numbers
+        ],
+        &Wrapper {
+            decision: Decision::Def(500),
+            numbers: vec![1, 2],
+        },
+    );
+}
+
+#[test]
+fn test_optional_choice() {
+    serialize_and_deserialize_ber(
+        &[0x30, 0x03, 0x81, 0x01, 0xFF],
+        &Holder {
+            decision: None,
+            flag: true,
+        },
+    );
+    serialize_and_deserialize_ber(
+        &[0x30, 0x08, 0xA0, 0x03, 0x80, 0x01, 0x00, 0x81, 0x01, 0xFF],
+        &Holder {
+            decision: Some(Decision::Abc(false)),
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_indefinite_length() {
+    let value = Wrapper {
+        decision: Decision::Def(500),
+        numbers: vec![1, 2],
+    };
+    let bytes = [
+        0x30, 0x80, // SEQUENCE
+        0xA0, 0x80, 0x81, 0x02, 0x01, 0xF4, 0x00, 0x00, // decision
+        0xA1, 0x80, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x00, 0x00, // numbers
+        0x00, 0x00,
+    ];
+
+    let mut writer = BerWriter::default().with_indefinite_length();
+    writer.write(&value).unwrap();
+    assert_eq!(&bytes[..], writer.byte_content());
+    assert_eq!(value, deserialize_ber::<Wrapper>(&bytes));
+}
+
+#[test]
+fn test_constructed_strings() {
+    let value = Simple {
+        small: 1,
+        flag: true,
+        text: "ABC".to_string(),
+        data: vec![0x01, 0x02, 0x03],
+        opt: None,
+    };
+    let bytes = [
+        0x30, 0x18, // SEQUENCE
+        0x80, 0x01, 0x01, // small
+        0x81, 0x01, 0xFF, // flag
+        0xA2, 0x07, 0x04, 0x02, b'A', b'B', 0x04, 0x01, b'C', // text
+        0xA3, 0x07, 0x04, 0x02, 0x01, 0x02, 0x04, 0x01, 0x03, // data
+    ];
+
+    let mut writer = BerWriter::default().with_constructed_strings(2);
+    writer.write(&value).unwrap();
+    assert_eq!(&bytes[..], writer.byte_content());
+    assert_eq!(value, deserialize_ber::<Simple>(&bytes));
+
+    // nested constructed segment in the indefinite length form
+    assert_eq!(
+        value,
+        deserialize_ber::<Simple>(&[
+            0x30, 0x80, // SEQUENCE
+            0x80, 0x01, 0x01, // small
+            0x81, 0x01, 0xFF, // flag
+            0x82, 0x03, b'A', b'B', b'C', // text
+            0xA3, 0x80, 0x04, 0x01, 0x01, 0x24, 0x80, 0x04, 0x02, 0x02, 0x03, 0x00, 0x00, 0x00,
+            0x00, // data
+            0x00, 0x00,
+        ])
+    );
+}
+
+#[test]
+fn test_set_components_in_any_order() {
+    let value = Unordered {
+        first: 1,
+        second: true,
+    };
+    serialize_and_deserialize_ber(&[0x31, 0x06, 0x80, 0x01, 0x01, 0x81, 0x01, 0xFF], &value);
+    assert_eq!(
+        value,
+        deserialize_ber::<Unordered>(&[0x31, 0x06, 0x81, 0x01, 0xFF, 0x80, 0x01, 0x01])
+    );
+}
+
+#[test]
+fn test_unknown_extension_is_skipped() {
+    serialize_and_deserialize_ber(
+        &[0x30, 0x06, 0x80, 0x01, 0x01, 0x81, 0x01, 0xFF],
+        &Extensible {
+            abc: 1,
+            def: Some(true),
+        },
+    );
+    assert_eq!(
+        Extensible { abc: 1, def: None },
+        deserialize_ber::<Extensible>(&[0x30, 0x07, 0x80, 0x01, 0x01, 0x82, 0x02, 0xCA, 0xFE])
+    );
+}
+
+#[test]
+fn test_unknown_component_in_root_is_rejected() {
+    let mut reader = BerReader::from(&[0x30, 0x06, 0x80, 0x01, 0x01, 0x85, 0x01, 0x00][..]);
+    assert_eq!(
+        &asn1rs::io::ber::ErrorKind::UnexpectedComponents("WithDefault"),
+        reader.read::<WithDefault>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_tagged_type_reference() {
+    serialize_and_deserialize_ber(&[0x43, 0x01, 0x05], &Tagged(5));
+    serialize_and_deserialize_ber(
+        &[0x30, 0x03, 0x80, 0x01, 0x05],
+        &Reference { value: Tagged(5) },
+    );
+}
+
+#[test]
+fn test_bit_string() {
+    serialize_and_deserialize_ber(
+        &[0x03, 0x02, 0x05, 0xA0],
+        &Flags(BitVec::from_bytes(vec![0b1010_0000], 3)),
+    );
+    serialize_and_deserialize_ber(&[0x03, 0x01, 0x00], &Flags(BitVec::default()));
+}
+
+#[test]
+fn test_default_value_is_omitted() {
+    serialize_and_deserialize_ber(&[0x30, 0x00], &WithDefault { value: 5 });
+    serialize_and_deserialize_ber(&[0x30, 0x03, 0x80, 0x01, 0x06], &WithDefault { value: 6 });
+}
+
+#[test]
+fn test_enumerated_is_encoded_by_its_value() {
+    serialize_and_deserialize_ber(&[0x0A, 0x01, 0x05], &Level::Low);
+    serialize_and_deserialize_ber(&[0x0A, 0x01, 0x0A], &Level::High);
+    serialize_and_deserialize_ber(&[0x0A, 0x02, 0x00, 0xC8], &Level::Huge);
+
+    let mut reader = BerReader::from(&[0x0A, 0x01, 0x01][..]);
+    assert_eq!(
+        &asn1rs::io::ber::ErrorKind::InvalidEnumeratedValue(1),
+        reader.read::<Level>().unwrap_err().kind()
+    );
+}
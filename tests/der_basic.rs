@@ -0,0 +1,132 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"DerBasic DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Unordered ::= SET {
+        first [2] INTEGER,
+        second [0] BOOLEAN,
+        ...,
+        third [1] INTEGER OPTIONAL
+    }
+
+    Decision ::= CHOICE {
+        abc [3] BOOLEAN,
+        def [5] INTEGER
+    }
+
+    WithChoice ::= SET {
+        value [4] INTEGER,
+        decision Decision
+    }
+
+    Names ::= SET OF OCTET STRING
+
+    Numbers ::= SET OF INTEGER
+
+    WithDefault ::= SET {
+        value INTEGER DEFAULT 5,
+        flag BOOLEAN DEFAULT FALSE
+    }
+
+    Flags ::= BIT STRING
+
+    END"
+);
+
+#[test]
+fn test_set_components_sorted_by_tag() {
+    let value = Unordered {
+        first: 1,
+        second: true,
+        third: Some(3),
+    };
+    serialize_and_deserialize_der(
+        &[
+            0x31, 0x09, // SET
+            0x80, 0x01, 0xFF, // second
+            0x81, 0x01, 0x03, // third
+            0x82, 0x01, 0x01, // first
+        ],
+        &value,
+    );
+    // BER keeps the extension addition after the root components
+    assert_eq!(
+        &[0x31, 0x09, 0x80, 0x01, 0xFF, 0x82, 0x01, 0x01, 0x81, 0x01, 0x03][..],
+        &serialize_ber(&value)[..]
+    );
+}
+
+#[test]
+fn test_set_component_untagged_choice_sorted_by_chosen_alternative() {
+    serialize_and_deserialize_der(
+        &[0x31, 0x06, 0x83, 0x01, 0x00, 0x84, 0x01, 0x07],
+        &WithChoice {
+            value: 7,
+            decision: Decision::Abc(false),
+        },
+    );
+    serialize_and_deserialize_der(
+        &[0x31, 0x06, 0x84, 0x01, 0x07, 0x85, 0x01, 0x09],
+        &WithChoice {
+            value: 7,
+            decision: Decision::Def(9),
+        },
+    );
+}
+
+#[test]
+fn test_set_of_sorted_by_encoding() {
+    serialize_and_deserialize_der(
+        &[
+            0x31, 0x0A, // SET OF
+            0x04, 0x01, 0x01, //
+            0x04, 0x01, 0x02, //
+            0x04, 0x02, 0x01, 0x00,
+        ],
+        &Names(vec![vec![0x01], vec![0x02], vec![0x01, 0x00]]),
+    );
+    assert_eq!(
+        &[0x31, 0x06, 0x04, 0x01, 0x01, 0x04, 0x01, 0x02][..],
+        &serialize_der(&Names(vec![vec![0x02], vec![0x01]]))[..]
+    );
+    assert_eq!(
+        &[0x31, 0x0A, 0x02, 0x01, 0x01, 0x02, 0x01, 0x03, 0x02, 0x02, 0x01, 0x00][..],
+        &serialize_der(&Numbers(vec![256, 3, 1]))[..]
+    );
+}
+
+#[test]
+fn test_default_values_omitted() {
+    serialize_and_deserialize_der(
+        &[0x31, 0x00],
+        &WithDefault {
+            value: 5,
+            flag: false,
+        },
+    );
+    serialize_and_deserialize_der(
+        &[0x31, 0x03, 0x81, 0x01, 0xFF],
+        &WithDefault {
+            value: 5,
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_unused_bits_are_zero() {
+    assert_eq!(
+        &[0x03, 0x02, 0x05, 0xA0][..],
+        &serialize_der(&Flags(BitVec::from_bytes(vec![0b1011_1111], 3)))[..]
+    );
+}
+
+#[test]
+#[should_panic(expected = "DER does not allow the indefinite length form")]
+fn test_indefinite_length_is_rejected() {
+    let _ = BerWriter::distinguished().with_indefinite_length();
+}
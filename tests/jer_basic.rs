@@ -0,0 +1,213 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"JerBasic DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Simple ::= SEQUENCE {
+        small INTEGER (0..7),
+        someFlag BOOLEAN,
+        text IA5String,
+        data OCTET STRING,
+        opt INTEGER OPTIONAL
+    }
+
+    Decision ::= CHOICE {
+        abc BOOLEAN,
+        def-ghi INTEGER (0..1000),
+        ...,
+        nothing NULL
+    }
+
+    Color ::= ENUMERATED {
+        red,
+        dark-green,
+        blue
+    }
+
+    Wrapper ::= SEQUENCE {
+        decision Decision,
+        colors SEQUENCE OF Color
+    }
+
+    Unordered ::= SET {
+        second BOOLEAN,
+        first INTEGER
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Flags ::= BIT STRING
+
+    FixedFlags ::= BIT STRING (SIZE(12))
+
+    Reference ::= Simple
+
+    WithDefault ::= SEQUENCE {
+        value INTEGER DEFAULT 5
+    }
+
+    END"
+);
+
+#[test]
+fn test_simple_sequence() {
+    serialize_and_deserialize_jer(
+        r#"{"small":5,"someFlag":true,"text":"A\"B","data":"DEAD","opt":255}"#,
+        &Simple {
+            small: 5,
+            some_flag: true,
+            text: "A\"B".to_string(),
+            data: vec![0xDE, 0xAD],
+            opt: Some(255),
+        },
+    );
+    serialize_and_deserialize_jer(
+        r#"{"small":5,"someFlag":false,"text":"","data":""}"#,
+        &Simple {
+            small: 5,
+            some_flag: false,
+            text: String::default(),
+            data: Vec::default(),
+            opt: None,
+        },
+    );
+}
+
+#[test]
+fn test_members_in_any_order_and_whitespace() {
+    assert_eq!(
+        Simple {
+            small: 1,
+            some_flag: true,
+            text: "x".to_string(),
+            data: vec![0xAB],
+            opt: None,
+        },
+        deserialize_jer::<Simple>(
+            "{ \"data\" : \"ab\",\n \"text\": \"x\", \"someFlag\": true, \"small\": 1 }\n"
+        )
+    );
+}
+
+#[test]
+fn test_choice_and_enumerated_use_identifiers() {
+    serialize_and_deserialize_jer(r#"{"def-ghi":500}"#, &Decision::DefGhi(500));
+    serialize_and_deserialize_jer(r#"{"nothing":null}"#, &Decision::Nothing(Null));
+    serialize_and_deserialize_jer(r#""dark-green""#, &Color::DarkGreen);
+    serialize_and_deserialize_jer(
+        r#"{"decision":{"abc":false},"colors":["red","blue"]}"#,
+        &Wrapper {
+            decision: Decision::Abc(false),
+            colors: vec![Color::Red, Color::Blue],
+        },
+    );
+}
+
+#[test]
+fn test_set_in_canonical_order() {
+    serialize_and_deserialize_jer(
+        r#"{"second":true,"first":1}"#,
+        &Unordered {
+            first: 1,
+            second: true,
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_is_ignored() {
+    serialize_and_deserialize_jer(
+        r#"{"abc":1,"def":true}"#,
+        &Extensible {
+            abc: 1,
+            def: Some(true),
+        },
+    );
+    assert_eq!(
+        Extensible { abc: 1, def: None },
+        deserialize_jer::<Extensible>(r#"{"abc":1,"xyz":[1,2]}"#)
+    );
+}
+
+#[test]
+fn test_unknown_member_in_root_is_rejected() {
+    let mut reader = JerReader::from(r#"{"value":1,"other":2}"#);
+    assert_eq!(
+        &asn1rs::io::jer::ErrorKind::UnexpectedMembers("WithDefault"),
+        reader.read::<WithDefault>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_missing_member_is_rejected() {
+    let mut reader = JerReader::from(r#"{"decision":{"abc":true}}"#);
+    assert_eq!(
+        &asn1rs::io::jer::ErrorKind::MissingMember("colors"),
+        reader.read::<Wrapper>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_unknown_identifier_is_rejected() {
+    let mut reader = JerReader::from(r#""green""#);
+    assert_eq!(
+        &asn1rs::io::jer::ErrorKind::UnknownIdentifier("green".to_string()),
+        reader.read::<Color>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_bit_string() {
+    serialize_and_deserialize_jer(
+        r#"{"value":"A0","length":3}"#,
+        &Flags(BitVec::from_bytes(vec![0b1010_0000], 3)),
+    );
+    serialize_and_deserialize_jer(r#"{"value":"","length":0}"#, &Flags(BitVec::default()));
+    serialize_and_deserialize_jer(
+        r#""ABC0""#,
+        &FixedFlags(BitVec::from_bytes(vec![0xAB, 0xC0], 12)),
+    );
+}
+
+#[test]
+fn test_type_reference_is_not_framed() {
+    serialize_and_deserialize_jer(
+        r#"{"small":0,"someFlag":true,"text":"","data":"00"}"#,
+        &Reference(Simple {
+            small: 0,
+            some_flag: true,
+            text: String::default(),
+            data: vec![0x00],
+            opt: None,
+        }),
+    );
+}
+
+#[test]
+fn test_default_value_is_omitted() {
+    serialize_and_deserialize_jer("{}", &WithDefault { value: 5 });
+    serialize_and_deserialize_jer(r#"{"value":6}"#, &WithDefault { value: 6 });
+}
+
+#[test]
+fn test_multiple_values() {
+    let mut writer = JerWriter::default();
+    writer.write(&Color::Red).unwrap();
+    writer.write(&WithDefault { value: 7 }).unwrap();
+    assert_eq!("\"red\"\n{\"value\":7}", writer.as_str());
+
+    let mut reader = writer.as_reader();
+    assert_eq!(Color::Red, reader.read::<Color>().unwrap());
+    assert_eq!(
+        WithDefault { value: 7 },
+        reader.read::<WithDefault>().unwrap()
+    );
+    assert_eq!(0, reader.bytes_remaining());
+}
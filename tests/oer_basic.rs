@@ -0,0 +1,240 @@
+mod test_utils;
+
+use asn1rs::model::Tag;
+use test_utils::*;
+
+asn_to_rust!(
+    r"OerBasic DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Widths ::= SEQUENCE {
+        one INTEGER (0..255),
+        two INTEGER (-200..200),
+        four INTEGER (0..4294967295),
+        eight INTEGER (-1..4294967296),
+        unbounded INTEGER (0..MAX)
+    }
+
+    Optionals ::= SEQUENCE {
+        abc BOOLEAN OPTIONAL,
+        def INTEGER (0..255) DEFAULT 3,
+        ghi NULL OPTIONAL
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...,
+        def BOOLEAN OPTIONAL,
+        ghi UTF8String OPTIONAL
+    }
+
+    Truncated ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...
+    }
+
+    Decision ::= CHOICE {
+        abc BOOLEAN,
+        def INTEGER (0..1000),
+        ghi Optionals,
+        ...,
+        nothing NULL
+    }
+
+    Color ::= ENUMERATED {
+        red,
+        green,
+        blue
+    }
+
+    Colors ::= SEQUENCE {
+        colors SEQUENCE OF Color
+    }
+
+    Level ::= ENUMERATED { low(5), high(10), huge(200) }
+
+    Strings ::= SEQUENCE {
+        fixed-octets OCTET STRING (SIZE(2)),
+        octets OCTET STRING,
+        fixed-bits BIT STRING (SIZE(4)),
+        bits BIT STRING,
+        code IA5String (SIZE(2)),
+        name UTF8String (SIZE(2))
+    }
+
+    END"
+);
+
+#[test]
+fn test_constrained_integers_have_fixed_widths() {
+    serialize_and_deserialize_oer(
+        &[
+            0x05, // one
+            0xFF, 0xFE, // two
+            0x01, 0x02, 0x03, 0x04, // four
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // eight
+            0x02, 0x01, 0x00, // unbounded
+        ],
+        &Widths {
+            one: 5,
+            two: -2,
+            four: 0x01_02_03_04,
+            eight: -1,
+            unbounded: 256,
+        },
+    );
+}
+
+#[test]
+fn test_presence_bitmap() {
+    serialize_and_deserialize_oer(
+        &[0b1010_0000, 0xFF],
+        &Optionals {
+            abc: Some(true),
+            def: 3,
+            ghi: Some(Null),
+        },
+    );
+    serialize_and_deserialize_oer(
+        &[0b0100_0000, 0x04],
+        &Optionals {
+            abc: None,
+            def: 4,
+            ghi: None,
+        },
+    );
+}
+
+#[test]
+fn test_extension_additions() {
+    serialize_and_deserialize_oer(
+        &[0x00, 0x01],
+        &Extensible {
+            abc: 1,
+            def: None,
+            ghi: None,
+        },
+    );
+    serialize_and_deserialize_oer(
+        &[0x80, 0x01, 0x02, 0x06, 0b1000_0000, 0x01, 0xFF],
+        &Extensible {
+            abc: 1,
+            def: Some(true),
+            ghi: None,
+        },
+    );
+    serialize_and_deserialize_oer(
+        &[0x80, 0x01, 0x02, 0x06, 0b0100_0000, 0x03, 0x02, b'h', b'i'],
+        &Extensible {
+            abc: 1,
+            def: None,
+            ghi: Some("hi".to_string()),
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_additions_are_skipped() {
+    let data = serialize_oer(&Extensible {
+        abc: 7,
+        def: Some(false),
+        ghi: Some("hi".to_string()),
+    });
+    assert_eq!(Truncated { abc: 7 }, deserialize_oer::<Truncated>(&data));
+}
+
+#[test]
+fn test_choice_is_identified_by_tag() {
+    serialize_and_deserialize_oer(&[0x80, 0xFF], &Decision::Abc(true));
+    serialize_and_deserialize_oer(&[0x81, 0x01, 0xF4], &Decision::Def(500));
+    serialize_and_deserialize_oer(
+        &[0x82, 0b0100_0000, 0x02],
+        &Decision::Ghi(Optionals {
+            abc: None,
+            def: 2,
+            ghi: None,
+        }),
+    );
+    serialize_and_deserialize_oer(&[0x83, 0x00], &Decision::Nothing(Null));
+}
+
+#[test]
+fn test_unknown_alternative_is_rejected() {
+    let mut reader = OerReader::from(&[0x84, 0x00][..]);
+    assert_eq!(
+        &asn1rs::io::oer::ErrorKind::UnknownAlternative("Decision", Tag::ContextSpecific(4)),
+        reader.read::<Decision>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_enumerated_and_sequence_of() {
+    serialize_and_deserialize_oer(&[0x02], &Color::Blue);
+    serialize_and_deserialize_oer(
+        &[0x01, 0x02, 0x00, 0x02],
+        &Colors {
+            colors: vec![Color::Red, Color::Blue],
+        },
+    );
+    serialize_and_deserialize_oer(&[0x01, 0x00], &Colors { colors: vec![] });
+}
+
+#[test]
+fn test_fixed_and_variable_size_strings() {
+    serialize_and_deserialize_oer(
+        &[
+            0xAB,
+            0xCD, // fixed-octets
+            0x01,
+            0x01,        // octets
+            0b1010_0000, // fixed-bits
+            0x02,
+            0x05,
+            0b1010_0000, // bits
+            b'D',
+            b'E', // code
+            0x02,
+            b'h',
+            b'i', // name
+        ],
+        &Strings {
+            fixed_octets: vec![0xAB, 0xCD],
+            octets: vec![0x01],
+            fixed_bits: BitVec::from_bytes(vec![0b1010_0000], 4),
+            bits: BitVec::from_bytes(vec![0b1010_0000], 3),
+            code: "DE".to_string(),
+            name: "hi".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_value_not_in_range_is_rejected() {
+    let mut writer = OerWriter::default();
+    assert_eq!(
+        &asn1rs::io::oer::ErrorKind::ValueNotInRange(1001, 0, 1000),
+        writer.write(&Decision::Def(1001)).unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_insufficient_data_is_rejected() {
+    let mut reader = OerReader::from(&[0x01, 0x02, 0x00][..]);
+    assert_eq!(
+        &asn1rs::io::oer::ErrorKind::InsufficientDataInSourceBuffer,
+        reader.read::<Colors>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_enumerated_is_encoded_by_its_value() {
+    serialize_and_deserialize_oer(&[0x05], &Level::Low);
+    serialize_and_deserialize_oer(&[0x0A], &Level::High);
+    serialize_and_deserialize_oer(&[0x82, 0x00, 0xC8], &Level::Huge);
+
+    let mut reader = OerReader::from(&[0x01][..]);
+    assert_eq!(
+        &asn1rs::io::oer::ErrorKind::InvalidEnumeratedValue(1),
+        reader.read::<Level>().unwrap_err().kind()
+    );
+}
@@ -69,33 +69,27 @@ pub fn emulate_macro_expansion_fallible(mut file: fs::File) {
     fn feed_derive_parser(
         attributes: &[Attribute],
         attribute_path: &syn::Path,
-        item: impl Fn() -> String,
-        body_start_marker: &str,
+        body: impl Fn() -> String,
     ) {
         for attr in attributes {
             if attr.path == *attribute_path {
                 let attribute_meta = attr.parse_meta().unwrap();
                 let attribute_meta = attribute_meta.into_token_stream().to_string();
 
-                let item = item();
+                let body = body();
                 // skip 'asn (' and ')'
                 let start = attribute_meta.find('(').unwrap();
                 let end = attribute_meta.rfind(')').unwrap();
                 let header = &attribute_meta[start + 1..end];
-                let body = {
-                    let body_start = item.find(body_start_marker).unwrap();
-                    &item[body_start..]
-                };
 
                 if cfg!(feature = "debug-proc-macro") {
-                    println!("##########: {}", item);
                     println!("      meta: {}", attribute_meta);
                     println!("    header: {}", header);
                     println!("      body: {}", body);
                     println!();
                 }
 
-                let result = ast_parse_str(header, body).to_string();
+                let result = ast_parse_str(header, &body).to_string();
 
                 if result.contains("compile_error") {
                     panic!("{}", result);
@@ -113,21 +107,20 @@ pub fn emulate_macro_expansion_fallible(mut file: fs::File) {
     }
     impl<'ast> syn::visit::Visit<'ast> for MacroVisitor {
         fn visit_item_enum(&mut self, i: &'ast ItemEnum) {
-            feed_derive_parser(
-                &i.attrs[..],
-                &self.attribute_path,
-                || i.into_token_stream().to_string(),
-                " pub enum ",
-            );
+            feed_derive_parser(&i.attrs[..], &self.attribute_path, || {
+                // the body is the item without its outer attributes
+                let mut body = i.clone();
+                body.attrs.clear();
+                body.into_token_stream().to_string()
+            });
         }
 
         fn visit_item_struct(&mut self, i: &'ast ItemStruct) {
-            feed_derive_parser(
-                &i.attrs[..],
-                &self.attribute_path,
-                || i.into_token_stream().to_string(),
-                " pub struct ",
-            );
+            feed_derive_parser(&i.attrs[..], &self.attribute_path, || {
+                let mut body = i.clone();
+                body.attrs.clear();
+                body.into_token_stream().to_string()
+            });
         }
 
         fn visit_macro(&mut self, macro_item: &'ast syn::Macro) {
@@ -14,7 +14,7 @@
 //!                          V                             |
 //!                      Rust-Model   <--- proc-macro  <---+                                                     
 
-use asn1rs::model::{Definition, Model, Rust};
+use asn1rs::model::{Definition, Model, Rust, TagDefault};
 use asn1rs::parser::Tokenizer;
 use asn1rs_model::gen::RustCodeGenerator;
 use codegen::Scope;
@@ -154,6 +154,46 @@ END"#,
     )
 }
 
+#[test]
+fn test_explicit_and_implicit_tags() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS EXPLICIT TAGS ::= BEGIN
+
+  Wrapped ::= [APPLICATION 3] INTEGER
+
+  Fields ::= SEQUENCE {
+    abc [1] IMPLICIT INTEGER,
+    def [2] BOOLEAN OPTIONAL,
+    ghi [3] Wrapped,
+    jkl UTF8String
+  }
+
+  Decision ::= [APPLICATION 5] CHOICE {
+    abc [1] INTEGER,
+    def [2] IMPLICIT BOOLEAN
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_implicit_tag_default() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS IMPLICIT TAGS ::= BEGIN
+
+  Wrapped ::= [APPLICATION 3] EXPLICIT INTEGER
+
+  Fields ::= SET {
+    abc [1] INTEGER,
+    def [2] EXPLICIT BOOLEAN DEFAULT TRUE,
+    ghi UTF8String
+  }
+  
+END"#,
+    )
+}
+
 fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
     asn: &str,
 ) {
@@ -162,7 +202,8 @@ fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_ch
     let rust_model = asn_model.to_rust();
 
     for definition in rust_model.definitions {
-        let stringified = generate_rust_code_with_proc_macro_attributes(&definition);
+        let stringified =
+            generate_rust_code_with_proc_macro_attributes(&definition, rust_model.tag_default);
         let mut lines = stringified.lines().map(str::trim).filter(|s| !s.is_empty());
 
         let attribute = extract_attribute(lines.next().unwrap());
@@ -178,13 +219,14 @@ fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_ch
         println!("BODY:      {}", body.to_string());
         println!("---");
 
-        let re_parsed = asn1rs::ast::parse_asn_definition(attribute, body)
-            .map(|(d, _item)| d)
-            .unwrap()
-            .unwrap();
+        let (re_parsed, tag_default) =
+            asn1rs::ast::parse_asn_definition_with_tag_default(attribute, body)
+                .map(|(d, tag_default, _item)| (d.unwrap(), tag_default))
+                .unwrap();
 
         let re_parsed_model = Model {
             name: rust_model.name.clone(),
+            tag_default,
             imports: rust_model.imports.clone(),
             definitions: vec![re_parsed],
             ..Default::default()
@@ -195,9 +237,16 @@ fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_ch
     }
 }
 
-fn generate_rust_code_with_proc_macro_attributes(definition: &Definition<Rust>) -> String {
+fn generate_rust_code_with_proc_macro_attributes(
+    definition: &Definition<Rust>,
+    tag_default: TagDefault,
+) -> String {
     let mut scope = Scope::new();
-    RustCodeGenerator::default().add_definition(&mut scope, &definition);
+    RustCodeGenerator::default().add_definition_with_tag_default(
+        &mut scope,
+        &definition,
+        tag_default,
+    );
     scope.to_string()
 }
 
@@ -0,0 +1,104 @@
+mod test_utils;
+
+use asn1rs::io::per::ErrorKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r"UperBorrowedBuffer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Status ::= CHOICE {
+        idle NULL,
+        moving INTEGER (0..16383),
+        ...,
+        parked BOOLEAN,
+        note UTF8String
+    }
+
+    Message ::= SEQUENCE {
+        station-id INTEGER (0..4294967295),
+        speed INTEGER (0..16383) OPTIONAL,
+        status Status,
+        ...,
+        heading INTEGER (0..3601),
+        payload OCTET STRING
+    }
+
+    END"
+);
+
+fn message(status: Status, payload_len: usize) -> Message {
+    Message {
+        station_id: 1_234_567,
+        speed: Some(1337),
+        status,
+        heading: Some(900),
+        payload: if payload_len > 0 {
+            Some((0..payload_len).map(|i| i as u8).collect())
+        } else {
+            None
+        },
+    }
+}
+
+fn write_into(buffer: &mut [u8], value: &Message) -> Result<Vec<u8>, ErrorKind> {
+    let mut writer = UperWriter::from(buffer);
+    writer.write(value).map_err(|e| e.kind().clone())?;
+    Ok(writer.byte_content().to_vec())
+}
+
+#[test]
+fn test_same_encoding_as_growing_buffer() {
+    let values = [
+        message(Status::Idle(Null), 0),
+        message(Status::Moving(42), 3),
+        message(Status::Parked(true), 127),
+        message(Status::Note("at the depot".to_string()), 128),
+        message(Status::Note("x".repeat(200)), 1000),
+    ];
+    let mut buffer = vec![0_u8; 2 * 1024];
+
+    for value in &values {
+        let (bits, bytes) = serialize_uper(value);
+        let mut writer = UperWriter::from(&mut buffer[..]);
+        writer.write(value).unwrap();
+        assert_eq!(bits, writer.bit_len());
+        assert_eq!(&bytes[..], writer.byte_content());
+        assert_eq!(value, &writer.as_reader().read::<Message>().unwrap());
+    }
+}
+
+#[test]
+fn test_fragmented_extension_addition() {
+    let value = message(Status::Parked(false), 40_000);
+    let mut buffer = vec![0_u8; 64 * 1024];
+    assert_eq!(
+        Ok(serialize_uper(&value).1),
+        write_into(&mut buffer, &value)
+    );
+}
+
+#[test]
+fn test_previous_content_is_overwritten() {
+    let value = message(Status::Parked(true), 5);
+    let mut buffer = [0xFF_u8; 256];
+    assert_eq!(
+        Ok(serialize_uper(&value).1),
+        write_into(&mut buffer, &value)
+    );
+}
+
+#[test]
+fn test_insufficient_space_is_reported() {
+    let value = message(Status::Note("at the depot".to_string()), 20);
+    let len = serialize_uper(&value).1.len();
+    let mut buffer = vec![0_u8; len];
+
+    for too_short in 0..len {
+        assert!(matches!(
+            write_into(&mut buffer[..too_short], &value),
+            Err(ErrorKind::InsufficientSpaceInDestinationBuffer(_))
+        ));
+    }
+    assert!(write_into(&mut buffer, &value).is_ok());
+}
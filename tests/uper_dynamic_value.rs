@@ -0,0 +1,557 @@
+mod test_utils;
+
+use asn1rs::dynamic::{Error, Schema, Value};
+use asn1rs::io::per::unaligned::buffer::{BitBuffer, Bits};
+use asn1rs::io::per::unaligned::ScopedBitRead;
+use asn1rs::io::per::ErrorKind;
+use asn1rs::model::Model;
+use asn1rs::parser::Tokenizer;
+use test_utils::*;
+
+/// Generates the Rust types for the definitions and provides the same definitions as [`Schema`]
+macro_rules! asn_to_rust_and_schema {
+    ($asn:literal) => {
+        asn_to_rust!($asn);
+
+        fn schema() -> Schema {
+            Schema::from(
+                Model::try_from(Tokenizer::default().parse($asn))
+                    .unwrap()
+                    .try_resolve()
+                    .unwrap(),
+            )
+        }
+    };
+}
+
+asn_to_rust_and_schema!(
+    r#"UperDynamicValue DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Kind ::= ENUMERATED { request, response, ..., notification }
+
+    Header ::= SEQUENCE {
+        id INTEGER (0..1023),
+        offset INTEGER (-100..100),
+        counter INTEGER,
+        kind Kind,
+        label IA5String (SIZE(1..8)) OPTIONAL,
+        digits NumericString (SIZE(4)),
+        code PrintableString (FROM("A".."F")),
+        name UTF8String,
+        retries INTEGER (0..7) DEFAULT 3,
+        urgent BOOLEAN,
+        payload OCTET STRING (SIZE(0..16)),
+        mask BIT STRING (SIZE(12))
+    }
+
+    Message ::= SEQUENCE {
+        header Header,
+        entries SEQUENCE (SIZE(0..4)) OF Entry,
+        body Body,
+        ...,
+        priority INTEGER (0..7),
+        [[
+            trace-id INTEGER (0..65535),
+            trace-tag VisibleString OPTIONAL
+        ]],
+        note UTF8String
+    }
+
+    Entry ::= SEQUENCE {
+        key INTEGER (0..255),
+        value INTEGER (0..255, ...)
+    }
+
+    Body ::= CHOICE {
+        empty NULL,
+        text UTF8String,
+        numbers Numbers,
+        ...,
+        flag BOOLEAN
+    }
+
+    Numbers ::= SEQUENCE OF INTEGER (0..15)
+
+    END"#
+);
+
+fn assert_same_uper(type_name: &str, generated: &impl Writable, value: &Value) {
+    let schema = schema();
+    let (bits, bytes) = serialize_uper(generated);
+
+    let mut buffer = BitBuffer::default();
+    schema.write_uper(type_name, value, &mut buffer).unwrap();
+    assert_eq!((bits, &bytes[..]), (buffer.bit_len(), buffer.content()));
+
+    let mut reader = Bits::from((&bytes[..], bits));
+    assert_eq!(value, &schema.read_uper(type_name, &mut reader).unwrap());
+    assert_eq!(0, reader.remaining());
+}
+
+fn sequence(components: Vec<(&str, Value)>) -> Value {
+    Value::Sequence(
+        components
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+        Default::default(),
+    )
+}
+
+fn choice(identifier: &str, value: Value) -> Value {
+    Value::Choice(identifier.to_string(), Box::new(value))
+}
+
+fn string(value: &str) -> Value {
+    Value::String(value.to_string())
+}
+
+fn header() -> (Header, Value) {
+    (
+        Header {
+            id: 1000,
+            offset: -42,
+            counter: 123_456,
+            kind: Kind::Response,
+            label: Some("abc".to_string()),
+            digits: "0 42".to_string(),
+            code: "CAFE".to_string(),
+            name: "Grüße".to_string(),
+            retries: 3,
+            urgent: true,
+            payload: vec![0xDE, 0xAD],
+            mask: BitVec::from_bytes(vec![0xAB, 0xC0], 12),
+        },
+        sequence(vec![
+            ("id", Value::Integer(1000)),
+            ("offset", Value::Integer(-42)),
+            ("counter", Value::Integer(123_456)),
+            ("kind", Value::Enumerated("response".to_string())),
+            ("label", string("abc")),
+            ("digits", string("0 42")),
+            ("code", string("CAFE")),
+            ("name", string("Grüße")),
+            ("retries", Value::Integer(3)),
+            ("urgent", Value::Boolean(true)),
+            ("payload", Value::OctetString(vec![0xDE, 0xAD])),
+            ("mask", Value::BitString(vec![0xAB, 0xC0], 12)),
+        ]),
+    )
+}
+
+fn message(body: Body, body_value: Value) -> (Message, Value) {
+    let (header, header_value) = header();
+    (
+        Message {
+            header,
+            entries: vec![Entry { key: 1, value: 2 }, Entry { key: 3, value: 300 }],
+            body,
+            priority: None,
+            extension_group_1: None,
+            note: None,
+        },
+        sequence(vec![
+            ("header", header_value),
+            (
+                "entries",
+                Value::SequenceOf(vec![
+                    sequence(vec![
+                        ("key", Value::Integer(1)),
+                        ("value", Value::Integer(2)),
+                    ]),
+                    sequence(vec![
+                        ("key", Value::Integer(3)),
+                        ("value", Value::Integer(300)),
+                    ]),
+                ]),
+            ),
+            ("body", body_value),
+        ]),
+    )
+}
+
+#[test]
+fn test_sequence_of_simple_types() {
+    let (generated, value) = header();
+    assert_same_uper("Header", &generated, &value);
+}
+
+#[test]
+fn test_default_is_not_written_but_read() {
+    let (generated, value) = header();
+    let without_retries = match &value {
+        Value::Sequence(components, unknown) => Value::Sequence(
+            components
+                .iter()
+                .filter(|(name, _)| name != "retries")
+                .cloned()
+                .collect(),
+            unknown.clone(),
+        ),
+        _ => unreachable!(),
+    };
+    let schema = schema();
+    let bytes = schema.encode_uper("Header", &without_retries).unwrap();
+    assert_eq!(bytes, schema.encode_uper("Header", &value).unwrap());
+    assert_eq!(serialize_uper(&generated).1, bytes);
+    assert_eq!(value, schema.decode_uper("Header", &bytes).unwrap());
+}
+
+#[test]
+fn test_choice_and_sequence_of() {
+    let (generated, value) = message(Body::Empty(Null), choice("empty", Value::Null));
+    assert_same_uper("Message", &generated, &value);
+
+    let (generated, value) = message(
+        Body::Numbers(Numbers(vec![1, 15, 7])),
+        choice(
+            "numbers",
+            Value::SequenceOf(vec![
+                Value::Integer(1),
+                Value::Integer(15),
+                Value::Integer(7),
+            ]),
+        ),
+    );
+    assert_same_uper("Message", &generated, &value);
+}
+
+#[test]
+fn test_choice_extension_alternative() {
+    let (generated, value) = message(Body::Flag(true), choice("flag", Value::Boolean(true)));
+    assert_same_uper("Message", &generated, &value);
+}
+
+#[test]
+fn test_extension_additions_and_group() {
+    let (mut generated, value) =
+        message(Body::Text("hi".to_string()), choice("text", string("hi")));
+    generated.priority = Some(5);
+    generated.extension_group_1 = Some(MessageExtensionGroup1 {
+        trace_id: 4711,
+        trace_tag: Some("x".to_string()),
+    });
+    generated.note = Some("done".to_string());
+
+    let value = match value {
+        Value::Sequence(mut components, unknown) => {
+            components.push(("priority".to_string(), Value::Integer(5)));
+            components.push(("trace-id".to_string(), Value::Integer(4711)));
+            components.push(("trace-tag".to_string(), string("x")));
+            components.push(("note".to_string(), string("done")));
+            Value::Sequence(components, unknown)
+        }
+        _ => unreachable!(),
+    };
+    assert_same_uper("Message", &generated, &value);
+}
+
+#[test]
+fn test_unknown_extensions_are_kept() {
+    let schema = schema();
+    let (mut generated, value) = message(Body::Empty(Null), choice("empty", Value::Null));
+    let value = match value {
+        Value::Sequence(mut components, _) => {
+            components.push(("priority".to_string(), Value::Integer(1)));
+            Value::Sequence(
+                components,
+                UnknownExtensions::new(vec![None, Some(OpenType::new(vec![0x80]))]),
+            )
+        }
+        _ => unreachable!(),
+    };
+
+    let mut buffer = BitBuffer::default();
+    schema.write_uper("Message", &value, &mut buffer).unwrap();
+    let bytes = schema.encode_uper("Message", &value).unwrap();
+    assert_eq!(value, schema.decode_uper("Message", &bytes).unwrap());
+
+    // the generated type does not keep them
+    generated.priority = Some(1);
+    assert_eq!(
+        generated,
+        deserialize_uper::<Message>(buffer.content(), buffer.bit_len())
+    );
+}
+
+#[test]
+fn test_unknown_choice_alternative_is_kept() {
+    let schema = schema();
+    let (_, value) = message(Body::Empty(Null), choice("empty", Value::Null));
+    let value = match value {
+        Value::Sequence(components, unknown) => Value::Sequence(
+            components
+                .into_iter()
+                .map(|(name, value)| match name.as_str() {
+                    "body" => (
+                        name,
+                        Value::UnknownAlternative(4, OpenType::new(vec![0x42])),
+                    ),
+                    _ => (name, value),
+                })
+                .collect(),
+            unknown,
+        ),
+        _ => unreachable!(),
+    };
+
+    let bytes = schema.encode_uper("Message", &value).unwrap();
+    assert_eq!(value, schema.decode_uper("Message", &bytes).unwrap());
+}
+
+#[test]
+fn test_invalid_values_are_rejected() {
+    let schema = schema();
+    let (_, value) = header();
+    let replace = |name: &str, replacement: Option<Value>| match &value {
+        Value::Sequence(components, unknown) => Value::Sequence(
+            components
+                .iter()
+                .filter_map(|(n, v)| {
+                    if n == name {
+                        replacement.clone().map(|r| (n.clone(), r))
+                    } else {
+                        Some((n.clone(), v.clone()))
+                    }
+                })
+                .collect(),
+            unknown.clone(),
+        ),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        Err(Error::UnknownType("Nothing".to_string())),
+        schema.encode_uper("Nothing", &value)
+    );
+    assert_eq!(
+        Err(Error::MissingComponent("urgent".to_string())),
+        schema.encode_uper("Header", &replace("urgent", None))
+    );
+    assert_eq!(
+        Err(Error::UnexpectedValue("BOOLEAN", "INTEGER")),
+        schema.encode_uper("Header", &replace("urgent", Some(Value::Integer(1))))
+    );
+    assert_eq!(
+        Err(Error::UnknownVariant("unknown".to_string())),
+        schema.encode_uper(
+            "Header",
+            &replace("kind", Some(Value::Enumerated("unknown".to_string())))
+        )
+    );
+    assert_eq!(
+        Err(ErrorKind::SizeNotInRange(3, 4, 4).into()),
+        schema.encode_uper("Header", &replace("digits", Some(string("123"))))
+    );
+
+    let mut with_unknown_component = value.clone();
+    if let Value::Sequence(components, _) = &mut with_unknown_component {
+        components.push(("unknown".to_string(), Value::Null));
+    }
+    assert_eq!(
+        Err(Error::UnknownComponent("unknown".to_string())),
+        schema.encode_uper("Header", &with_unknown_component)
+    );
+}
+
+#[test]
+fn test_open_type_is_selected_by_related_component() {
+    let schema = Schema::from(
+        Model::try_from(Tokenizer::default().parse(
+            r"DynamicInformationObjects DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            PROTOCOL-IES ::= CLASS {
+                &id             INTEGER (0..65535) UNIQUE,
+                &Value
+            }
+            WITH SYNTAX {
+                ID              &id
+                TYPE            &Value
+            }
+
+            Cause ::= ENUMERATED { unspecified, overload, ... }
+
+            ExampleIEs PROTOCOL-IES ::= {
+                { ID 1  TYPE Cause } |
+                { ID 9  TYPE NULL },
+                ...
+            }
+
+            ProtocolIE-Field ::= SEQUENCE {
+                id              PROTOCOL-IES.&id            ({ExampleIEs}),
+                value           PROTOCOL-IES.&Value         ({ExampleIEs}{@id})
+            }
+
+            END",
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap(),
+    );
+
+    let cause = sequence(vec![
+        ("id", Value::Integer(1)),
+        (
+            "value",
+            choice("Cause", Value::Enumerated("overload".to_string())),
+        ),
+    ]);
+    assert_eq!(
+        cause,
+        schema
+            .decode_uper("ProtocolIE-Field", &[0x00, 0x01, 0x01, 0x40])
+            .unwrap()
+    );
+    assert_eq!(
+        vec![0x00, 0x01, 0x01, 0x40],
+        schema.encode_uper("ProtocolIE-Field", &cause).unwrap()
+    );
+
+    // the empty encoding of NULL is replaced by a single octet
+    let null = sequence(vec![
+        ("id", Value::Integer(9)),
+        ("value", choice("Value-9", Value::Null)),
+    ]);
+    assert_eq!(
+        vec![0x00, 0x09, 0x01, 0x00],
+        schema.encode_uper("ProtocolIE-Field", &null).unwrap()
+    );
+    assert_eq!(
+        Err(ErrorKind::InvalidOpenTypeKey(2).into()),
+        schema.decode_uper("ProtocolIE-Field", &[0x00, 0x02, 0x01, 0x00])
+    );
+}
+
+fn schema_of_modules(modules: &[&str]) -> Schema {
+    Schema::new(modules.iter().map(|asn| {
+        Model::try_from(Tokenizer::default().parse(asn))
+            .unwrap()
+            .try_resolve()
+            .unwrap()
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_references_refer_to_the_definition_of_their_module() {
+    let schema = schema_of_modules(&[
+        r"Alpha DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Id ::= INTEGER (0..255)
+            Alpha-Packet ::= SEQUENCE { id Id }
+        END",
+        r"Beta DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Id ::= BOOLEAN
+            Beta-Packet ::= SEQUENCE { id Id }
+        END",
+        r"Gamma DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Id FROM Beta;
+            Gamma-Packet ::= SEQUENCE { id Id }
+        END",
+    ]);
+
+    assert_eq!(
+        sequence(vec![("id", Value::Integer(42))]),
+        schema.decode_uper("Alpha-Packet", &[0x2A]).unwrap()
+    );
+    assert_eq!(
+        sequence(vec![("id", Value::Boolean(true))]),
+        schema.decode_uper("Beta-Packet", &[0x80]).unwrap()
+    );
+    assert_eq!(
+        sequence(vec![("id", Value::Boolean(true))]),
+        schema.decode_uper("Gamma-Packet", &[0x80]).unwrap()
+    );
+    assert_eq!(
+        Value::Integer(7),
+        schema.decode_uper("Alpha.Id", &[0x07]).unwrap()
+    );
+    assert_eq!(
+        Err(Error::AmbiguousType("Id".to_string())),
+        schema.decode_uper("Id", &[0x07])
+    );
+    assert_eq!(None, schema.definition("Id"));
+}
+
+#[test]
+fn test_decode_rejects_trailing_bits() {
+    let schema = schema_of_modules(&[r"Trailing DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Flag ::= BOOLEAN
+        Id ::= INTEGER (0..255)
+        Nothing ::= NULL
+    END"]);
+
+    assert_eq!(
+        Ok(Value::Boolean(true)),
+        schema.decode_uper("Flag", &[0x80])
+    );
+    assert_eq!(
+        Err(Error::TrailingBits(7)),
+        schema.decode_uper("Flag", &[0x81])
+    );
+    assert_eq!(
+        Err(Error::TrailingBits(8)),
+        schema.decode_uper("Id", &[0x2A, 0x00])
+    );
+    assert_eq!(Ok(Value::Null), schema.decode_uper("Nothing", &[0x00]));
+    assert_eq!(Ok(Value::Null), schema.decode_uper("Nothing", &[]));
+    assert_eq!(
+        Err(Error::TrailingBits(16)),
+        schema.decode_uper("Nothing", &[0x00, 0x00])
+    );
+}
+
+#[test]
+fn test_versions_of_a_module_are_told_apart_by_their_oid() {
+    let schema = schema_of_modules(&[
+        r"Container {1 2 1} DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Id ::= INTEGER (0..3)
+        END",
+        r"Container {1 2 2} DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Id ::= INTEGER (0..65535)
+        END",
+        r"User DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Id FROM Container {1 2 1};
+            Msg ::= SEQUENCE { id Id }
+        END",
+    ]);
+
+    let msg = sequence(vec![("id", Value::Integer(2))]);
+    assert_eq!(vec![0x80], schema.encode_uper("User.Msg", &msg).unwrap());
+    assert_eq!(msg, schema.decode_uper("Msg", &[0x80]).unwrap());
+    assert_eq!(
+        vec![0x00, 0x02],
+        schema
+            .encode_uper("Container { 1 2 2 }.Id", &Value::Integer(2))
+            .unwrap()
+    );
+    assert_eq!(
+        vec![0x80],
+        schema
+            .encode_uper("Container { 1 2 1 }.Id", &Value::Integer(2))
+            .unwrap()
+    );
+    assert_eq!(
+        Err(Error::AmbiguousType("Container.Id".to_string())),
+        schema.encode_uper("Container.Id", &Value::Integer(2))
+    );
+}
+
+#[test]
+fn test_duplicate_module_is_rejected() {
+    let module = || {
+        Model::try_from(Tokenizer::default().parse(
+            r"Container {1 2 1} DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Id ::= INTEGER (0..3)
+            END",
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+    };
+    assert_eq!(
+        Some(Error::DuplicateModule("Container { 1 2 1 }".to_string())),
+        Schema::new(vec![module(), module()]).err()
+    );
+}
@@ -0,0 +1,109 @@
+mod test_utils;
+
+use asn1rs::io::per::ErrorKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r"UperEncodedBits DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Container ::= CHOICE {
+        low-frequency OCTET STRING,
+        special BIT STRING (SIZE(1..64)),
+        ...,
+        raw OCTET STRING
+    }
+
+    Awareness ::= SEQUENCE {
+        station-id INTEGER (0..4294967295),
+        heading INTEGER (0..3601),
+        name UTF8String OPTIONAL,
+        containers SEQUENCE (SIZE(0..8, ...)) OF Container,
+        level ENUMERATED { low, medium, high, ... },
+        ...,
+        speed INTEGER (0..16383),
+        [[
+            path-length INTEGER (0..7),
+            path-points SEQUENCE OF INTEGER OPTIONAL
+        ]],
+        trailer OCTET STRING
+    }
+
+    END"
+);
+
+fn awareness(containers: Vec<Container>) -> Awareness {
+    Awareness {
+        station_id: 4_000_000_000,
+        heading: 3601,
+        name: None,
+        containers,
+        level: AwarenessLevel::Medium,
+        speed: None,
+        extension_group_1: None,
+        trailer: None,
+    }
+}
+
+fn assert_same_bits(value: &Awareness) {
+    assert_eq!(Ok(serialize_uper(value).0), uper_encoded_bits(value));
+}
+
+#[test]
+fn test_root_components() {
+    let mut value = awareness(Vec::new());
+    assert_same_bits(&value);
+    value.name = Some("Ümlaut".to_string());
+    value.level = AwarenessLevel::High;
+    assert_same_bits(&value);
+}
+
+#[test]
+fn test_extension_additions_and_groups() {
+    let mut value = awareness(vec![Container::Special(BitVec::from_bytes(
+        vec![0xAA, 0x55],
+        13,
+    ))]);
+    value.speed = Some(42);
+    assert_same_bits(&value);
+    value.extension_group_1 = Some(AwarenessExtensionGroup1 {
+        path_length: 3,
+        path_points: Some((0..200).collect()),
+    });
+    assert_same_bits(&value);
+    value.trailer = Some(vec![0xFF; 100]);
+    assert_same_bits(&value);
+}
+
+#[test]
+fn test_fragmented_lengths() {
+    let mut value = awareness(vec![
+        Container::LowFrequency(vec![0x11; 16 * 1024]),
+        Container::Raw(vec![0x22; 70_000]),
+    ]);
+    assert_same_bits(&value);
+    value.speed = Some(16383);
+    value.trailer = Some(vec![0x33; 40_000]);
+    assert_same_bits(&value);
+}
+
+#[test]
+fn test_optional_container_is_dropped_to_fit() {
+    let mtu_bits = 100 * 8;
+    let mut value = awareness(vec![Container::LowFrequency(vec![0x00; 50])]);
+    value
+        .containers
+        .push(Container::LowFrequency(vec![0x00; 60]));
+    while uper_encoded_bits(&value).unwrap() > mtu_bits {
+        value.containers.pop();
+    }
+    assert_eq!(1, value.containers.len());
+    assert!(serialize_uper(&value).1.len() * 8 <= mtu_bits);
+}
+
+#[test]
+fn test_invalid_value_is_rejected() {
+    let value = awareness(vec![Container::Special(BitVec::default())]);
+    let error = uper_encoded_bits(&value).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::SizeNotInRange(0, 1, 64)));
+}
@@ -0,0 +1,123 @@
+mod test_utils;
+
+use asn1rs::io::per::ErrorKind;
+use std::io::Read;
+use test_utils::*;
+
+asn_to_rust!(
+    r"UperStream DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Sample ::= SEQUENCE {
+        id INTEGER (0..65535),
+        label UTF8String OPTIONAL,
+        ...,
+        flag BOOLEAN
+    }
+
+    Batch ::= SEQUENCE {
+        name IA5String,
+        samples SEQUENCE OF Sample
+    }
+
+    Mode ::= ENUMERATED { start, stop, pause }
+
+    END"
+);
+
+/// Returns at most one byte per call, like a slow connection
+struct Trickle<R: Read>(R);
+
+impl<R: Read> Read for Trickle<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+fn sample(id: u16) -> Sample {
+    Sample {
+        id,
+        label: if id % 2 == 0 {
+            Some(format!("sample {}", id))
+        } else {
+            None
+        },
+        flag: if id % 3 == 0 { Some(true) } else { None },
+    }
+}
+
+fn batch(len: u16) -> Batch {
+    Batch {
+        name: "batch".to_string(),
+        samples: (0..len).map(sample).collect(),
+    }
+}
+
+fn concatenated_uper(values: &[Batch]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| serialize_uper(value).1)
+        .collect()
+}
+
+#[test]
+fn test_back_to_back_messages_from_read() {
+    let batches = vec![batch(3), batch(0), batch(1000)];
+    let bytes = concatenated_uper(&batches);
+    let mut reader = UperReader::from_read(Trickle(&bytes[..]));
+
+    for batch in &batches {
+        assert_eq!(batch, &reader.read_message::<Batch>().unwrap());
+    }
+    assert_eq!(
+        &ErrorKind::EndOfStream,
+        reader.read_message::<Batch>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_chunked_input_needs_more_data() {
+    let batches = vec![batch(5), batch(2)];
+    let bytes = concatenated_uper(&batches);
+    let mut reader = UperReader::from(StreamBits::chunked());
+    let mut read = Vec::new();
+
+    for chunk in bytes.chunks(3) {
+        reader.push(chunk);
+        loop {
+            match reader.read_message::<Batch>() {
+                Ok(batch) => read.push(batch),
+                Err(e) if e.kind() == &ErrorKind::NeedMoreData => break,
+                Err(e) => panic!("{}", e),
+            }
+        }
+    }
+    assert_eq!(batches, read);
+}
+
+#[test]
+fn test_truncated_message_is_the_end_of_stream() {
+    let bytes = serialize_uper(&batch(4)).1;
+    let mut reader = UperReader::from_read(&bytes[..bytes.len() / 2]);
+    assert_eq!(
+        &ErrorKind::EndOfStream,
+        reader.read_message::<Batch>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_malformed_message_is_dropped() {
+    let mut reader = UperReader::from(StreamBits::chunked());
+    // the index 3 is not a variant of Mode
+    reader.push(&[0xC0]);
+    reader.push(&serialize_uper(&Mode::Stop).1);
+
+    let error = reader.read_message::<Mode>().unwrap_err();
+    assert_ne!(&ErrorKind::NeedMoreData, error.kind());
+    assert_eq!(Mode::Stop, reader.read_message::<Mode>().unwrap());
+    assert_eq!(
+        &ErrorKind::NeedMoreData,
+        reader.read_message::<Mode>().unwrap_err().kind()
+    );
+}
@@ -0,0 +1,228 @@
+mod test_utils;
+
+use asn1rs::io::per::ErrorKind;
+use test_utils::*;
+
+asn_to_rust!(
+    r"UperTrace DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Position ::= SEQUENCE {
+        latitude INTEGER (-900000000..900000001),
+        longitude INTEGER (-1800000000..1800000001)
+    }
+
+    Payload ::= CHOICE {
+        empty NULL,
+        position Position,
+        ...,
+        note UTF8String
+    }
+
+    Report ::= SEQUENCE {
+        station-id INTEGER (0..255),
+        name UTF8String OPTIONAL,
+        level ENUMERATED { low, medium, high },
+        payload Payload,
+        samples SEQUENCE (SIZE(0..4)) OF INTEGER (0..7),
+        ...,
+        flag BOOLEAN
+    }
+
+    ReportV2 ::= SEQUENCE {
+        station-id INTEGER (0..255),
+        name UTF8String OPTIONAL,
+        level ENUMERATED { low, medium, high },
+        payload Payload,
+        samples SEQUENCE (SIZE(0..4)) OF INTEGER (0..7),
+        ...,
+        flag BOOLEAN,
+        comment UTF8String
+    }
+
+    END"
+);
+
+fn report() -> Report {
+    Report {
+        station_id: 42,
+        name: None,
+        level: ReportLevel::High,
+        payload: Payload::Position(Position {
+            latitude: 1,
+            longitude: -1,
+        }),
+        samples: vec![1, 7],
+        flag: Some(true),
+    }
+}
+
+fn traced(bytes: &[u8], bits: usize) -> (Result<Report, ErrorKind>, Trace) {
+    let mut reader = UperReader::from((bytes, bits)).with_trace();
+    let result = reader.read::<Report>().map_err(|e| e.kind().clone());
+    (result, reader.take_trace().unwrap())
+}
+
+#[test]
+fn test_components_are_named_by_path() {
+    let (bits, bytes) = serialize_uper(&report());
+    let (result, trace) = traced(&bytes, bits);
+    assert_eq!(Ok(report()), result);
+    assert_eq!(
+        vec![
+            "Report",
+            "Report.station-id",
+            "Report.name",
+            "Report.level",
+            "Report.payload",
+            "Report.payload.position",
+            "Report.payload.position.latitude",
+            "Report.payload.position.longitude",
+            "Report.samples",
+            "Report.samples[0]",
+            "Report.samples[1]",
+            "Report.flag",
+        ],
+        trace
+            .entries()
+            .iter()
+            .map(|e| e.path.as_str())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_offsets_and_raw_bits() {
+    let (bits, bytes) = serialize_uper(&report());
+    let (_, trace) = traced(&bytes, bits);
+
+    let root = &trace.entries()[0];
+    assert_eq!((0, bits), (root.offset, root.len));
+    assert_eq!(&bytes[..], &root.bits[..]);
+
+    // behind the extension and the presence bit
+    let station_id = trace.entry("Report.station-id").unwrap();
+    assert_eq!((2, 8), (station_id.offset, station_id.len));
+    assert_eq!(vec![42], station_id.bits);
+
+    // the open type wrapping of the extension addition is part of the entry
+    let flag = trace.entry("Report.flag").unwrap();
+    assert_eq!((94, 16), (flag.offset, flag.len));
+    assert_eq!(vec![0x01, 0x80], flag.bits);
+    assert_eq!("BOOLEAN", flag.kind);
+}
+
+#[test]
+fn test_decoded_values() {
+    let (bits, bytes) = serialize_uper(&report());
+    let (_, trace) = traced(&bytes, bits);
+    let value = |path: &str| trace.entry(path).unwrap().value.as_deref();
+
+    assert_eq!(Some("42"), value("Report.station-id"));
+    assert_eq!(Some("absent"), value("Report.name"));
+    assert_eq!(Some("high (2)"), value("Report.level"));
+    assert_eq!(Some("position (1)"), value("Report.payload"));
+    assert_eq!(None, value("Report.payload.position"));
+    assert_eq!(Some("-1"), value("Report.payload.position.longitude"));
+    assert_eq!(Some("2 elements"), value("Report.samples"));
+    assert_eq!(Some("true"), value("Report.flag"));
+}
+
+#[test]
+fn test_display_like_a_dissector_tree() {
+    let mut value = report();
+    value.name = Some("A1".to_string());
+    value.payload = Payload::Note("hi".to_string());
+    value.samples.clear();
+    value.flag = None;
+    let (bits, bytes) = serialize_uper(&value);
+    let (_, trace) = traced(&bytes, bits);
+
+    assert_eq!(
+        r#"     0    +79  Report: SEQUENCE
+     2     +8    station-id: INTEGER = 42  [00101010]
+    10    +24    name: UTF8String = "A1"  [00000010 01000001 00110001]
+    34     +2    level: ENUMERATED ReportLevel = high (2)  [10]
+    36    +40    payload: CHOICE Payload = note (2)
+    52    +24      note: UTF8String = "hi"  [00000010 01101000 01101001]
+    76     +3    samples: SEQUENCE OF = 0 elements  [000]
+    79     +0    flag: OPTIONAL = absent
+"#,
+        trace.to_string()
+    );
+}
+
+#[test]
+fn test_unknown_extension_additions() {
+    let value = ReportV2 {
+        station_id: 1,
+        name: None,
+        level: ReportV2Level::Low,
+        payload: Payload::Empty(Null),
+        samples: Vec::new(),
+        flag: Some(false),
+        comment: Some("newer".to_string()),
+    };
+    let (bits, bytes) = serialize_uper(&value);
+    let (result, trace) = traced(&bytes, bits);
+    assert!(result.is_ok());
+
+    let additions = trace.entries().last().unwrap();
+    assert_eq!("Report....", additions.path);
+    assert_eq!(Some("1 of 1 present"), additions.value.as_deref());
+    assert_eq!(bits - additions.offset, additions.len);
+}
+
+#[test]
+fn test_failed_decoding_points_at_the_component() {
+    let (_, bytes) = serialize_uper(&report());
+    let (result, trace) = traced(&bytes[..6], 48);
+    assert!(matches!(
+        result,
+        Err(ErrorKind::InsufficientDataInSourceBuffer(_))
+    ));
+
+    let failed = trace
+        .entries()
+        .iter()
+        .filter(|e| e.error.is_some())
+        .map(|e| e.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["Report.payload.position.longitude"], failed);
+
+    let longitude = trace.entries().last().unwrap();
+    assert_eq!(None, longitude.value);
+    assert_eq!((45, 0), (longitude.offset, longitude.len));
+    assert!(trace.to_string().ends_with(
+        "    45     +0        longitude: INTEGER !! \
+         There is insufficient data in the source buffer for this operation\n"
+    ));
+}
+
+#[test]
+fn test_message_waiting_for_more_data_is_traced_once() {
+    let (_, bytes) = serialize_uper(&report());
+    let mut reader = UperReader::from(StreamBits::chunked()).with_trace();
+
+    let mut result = Err(ErrorKind::NeedMoreData);
+    for chunk in bytes.chunks(3) {
+        reader.push(chunk);
+        result = reader
+            .read_message::<Report>()
+            .map_err(|e| e.kind().clone());
+    }
+    assert_eq!(Ok(report()), result);
+
+    let trace = reader.trace().unwrap();
+    assert_eq!(12, trace.entries().len());
+    assert_eq!(1, trace.entries().iter().filter(|e| e.depth == 0).count());
+}
+
+#[test]
+fn test_tracing_is_disabled_by_default() {
+    let (bits, bytes) = serialize_uper(&report());
+    let mut reader = UperReader::from((&bytes[..], bits));
+    assert_eq!(Ok(report()), reader.read::<Report>());
+    assert!(reader.trace().is_none());
+    assert!(reader.take_trace().is_none());
+}
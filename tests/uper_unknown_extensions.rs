@@ -0,0 +1,204 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"UperUnknownExtensions DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    OldSequence ::= SEQUENCE {
+        id INTEGER (0..255),
+        ...,
+        flag BOOLEAN
+    }
+
+    NewSequence ::= SEQUENCE {
+        id INTEGER (0..255),
+        ...,
+        flag BOOLEAN,
+        text UTF8String,
+        level INTEGER (0..7)
+    }
+
+    OldChoice ::= CHOICE {
+        number INTEGER (0..255),
+        ...
+    }
+
+    NewChoice ::= CHOICE {
+        number INTEGER (0..255),
+        ...,
+        flag BOOLEAN,
+        text UTF8String
+    }
+
+    END"
+);
+
+/// ```asn
+/// RelayedSequence ::= SEQUENCE {
+///     id INTEGER (0..255),
+///     ...,
+///     flag BOOLEAN
+/// }
+/// ```
+#[asn(sequence, extensible_after(id))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RelayedSequence {
+    #[asn(integer(0..255))]
+    id: u8,
+    #[asn(optional(boolean))]
+    flag: Option<bool>,
+    unknown_extensions: UnknownExtensions,
+}
+
+/// ```asn
+/// RelayedChoice ::= CHOICE {
+///     number INTEGER (0..255),
+///     ...
+/// }
+/// ```
+#[asn(choice, extensible_after(Number))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelayedChoice {
+    #[asn(integer(0..255))]
+    Number(u8),
+    UnknownExtension(u64, OpenType),
+}
+
+fn relay_uper<Old: Readable + Writable, New: Writable>(value: &New) -> Old {
+    let (bits, bytes) = serialize_uper(value);
+    let old = deserialize_uper::<Old>(&bytes, bits);
+    assert_eq!((bits, bytes), serialize_uper(&old));
+    old
+}
+
+fn relay_aper<Old: Readable + Writable, New: Writable>(value: &New) -> Old {
+    let (bits, bytes) = serialize_aper(value);
+    let old = deserialize_aper::<Old>(&bytes, bits);
+    assert_eq!((bits, bytes), serialize_aper(&old));
+    old
+}
+
+#[test]
+fn test_unknown_sequence_extensions_are_written_back() {
+    let old: RelayedSequence = relay_uper(&NewSequence {
+        id: 5,
+        flag: Some(true),
+        text: Some("hi".to_string()),
+        level: None,
+    });
+    assert_eq!(5, old.id);
+    assert_eq!(Some(true), old.flag);
+    assert_eq!(
+        &[Some(OpenType::new(vec![0x02, b'h', b'i'])), None][..],
+        old.unknown_extensions.additions()
+    );
+}
+
+#[test]
+fn test_unknown_sequence_extensions_without_known_ones_are_written_back() {
+    let old = RelayedSequence {
+        id: 5,
+        flag: None,
+        unknown_extensions: UnknownExtensions::new(vec![None, Some(OpenType::new(vec![0x60]))]),
+    };
+    let (bits, bytes) = serialize_uper(&old);
+    assert_eq!(old, deserialize_uper::<RelayedSequence>(&bytes, bits));
+    assert_eq!(
+        NewSequence {
+            id: 5,
+            flag: None,
+            text: None,
+            level: Some(3),
+        },
+        deserialize_uper::<NewSequence>(&bytes, bits)
+    );
+}
+
+#[test]
+fn test_sequence_without_extensions_has_no_unknown_ones() {
+    let old: RelayedSequence = relay_uper(&NewSequence {
+        id: 5,
+        flag: None,
+        text: None,
+        level: None,
+    });
+    assert!(old.unknown_extensions.is_empty());
+}
+
+#[test]
+fn test_sequence_of_older_peer() {
+    let (bits, bytes) = serialize_uper(&OldSequence {
+        id: 5,
+        flag: Some(false),
+    });
+    assert_eq!(
+        NewSequence {
+            id: 5,
+            flag: Some(false),
+            text: None,
+            level: None,
+        },
+        deserialize_uper::<NewSequence>(&bytes, bits)
+    );
+}
+
+#[test]
+fn test_unknown_sequence_extensions_are_skipped_without_the_field() {
+    let new = NewSequence {
+        id: 5,
+        flag: Some(true),
+        text: Some("hi".to_string()),
+        level: Some(3),
+    };
+    let old = OldSequence {
+        id: 5,
+        flag: Some(true),
+    };
+    let (bits, bytes) = serialize_uper(&new);
+    assert_eq!(old, deserialize_uper::<OldSequence>(&bytes, bits));
+    let (bits, bytes) = serialize_aper(&new);
+    assert_eq!(old, deserialize_aper::<OldSequence>(&bytes, bits));
+}
+
+#[test]
+fn test_unknown_choice_alternative_is_rejected_without_the_variant() {
+    let (bits, bytes) = serialize_uper(&NewChoice::Text("hi".to_string()));
+    let mut reader = UperReader::from((&bytes[..], bits));
+    assert!(reader.read::<OldChoice>().is_err());
+}
+
+#[test]
+fn test_unknown_choice_alternative_is_written_back() {
+    let old: RelayedChoice = relay_uper(&NewChoice::Text("hi".to_string()));
+    assert_eq!(
+        RelayedChoice::UnknownExtension(2, OpenType::new(vec![0x02, b'h', b'i'])),
+        old
+    );
+}
+
+#[test]
+fn test_unknown_extensions_are_written_back_aligned() {
+    let old: RelayedSequence = relay_aper(&NewSequence {
+        id: 5,
+        flag: Some(true),
+        text: Some("hi".to_string()),
+        level: Some(3),
+    });
+    assert_eq!(2, old.unknown_extensions.additions().len());
+
+    let old: RelayedChoice = relay_aper(&NewChoice::Flag(true));
+    assert!(matches!(old, RelayedChoice::UnknownExtension(1, _)));
+}
+
+#[test]
+fn test_unknown_choice_alternative_cannot_be_written_as_ber() {
+    let mut writer = BerWriter::default();
+    assert!(writer
+        .write(&RelayedChoice::UnknownExtension(
+            1,
+            OpenType::new(vec![0x80])
+        ))
+        .is_err());
+}
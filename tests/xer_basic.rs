@@ -0,0 +1,215 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"XerBasic DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Simple ::= SEQUENCE {
+        small INTEGER (0..7),
+        someFlag BOOLEAN,
+        text IA5String,
+        data OCTET STRING,
+        opt INTEGER OPTIONAL
+    }
+
+    Decision ::= CHOICE {
+        abc BOOLEAN,
+        def-ghi INTEGER (0..1000),
+        ...,
+        nothing NULL
+    }
+
+    Color ::= ENUMERATED {
+        red,
+        dark-green,
+        blue
+    }
+
+    Wrapper ::= SEQUENCE {
+        decision Decision,
+        colors SEQUENCE OF Color,
+        numbers SEQUENCE OF INTEGER,
+        decisions SEQUENCE OF Decision
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER (0..255),
+        ...,
+        def BOOLEAN OPTIONAL
+    }
+
+    Flags ::= BIT STRING
+
+    Reference ::= Simple
+
+    WithDefault ::= SEQUENCE {
+        value INTEGER DEFAULT 5
+    }
+
+    END"
+);
+
+#[test]
+fn test_simple_sequence() {
+    serialize_and_deserialize_xer(
+        "<Simple><small>5</small><someFlag><true/></someFlag><text>A&lt;B</text><data>DEAD</data><opt>255</opt></Simple>",
+        &Simple {
+            small: 5,
+            some_flag: true,
+            text: "A<B".to_string(),
+            data: vec![0xDE, 0xAD],
+            opt: Some(255),
+        },
+    );
+    serialize_and_deserialize_xer(
+        "<Simple><small>5</small><someFlag><false/></someFlag><text/><data/></Simple>",
+        &Simple {
+            small: 5,
+            some_flag: false,
+            text: String::default(),
+            data: Vec::default(),
+            opt: None,
+        },
+    );
+}
+
+#[test]
+fn test_elements_in_any_order_and_whitespace() {
+    assert_eq!(
+        Simple {
+            small: 1,
+            some_flag: true,
+            text: " x ".to_string(),
+            data: vec![0xAB, 0xCD],
+            opt: None,
+        },
+        deserialize_xer::<Simple>(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Simple>\n  <data>ab cd</data>\n  <text> x </text>\n  <someFlag>\n    <true/>\n  </someFlag>\n  <small> 1 </small>\n</Simple>\n"
+        )
+    );
+}
+
+#[test]
+fn test_choice_and_enumerated_use_identifiers() {
+    serialize_and_deserialize_xer(
+        "<Decision><def-ghi>500</def-ghi></Decision>",
+        &Decision::DefGhi(500),
+    );
+    serialize_and_deserialize_xer("<Decision><nothing/></Decision>", &Decision::Nothing(Null));
+    serialize_and_deserialize_xer("<Color><dark-green/></Color>", &Color::DarkGreen);
+}
+
+#[test]
+fn test_sequence_of_elements() {
+    serialize_and_deserialize_xer(
+        concat!(
+            "<Wrapper>",
+            "<decision><abc><false/></abc></decision>",
+            "<colors><red/><blue/></colors>",
+            "<numbers><INTEGER>1</INTEGER><INTEGER>2</INTEGER></numbers>",
+            "<decisions><Decision><def-ghi>3</def-ghi></Decision></decisions>",
+            "</Wrapper>"
+        ),
+        &Wrapper {
+            decision: Decision::Abc(false),
+            colors: vec![Color::Red, Color::Blue],
+            numbers: vec![1, 2],
+            decisions: vec![Decision::DefGhi(3)],
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_is_ignored() {
+    serialize_and_deserialize_xer(
+        "<Extensible><abc>1</abc><def><true/></def></Extensible>",
+        &Extensible {
+            abc: 1,
+            def: Some(true),
+        },
+    );
+    assert_eq!(
+        Extensible { abc: 1, def: None },
+        deserialize_xer::<Extensible>("<Extensible><abc>1</abc><xyz><a/></xyz></Extensible>")
+    );
+}
+
+#[test]
+fn test_unknown_element_in_root_is_rejected() {
+    let mut reader = XerReader::from("<WithDefault><value>1</value><other/></WithDefault>");
+    assert_eq!(
+        &asn1rs::io::xer::ErrorKind::UnexpectedElements("WithDefault"),
+        reader.read::<WithDefault>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_missing_element_is_rejected() {
+    let mut reader = XerReader::from("<Extensible><def><true/></def></Extensible>");
+    assert_eq!(
+        &asn1rs::io::xer::ErrorKind::MissingElement("abc"),
+        reader.read::<Extensible>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_unknown_identifier_is_rejected() {
+    let mut reader = XerReader::from("<Color><green/></Color>");
+    assert_eq!(
+        &asn1rs::io::xer::ErrorKind::UnknownIdentifier("green".to_string()),
+        reader.read::<Color>().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_bit_string() {
+    serialize_and_deserialize_xer(
+        "<Flags>101</Flags>",
+        &Flags(BitVec::from_bytes(vec![0b1010_0000], 3)),
+    );
+    serialize_and_deserialize_xer("<Flags/>", &Flags(BitVec::default()));
+}
+
+#[test]
+fn test_type_reference_is_named_after_itself() {
+    serialize_and_deserialize_xer(
+        "<Reference><small>0</small><someFlag><true/></someFlag><text/><data>00</data></Reference>",
+        &Reference(Simple {
+            small: 0,
+            some_flag: true,
+            text: String::default(),
+            data: vec![0x00],
+            opt: None,
+        }),
+    );
+}
+
+#[test]
+fn test_default_value_is_omitted() {
+    serialize_and_deserialize_xer("<WithDefault/>", &WithDefault { value: 5 });
+    serialize_and_deserialize_xer(
+        "<WithDefault><value>6</value></WithDefault>",
+        &WithDefault { value: 6 },
+    );
+}
+
+#[test]
+fn test_multiple_values() {
+    let mut writer = XerWriter::default();
+    writer.write(&Color::Red).unwrap();
+    writer.write(&WithDefault { value: 7 }).unwrap();
+    assert_eq!(
+        "<Color><red/></Color>\n<WithDefault><value>7</value></WithDefault>",
+        writer.as_str()
+    );
+
+    let mut reader = writer.as_reader();
+    assert_eq!(Color::Red, reader.read::<Color>().unwrap());
+    assert_eq!(
+        WithDefault { value: 7 },
+        reader.read::<WithDefault>().unwrap()
+    );
+    assert_eq!(0, reader.bytes_remaining());
+}